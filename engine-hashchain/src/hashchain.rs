@@ -104,6 +104,33 @@ impl Hashchain {
         self.block_hashchain_computer.is_empty()
     }
 
+    /// Recomputes the hashchain of the current block from the given transactions and compares it
+    /// with the hashchain computed from the transactions added to the structure.
+    /// The result contains both values, so the mismatches can be reported.
+    pub fn verify_block_txs<'a, I>(&self, txs: I) -> BlockHashchainVerification
+    where
+        I: IntoIterator<Item = BlockTx<'a>>,
+    {
+        let mut computer = BlockHashchainComputer::new();
+        for tx in txs {
+            computer.add_tx(tx.method_name, tx.input, tx.output, tx.log_bloom);
+        }
+
+        let compute = |computer: &BlockHashchainComputer| {
+            computer.compute_block_hashchain(
+                &self.chain_id,
+                self.contract_account_id.as_bytes(),
+                self.current_block_height,
+                self.previous_block_hashchain,
+            )
+        };
+
+        BlockHashchainVerification {
+            computed: compute(&computer),
+            stored: compute(&self.block_hashchain_computer),
+        }
+    }
+
     pub fn try_serialize(&self) -> Result<Vec<u8>, io::Error> {
         let serializable: BorshableHashchain = self.into();
         borsh::to_vec(&serializable)
@@ -115,6 +142,31 @@ impl Hashchain {
     }
 }
 
+/// Transaction of a block as it is accounted in the block hashchain.
+#[derive(Debug, Clone, Copy)]
+pub struct BlockTx<'a> {
+    pub method_name: &'a str,
+    pub input: &'a [u8],
+    pub output: &'a [u8],
+    pub log_bloom: &'a Bloom,
+}
+
+/// Result of the verification of the block hashchain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockHashchainVerification {
+    /// Block hashchain recomputed from the given transactions.
+    pub computed: RawH256,
+    /// Block hashchain of the transactions recorded in the hashchain state.
+    pub stored: RawH256,
+}
+
+impl BlockHashchainVerification {
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.computed == self.stored
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct HashchainBuilder {
     chain_id: [u8; 32],
@@ -1,6 +1,6 @@
 use crate::{
     bloom::Bloom,
-    hashchain::{Hashchain, HashchainBuilder},
+    hashchain::{BlockTx, Hashchain, HashchainBuilder},
 };
 use aurora_engine_types::account_id::AccountId;
 
@@ -175,6 +175,62 @@ fn test_serialization_round_trip() {
     assert_eq!(round_trip, hashchain);
 }
 
+#[test]
+fn test_verify_block_txs() {
+    let bloom = {
+        let mut bloom = Bloom::default();
+        bloom.accrue(&[0xde, 0xad, 0xbe, 0xef]);
+        bloom
+    };
+    let txs = [
+        BlockTx {
+            method_name: "foo",
+            input: b"foo_input",
+            output: b"foo_output",
+            log_bloom: &bloom,
+        },
+        BlockTx {
+            method_name: "bar",
+            input: b"bar_input",
+            output: &[],
+            log_bloom: &Bloom::default(),
+        },
+    ];
+
+    let mut hashchain = HashchainBuilder::default()
+        .with_account_id("aurora".parse().unwrap())
+        .with_u64_chain_id(1_313_161_554)
+        .with_current_block_height(5)
+        .with_previous_hashchain([3; 32])
+        .build();
+    for tx in txs {
+        hashchain
+            .add_block_tx(5, tx.method_name, tx.input, tx.output, tx.log_bloom)
+            .unwrap();
+    }
+
+    let verification = hashchain.verify_block_txs(txs);
+    assert!(verification.is_valid());
+
+    // The computed value is the hashchain of the block.
+    let mut next_block = hashchain.clone();
+    next_block.move_to_block(6).unwrap();
+    assert_eq!(
+        verification.computed,
+        next_block.get_previous_block_hashchain()
+    );
+
+    // Missing or reordered transactions are detected.
+    let verification = hashchain.verify_block_txs(txs.into_iter().take(1));
+    assert!(!verification.is_valid());
+    assert_eq!(
+        verification.stored,
+        next_block.get_previous_block_hashchain()
+    );
+    let verification = hashchain.verify_block_txs(txs.into_iter().rev());
+    assert!(!verification.is_valid());
+}
+
 fn len_be_bytes(arr: &[u8]) -> [u8; 4] {
     let len = arr.len();
     u32::try_from(len).unwrap().to_be_bytes()
@@ -25,6 +25,15 @@ impl ModExpAlgorithm for AuroraModExp {
     }
 }
 
+/// Implementation based on the `num` crate, see `modexp_num`.
+pub struct NumModExp;
+
+impl ModExpAlgorithm for NumModExp {
+    fn modexp(base: &[u8], exp: &[u8], modulus: &[u8]) -> Vec<u8> {
+        modexp_num(base, exp, modulus)
+    }
+}
+
 /// Computes `(base ^ exp) % modulus`, where all values are given as big-endian
 /// encoded bytes.
 pub fn modexp(base: &[u8], exp: &[u8], modulus: &[u8]) -> Vec<u8> {
@@ -52,7 +61,8 @@ pub fn modexp_ibig(base: &[u8], exp: &[u8], modulus: &[u8]) -> Vec<u8> {
     result.residue().to_be_bytes()
 }
 
-#[cfg(feature = "bench")]
+/// Computes `(base ^ exp) % modulus` using the `num` crate. Unlike `modexp_ibig` it is always
+/// available, so it can be selected at runtime as an alternative to `modexp`.
 pub fn modexp_num(base: &[u8], exp: &[u8], modulus: &[u8]) -> Vec<u8> {
     use num::Zero;
 
@@ -897,4 +897,67 @@ mod tests {
             .output;
         assert_eq!(res, expected);
     }
+
+    #[test]
+    fn test_alt_bn128_istanbul_gas() {
+        // EIP-1108 prices.
+        assert_eq!(
+            Bn256Add::<Istanbul>::required_gas(&[]).unwrap(),
+            EthGas::new(150)
+        );
+        assert_eq!(
+            Bn256Mul::<Istanbul>::required_gas(&[]).unwrap(),
+            EthGas::new(6_000)
+        );
+        // Base cost of 45_000 plus 34_000 per pair.
+        for (pairs, gas) in [(0, 45_000), (1, 79_000), (2, 113_000), (3, 147_000)] {
+            let input = crate::vec![0u8; pairs * consts::PAIR_ELEMENT_LEN];
+            assert_eq!(
+                Bn256Pair::<Istanbul>::required_gas(&input).unwrap(),
+                EthGas::new(gas)
+            );
+        }
+
+        // The cost reported by `run` must match as well.
+        let context = new_context();
+        let cost = Bn256Add::<Istanbul>::new()
+            .run(&[], Some(EthGas::new(150)), &context, false)
+            .unwrap()
+            .cost;
+        assert_eq!(cost, EthGas::new(150));
+        let cost = Bn256Mul::<Istanbul>::new()
+            .run(&[], Some(EthGas::new(6_000)), &context, false)
+            .unwrap()
+            .cost;
+        assert_eq!(cost, EthGas::new(6_000));
+        let input = [0u8; consts::PAIR_ELEMENT_LEN];
+        let cost = Bn256Pair::<Istanbul>::new()
+            .run(&input, Some(EthGas::new(79_000)), &context, false)
+            .unwrap()
+            .cost;
+        assert_eq!(cost, EthGas::new(79_000));
+        let res =
+            Bn256Pair::<Istanbul>::new().run(&input, Some(EthGas::new(78_999)), &context, false);
+        assert!(matches!(res, Err(ExitError::OutOfGas)));
+    }
+
+    #[test]
+    fn test_alt_bn128_byzantium_gas() {
+        assert_eq!(
+            Bn256Add::<Byzantium>::required_gas(&[]).unwrap(),
+            EthGas::new(500)
+        );
+        assert_eq!(
+            Bn256Mul::<Byzantium>::required_gas(&[]).unwrap(),
+            EthGas::new(40_000)
+        );
+        // Base cost of 100_000 plus 80_000 per pair.
+        for (pairs, gas) in [(0, 100_000), (1, 180_000), (2, 260_000), (3, 340_000)] {
+            let input = crate::vec![0u8; pairs * consts::PAIR_ELEMENT_LEN];
+            assert_eq!(
+                Bn256Pair::<Byzantium>::required_gas(&input).unwrap(),
+                EthGas::new(gas)
+            );
+        }
+    }
 }
@@ -19,6 +19,7 @@ mod prelude;
 pub mod prepaid_gas;
 pub mod promise_result;
 pub mod random;
+pub mod requests;
 pub mod secp256k1;
 mod utils;
 pub mod xcc;
@@ -34,6 +35,7 @@ use crate::prelude::types::EthGas;
 use crate::prelude::{Vec, H256};
 use crate::prepaid_gas::PrepaidGas;
 use crate::random::RandomSeed;
+use crate::requests::{consolidation_request, withdrawal_request, RequestNoop};
 use crate::secp256k1::ECRecover;
 use crate::xcc::CrossContractCall;
 use aurora_engine_modexp::ModExpAlgorithm;
@@ -47,7 +49,7 @@ use evm::executor::{
     stack::{PrecompileFailure, PrecompileHandle},
 };
 use evm::{Context, ExitError, ExitFatal, ExitSucceed};
-use promise_result::PromiseResult;
+use promise_result::{PromiseResult, PromiseResultsCount};
 use xcc::cross_contract_call;
 
 #[derive(Debug, Default, PartialEq, Eq)]
@@ -117,15 +119,49 @@ impl HardFork for Istanbul {}
 
 impl HardFork for Berlin {}
 
+/// Maximum length of the input of a call to the Identity, `ModExp` and Aurora-specific
+/// precompiles. The calls with a longer input fail before the precompile is run, so that the
+/// precompile doesn't process (e.g. copy) an arbitrarily large input before its gas cost is
+/// charged. The other standard precompiles are not limited. The limit is well above the input
+/// of any practical call.
+pub const MAX_INPUT_LEN: usize = 1024 * 1024;
+
+/// Returns whether the address belongs to the range reserved for the standard precompiles,
+/// `0x01..=0xff`, no matter if a precompile is implemented at the address.
+#[must_use]
+pub fn is_reserved_address(address: &Address) -> bool {
+    let bytes = address.as_bytes();
+    bytes[..19].iter().all(|b| *b == 0) && bytes[19] != 0
+}
+
 pub struct Precompiles<'a, I, E, H> {
     pub all_precompiles: BTreeMap<Address, AllPrecompiles<'a, I, E, H>>,
     pub paused_precompiles: BTreeSet<Address>,
+    /// Whether the calls to the reserved addresses without a precompile fail with
+    /// `ERR_UNKNOWN_PRECOMPILE` instead of executing as calls to an empty account.
+    pub reject_unknown: bool,
 }
 
 impl<'a, I, E, H> Precompiles<'a, I, E, H> {
     fn is_paused(&self, address: &Address) -> bool {
         self.paused_precompiles.contains(address)
     }
+
+    fn is_unknown_rejected(&self, address: &Address) -> bool {
+        self.reject_unknown
+            && is_reserved_address(address)
+            && !self.all_precompiles.contains_key(address)
+    }
+
+    /// Out of the standard precompiles, the limit only applies to Identity and `ModExp`, whose
+    /// cost grows with an input they copy or parse before being charged.
+    fn is_input_too_long(&self, address: &Address, input: &[u8]) -> bool {
+        let is_limited = !is_reserved_address(address)
+            || *address == Identity::ADDRESS
+            || *address == ModExp::<Byzantium>::ADDRESS;
+
+        input.len() > MAX_INPUT_LEN && is_limited && self.all_precompiles.contains_key(address)
+    }
 }
 
 impl<'a, I: IO + Copy, E: Env, H: ReadOnlyPromiseHandler> executor::stack::PrecompileSet
@@ -143,6 +179,18 @@ impl<'a, I: IO + Copy, E: Env, H: ReadOnlyPromiseHandler> executor::stack::Preco
             }));
         }
 
+        if self.is_unknown_rejected(&address) {
+            return Some(Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other(prelude::Cow::Borrowed("ERR_UNKNOWN_PRECOMPILE")),
+            }));
+        }
+
+        if self.is_input_too_long(&address, handle.input()) {
+            return Some(Err(PrecompileFailure::Error {
+                exit_status: ExitError::Other(prelude::Cow::Borrowed("ERR_INPUT_TOO_LONG")),
+            }));
+        }
+
         let result = match self.all_precompiles.get(&address)? {
             AllPrecompiles::ExitToNear(p) => process_precompile(p, handle),
             AllPrecompiles::ExitToEthereum(p) => process_precompile(p, handle),
@@ -351,6 +399,60 @@ impl<'a, I: IO + Copy, E: Env, H: ReadOnlyPromiseHandler> Precompiles<'a, I, E,
         Self::new_berlin(ctx)
     }
 
+    /// Adds the EIP-7685 request predeploys as no-op precompiles (see the `requests` module).
+    pub fn new_prague<M: ModExpAlgorithm + 'static>(
+        ctx: PrecompileConstructorContext<'a, I, E, H, M>,
+    ) -> Self {
+        let mut precompiles = Self::new_london(ctx);
+        precompiles.all_precompiles.insert(
+            withdrawal_request::ADDRESS,
+            AllPrecompiles::Generic(Box::new(RequestNoop::withdrawal())),
+        );
+        precompiles.all_precompiles.insert(
+            consolidation_request::ADDRESS,
+            AllPrecompiles::Generic(Box::new(RequestNoop::consolidation())),
+        );
+        precompiles
+    }
+
+    /// Returns the precompiles of the EVM configuration of the given hard fork. The forks after
+    /// London and before Prague don't add any precompile, so they use the London set.
+    pub fn new_for_fork<M: ModExpAlgorithm + 'static>(
+        fork: &str,
+        ctx: PrecompileConstructorContext<'a, I, E, H, M>,
+    ) -> Self {
+        match fork {
+            "prague" => Self::new_prague(ctx),
+            _ => Self::new_london(ctx),
+        }
+    }
+
+    /// Returns the hard fork which introduced each precompile of the London set (the one used by
+    /// the engine). The forks are derived from the sets built by the constructors above, so the
+    /// Aurora-specific precompiles are reported as introduced in Homestead, the earliest set.
+    pub fn precompile_forks<M, F>(make_ctx: F) -> BTreeMap<Address, &'static str>
+    where
+        M: ModExpAlgorithm + 'static,
+        F: Fn() -> PrecompileConstructorContext<'a, I, E, H, M>,
+    {
+        let forks = [
+            ("homestead", Self::new_homestead(make_ctx())),
+            ("byzantium", Self::new_byzantium(make_ctx())),
+            ("istanbul", Self::new_istanbul(make_ctx())),
+            ("berlin", Self::new_berlin(make_ctx())),
+            ("london", Self::new_london(make_ctx())),
+        ];
+        let mut precompile_forks = BTreeMap::new();
+
+        for (fork, precompiles) in forks {
+            for address in precompiles.all_precompiles.into_keys() {
+                precompile_forks.entry(address).or_insert(fork);
+            }
+        }
+
+        precompile_forks
+    }
+
     fn with_generic_precompiles<M: ModExpAlgorithm + 'static>(
         mut generic_precompiles: BTreeMap<Address, AllPrecompiles<'a, I, E, H>>,
         ctx: PrecompileConstructorContext<'a, I, E, H, M>,
@@ -363,6 +465,7 @@ impl<'a, I: IO + Copy, E: Env, H: ReadOnlyPromiseHandler> Precompiles<'a, I, E,
         let cross_contract_call = CrossContractCall::new(ctx.current_account_id, ctx.io);
         let predecessor_account_id = PredecessorAccount::new(ctx.env);
         let prepaid_gas = PrepaidGas::new(ctx.env);
+        let promise_results_count = PromiseResultsCount::new(&ctx.promise_handler);
         let promise_results = PromiseResult::new(ctx.promise_handler);
 
         generic_precompiles.insert(exit_to_near::ADDRESS, AllPrecompiles::ExitToNear(near_exit));
@@ -386,10 +489,15 @@ impl<'a, I: IO + Copy, E: Env, H: ReadOnlyPromiseHandler> Precompiles<'a, I, E,
             promise_result::ADDRESS,
             AllPrecompiles::PromiseResult(promise_results),
         );
+        generic_precompiles.insert(
+            promise_result::COUNT_ADDRESS,
+            AllPrecompiles::Generic(Box::new(promise_results_count)),
+        );
 
         Self {
             all_precompiles: generic_precompiles,
             paused_precompiles: BTreeSet::new(),
+            reject_unknown: false,
         }
     }
 }
@@ -586,6 +694,7 @@ mod tests {
                 set.insert(precompile_address);
                 set
             },
+            reject_unknown: false,
         };
         let mut precompile_handle = MockPrecompileHandle::new(precompile_address.raw());
 
@@ -600,6 +709,143 @@ mod tests {
         assert_eq!(expected_failure, actual_failure);
     }
 
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn test_too_long_input_is_rejected() {
+        use crate::identity::Identity;
+        use crate::{AllPrecompiles, Context, ExitError, Precompiles};
+        use aurora_engine_sdk::env::Fixed;
+        use aurora_engine_sdk::promise::Noop;
+        use aurora_engine_test_doubles::io::StoragePointer;
+        use evm::executor::stack::{PrecompileFailure, PrecompileHandle, PrecompileSet};
+        use evm::{ExitReason, Transfer};
+
+        struct MockPrecompileHandle {
+            code_address: H160,
+            input: Vec<u8>,
+            context: Context,
+        }
+
+        impl MockPrecompileHandle {
+            pub const fn new(code_address: H160, input: Vec<u8>) -> Self {
+                Self {
+                    code_address,
+                    input,
+                    context: Context {
+                        address: code_address,
+                        caller: H160::zero(),
+                        apparent_value: prelude::U256::zero(),
+                    },
+                }
+            }
+        }
+
+        impl PrecompileHandle for MockPrecompileHandle {
+            fn call(
+                &mut self,
+                _to: H160,
+                _transfer: Option<Transfer>,
+                _input: Vec<u8>,
+                _gas_limit: Option<u64>,
+                _is_static: bool,
+                _context: &Context,
+            ) -> (ExitReason, Vec<u8>) {
+                unimplemented!()
+            }
+
+            fn record_cost(&mut self, _cost: u64) -> Result<(), ExitError> {
+                Ok(())
+            }
+
+            fn record_external_cost(
+                &mut self,
+                _ref_time: Option<u64>,
+                _proof_size: Option<u64>,
+                _storage_growth: Option<u64>,
+            ) -> Result<(), ExitError> {
+                unimplemented!()
+            }
+
+            fn refund_external_cost(&mut self, _ref_time: Option<u64>, _proof_size: Option<u64>) {
+                unimplemented!()
+            }
+
+            fn remaining_gas(&self) -> u64 {
+                unimplemented!()
+            }
+
+            fn log(
+                &mut self,
+                _address: H160,
+                _topics: Vec<aurora_engine_types::H256>,
+                _data: Vec<u8>,
+            ) -> Result<(), ExitError> {
+                unimplemented!()
+            }
+
+            fn code_address(&self) -> H160 {
+                self.code_address
+            }
+
+            fn input(&self) -> &[u8] {
+                &self.input
+            }
+
+            fn context(&self) -> &Context {
+                &self.context
+            }
+
+            fn is_static(&self) -> bool {
+                false
+            }
+
+            fn gas_limit(&self) -> Option<u64> {
+                None
+            }
+        }
+
+        let aurora_address = super::exit_to_near::ADDRESS;
+        let sha256_address = super::hash::SHA256::ADDRESS;
+        let precompiles: Precompiles<StoragePointer, Fixed, Noop> = Precompiles {
+            all_precompiles: [aurora_address, Identity::ADDRESS, sha256_address]
+                .into_iter()
+                .map(|address| (address, AllPrecompiles::Generic(Box::new(Identity))))
+                .collect(),
+            paused_precompiles: prelude::BTreeSet::new(),
+            reject_unknown: false,
+        };
+        let execute = |address: Address, input_len: usize| {
+            let mut handle = MockPrecompileHandle::new(address.raw(), vec![0; input_len]);
+            precompiles
+                .execute(&mut handle)
+                .expect("result must contain the outcome of the call")
+        };
+        let expected_failure = PrecompileFailure::Error {
+            exit_status: ExitError::Other(prelude::Cow::Borrowed("ERR_INPUT_TOO_LONG")),
+        };
+
+        for address in [aurora_address, Identity::ADDRESS] {
+            assert!(execute(address, super::MAX_INPUT_LEN).is_ok());
+            let actual_failure = execute(address, super::MAX_INPUT_LEN + 1)
+                .expect_err("result must contain failure but is successful");
+            assert_eq!(expected_failure, actual_failure);
+        }
+        // The other standard precompiles are not limited.
+        assert!(execute(sha256_address, super::MAX_INPUT_LEN + 1).is_ok());
+    }
+
+    #[test]
+    fn test_reserved_addresses() {
+        assert!(!super::is_reserved_address(&Address::zero()));
+        assert!(super::is_reserved_address(&u8_to_address(1)));
+        assert!(super::is_reserved_address(&u8_to_address(0x0a)));
+        assert!(super::is_reserved_address(&u8_to_address(0xff)));
+        assert!(!super::is_reserved_address(&prelude::types::make_address(
+            0, 0x100
+        )));
+        assert!(!super::is_reserved_address(&super::exit_to_near::ADDRESS));
+    }
+
     const fn u8_to_address(x: u8) -> Address {
         let mut bytes = [0u8; 20];
         bytes[19] = x;
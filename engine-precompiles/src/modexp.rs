@@ -7,6 +7,9 @@ use aurora_engine_modexp::{AuroraModExp, ModExpAlgorithm};
 use evm::{Context, ExitError};
 use num::{Integer, Zero};
 
+/// Signature of the functions computing `(base ^ exp) % modulus`, see `ModExpAlgorithm::modexp`.
+pub type ModExpFn = fn(&[u8], &[u8], &[u8]) -> Vec<u8>;
+
 #[derive(Default)]
 pub struct ModExp<HF: HardFork, M = AuroraModExp>(PhantomData<HF>, PhantomData<M>);
 
@@ -43,39 +46,7 @@ impl<HF: HardFork, M: ModExpAlgorithm> ModExp<HF, M> {
     }
 
     fn run_inner(input: &[u8]) -> Vec<u8> {
-        let (base_len, exp_len, mod_len) = parse_lengths(input);
-        let base_len = usize::try_from(base_len).unwrap_or(usize::MAX);
-        let exp_len = usize::try_from(exp_len).unwrap_or(usize::MAX);
-        let mod_len = usize::try_from(mod_len).unwrap_or(usize::MAX);
-
-        let base_start = 96;
-        let base_end = base_len.saturating_add(base_start);
-
-        let exp_start = base_end;
-        let exp_end = exp_len.saturating_add(exp_start);
-
-        let mod_start = exp_end;
-
-        let modulus = parse_input_range_to_slice(input, mod_start, mod_len);
-        let computed_result = if modulus.iter().all(Zero::is_zero) {
-            Vec::new()
-        } else {
-            let base = parse_input_range_to_slice(input, base_start, base_len);
-            let exponent = parse_input_range_to_slice(input, exp_start, exp_len);
-            M::modexp(&base, &exponent, &modulus)
-        };
-
-        // The result must be the same length as the input modulus.
-        // To ensure this we pad on the left with zeros.
-        if mod_len > computed_result.len() {
-            let diff = mod_len - computed_result.len();
-            let mut padded_result = Vec::with_capacity(mod_len);
-            padded_result.extend(core::iter::repeat(0).take(diff));
-            padded_result.extend_from_slice(&computed_result);
-            padded_result
-        } else {
-            computed_result
-        }
+        compute_modexp(input, M::modexp)
     }
 }
 
@@ -176,6 +147,92 @@ impl<M: ModExpAlgorithm> Precompile for ModExp<Berlin, M> {
     }
 }
 
+/// Same as `ModExp`, but the algorithm is selected at runtime instead of by the type parameter.
+/// It costs an indirect call per execution (see the `modexp_dispatch` benchmark), so `ModExp`
+/// is still used for the default algorithm.
+pub struct DynModExp<HF: HardFork> {
+    modexp: ModExpFn,
+    hard_fork: PhantomData<HF>,
+}
+
+impl<HF: HardFork> DynModExp<HF> {
+    pub const ADDRESS: Address = make_address(0, 5);
+
+    #[must_use]
+    pub const fn new(modexp: ModExpFn) -> Self {
+        Self {
+            modexp,
+            hard_fork: PhantomData,
+        }
+    }
+}
+
+impl<HF: HardFork> Precompile for DynModExp<HF>
+where
+    ModExp<HF>: Precompile,
+{
+    fn required_gas(input: &[u8]) -> Result<EthGas, ExitError> {
+        // The cost doesn't depend on the algorithm.
+        ModExp::<HF>::required_gas(input)
+    }
+
+    fn run(
+        &self,
+        input: &[u8],
+        target_gas: Option<EthGas>,
+        _context: &Context,
+        _is_static: bool,
+    ) -> EvmPrecompileResult {
+        let cost = Self::required_gas(input)?;
+        if let Some(target_gas) = target_gas {
+            if cost > target_gas {
+                return Err(ExitError::OutOfGas);
+            }
+        }
+
+        let output = compute_modexp(input, self.modexp);
+        Ok(PrecompileOutput::without_logs(cost, output))
+    }
+}
+
+/// Parses the precompile input and computes the result with the given algorithm. Passing
+/// `M::modexp` keeps the call static, while a `ModExpFn` pointer makes it dynamic.
+fn compute_modexp<F: Fn(&[u8], &[u8], &[u8]) -> Vec<u8>>(input: &[u8], modexp: F) -> Vec<u8> {
+    let (base_len, exp_len, mod_len) = parse_lengths(input);
+    let base_len = usize::try_from(base_len).unwrap_or(usize::MAX);
+    let exp_len = usize::try_from(exp_len).unwrap_or(usize::MAX);
+    let mod_len = usize::try_from(mod_len).unwrap_or(usize::MAX);
+
+    let base_start = 96;
+    let base_end = base_len.saturating_add(base_start);
+
+    let exp_start = base_end;
+    let exp_end = exp_len.saturating_add(exp_start);
+
+    let mod_start = exp_end;
+
+    let modulus = parse_input_range_to_slice(input, mod_start, mod_len);
+    let computed_result = if modulus.iter().all(Zero::is_zero) {
+        Vec::new()
+    } else {
+        let base = parse_input_range_to_slice(input, base_start, base_len);
+        let exponent = parse_input_range_to_slice(input, exp_start, exp_len);
+        modexp(&base, &exponent, &modulus)
+    };
+
+    // The result must be the same length as the input modulus.
+    // To ensure this we pad on the left with zeros.
+    if mod_len > computed_result.len() {
+        let diff = mod_len - computed_result.len();
+        let mut padded_result = Vec::with_capacity(mod_len);
+        padded_result.extend(core::iter::repeat(0).take(diff));
+        padded_result.extend_from_slice(&computed_result);
+        padded_result
+    } else {
+        computed_result
+    }
+}
+
 fn parse_input_range_to_slice(input: &[u8], start: usize, size: usize) -> Cow<[u8]> {
     let len = input.len();
     if start >= len {
@@ -494,6 +551,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_dyn_modexp() {
+        let algorithms: [ModExpFn; 2] = [
+            aurora_engine_modexp::modexp,
+            aurora_engine_modexp::modexp_num,
+        ];
+        for modexp in algorithms {
+            for (test, test_gas) in TESTS.iter().zip(BERLIN_GAS.iter()) {
+                let input = hex::decode(test.input).unwrap();
+
+                let res = DynModExp::<Berlin>::new(modexp)
+                    .run(&input, Some(*test_gas), &new_context(), false)
+                    .unwrap();
+                let expected = hex::decode(test.expected).unwrap();
+                assert_eq!(res.output, expected, "{}", test.name);
+                assert_eq!(res.cost, *test_gas, "{} gas", test.name);
+            }
+        }
+    }
+
     #[test]
     fn test_berlin_modexp_big_input() {
         let input = generate_modexp_test_input(&ModExpTestInput {
@@ -7,9 +7,8 @@ use crate::prelude::{
     storage::{bytes_to_key, KeyPrefix},
     str,
     types::{Address, Yocto},
-    vec, Cow, ToString, Vec, U256,
+    Cow, ToString, Vec, U256,
 };
-#[cfg(feature = "error_refund")]
 use crate::prelude::{parameters::RefundCallArgs, types};
 use crate::xcc::state::get_wnear_address;
 use crate::PrecompileOutput;
@@ -28,6 +27,7 @@ use evm::backend::Log;
 use evm::{Context, ExitError};
 
 const ERR_TARGET_TOKEN_NOT_FOUND: &str = "Target token not found";
+const ERR_EXIT_AMOUNT_BELOW_FEE: &str = "ERR_EXIT_AMOUNT_BELOW_FEE";
 const UNWRAP_WNEAR_MSG: &str = "unwrap";
 
 mod costs {
@@ -201,6 +201,112 @@ pub mod events {
     }
 }
 
+pub mod exit_fee {
+    //! Functions for reading and writing the fees charged by the exit precompiles.
+
+    use aurora_engine_sdk::io::{StorageIntermediate, IO};
+    use aurora_engine_types::parameters::connector::ExitFeeConfig;
+    use aurora_engine_types::storage::{self, KeyPrefix};
+    use aurora_engine_types::types::Address;
+    use aurora_engine_types::Vec;
+
+    /// Get the exit fee of the token (the zero address stands for ETH), if any.
+    pub fn get_exit_fee<I: IO>(io: &I, token: &Address) -> Option<ExitFeeConfig> {
+        io.read_storage(&exit_fee_key(token))
+            .and_then(|value| value.to_value().ok())
+    }
+
+    /// Set the exit fee of the token, `None` removes the fee.
+    pub fn set_exit_fee<I: IO>(io: &mut I, token: &Address, config: Option<&ExitFeeConfig>) {
+        let key = exit_fee_key(token);
+
+        match config {
+            Some(config) => {
+                io.write_borsh(&key, config);
+            }
+            None => {
+                io.remove_storage(&key);
+            }
+        }
+    }
+
+    fn exit_fee_key(token: &Address) -> Vec<u8> {
+        storage::bytes_to_key(KeyPrefix::ExitFee, token.as_bytes())
+    }
+}
+
+/// Deducts the exit fee of the token from the exited `amount`. Returns the amount which
+/// continues to the exit and the log of the promise transferring the fee to the treasury.
+///
+/// The `refund` is the account of the engine and the address which the fee is credited back to
+/// by the `exit_to_near_precompile_callback` if the transfer fails, the same way as the exit
+/// itself. Without it, the fee of a failed transfer is lost as the exited amount is.
+fn charge_exit_fee<I: IO>(
+    io: &I,
+    token: Address,
+    nep141_address: &AccountId,
+    amount: U256,
+    precompile_address: Address,
+    refund: Option<(&AccountId, Address)>,
+) -> Result<(U256, Option<Log>), ExitError> {
+    let Some(config) = exit_fee::get_exit_fee(io, &token) else {
+        return Ok((amount, None));
+    };
+    validate_amount(amount)?;
+    let amount = amount.as_u128();
+    let fee = config
+        .fee
+        .compute(amount)
+        .ok_or(ExitError::Other(Cow::Borrowed(ERR_EXIT_AMOUNT_BELOW_FEE)))?;
+    if fee == 0 {
+        return Ok((amount.into(), None));
+    }
+
+    let transfer_promise = PromiseCreateArgs {
+        target_account_id: nep141_address.clone(),
+        method: "ft_transfer".to_string(),
+        // There is no way to inject json, given the encoding of both arguments
+        // as decimal and valid account id respectively.
+        args: format!(
+            r#"{{"receiver_id": "{}", "amount": "{}", "memo": null}}"#,
+            config.treasury, fee
+        )
+        .into_bytes(),
+        attached_balance: Yocto::new(1),
+        attached_gas: costs::FT_TRANSFER_GAS,
+    };
+    let promise = match refund {
+        Some((current_account_id, recipient_address)) => {
+            let callback_args = ExitToNearPrecompileCallbackCallArgs {
+                refund: Some(RefundCallArgs {
+                    recipient_address,
+                    erc20_address: (token != events::ETH_ADDRESS).then_some(token),
+                    amount: types::u256_to_arr(&fee.into()),
+                }),
+                transfer_near: None,
+            };
+            PromiseArgs::Callback(PromiseWithCallbackArgs {
+                base: transfer_promise,
+                callback: PromiseCreateArgs {
+                    target_account_id: current_account_id.clone(),
+                    method: "exit_to_near_precompile_callback".to_string(),
+                    args: borsh::to_vec(&callback_args).unwrap(),
+                    attached_balance: Yocto::new(0),
+                    attached_gas: costs::EXIT_TO_NEAR_CALLBACK_GAS,
+                },
+            })
+        }
+        None => PromiseArgs::Create(transfer_promise),
+    };
+    let fee_log = Log {
+        address: precompile_address.raw(),
+        topics: Vec::new(),
+        data: borsh::to_vec(&promise).unwrap(),
+    };
+
+    Ok(((amount - fee).into(), Some(fee_log)))
+}
+
 //TransferEthToNear
 pub struct ExitToNear<I> {
     current_account_id: AccountId,
@@ -359,8 +465,13 @@ impl<I: IO> Precompile for ExitToNear<I> {
         let eth_connector_account_id = self.current_account_id.clone();
         #[cfg(feature = "ext-connector")]
         let eth_connector_account_id = get_eth_connector_contract_account(&self.io)?;
+        // The fee is refunded if the exit is.
+        #[cfg(feature = "error_refund")]
+        let fee_refund = Some((&self.current_account_id, refund_address));
+        #[cfg(not(feature = "error_refund"))]
+        let fee_refund = None;
 
-        let (nep141_address, args, exit_event, method, transfer_near_args) = match flag {
+        let (nep141_address, args, exit_event, method, transfer_near_args, fee_log) = match flag {
             0x0 => {
                 // ETH transfer
                 //
@@ -368,6 +479,14 @@ impl<I: IO> Precompile for ExitToNear<I> {
                 // recipient_account_id (bytes) - the NEAR recipient account which will receive NEP-141 ETH tokens
 
                 if let Ok(dest_account) = AccountId::try_from(input) {
+                    let (amount, fee_log) = charge_exit_fee(
+                        &self.io,
+                        events::ETH_ADDRESS,
+                        &eth_connector_account_id,
+                        context.apparent_value,
+                        exit_to_near::ADDRESS,
+                        fee_refund,
+                    )?;
                     (
                         eth_connector_account_id,
                         // There is no way to inject json, given the encoding of both arguments
@@ -375,16 +494,17 @@ impl<I: IO> Precompile for ExitToNear<I> {
                         format!(
                             r#"{{"receiver_id": "{}", "amount": "{}", "memo": null}}"#,
                             dest_account,
-                            context.apparent_value.as_u128()
+                            amount.as_u128()
                         ),
                         events::ExitToNear {
                             sender: Address::new(context.caller),
                             erc20_address: events::ETH_ADDRESS,
                             dest: dest_account.to_string(),
-                            amount: context.apparent_value,
+                            amount,
                         },
                         "ft_transfer",
                         None,
+                        fee_log,
                     )
                 } else {
                     return Err(ExitError::Other(Cow::from(
@@ -415,6 +535,14 @@ impl<I: IO> Precompile for ExitToNear<I> {
 
                 validate_amount(amount)?;
                 let recipient = parse_recipient(input)?;
+                let (amount, fee_log) = charge_exit_fee(
+                    &self.io,
+                    Address::new(erc20_address),
+                    &nep141_address,
+                    amount,
+                    exit_to_near::ADDRESS,
+                    fee_refund,
+                )?;
 
                 let (args, method, transfer_near_args) = if recipient.message
                     == Some(UNWRAP_WNEAR_MSG)
@@ -453,6 +581,7 @@ impl<I: IO> Precompile for ExitToNear<I> {
                     },
                     method,
                     transfer_near_args,
+                    fee_log,
                 )
             }
             _ => return Err(ExitError::Other(Cow::from("ERR_INVALID_FLAG"))),
@@ -514,7 +643,11 @@ impl<I: IO> Precompile for ExitToNear<I> {
         };
 
         Ok(PrecompileOutput {
-            logs: vec![promise_log, exit_event_log],
+            // The fee is transferred before the exit.
+            logs: fee_log
+                .into_iter()
+                .chain([promise_log, exit_event_log])
+                .collect(),
             cost: Self::required_gas(input)?,
             output: Vec::new(),
         })
@@ -597,7 +730,7 @@ impl<I: IO> Precompile for ExitToEthereum<I> {
         #[cfg(feature = "ext-connector")]
         let eth_connector_account_id = get_eth_connector_contract_account(&self.io)?;
 
-        let (nep141_address, serialized_args, exit_event) = match flag {
+        let (nep141_address, serialized_args, exit_event, fee_log) = match flag {
             0x0 => {
                 // ETH transfer
                 //
@@ -610,17 +743,26 @@ impl<I: IO> Precompile for ExitToEthereum<I> {
                     WithdrawSerializeType::Json => json_args,
                     WithdrawSerializeType::Borsh => borsh_args,
                 };
+                let (amount, fee_log) = charge_exit_fee(
+                    &self.io,
+                    events::ETH_ADDRESS,
+                    &eth_connector_account_id,
+                    context.apparent_value,
+                    exit_to_ethereum::ADDRESS,
+                    None,
+                )?;
                 (
                     eth_connector_account_id,
                     // There is no way to inject json, given the encoding of both arguments
                     // as decimal and hexadecimal respectively.
-                    serialize_fn(recipient_address, context.apparent_value)?,
+                    serialize_fn(recipient_address, amount)?,
                     events::ExitToEth {
                         sender: Address::new(context.caller),
                         erc20_address: events::ETH_ADDRESS,
                         dest: recipient_address,
-                        amount: context.apparent_value,
+                        amount,
                     },
+                    fee_log,
                 )
             }
             0x1 => {
@@ -646,6 +788,14 @@ impl<I: IO> Precompile for ExitToEthereum<I> {
                 input = &input[32..];
 
                 validate_amount(amount)?;
+                let (amount, fee_log) = charge_exit_fee(
+                    &self.io,
+                    Address::new(erc20_address),
+                    &nep141_address,
+                    amount,
+                    exit_to_ethereum::ADDRESS,
+                    None,
+                )?;
 
                 if input.len() == 20 {
                     // Parse ethereum address in hex
@@ -671,6 +821,7 @@ impl<I: IO> Precompile for ExitToEthereum<I> {
                             dest: recipient_address,
                             amount,
                         },
+                        fee_log,
                     )
                 } else {
                     return Err(ExitError::Other(Cow::from("ERR_INVALID_RECIPIENT_ADDRESS")));
@@ -705,7 +856,11 @@ impl<I: IO> Precompile for ExitToEthereum<I> {
         };
 
         Ok(PrecompileOutput {
-            logs: vec![promise_log, exit_event_log],
+            // The fee is transferred before the exit.
+            logs: fee_log
+                .into_iter()
+                .chain([promise_log, exit_event_log])
+                .collect(),
             cost: Self::required_gas(input)?,
             output: Vec::new(),
         })
@@ -11,6 +11,12 @@ use evm::{Context, ExitError};
 /// This address is computed as: `&keccak("getPromiseResults")[12..]`
 pub const ADDRESS: Address = make_address(0x0a3540f7, 0x9be10ef14890e87c1a0040a68cc6af71);
 
+/// `get_promise_results_count` precompile address
+///
+/// Address: `0xac0622aa2d7b346215a73ae10cf9717552ceca08`
+/// This address is computed as: `&keccak("getPromiseResultsCount")[12..]`
+pub const COUNT_ADDRESS: Address = make_address(0xac0622aa, 0x2d7b346215a73ae10cf9717552ceca08);
+
 pub mod costs {
     use crate::prelude::types::EthGas;
 
@@ -18,6 +24,8 @@ pub mod costs {
     pub const PROMISE_RESULT_BASE_COST: EthGas = EthGas::new(111);
     /// This is the cost per byte of promise result data.
     pub const PROMISE_RESULT_BYTE_COST: EthGas = EthGas::new(2);
+    /// This is the cost for calling the promise results count precompile.
+    pub const PROMISE_RESULTS_COUNT_COST: EthGas = EthGas::new(111);
 }
 
 pub struct PromiseResult<H> {
@@ -77,6 +85,48 @@ impl<H: ReadOnlyPromiseHandler> Precompile for PromiseResult<H> {
     }
 }
 
+/// Returns the number of promise results available to the current call as a single
+/// big-endian 256-bit word. The count is read from the handler when the precompile is
+/// created since it does not change during the execution of a transaction.
+pub struct PromiseResultsCount {
+    count: u64,
+}
+
+impl PromiseResultsCount {
+    #[must_use]
+    pub fn new<H: ReadOnlyPromiseHandler>(handler: &H) -> Self {
+        Self {
+            count: handler.ro_promise_results_count(),
+        }
+    }
+}
+
+impl Precompile for PromiseResultsCount {
+    fn required_gas(_input: &[u8]) -> Result<EthGas, ExitError> {
+        Ok(costs::PROMISE_RESULTS_COUNT_COST)
+    }
+
+    fn run(
+        &self,
+        input: &[u8],
+        target_gas: Option<EthGas>,
+        context: &Context,
+        _is_static: bool,
+    ) -> EvmPrecompileResult {
+        utils::validate_no_value_attached_to_precompile(context.apparent_value)?;
+        let cost = Self::required_gas(input)?;
+        if let Some(target_gas) = target_gas {
+            if cost > target_gas {
+                return Err(ExitError::OutOfGas);
+            }
+        }
+
+        let mut output = [0u8; 32];
+        output[24..].copy_from_slice(&self.count.to_be_bytes());
+        Ok(PrecompileOutput::without_logs(cost, output.to_vec()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::sdk::types::near_account_to_evm_address;
@@ -89,4 +139,12 @@ mod tests {
             near_account_to_evm_address(b"getPromiseResults")
         );
     }
+
+    #[test]
+    fn test_get_promise_results_count_precompile_id() {
+        assert_eq!(
+            promise_result::COUNT_ADDRESS,
+            near_account_to_evm_address(b"getPromiseResultsCount")
+        );
+    }
 }
@@ -24,6 +24,8 @@ impl RandomSeed {
     /// This address is computed as: `&keccak("randomSeed")[12..]`
     pub const ADDRESS: Address = make_address(0xc104f484, 0x0573bed437190daf5d2898c2bdf928ac);
 
+    /// The seed is the NEAR block seed, unless the owner of an engine built with the
+    /// `random-seed-override` feature pinned it with `set_random_seed_override`.
     #[must_use]
     pub const fn new(random_seed: H256) -> Self {
         Self { random_seed }
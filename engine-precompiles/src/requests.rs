@@ -0,0 +1,186 @@
+//! No-op shims of the EIP-7685 general purpose requests.
+//!
+//! Aurora has no consensus layer, so there are no validators to exit or consolidate.
+//! For compatibility with the tooling and the contracts which probe for the request
+//! predeploys introduced in the Prague hard fork, the withdrawal (EIP-7002) and consolidation
+//! (EIP-7251) request contracts are provided as precompiles which report a zero fee,
+//! accept the requests and drop them. Every dropped request emits a log documenting it.
+//! The deposits (EIP-6110) are taken from the deposit contract logs without any system call,
+//! so there is nothing to provide for them.
+use super::{EvmPrecompileResult, Precompile};
+use crate::prelude::types::{Address, EthGas};
+use crate::prelude::{vec, Borrowed, Vec, H256};
+use crate::{utils, PrecompileOutput};
+use evm::backend::Log;
+use evm::{Context, ExitError};
+
+pub mod withdrawal_request {
+    use crate::prelude::types::{make_address, Address};
+
+    /// Address of the EIP-7002 withdrawal request predeploy.
+    ///
+    /// Address: `0x00000961ef480eb55e80d19ad83579a64c007002`
+    pub const ADDRESS: Address = make_address(0x00000961, 0xef480eb55e80d19ad83579a64c007002);
+}
+
+pub mod consolidation_request {
+    use crate::prelude::types::{make_address, Address};
+
+    /// Address of the EIP-7251 consolidation request predeploy.
+    ///
+    /// Address: `0x0000bbddc7ce488642fb579f8b00f3a590007251`
+    pub const ADDRESS: Address = make_address(0x0000bbdd, 0xc7ce488642fb579f8b00f3a590007251);
+}
+
+mod costs {
+    use crate::prelude::types::EthGas;
+
+    /// Same as the cost of the `LOG3` opcode: base cost and the cost per topic.
+    pub(super) const LOG_BASE_COST: EthGas = EthGas::new(375 * 4);
+
+    /// Same as the cost of the `LOG3` opcode per byte of data.
+    pub(super) const LOG_DATA_BYTE_COST: u64 = 8;
+}
+
+/// Signature of the log emitted for every dropped request:
+/// `RequestIgnored(uint8 requestType, address source)` with the request data in the log data.
+const REQUEST_IGNORED_SIGNATURE: &[u8] = b"RequestIgnored(uint8,address)";
+
+/// Precompile which stands for an EIP-7685 request predeploy. Calling it without input returns
+/// the fee of the request (always zero); calling it with input accepts the request as a no-op.
+pub struct RequestNoop {
+    address: Address,
+    request_type: u8,
+}
+
+impl RequestNoop {
+    /// EIP-7685 type of the withdrawal requests.
+    pub const WITHDRAWAL_REQUEST_TYPE: u8 = 0x01;
+    /// EIP-7685 type of the consolidation requests.
+    pub const CONSOLIDATION_REQUEST_TYPE: u8 = 0x02;
+
+    #[must_use]
+    pub const fn withdrawal() -> Self {
+        Self {
+            address: withdrawal_request::ADDRESS,
+            request_type: Self::WITHDRAWAL_REQUEST_TYPE,
+        }
+    }
+
+    #[must_use]
+    pub const fn consolidation() -> Self {
+        Self {
+            address: consolidation_request::ADDRESS,
+            request_type: Self::CONSOLIDATION_REQUEST_TYPE,
+        }
+    }
+}
+
+impl Precompile for RequestNoop {
+    fn required_gas(input: &[u8]) -> Result<EthGas, ExitError> {
+        if input.is_empty() {
+            return Ok(EthGas::new(0));
+        }
+        let data_len = u64::try_from(input.len()).map_err(utils::err_usize_conv)?;
+        Ok(costs::LOG_BASE_COST + EthGas::new(data_len * costs::LOG_DATA_BYTE_COST))
+    }
+
+    fn run(
+        &self,
+        input: &[u8],
+        target_gas: Option<EthGas>,
+        context: &Context,
+        is_static: bool,
+    ) -> EvmPrecompileResult {
+        // The fee is always zero, so the requests must not pay anything.
+        utils::validate_no_value_attached_to_precompile(context.apparent_value)?;
+        let cost = Self::required_gas(input)?;
+        if let Some(target_gas) = target_gas {
+            if cost > target_gas {
+                return Err(ExitError::OutOfGas);
+            }
+        }
+
+        // An empty input is a query of the current fee.
+        if input.is_empty() {
+            return Ok(PrecompileOutput::without_logs(cost, vec![0; 32]));
+        }
+
+        // The log can't be emitted in static mode.
+        if is_static {
+            return Err(ExitError::Other(Borrowed("ERR_INVALID_IN_STATIC")));
+        }
+
+        let log = Log {
+            address: self.address.raw(),
+            topics: vec![
+                aurora_engine_sdk::keccak(REQUEST_IGNORED_SIGNATURE),
+                H256::from_low_u64_be(self.request_type.into()),
+                H256::from(context.caller),
+            ],
+            data: input.to_vec(),
+        };
+
+        Ok(PrecompileOutput {
+            cost,
+            output: Vec::new(),
+            logs: vec![log],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::{H160, U256};
+    use crate::utils::new_context;
+
+    #[test]
+    fn test_fee_query() {
+        for precompile in [RequestNoop::withdrawal(), RequestNoop::consolidation()] {
+            let output = precompile
+                .run(&[], Some(EthGas::new(0)), &new_context(), true)
+                .unwrap();
+            assert_eq!(
+                output,
+                PrecompileOutput::without_logs(EthGas::new(0), vec![0; 32])
+            );
+        }
+    }
+
+    #[test]
+    fn test_request_is_ignored() {
+        let precompile = RequestNoop::withdrawal();
+        let caller = H160::from_low_u64_be(0x1234);
+        let context = Context {
+            caller,
+            ..new_context()
+        };
+        // Validator public key (48 bytes) and the amount (8 bytes).
+        let input = [1u8; 56];
+
+        let output = precompile.run(&input, None, &context, false).unwrap();
+        assert_eq!(output.cost, EthGas::new(375 * 4 + 8 * 56));
+        assert!(output.output.is_empty());
+        assert_eq!(
+            output.logs,
+            vec![Log {
+                address: withdrawal_request::ADDRESS.raw(),
+                topics: vec![
+                    aurora_engine_sdk::keccak(REQUEST_IGNORED_SIGNATURE),
+                    H256::from_low_u64_be(1),
+                    H256::from(caller),
+                ],
+                data: input.to_vec(),
+            }]
+        );
+
+        // Requests can't be made in static mode nor pay a fee.
+        assert!(precompile.run(&input, None, &context, true).is_err());
+        let context = Context {
+            apparent_value: U256::one(),
+            ..context
+        };
+        assert!(precompile.run(&input, None, &context, false).is_err());
+    }
+}
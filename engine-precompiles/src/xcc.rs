@@ -39,6 +39,8 @@ pub mod costs {
     /// The units on this quantity are `NEAR Gas / EVM Gas`.
     /// The report gives a value `0.175 T(NEAR_gas) / k(EVM_gas)`. To convert the units to
     /// `NEAR Gas / EVM Gas`, we simply multiply `0.175 * 10^12 / 10^3 = 175 * 10^6`.
+    /// This is the default value, the owner can override it with `set_xcc_near_gas_per_evm_gas`
+    /// (see `state::get_near_gas_per_evm_gas`).
     pub const CROSS_CONTRACT_CALL_NEAR_GAS: u64 = 175_000_000;
 
     pub const ROUTER_EXEC_BASE: NearGas = NearGas::new(7_000_000_000_000);
@@ -168,7 +170,8 @@ impl<I: IO> HandleBasedPrecompile for CrossContractCall<I> {
                 (promise, attached_near)
             }
         };
-        cost += EthGas::new(promise.attached_gas.as_u64() / costs::CROSS_CONTRACT_CALL_NEAR_GAS);
+        cost +=
+            EthGas::new(promise.attached_gas.as_u64() / state::get_near_gas_per_evm_gas(&self.io));
         check_cost(cost)?;
 
         let required_near =
@@ -248,6 +251,8 @@ pub mod state {
     pub const ERR_MISSING_WNEAR_ADDRESS: &str = "ERR_MISSING_WNEAR_ADDRESS";
     pub const VERSION_KEY: &[u8] = b"version";
     pub const WNEAR_KEY: &[u8] = b"wnear";
+    /// Must not be 20 bytes long to not collide with the keys of the router versions.
+    pub const NEAR_GAS_PER_EVM_GAS_KEY: &[u8] = b"near_gas_rate";
     /// Amount of NEAR needed to cover storage for a router contract.
     pub const STORAGE_AMOUNT: Yocto = Yocto::new(2_000_000_000_000_000_000_000_000);
 
@@ -264,6 +269,15 @@ pub mod state {
         )
     }
 
+    /// Get the amount of NEAR gas attached to the created promise which is charged as one EVM gas.
+    pub fn get_near_gas_per_evm_gas<I: IO>(io: &I) -> u64 {
+        let key = storage::bytes_to_key(KeyPrefix::CrossContractCall, NEAR_GAS_PER_EVM_GAS_KEY);
+        io.read_u64(&key)
+            .ok()
+            .filter(|value| *value != 0)
+            .unwrap_or(super::costs::CROSS_CONTRACT_CALL_NEAR_GAS)
+    }
+
     /// Get the latest router contract version.
     pub fn get_latest_code_version<I: IO>(io: &I) -> CodeVersion {
         let key = storage::bytes_to_key(KeyPrefix::CrossContractCall, VERSION_KEY);
@@ -205,6 +205,18 @@ macro_rules! log {
     };
 }
 
+/// Same as `log!`, but the message is logged only if the condition holds. The condition
+/// isn't evaluated at all if the `log` feature is disabled.
+#[macro_export]
+macro_rules! log_if {
+    ($enabled:expr, $($args:tt)*) => {
+        #[cfg(feature = "log")]
+        if $enabled {
+            $crate::log!($($args)*);
+        }
+    };
+}
+
 #[must_use]
 pub const fn storage_byte_cost() -> u128 {
     STORAGE_PRICE_PER_BYTE
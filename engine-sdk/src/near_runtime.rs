@@ -47,9 +47,10 @@ impl Runtime {
 
     const GAS_FOR_STATE_MIGRATION: NearGas = NearGas::new(100_000_000_000_000);
 
-    /// Deploy code from given key in place of the current contract.
+    /// Deploy code from given key in place of the current contract and return its keccak256 hash.
     /// Not implemented in terms of higher level traits (e.g. IO) for efficiency reasons.
-    pub fn self_deploy(code_key: &[u8]) {
+    #[must_use]
+    pub fn self_deploy(code_key: &[u8]) -> H256 {
         unsafe {
             // Load current account id into register 0.
             exports::current_account_id(0);
@@ -70,6 +71,11 @@ impl Runtime {
                 }
                 _ => panic!("ERR_NO_CODE_STAGED"),
             };
+            // Hash the code which is already in register 1 and store the hash in register 2.
+            exports::keccak256(u64::MAX, 1, 2);
+            let code_hash = H256::zero();
+            exports::read_register(2, code_hash.0.as_ptr() as u64);
+            code_hash
         }
     }
 
@@ -0,0 +1,142 @@
+use crate::{construct_engine_key, DiffValue, Error, Storage};
+use std::io::{self, Read, Write};
+
+/// Version of the binary snapshot format, written as the first byte of every snapshot.
+pub const BINARY_SNAPSHOT_VERSION: u8 = 0;
+
+/// Compression of the binary snapshot entries, written as the second byte of every snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotCompression {
+    None,
+    Zstd,
+}
+
+impl From<SnapshotCompression> for u8 {
+    fn from(value: SnapshotCompression) -> Self {
+        match value {
+            SnapshotCompression::None => 0,
+            SnapshotCompression::Zstd => 1,
+        }
+    }
+}
+
+impl TryFrom<u8> for SnapshotCompression {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Zstd),
+            other => Err(Error::UnknownSnapshotCompression(other)),
+        }
+    }
+}
+
+impl Storage {
+    /// Writes the post-state of the Engine at the given block height (see `get_snapshot`) in a
+    /// compact binary format:
+    /// * the format version and the compression (1 byte each);
+    /// * the block height and the number of entries (8 bytes each, little-endian);
+    /// * the entries: the key and the value, each prefixed by its length (4 bytes, little-endian).
+    ///
+    /// Everything after the first two bytes is compressed with the given compression.
+    /// The entries are sorted by key, so the same state always gives the same snapshot.
+    pub fn export_binary_snapshot<W: Write>(
+        &self,
+        block_height: u64,
+        mut writer: W,
+        compression: SnapshotCompression,
+    ) -> Result<(), Error> {
+        let mut entries: Vec<_> = self.get_snapshot(block_height)?.into_iter().collect();
+        entries.sort_unstable();
+
+        writer.write_all(&[BINARY_SNAPSHOT_VERSION, compression.into()])?;
+        match compression {
+            SnapshotCompression::None => {
+                write_entries(&mut writer, block_height, &entries)?;
+                writer.flush()?;
+            }
+            SnapshotCompression::Zstd => {
+                let mut encoder = zstd::Encoder::new(writer, zstd::DEFAULT_COMPRESSION_LEVEL)?;
+                write_entries(&mut encoder, block_height, &entries)?;
+                encoder.finish()?.flush()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the Engine state from a snapshot created by `export_binary_snapshot` directly into
+    /// the storage. The state is inserted as the post-state of the snapshot block height,
+    /// which is returned.
+    pub fn import_binary_snapshot<R: Read>(&self, mut reader: R) -> Result<u64, Error> {
+        let mut header = [0u8; 2];
+        reader.read_exact(&mut header)?;
+        let [version, compression] = header;
+        if version != BINARY_SNAPSHOT_VERSION {
+            return Err(Error::UnsupportedSnapshotVersion(version));
+        }
+
+        match SnapshotCompression::try_from(compression)? {
+            SnapshotCompression::None => self.import_entries(reader),
+            SnapshotCompression::Zstd => self.import_entries(zstd::Decoder::new(reader)?),
+        }
+    }
+
+    fn import_entries<R: Read>(&self, mut reader: R) -> Result<u64, Error> {
+        let block_height = read_u64(&mut reader)?;
+        let count = read_u64(&mut reader)?;
+        let transaction_position = u16::MAX;
+
+        let mut batch = rocksdb::WriteBatch::default();
+        for _ in 0..count {
+            let key = read_bytes(&mut reader)?;
+            let value = read_bytes(&mut reader)?;
+            let storage_key = construct_engine_key(&key, block_height, transaction_position);
+            batch.put(storage_key, DiffValue::Modified(value).try_to_bytes()?);
+        }
+        self.db.write(batch)?;
+
+        Ok(block_height)
+    }
+}
+
+fn write_entries<W: Write>(
+    writer: &mut W,
+    block_height: u64,
+    entries: &[(Vec<u8>, Vec<u8>)],
+) -> io::Result<()> {
+    let count = u64::try_from(entries.len()).map_err(|_| io::ErrorKind::InvalidInput)?;
+    writer.write_all(&block_height.to_le_bytes())?;
+    writer.write_all(&count.to_le_bytes())?;
+    for (key, value) in entries {
+        write_bytes(writer, key)?;
+        write_bytes(writer, value)?;
+    }
+    Ok(())
+}
+
+fn write_bytes<W: Write>(writer: &mut W, bytes: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(bytes.len()).map_err(|_| io::ErrorKind::InvalidInput)?;
+    writer.write_all(&len.to_le_bytes())?;
+    writer.write_all(bytes)
+}
+
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut buf = [0u8; 8];
+    reader.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+fn read_bytes<R: Read>(reader: &mut R) -> io::Result<Vec<u8>> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf)?;
+    let len = u64::from(u32::from_le_bytes(buf));
+    // Don't trust the length to allocate the buffer upfront, the snapshot can be corrupted.
+    let mut bytes = Vec::new();
+    reader.by_ref().take(len).read_to_end(&mut bytes)?;
+    if u64::try_from(bytes.len()) != Ok(len) {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(bytes)
+}
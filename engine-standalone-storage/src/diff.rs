@@ -1,4 +1,7 @@
 use aurora_engine_types::borsh::{self, BorshDeserialize, BorshSerialize};
+use aurora_engine_types::storage::{KeyPrefix, VersionPrefix};
+use aurora_engine_types::types::Address;
+use aurora_engine_types::H256;
 use std::collections::{btree_map, BTreeMap};
 
 #[derive(Debug, Default, Clone, BorshDeserialize, BorshSerialize, PartialEq, Eq)]
@@ -84,6 +87,87 @@ impl Diff {
     pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, std::io::Error> {
         Self::try_from_slice(bytes)
     }
+
+    /// Classify the entries of the Diff by the kind of the Engine state they belong to,
+    /// decoding the addresses and storage slots from the keys.
+    #[must_use]
+    pub fn to_structured(&self) -> StructuredDiff {
+        let mut result = StructuredDiff::default();
+
+        for (key, value) in self {
+            let value = value.clone();
+            let address = key.get(2..22).and_then(|b| Address::try_from_slice(b).ok());
+
+            match (key.first(), key.get(1), address) {
+                (Some(&version), Some(&prefix), Some(address))
+                    if version == u8::from(VersionPrefix::V1) =>
+                {
+                    match (prefix, key.len()) {
+                        (p, 22) if p == u8::from(KeyPrefix::Balance) => {
+                            result.balances.insert(address, value);
+                        }
+                        (p, 22) if p == u8::from(KeyPrefix::Nonce) => {
+                            result.nonces.insert(address, value);
+                        }
+                        (p, 22) if p == u8::from(KeyPrefix::Code) => {
+                            result.codes.insert(address, value);
+                        }
+                        (p, 54) if p == u8::from(KeyPrefix::Storage) => {
+                            result.storage.push(StorageDiff {
+                                address,
+                                generation: 0,
+                                slot: H256::from_slice(&key[22..54]),
+                                value,
+                            });
+                        }
+                        (p, 58) if p == u8::from(KeyPrefix::Storage) => {
+                            let mut generation = [0u8; 4];
+                            generation.copy_from_slice(&key[22..26]);
+                            result.storage.push(StorageDiff {
+                                address,
+                                generation: u32::from_le_bytes(generation),
+                                slot: H256::from_slice(&key[26..58]),
+                                value,
+                            });
+                        }
+                        _ => {
+                            result.other.0.insert(key.clone(), value);
+                        }
+                    }
+                }
+                _ => {
+                    result.other.0.insert(key.clone(), value);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Engine state changes of a transaction split by category.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StructuredDiff {
+    /// Changed balances of the EVM accounts.
+    pub balances: BTreeMap<Address, DiffValue>,
+    /// Changed nonces of the EVM accounts.
+    pub nonces: BTreeMap<Address, DiffValue>,
+    /// Changed code of the EVM accounts.
+    pub codes: BTreeMap<Address, DiffValue>,
+    /// Changed storage slots of the EVM accounts.
+    pub storage: Vec<StorageDiff>,
+    /// All other changes (config, connector, custom data, generations, etc.) with raw keys.
+    pub other: Diff,
+}
+
+/// Change of a single EVM storage slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageDiff {
+    pub address: Address,
+    /// Storage generation of the account, `0` for the keys without generation.
+    pub generation: u32,
+    pub slot: H256,
+    pub value: DiffValue,
 }
 
 impl<'diff> IntoIterator for &'diff Diff {
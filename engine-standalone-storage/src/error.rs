@@ -7,11 +7,14 @@ pub enum Error {
     BlockNotFound(H256),
     Borsh(String),
     NoBlockAtHeight(u64),
+    BlockPruned(u64),
     TransactionNotFound(TransactionIncluded),
     TransactionHashNotFound(H256),
     Rocksdb(rocksdb::Error),
     EngineAccountIdNotSet,
     EngineAccountIdCorrupted,
+    UnsupportedSnapshotVersion(u8),
+    UnknownSnapshotCompression(u8),
 }
 
 impl From<rocksdb::Error> for Error {
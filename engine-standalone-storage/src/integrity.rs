@@ -0,0 +1,204 @@
+use crate::{
+    construct_storage_key, Error, Storage, StoragePrefix, TransactionIncluded,
+    ENGINE_KEY_SUFFIX_LEN,
+};
+use aurora_engine_types::H256;
+
+/// Result of `Storage::verify_integrity`. Contains all the violations found in the storage.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct IntegrityReport {
+    pub violations: Vec<IntegrityViolation>,
+}
+
+impl IntegrityReport {
+    #[must_use]
+    pub fn is_ok(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegrityViolation {
+    /// An entry which could not be parsed. Contains the full DB key.
+    MalformedEntry { db_key: Vec<u8> },
+    /// A `TransactionHash` entry without the corresponding `TransactionData` entry.
+    MissingTransactionData {
+        tx_hash: H256,
+        tx_included: TransactionIncluded,
+    },
+    /// A `TransactionHash` entry without the corresponding `Diff` entry.
+    MissingDiff {
+        tx_hash: H256,
+        tx_included: TransactionIncluded,
+    },
+    /// The block hash stored for the height does not map back to the same height.
+    BlockHeightMismatch {
+        block_height: u64,
+        block_hash: H256,
+        stored_height: Option<u64>,
+    },
+    /// A version of the engine key was written in a block which the block index places before
+    /// the block of the previous version of the same key. The heights are the ones stored in
+    /// the block index for the blocks of the versions.
+    NonMonotonicEngineKey {
+        engine_key: Vec<u8>,
+        block_height: u64,
+        previous_block_height: u64,
+    },
+}
+
+impl Storage {
+    /// Checks the storage invariants and returns all the violations found:
+    /// * every `TransactionHash` entry has matching `TransactionData` and `Diff` entries;
+    /// * every block height maps to a block hash which maps back to the same height;
+    /// * the versions of each engine key are monotonic in the block heights stored in the block
+    ///   index and in the transaction position.
+    pub fn verify_integrity(&self) -> Result<IntegrityReport, Error> {
+        let mut report = IntegrityReport::default();
+
+        self.verify_transactions(&mut report)?;
+        self.verify_blocks(&mut report)?;
+        self.verify_engine_keys(&mut report)?;
+
+        Ok(report)
+    }
+
+    fn verify_transactions(&self, report: &mut IntegrityReport) -> Result<(), Error> {
+        self.for_each_with_prefix(StoragePrefix::TransactionHash, |db_key, key, value| {
+            let tx_included = match <[u8; 34]>::try_from(key) {
+                Ok(bytes) if value.len() == 32 => TransactionIncluded::from_bytes(bytes),
+                _ => {
+                    report.violations.push(IntegrityViolation::MalformedEntry {
+                        db_key: db_key.to_vec(),
+                    });
+                    return Ok(());
+                }
+            };
+            let tx_hash = H256::from_slice(value);
+
+            let data_key = construct_storage_key(StoragePrefix::TransactionData, tx_hash.as_ref());
+            if self.db.get_pinned(data_key)?.is_none() {
+                report
+                    .violations
+                    .push(IntegrityViolation::MissingTransactionData {
+                        tx_hash,
+                        tx_included,
+                    });
+            }
+
+            let diff_key = construct_storage_key(StoragePrefix::Diff, key);
+            if self.db.get_pinned(diff_key)?.is_none() {
+                report.violations.push(IntegrityViolation::MissingDiff {
+                    tx_hash,
+                    tx_included,
+                });
+            }
+
+            Ok(())
+        })
+    }
+
+    fn verify_blocks(&self, report: &mut IntegrityReport) -> Result<(), Error> {
+        self.for_each_with_prefix(StoragePrefix::BlockHash, |db_key, key, value| {
+            let block_height = match <[u8; 8]>::try_from(key) {
+                Ok(bytes) if value.len() == 32 => u64::from_be_bytes(bytes),
+                _ => {
+                    report.violations.push(IntegrityViolation::MalformedEntry {
+                        db_key: db_key.to_vec(),
+                    });
+                    return Ok(());
+                }
+            };
+            let block_hash = H256::from_slice(value);
+
+            let stored_height = match self.get_block_height_by_hash(block_hash) {
+                Ok(height) => Some(height),
+                Err(Error::BlockNotFound(_)) => None,
+                Err(e) => return Err(e),
+            };
+            if stored_height != Some(block_height) {
+                report
+                    .violations
+                    .push(IntegrityViolation::BlockHeightMismatch {
+                        block_height,
+                        block_hash,
+                        stored_height,
+                    });
+            }
+
+            Ok(())
+        })
+    }
+
+    fn verify_engine_keys(&self, report: &mut IntegrityReport) -> Result<(), Error> {
+        let mut previous: Option<(Vec<u8>, u64, u16)> = None;
+
+        self.for_each_with_prefix(StoragePrefix::Engine, |db_key, key, _value| {
+            if key.len() < ENGINE_KEY_SUFFIX_LEN {
+                report.violations.push(IntegrityViolation::MalformedEntry {
+                    db_key: db_key.to_vec(),
+                });
+                return Ok(());
+            }
+            let split = key.len() - ENGINE_KEY_SUFFIX_LEN;
+            let (engine_key, suffix) = key.split_at(split);
+            let block_height = {
+                let mut buf = [0u8; 8];
+                buf.copy_from_slice(&suffix[0..8]);
+                u64::from_be_bytes(buf)
+            };
+            let position = {
+                let mut buf = [0u8; 2];
+                buf.copy_from_slice(&suffix[8..10]);
+                u16::from_be_bytes(buf)
+            };
+
+            // The versions are sorted by the height in the key suffix, so compare the heights
+            // stored in the block index for their blocks instead.
+            let block_height = match self
+                .get_block_hash_by_height(block_height)
+                .and_then(|block_hash| self.get_block_height_by_hash(block_hash))
+            {
+                Ok(stored_height) => stored_height,
+                // A missing block is reported by `verify_blocks`.
+                Err(Error::NoBlockAtHeight(_) | Error::BlockNotFound(_)) => block_height,
+                Err(e) => return Err(e),
+            };
+
+            if let Some((previous_key, previous_block_height, previous_position)) = &previous {
+                if previous_key == engine_key
+                    && (block_height, position) <= (*previous_block_height, *previous_position)
+                {
+                    report
+                        .violations
+                        .push(IntegrityViolation::NonMonotonicEngineKey {
+                            engine_key: engine_key.to_vec(),
+                            block_height,
+                            previous_block_height: *previous_block_height,
+                        });
+                }
+            }
+            previous = Some((engine_key.to_vec(), block_height, position));
+
+            Ok(())
+        })
+    }
+
+    /// Calls `f` with the full DB key, the key without the storage prefix and the value of every
+    /// entry with the given prefix.
+    fn for_each_with_prefix<F>(&self, prefix: StoragePrefix, mut f: F) -> Result<(), Error>
+    where
+        F: FnMut(&[u8], &[u8], &[u8]) -> Result<(), Error>,
+    {
+        let db_prefix = construct_storage_key(prefix, &[]);
+        let n = db_prefix.len();
+        for maybe_elem in self.db.prefix_iterator(&db_prefix) {
+            let (k, v) = maybe_elem?;
+            if k.len() < n || k[0..n] != db_prefix {
+                break;
+            }
+            f(&k, &k[n..], &v)?;
+        }
+        Ok(())
+    }
+}
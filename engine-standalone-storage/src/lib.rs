@@ -5,7 +5,10 @@
     clippy::missing_panics_doc,
     clippy::missing_errors_doc
 )]
+use aurora_engine_hashchain::hashchain::{BlockHashchainVerification, BlockTx, Hashchain};
 use aurora_engine_sdk::env::Timestamp;
+use aurora_engine_types::storage::{self, KeyPrefix};
+use aurora_engine_types::types::Address;
 use aurora_engine_types::{account_id::AccountId, H256};
 use rocksdb::DB;
 use std::cell::{Cell, RefCell};
@@ -15,17 +18,22 @@ use sync::types::TransactionMessage;
 
 const VERSION: u8 = 0;
 
+pub mod binary_snapshot;
 pub mod diff;
 pub mod engine_state;
 pub mod error;
+pub mod integrity;
 pub mod json_snapshot;
 pub mod promise;
+pub mod pruning;
 pub mod relayer_db;
 /// Functions for receiving new blocks and transactions to keep the storage up to date.
 pub mod sync;
 
-pub use diff::{Diff, DiffValue};
+pub use binary_snapshot::SnapshotCompression;
+pub use diff::{Diff, DiffValue, StorageDiff, StructuredDiff};
 pub use error::Error;
+pub use integrity::{IntegrityReport, IntegrityViolation};
 
 /// Length (in bytes) of the suffix appended to Engine keys which specify the
 /// block height and transaction position. 64 bits for the block height,
@@ -44,6 +52,7 @@ pub enum StoragePrefix {
     EngineAccountId = 0x07,
     /// Prefix used for storing arbitrary data from the outside of the crate.
     CustomData = 0x8,
+    PruningWatermark = 0x9,
 }
 
 impl From<StoragePrefix> for u8 {
@@ -58,6 +67,7 @@ impl From<StoragePrefix> for u8 {
             StoragePrefix::BlockMetadata => 0x06,
             StoragePrefix::EngineAccountId => 0x07,
             StoragePrefix::CustomData => 0x08,
+            StoragePrefix::PruningWatermark => 0x09,
         }
     }
 }
@@ -98,7 +108,86 @@ impl Storage {
         self.block_read(rocksdb::IteratorMode::Start)
     }
 
+    /// Returns the value of the storage `slot` of the contract at `address` as of the end of the
+    /// block at `block_height`. Returns `Error::BlockPruned` if the height is before the earliest
+    /// queryable block in the storage.
+    pub fn get_storage_at_height(
+        &self,
+        address: &Address,
+        slot: &H256,
+        block_height: u64,
+    ) -> Result<H256, Error> {
+        let (_, earliest_block_height) = self.get_earliest_queryable_block()?;
+        if block_height < earliest_block_height {
+            return Err(Error::BlockPruned(block_height));
+        }
+
+        let result = self.with_engine_access(block_height, u16::MAX, &[], |io| {
+            let generation = aurora_engine::engine::get_generation(&io, address);
+            aurora_engine::engine::get_storage(&io, address, slot, generation)
+        });
+        Ok(result.result)
+    }
+
+    /// Recomputes the hashchain of the block at `block_height` from the given transactions
+    /// (in the order of their execution) and compares it with the hashchain recorded in the
+    /// engine state at the end of the block. Returns `None` if the hashchain was not started
+    /// at that height.
+    pub fn verify_block_hashchain<'a, I>(
+        &self,
+        block_height: u64,
+        txs: I,
+    ) -> Result<Option<BlockHashchainVerification>, Error>
+    where
+        I: IntoIterator<Item = BlockTx<'a>>,
+    {
+        let (_, earliest_block_height) = self.get_earliest_queryable_block()?;
+        if block_height < earliest_block_height {
+            return Err(Error::BlockPruned(block_height));
+        }
+
+        let key = storage::bytes_to_key(
+            KeyPrefix::Hashchain,
+            aurora_engine::hashchain::HASHCHAIN_STATE,
+        );
+        let Some(bytes) = self.get_engine_value(&key, block_height)? else {
+            return Ok(None);
+        };
+        let mut hashchain = Hashchain::try_deserialize(&bytes)?;
+        // The state is only updated by the transactions, so the blocks without any
+        // transactions leave the hashchain at an earlier height.
+        if hashchain.get_current_block_height() < block_height {
+            hashchain
+                .move_to_block(block_height)
+                .expect("the block height is greater than the current one");
+        }
+
+        Ok(Some(hashchain.verify_block_txs(txs)))
+    }
+
+    /// Returns the `n` latest blocks (height, hash and metadata), newest first.
+    /// Fewer blocks are returned if the storage contains less than `n` blocks.
+    pub fn get_recent_blocks(&self, n: usize) -> Result<Vec<(u64, H256, BlockMetadata)>, Error> {
+        self.block_iter(rocksdb::IteratorMode::End)
+            .take(n)
+            .map(|maybe_block| {
+                let (block_hash, block_height) = maybe_block?;
+                let block_metadata = self.get_block_metadata(block_hash)?;
+                Ok((block_height, block_hash, block_metadata))
+            })
+            .collect()
+    }
+
     fn block_read(&self, mode: rocksdb::IteratorMode) -> Result<(H256, u64), Error> {
+        self.block_iter(mode)
+            .next()
+            .ok_or(Error::NoBlockAtHeight(0))?
+    }
+
+    fn block_iter(
+        &self,
+        mode: rocksdb::IteratorMode,
+    ) -> impl Iterator<Item = Result<(H256, u64), Error>> + '_ {
         let upper_bound = construct_storage_key(StoragePrefix::BlockHash, &u64::MAX.to_be_bytes());
         let lower_bound = construct_storage_key(StoragePrefix::BlockHash, &[]);
         let prefix_len = lower_bound.len();
@@ -106,15 +195,16 @@ impl Storage {
         opt.set_iterate_upper_bound(upper_bound);
         opt.set_iterate_lower_bound(lower_bound);
 
-        let mut iter = self.db.iterator_opt(mode, opt);
-        let (key, value) = iter.next().ok_or(Error::NoBlockAtHeight(0))??;
-        let block_height = {
-            let mut buf = [0u8; 8];
-            buf.copy_from_slice(&key[prefix_len..]);
-            u64::from_be_bytes(buf)
-        };
-        let block_hash = H256::from_slice(&value);
-        Ok((block_hash, block_height))
+        self.db.iterator_opt(mode, opt).map(move |maybe_elem| {
+            let (key, value) = maybe_elem?;
+            let block_height = {
+                let mut buf = [0u8; 8];
+                buf.copy_from_slice(&key[prefix_len..]);
+                u64::from_be_bytes(buf)
+            };
+            let block_hash = H256::from_slice(&value);
+            Ok((block_hash, block_height))
+        })
     }
 
     pub fn get_block_hash_by_height(&self, block_height: u64) -> Result<H256, Error> {
@@ -182,6 +272,13 @@ impl Storage {
         Ok(message)
     }
 
+    /// Returns true if the transaction with the given hash is included in the storage.
+    /// Unlike `get_transaction_data` it doesn't deserialize the transaction.
+    pub fn is_transaction_included(&self, tx_hash: H256) -> Result<bool, Error> {
+        let storage_key = construct_storage_key(StoragePrefix::TransactionData, tx_hash.as_ref());
+        Ok(self.db.get_pinned(storage_key)?.is_some())
+    }
+
     pub fn get_transaction_by_position(
         &self,
         tx_included: TransactionIncluded,
@@ -307,15 +404,86 @@ impl Storage {
         &self,
         block_height: u64,
     ) -> Result<HashMap<Vec<u8>, Vec<u8>>, rocksdb::Error> {
+        let mut result = HashMap::new();
+        self.collect_snapshot(&[], block_height, &mut result)?;
+        Ok(result)
+    }
+
+    /// Same as `get_snapshot`, but only includes the balance, nonce, code, generation and storage
+    /// keys of the given addresses. Storage keys left over from previous generations of an
+    /// address (i.e. from before a self-destruct) are not included.
+    pub fn get_snapshot_for_addresses(
+        &self,
+        addresses: &[Address],
+        block_height: u64,
+    ) -> Result<HashMap<Vec<u8>, Vec<u8>>, rocksdb::Error> {
+        let mut result = HashMap::new();
+
+        for address in addresses {
+            for prefix in [
+                KeyPrefix::Balance,
+                KeyPrefix::Nonce,
+                KeyPrefix::Code,
+                KeyPrefix::Generation,
+            ] {
+                let key = storage::address_to_key(prefix, address);
+                if let Some(value) = self.get_engine_value(&key, block_height)? {
+                    result.insert(key.to_vec(), value);
+                }
+            }
+
+            let generation_key = storage::address_to_key(KeyPrefix::Generation, address);
+            let generation = result.get(generation_key.as_slice()).map_or(0, |value| {
+                let mut bytes = [0u8; 4];
+                bytes.copy_from_slice(value);
+                u32::from_be_bytes(bytes)
+            });
+            let storage_prefix = storage::address_to_key(KeyPrefix::Storage, address);
+            let mut storage = HashMap::new();
+            self.collect_snapshot(&storage_prefix, block_height, &mut storage)?;
+            result.extend(storage.into_iter().filter(|(key, _)| {
+                let prefix_len = storage_prefix.len();
+                if generation == 0 {
+                    key.len() == prefix_len + 32
+                } else {
+                    key.len() == prefix_len + 4 + 32
+                        && key[prefix_len..(prefix_len + 4)] == generation.to_le_bytes()
+                }
+            }));
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the value of the engine key as of the end of the given block,
+    /// or `None` if the key was not present at that time.
+    fn get_engine_value(
+        &self,
+        engine_key: &[u8],
+        block_height: u64,
+    ) -> Result<Option<Vec<u8>>, rocksdb::Error> {
+        let mut snapshot = HashMap::new();
+        self.collect_snapshot(engine_key, block_height, &mut snapshot)?;
+        Ok(snapshot.remove(engine_key))
+    }
+
+    /// Adds the post-state of all engine keys starting with `engine_key_prefix` at the given
+    /// block height to `result`.
+    fn collect_snapshot(
+        &self,
+        engine_key_prefix: &[u8],
+        block_height: u64,
+        result: &mut HashMap<Vec<u8>, Vec<u8>>,
+    ) -> Result<(), rocksdb::Error> {
         let engine_prefix = construct_storage_key(StoragePrefix::Engine, &[]);
         let engine_prefix_len = engine_prefix.len();
-        let mut iter: rocksdb::DBRawIterator = self.db.prefix_iterator(&engine_prefix).into();
-        let mut result = HashMap::new();
+        let iter_prefix = construct_storage_key(StoragePrefix::Engine, engine_key_prefix);
+        let mut iter: rocksdb::DBRawIterator = self.db.prefix_iterator(&iter_prefix).into();
 
         while iter.valid() {
             // unwrap is safe because the iterator is valid
             let db_key = iter.key().expect("iterator should is invalid").to_vec();
-            if db_key.get(0..engine_prefix_len) != Some(&engine_prefix) {
+            if db_key.get(0..iter_prefix.len()) != Some(&iter_prefix) {
                 break;
             }
             // raw engine key skips the 2-byte prefix and the block+position suffix
@@ -365,9 +533,7 @@ impl Storage {
             }
         }
 
-        iter.status()?;
-
-        Ok(result)
+        iter.status()
     }
 
     /// Same as `access_engine_storage_at_position`, but does not modify `self`, hence the immutable
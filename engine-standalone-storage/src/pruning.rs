@@ -0,0 +1,143 @@
+use crate::{
+    construct_storage_key, DiffValue, Error, Storage, StoragePrefix, ENGINE_KEY_SUFFIX_LEN,
+};
+use aurora_engine_types::H256;
+use std::ops::Range;
+
+const PRUNING_WATERMARK_KEY: &[u8] = b"pruning_watermark";
+/// Number of deletes after which the pending batch is written to the database.
+const PRUNING_BATCH_SIZE: usize = 10_000;
+
+impl Storage {
+    /// Removes the versions of the engine keys which are not needed to reconstruct the engine
+    /// state at `block_height` or later. For every key only the latest version written before
+    /// `block_height` is kept (or none if the key was deleted). The block and transaction data
+    /// are not removed. Returns the number of removed versions.
+    ///
+    /// The height becomes the pruning watermark used by `get_earliest_queryable_block`.
+    /// The watermark never moves back: a height below it is replaced by the watermark itself.
+    /// It is written before any version is removed, so an interrupted pruning doesn't leave
+    /// queryable blocks with missing state and can simply be run again.
+    pub fn prune_before(&mut self, block_height: u64) -> Result<usize, Error> {
+        let block_height = block_height.max(self.get_pruning_watermark()?.unwrap_or_default());
+        self.db
+            .put(pruning_watermark_key(), block_height.to_be_bytes())?;
+
+        let db_prefix = construct_storage_key(StoragePrefix::Engine, &[]);
+        let n = db_prefix.len();
+        let mut batch = rocksdb::WriteBatch::default();
+        let mut removed = 0;
+        // The versions of an engine key are iterated one after another, in the order they were
+        // written, so only the latest version written before the watermark of the current key
+        // has to be kept track of.
+        let mut current: Option<LatestVersion> = None;
+
+        for maybe_elem in self.db.prefix_iterator(&db_prefix) {
+            let (k, v) = maybe_elem?;
+            if k.len() < n || k[0..n] != db_prefix {
+                break;
+            }
+            let key = &k[n..];
+            if key.len() < ENGINE_KEY_SUFFIX_LEN {
+                continue;
+            }
+            let split = key.len() - ENGINE_KEY_SUFFIX_LEN;
+            let (engine_key, suffix) = key.split_at(split);
+            let version_height = {
+                let mut buf = [0u8; 8];
+                buf.copy_from_slice(&suffix[0..8]);
+                u64::from_be_bytes(buf)
+            };
+
+            let is_same_key = current
+                .as_ref()
+                .is_some_and(|latest| latest.engine_key() == engine_key);
+            if !is_same_key {
+                if let Some(latest) = current.take() {
+                    removed += latest.finish(&mut batch);
+                }
+            }
+            if version_height >= block_height {
+                continue;
+            }
+
+            // The previous version of the key is superseded by the current one.
+            if let Some(previous) = current.take() {
+                batch.delete(previous.db_key);
+                removed += 1;
+            }
+            current = Some(LatestVersion {
+                db_key: k.to_vec(),
+                engine_key_range: n..(n + split),
+                is_deleted: DiffValue::try_from_bytes(&v)? == DiffValue::Deleted,
+            });
+
+            if batch.len() >= PRUNING_BATCH_SIZE {
+                self.db.write(std::mem::take(&mut batch))?;
+            }
+        }
+
+        if let Some(latest) = current {
+            removed += latest.finish(&mut batch);
+        }
+        self.db.write(batch)?;
+
+        Ok(removed)
+    }
+
+    /// Returns the earliest block for which the engine state can be reconstructed, i.e. the first
+    /// block at or after the pruning watermark. Same as `get_earliest_block` if the storage was
+    /// never pruned.
+    pub fn get_earliest_queryable_block(&self) -> Result<(H256, u64), Error> {
+        let Some(watermark) = self.get_pruning_watermark()? else {
+            return self.get_earliest_block();
+        };
+
+        for maybe_block in self.block_iter(rocksdb::IteratorMode::Start) {
+            let (block_hash, block_height) = maybe_block?;
+            if block_height >= watermark {
+                return Ok((block_hash, block_height));
+            }
+        }
+
+        Err(Error::NoBlockAtHeight(watermark))
+    }
+
+    /// Returns the height passed to the latest `prune_before` call if the storage was pruned.
+    pub fn get_pruning_watermark(&self) -> Result<Option<u64>, Error> {
+        let value = self.db.get_pinned(pruning_watermark_key())?;
+        Ok(value.map(|slice| {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(slice.as_ref());
+            u64::from_be_bytes(buf)
+        }))
+    }
+}
+
+fn pruning_watermark_key() -> Vec<u8> {
+    construct_storage_key(StoragePrefix::PruningWatermark, PRUNING_WATERMARK_KEY)
+}
+
+/// The latest version of an engine key written before the pruning watermark.
+struct LatestVersion {
+    db_key: Vec<u8>,
+    engine_key_range: Range<usize>,
+    is_deleted: bool,
+}
+
+impl LatestVersion {
+    fn engine_key(&self) -> &[u8] {
+        &self.db_key[self.engine_key_range.clone()]
+    }
+
+    /// Called once all the versions of the key were seen. A deleted key reads the same as
+    /// a missing one, so its last version is not needed. Returns the number of removed versions.
+    fn finish(self, batch: &mut rocksdb::WriteBatch) -> usize {
+        if self.is_deleted {
+            batch.delete(self.db_key);
+            1
+        } else {
+            0
+        }
+    }
+}
@@ -224,6 +224,7 @@ mod test {
             upgrade_delay_blocks: 0,
             is_paused: false,
             key_manager: None,
+            state_version: state::CURRENT_STATE_VERSION,
         };
 
         // Initialize engine and connector states in storage.
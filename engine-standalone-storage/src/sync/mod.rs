@@ -46,10 +46,13 @@ pub fn parse_transaction_kind(
             })?;
             TransactionKind::Submit(eth_tx)
         }
-        TransactionKindTag::SubmitWithArgs => {
-            let args = parameters::SubmitArgs::try_from_slice(&bytes).map_err(f)?;
-            TransactionKind::SubmitWithArgs(args)
-        }
+        TransactionKindTag::SubmitWithArgs => parameters::SubmitArgs::try_from_slice(&bytes)
+            .map(TransactionKind::SubmitWithArgs)
+            .or_else(|_| {
+                parameters::SubmitArgsV2::try_from_slice(&bytes)
+                    .map(TransactionKind::SubmitWithArgsV2)
+            })
+            .map_err(f)?,
         TransactionKindTag::Call => {
             let call_args = parameters::CallArgs::deserialize(&bytes).ok_or_else(|| {
                 ParseTransactionKindError::failed_deserialization::<io::Error>(tx_kind_tag, None)
@@ -70,8 +73,13 @@ pub fn parse_transaction_kind(
         }
         TransactionKindTag::Deploy => TransactionKind::Deploy(bytes),
         TransactionKindTag::DeployErc20 => {
-            let deploy_args =
-                parameters::DeployErc20TokenArgs::try_from_slice(&bytes).map_err(f)?;
+            let deploy_args = parameters::DeployErc20TokenCallArgs::deserialize(&bytes)
+                .ok_or_else(|| {
+                    ParseTransactionKindError::failed_deserialization::<io::Error>(
+                        tx_kind_tag,
+                        None,
+                    )
+                })?;
             TransactionKind::DeployErc20(deploy_args)
         }
         TransactionKindTag::FtOnTransfer => {
@@ -198,7 +206,9 @@ pub fn parse_transaction_kind(
             TransactionKind::SetUpgradeDelayBlocks(args)
         }
         TransactionKindTag::FundXccSubAccount => {
-            let args = xcc::FundXccArgs::try_from_slice(&bytes).map_err(f)?;
+            let args = xcc::FundXccCallArgs::deserialize(&bytes).ok_or_else(|| {
+                ParseTransactionKindError::failed_deserialization::<io::Error>(tx_kind_tag, None)
+            })?;
             TransactionKind::FundXccSubAccount(args)
         }
         TransactionKindTag::PauseContract => TransactionKind::PauseContract,
@@ -264,6 +274,155 @@ pub fn parse_transaction_kind(
             let args = parameters::MirrorErc20TokenArgs::try_from_slice(&bytes).map_err(f)?;
             TransactionKind::MirrorErc20TokenCallback(args)
         }
+        TransactionKindTag::SetRelaxedNonce => {
+            let args = parameters::SetRelaxedNonceArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetRelaxedNonce(args)
+        }
+        TransactionKindTag::RegisterRelayersBatch => {
+            let args = parameters::RegisterRelayersBatchArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::RegisterRelayersBatch(args)
+        }
+        TransactionKindTag::AddDeployer => {
+            let args = parameters::DeployerArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::AddDeployer(args)
+        }
+        TransactionKindTag::RemoveDeployer => {
+            let args = parameters::DeployerArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::RemoveDeployer(args)
+        }
+        TransactionKindTag::SetDeployerAllowlistStatus => {
+            let args =
+                parameters::DeployerAllowlistStatusArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetDeployerAllowlistStatus(args)
+        }
+        TransactionKindTag::WithdrawCollectedFees => {
+            let args = parameters::WithdrawCollectedFeesArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::WithdrawCollectedFees(args)
+        }
+        TransactionKindTag::SetMaxLogsPerTx => {
+            let args = parameters::SetMaxLogsPerTxArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetMaxLogsPerTx(args)
+        }
+        TransactionKindTag::SetEngineMetadata => {
+            let args = parameters::EngineMetadata::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetEngineMetadata(args)
+        }
+        TransactionKindTag::SetGasRefundsEnabled => {
+            let args = bool::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetGasRefundsEnabled(args)
+        }
+        TransactionKindTag::SetEvmMemoryLimit => {
+            let args = parameters::SetEvmMemoryLimitArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetEvmMemoryLimit(args)
+        }
+        TransactionKindTag::SetTipRecipient => {
+            let args = parameters::SetTipRecipientArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetTipRecipient(args)
+        }
+        TransactionKindTag::SetErc20Template => {
+            let args = parameters::SetErc20TemplateArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetErc20Template(args)
+        }
+        TransactionKindTag::ConfigureSilo => {
+            let args = silo_params::SiloConfigArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::ConfigureSilo(args)
+        }
+        TransactionKindTag::SetLogLevel => {
+            let args = parameters::SetLogLevelArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetLogLevel(args)
+        }
+        TransactionKindTag::SetDiagnosticsEnabled => {
+            let args = bool::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetDiagnosticsEnabled(args)
+        }
+        TransactionKindTag::SetModExpAlgorithm => {
+            let args = parameters::SetModExpAlgorithmArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetModExpAlgorithm(args)
+        }
+        TransactionKindTag::SetAllowZeroGasPrice => {
+            let args = bool::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetAllowZeroGasPrice(args)
+        }
+        TransactionKindTag::SetExitFee => {
+            let args = parameters::SetExitFeeArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetExitFee(args)
+        }
+        TransactionKindTag::PauseConnector => TransactionKind::PauseConnector,
+        TransactionKindTag::ResumeConnector => TransactionKind::ResumeConnector,
+        TransactionKindTag::SetFtOnTransferGasLimit => {
+            let args =
+                parameters::SetFtOnTransferGasLimitArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetFtOnTransferGasLimit(args)
+        }
+        TransactionKindTag::SetAllowChainlessTx => {
+            let args = bool::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetAllowChainlessTx(args)
+        }
+        TransactionKindTag::SetAllowEmptyInitCode => {
+            let args = bool::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetAllowEmptyInitCode(args)
+        }
+        TransactionKindTag::SetFeeDistribution => {
+            let args = parameters::SetFeeDistributionArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetFeeDistribution(args)
+        }
+        TransactionKindTag::SetMaxNonceGap => {
+            let args = parameters::SetMaxNonceGapArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetMaxNonceGap(args)
+        }
+        TransactionKindTag::SetXccNearGasPerEvmGas => {
+            let args = xcc::SetNearGasPerEvmGasArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetXccNearGasPerEvmGas(args)
+        }
+        TransactionKindTag::SetPaymaster => {
+            let args = parameters::SetPaymasterArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetPaymaster(args)
+        }
+        TransactionKindTag::SetTxCountTrackingEnabled => {
+            let args = bool::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetTxCountTrackingEnabled(args)
+        }
+        TransactionKindTag::SetViewStepLimit => {
+            let args = parameters::SetViewStepLimitArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetViewStepLimit(args)
+        }
+        TransactionKindTag::ImportWhitelists => {
+            let args = silo_params::WhitelistsArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::ImportWhitelists(args)
+        }
+        TransactionKindTag::SetReceiptsPersistenceEnabled => {
+            let args = bool::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetReceiptsPersistenceEnabled(args)
+        }
+        TransactionKindTag::SetRandomSeedOverride => {
+            let args = parameters::SetRandomSeedOverrideArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetRandomSeedOverride(args)
+        }
+        TransactionKindTag::SubmitBatch => {
+            let args = parameters::SubmitBatchArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SubmitBatch(args)
+        }
+        TransactionKindTag::SetMaxRefundQuotient => {
+            let args = parameters::SetMaxRefundQuotientArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetMaxRefundQuotient(args)
+        }
+        TransactionKindTag::SetUnregisterFee => {
+            let args = parameters::SetUnregisterFeeArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetUnregisterFee(args)
+        }
+        TransactionKindTag::SetRejectUnknownPrecompiles => {
+            let args = bool::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetRejectUnknownPrecompiles(args)
+        }
+        TransactionKindTag::IndexWhitelistEntries => {
+            let args: Vec<silo_params::WhitelistArgs> =
+                BorshDeserialize::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::IndexWhitelistEntries(args)
+        }
+        TransactionKindTag::SetErc20DeployGasLimit => {
+            let args = parameters::SetErc20DeployGasLimitArgs::try_from_slice(&bytes).map_err(f)?;
+            TransactionKind::SetErc20DeployGasLimit(args)
+        }
         TransactionKindTag::Unknown => {
             return Err(ParseTransactionKindError::UnknownMethodName {
                 name: method_name.into(),
@@ -333,14 +492,25 @@ pub fn consume_message<M: ModExpAlgorithm + 'static>(
     }
 }
 
+/// Executes the transaction without committing it to the storage. If `timestamp_override` is
+/// given then the EVM sees it as the block timestamp instead of the one from the stored block
+/// metadata. Similarly, `block_height_override` replaces the block number seen by the EVM,
+/// while the storage is still read at the height of the block containing the transaction.
+/// The overrides are never written to the storage.
 pub fn execute_transaction_message<M: ModExpAlgorithm + 'static>(
     storage: &Storage,
     transaction_message: TransactionMessage,
+    timestamp_override: Option<env::Timestamp>,
+    block_height_override: Option<u64>,
 ) -> Result<TransactionIncludedOutcome, crate::Error> {
     let transaction_position = transaction_message.position;
     let block_hash = transaction_message.block_hash;
     let block_height = storage.get_block_height_by_hash(block_hash)?;
-    let block_metadata = storage.get_block_metadata(block_hash)?;
+    let mut block_metadata = storage.get_block_metadata(block_hash)?;
+    if let Some(timestamp) = timestamp_override {
+        block_metadata.timestamp = timestamp;
+    }
+    let evm_block_height = block_height_override.unwrap_or(block_height);
     let engine_account_id = storage.get_engine_account_id()?;
     let result = storage.with_engine_access(
         block_height,
@@ -349,7 +519,7 @@ pub fn execute_transaction_message<M: ModExpAlgorithm + 'static>(
         |io| {
             execute_transaction::<_, M, _>(
                 &transaction_message,
-                block_height,
+                evm_block_height,
                 &block_metadata,
                 engine_account_id,
                 io,
@@ -412,23 +582,25 @@ where
             };
             let tx_data: Vec<u8> = tx.into();
             let tx_hash = aurora_engine_sdk::keccak(&tx_data);
-            let result = contract_methods::evm_transactions::submit(io, &env, &mut handler)
-                .map(|submit_result| Some(TransactionExecutionResult::Submit(Ok(submit_result))))
-                .map_err(Into::into);
+            let result = submit_outcome(contract_methods::evm_transactions::submit(
+                io,
+                &env,
+                &mut handler,
+            ));
 
             (tx_hash, result)
         }
-        TransactionKind::SubmitWithArgs(args) => {
+        TransactionKind::SubmitWithArgs(parameters::SubmitArgs { tx_data, .. })
+        | TransactionKind::SubmitWithArgsV2(parameters::SubmitArgsV2 { tx_data, .. }) => {
             let mut handler = crate::promise::NoScheduler {
                 promise_data: &transaction_message.promise_data,
             };
-            let tx_hash = aurora_engine_sdk::keccak(&args.tx_data);
-            let result =
-                contract_methods::evm_transactions::submit_with_args(io, &env, &mut handler)
-                    .map(|submit_result| {
-                        Some(TransactionExecutionResult::Submit(Ok(submit_result)))
-                    })
-                    .map_err(Into::into);
+            let tx_hash = aurora_engine_sdk::keccak(tx_data);
+            let result = submit_outcome(contract_methods::evm_transactions::submit_with_args(
+                io,
+                &env,
+                &mut handler,
+            ));
 
             (tx_hash, result)
         }
@@ -438,11 +610,41 @@ where
         }
     };
 
-    let diff = get_diff(&io);
+    // The contract discards the state changes of a `submit` which returned an error.
+    let diff = match &result {
+        Ok(Some(TransactionExecutionResult::SubmitError(_))) => Diff::default(),
+        _ => get_diff(&io),
+    };
 
     (tx_hash, diff, result)
 }
 
+/// Returns the result of `submit` in the standalone form.
+#[cfg(not(feature = "verbose-errors"))]
+fn submit_outcome(
+    result: Result<SubmitResult, contract_methods::ContractError>,
+) -> Result<Option<TransactionExecutionResult>, error::Error> {
+    result
+        .map(|submit_result| Some(TransactionExecutionResult::Submit(Ok(submit_result))))
+        .map_err(Into::into)
+}
+
+/// Returns the result of `submit` in the standalone form. With the `verbose-errors` feature
+/// the contract returns the errors as `SubmitError`s instead of panicking, so the transaction
+/// is included as well.
+#[cfg(feature = "verbose-errors")]
+fn submit_outcome(
+    result: Result<SubmitResult, contract_methods::ContractError>,
+) -> Result<Option<TransactionExecutionResult>, error::Error> {
+    let outcome = match result {
+        Ok(submit_result) => TransactionExecutionResult::Submit(Ok(submit_result)),
+        Err(e) => TransactionExecutionResult::SubmitError(
+            contract_methods::evm_transactions::submit_error(&e),
+        ),
+    };
+    Ok(Some(outcome))
+}
+
 /// Based on nearcore implementation:
 /// <https://github.com/near/nearcore/blob/00ca2f3f73e2a547ba881f76ecc59450dbbef6e2/core/primitives/src/utils.rs#L295>
 fn compute_random_seed(action_hash: &H256, block_random_value: &H256) -> H256 {
@@ -656,7 +858,9 @@ fn non_submit_execute<I: IO + Copy>(
         }
         TransactionKind::Unknown => None,
         // Not handled in this function; is handled by the general `execute_transaction` function
-        TransactionKind::Submit(_) | TransactionKind::SubmitWithArgs(_) => unreachable!(),
+        TransactionKind::Submit(_)
+        | TransactionKind::SubmitWithArgs(_)
+        | TransactionKind::SubmitWithArgsV2(_) => unreachable!(),
         TransactionKind::PausePrecompiles(_) => {
             contract_methods::admin::pause_precompiles(io, env)?;
 
@@ -745,6 +949,187 @@ fn non_submit_execute<I: IO + Copy>(
 
             None
         }
+        TransactionKind::SetRelaxedNonce(_) => {
+            contract_methods::admin::set_relaxed_nonce(io, env)?;
+
+            None
+        }
+        TransactionKind::RegisterRelayersBatch(_) => {
+            contract_methods::admin::register_relayers_batch(io, env)?;
+
+            None
+        }
+        TransactionKind::AddDeployer(_) => {
+            contract_methods::admin::add_deployer(io, env)?;
+
+            None
+        }
+        TransactionKind::RemoveDeployer(_) => {
+            contract_methods::admin::remove_deployer(io, env)?;
+
+            None
+        }
+        TransactionKind::SetDeployerAllowlistStatus(_) => {
+            contract_methods::admin::set_deployer_allowlist_status(io, env)?;
+
+            None
+        }
+        TransactionKind::WithdrawCollectedFees(_) => {
+            contract_methods::admin::withdraw_collected_fees(io, env)?;
+
+            None
+        }
+        TransactionKind::SetMaxLogsPerTx(_) => {
+            contract_methods::admin::set_max_logs_per_tx(io, env)?;
+
+            None
+        }
+        TransactionKind::SetEngineMetadata(_) => {
+            contract_methods::admin::set_engine_metadata(io, env)?;
+
+            None
+        }
+        TransactionKind::SetGasRefundsEnabled(enabled) => {
+            silo::set_gas_refunds_enabled(&mut io, *enabled);
+            None
+        }
+        TransactionKind::SetEvmMemoryLimit(_) => {
+            contract_methods::admin::set_evm_memory_limit(io, env)?;
+
+            None
+        }
+        TransactionKind::SetTipRecipient(_) => {
+            contract_methods::admin::set_tip_recipient(io, env)?;
+
+            None
+        }
+        TransactionKind::SetErc20Template(_) => {
+            contract_methods::admin::set_erc20_template(io, env)?;
+
+            None
+        }
+        TransactionKind::ConfigureSilo(args) => {
+            silo::configure_silo(&mut io, args.clone())?;
+
+            None
+        }
+        TransactionKind::SetLogLevel(_) => {
+            contract_methods::admin::set_log_level(io, env)?;
+
+            None
+        }
+        TransactionKind::SetDiagnosticsEnabled(_) => {
+            contract_methods::admin::set_diagnostics_enabled(io, env)?;
+
+            None
+        }
+        TransactionKind::SetModExpAlgorithm(_) => {
+            contract_methods::admin::set_modexp_algorithm(io, env)?;
+
+            None
+        }
+        TransactionKind::SetAllowZeroGasPrice(_) => {
+            contract_methods::admin::set_allow_zero_gas_price(io, env)?;
+
+            None
+        }
+        TransactionKind::SetExitFee(_) => {
+            contract_methods::admin::set_exit_fee(io, env)?;
+
+            None
+        }
+        TransactionKind::PauseConnector => {
+            #[cfg(not(feature = "ext-connector"))]
+            contract_methods::connector::pause_connector(io, env)?;
+
+            None
+        }
+        TransactionKind::ResumeConnector => {
+            #[cfg(not(feature = "ext-connector"))]
+            contract_methods::connector::resume_connector(io, env)?;
+
+            None
+        }
+        TransactionKind::SetFtOnTransferGasLimit(_) => {
+            contract_methods::admin::set_ft_on_transfer_gas_limit(io, env)?;
+            None
+        }
+        TransactionKind::SetAllowChainlessTx(_) => {
+            contract_methods::admin::set_allow_chainless_tx(io, env)?;
+            None
+        }
+        TransactionKind::SetAllowEmptyInitCode(_) => {
+            contract_methods::admin::set_allow_empty_init_code(io, env)?;
+            None
+        }
+        TransactionKind::SetFeeDistribution(_) => {
+            contract_methods::admin::set_fee_distribution(io, env)?;
+
+            None
+        }
+        TransactionKind::SetMaxNonceGap(_) => {
+            contract_methods::admin::set_max_nonce_gap(io, env)?;
+
+            None
+        }
+        TransactionKind::SetXccNearGasPerEvmGas(_) => {
+            contract_methods::xcc::set_xcc_near_gas_per_evm_gas(io, env)?;
+
+            None
+        }
+        TransactionKind::SetPaymaster(_) => {
+            contract_methods::admin::set_paymaster(io, env)?;
+
+            None
+        }
+        TransactionKind::SetTxCountTrackingEnabled(_) => {
+            contract_methods::admin::set_tx_count_tracking_enabled(io, env)?;
+
+            None
+        }
+        TransactionKind::SetViewStepLimit(_) => {
+            contract_methods::admin::set_view_step_limit(io, env)?;
+            None
+        }
+        TransactionKind::ImportWhitelists(_) => {
+            contract_methods::admin::import_whitelists(io, env)?;
+            None
+        }
+        TransactionKind::SetReceiptsPersistenceEnabled(_) => {
+            contract_methods::admin::set_receipts_persistence_enabled(io, env)?;
+            None
+        }
+        TransactionKind::SetRandomSeedOverride(_) => {
+            contract_methods::admin::set_random_seed_override(io, env)?;
+            None
+        }
+        TransactionKind::SubmitBatch(_) => {
+            // We can ignore promises in the standalone engine (see above)
+            let mut handler = crate::promise::NoScheduler { promise_data };
+            let results = contract_methods::evm_transactions::submit_batch(io, env, &mut handler)?;
+
+            Some(TransactionExecutionResult::SubmitBatch(results))
+        }
+        TransactionKind::SetMaxRefundQuotient(_) => {
+            contract_methods::admin::set_max_refund_quotient(io, env)?;
+            None
+        }
+        TransactionKind::SetUnregisterFee(_) => {
+            contract_methods::admin::set_unregister_fee(io, env)?;
+            None
+        }
+        TransactionKind::SetRejectUnknownPrecompiles(_) => {
+            contract_methods::admin::set_reject_unknown_precompiles(io, env)?;
+            None
+        }
+        TransactionKind::IndexWhitelistEntries(_) => {
+            contract_methods::admin::index_whitelist_entries(io, env)?;
+            None
+        }
+        TransactionKind::SetErc20DeployGasLimit(_) => {
+            contract_methods::admin::set_erc20_deploy_gas_limit(io, env)?;
+            None
+        }
     };
 
     Ok(result)
@@ -789,6 +1174,9 @@ pub enum TransactionExecutionResult {
     Submit(engine::EngineResult<SubmitResult>),
     DeployErc20(Address),
     Promise(PromiseWithCallbackArgs),
+    SubmitBatch(parameters::SubmitBatchResult),
+    /// Error returned by `submit` in the engines built with the `verbose-errors` feature.
+    SubmitError(parameters::SubmitError),
 }
 
 pub mod error {
@@ -1,11 +1,13 @@
 use crate::Storage;
 use aurora_engine::contract_methods::connector::deposit_event;
 use aurora_engine::parameters;
-use aurora_engine::xcc::{AddressVersionUpdateArgs, FundXccArgs};
-use aurora_engine_transactions::{EthTransactionKind, NormalizedEthTransaction};
+use aurora_engine::xcc::{AddressVersionUpdateArgs, FundXccArgs, FundXccArgsV2, FundXccCallArgs};
+use aurora_engine_transactions::{
+    eip_2930::AccessTuple, EthTransactionKind, NormalizedEthTransaction,
+};
 use aurora_engine_types::account_id::AccountId;
 use aurora_engine_types::parameters::silo;
-use aurora_engine_types::parameters::xcc::WithdrawWnearToRouterArgs;
+use aurora_engine_types::parameters::xcc::{SetNearGasPerEvmGasArgs, WithdrawWnearToRouterArgs};
 use aurora_engine_types::types::Address;
 use aurora_engine_types::{
     borsh::{self, BorshDeserialize, BorshSerialize},
@@ -84,6 +86,13 @@ impl TransactionMessage {
             std::io::Error::new(std::io::ErrorKind::Other, message)
         })
     }
+
+    /// Returns the decoded access list of the Ethereum transaction contained in this message.
+    /// See [`TransactionKind::access_list`] for details.
+    #[must_use]
+    pub fn access_list(&self) -> Option<Vec<AccessTuple>> {
+        self.transaction.access_list()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -93,6 +102,8 @@ pub enum TransactionKind {
     Submit(EthTransactionKind),
     /// Raw Ethereum transaction with additional arguments submitted to the engine
     SubmitWithArgs(parameters::SubmitArgs),
+    /// Raw Ethereum transaction with additional arguments and a deadline submitted to the engine
+    SubmitWithArgsV2(parameters::SubmitArgsV2),
     /// Ethereum transaction triggered by a NEAR account
     Call(parameters::CallArgs),
     /// Administrative method that makes a subset of precompiles paused
@@ -102,7 +113,7 @@ pub enum TransactionKind {
     /// Input here represents the EVM code used to create the new contract
     Deploy(Vec<u8>),
     /// New bridged token
-    DeployErc20(parameters::DeployErc20TokenArgs),
+    DeployErc20(parameters::DeployErc20TokenCallArgs),
     /// This type of transaction can impact the aurora state because of the bridge
     FtOnTransfer(parameters::NEP141FtOnTransferArgs),
     /// Bytes here will be parsed into `aurora_engine::proof::Proof`
@@ -147,7 +158,7 @@ pub enum TransactionKind {
     /// Update the version of a deployed xcc-router contract
     FactoryUpdateAddressVersion(AddressVersionUpdateArgs),
     FactorySetWNearAddress(Address),
-    FundXccSubAccount(FundXccArgs),
+    FundXccSubAccount(FundXccCallArgs),
     /// Self-call used during XCC flow to move wNEAR tokens to user's XCC account
     WithdrawWnearToRouter(WithdrawWnearToRouterArgs),
     /// Pause the contract
@@ -172,12 +183,111 @@ pub enum TransactionKind {
     SetWhitelistStatus(silo::WhitelistStatusArgs),
     /// Callback which mirrors existed ERC-20 contract deployed on the main contract.
     MirrorErc20TokenCallback(parameters::MirrorErc20TokenArgs),
+    /// Enable or disable the relaxed nonce mode for a relayer
+    SetRelaxedNonce(parameters::SetRelaxedNonceArgs),
+    /// Register several relayers at once (owner or key manager only)
+    RegisterRelayersBatch(parameters::RegisterRelayersBatchArgs),
+    /// Add an address to the deployer allowlist
+    AddDeployer(parameters::DeployerArgs),
+    /// Remove an address from the deployer allowlist
+    RemoveDeployer(parameters::DeployerArgs),
+    /// Enable or disable the deployer allowlist
+    SetDeployerAllowlistStatus(parameters::DeployerAllowlistStatusArgs),
+    /// Move the fees collected by the engine to a treasury address
+    WithdrawCollectedFees(parameters::WithdrawCollectedFeesArgs),
+    /// Set the maximum number of logs a transaction may emit
+    SetMaxLogsPerTx(parameters::SetMaxLogsPerTxArgs),
+    /// Set the human-readable description of the engine instance
+    SetEngineMetadata(parameters::EngineMetadata),
+    /// Enable or disable the gas refunds
+    SetGasRefundsEnabled(bool),
+    /// Set the maximum size of the EVM memory
+    SetEvmMemoryLimit(parameters::SetEvmMemoryLimitArgs),
+    /// Set the address which receives the priority fees
+    SetTipRecipient(parameters::SetTipRecipientArgs),
+    /// Set the bytecode used to deploy the bridged ERC-20 tokens
+    SetErc20Template(parameters::SetErc20TemplateArgs),
+    /// Apply the whole silo configuration at once
+    ConfigureSilo(silo::SiloConfigArgs),
+    /// Set the verbosity of the engine logs
+    SetLogLevel(parameters::SetLogLevelArgs),
+    /// Enable or disable the optional details of the submit results
+    SetDiagnosticsEnabled(bool),
+    /// Select the implementation of the modexp precompile
+    SetModExpAlgorithm(parameters::SetModExpAlgorithmArgs),
+    /// Allow or reject the transactions with a zero gas price
+    SetAllowZeroGasPrice(bool),
+    /// Set the fee charged on the exits of a token
+    SetExitFee(parameters::SetExitFeeArgs),
+    /// Pause the deposits, withdrawals and transfers of the eth-connector
+    PauseConnector,
+    /// Resume the deposits, withdrawals and transfers of the eth-connector
+    ResumeConnector,
+    /// Set the EVM gas limit of ERC-20 mints triggered by `ft_on_transfer`
+    SetFtOnTransferGasLimit(parameters::SetFtOnTransferGasLimitArgs),
+    /// Allow or reject the legacy transactions without a chain ID
+    SetAllowChainlessTx(bool),
+    /// Allow or reject the contract creations with an empty init code
+    SetAllowEmptyInitCode(bool),
+    /// Set the shares used to split the collected fees
+    SetFeeDistribution(parameters::SetFeeDistributionArgs),
+    /// Set the maximum nonce gap allowed in the relaxed nonce mode
+    SetMaxNonceGap(parameters::SetMaxNonceGapArgs),
+    /// Set the NEAR gas charged as one EVM gas by the cross-contract calls
+    SetXccNearGasPerEvmGas(SetNearGasPerEvmGasArgs),
+    /// Set the contract which may sponsor the gas of the transactions
+    SetPaymaster(parameters::SetPaymasterArgs),
+    /// Enable or disable counting the transactions submitted by each address
+    SetTxCountTrackingEnabled(bool),
+    /// Set the maximum number of EVM opcodes a view call may execute
+    SetViewStepLimit(parameters::SetViewStepLimitArgs),
+    /// Replace all the white lists with an exported blob
+    ImportWhitelists(silo::WhitelistsArgs),
+    /// Enable or disable persisting the receipts of the submitted transactions
+    SetReceiptsPersistenceEnabled(bool),
+    /// Pin the seed returned by the random seed precompile
+    SetRandomSeedOverride(parameters::SetRandomSeedOverrideArgs),
+    /// Execute several Ethereum transactions in order
+    SubmitBatch(parameters::SubmitBatchArgs),
+    /// Set the quotient of the refund cap
+    SetMaxRefundQuotient(parameters::SetMaxRefundQuotientArgs),
+    /// Set the fee withheld from the refund of `storage_unregister`
+    SetUnregisterFee(parameters::SetUnregisterFeeArgs),
+    /// Revert or allow the calls to the unimplemented precompile addresses
+    SetRejectUnknownPrecompiles(bool),
+    /// Index the white list entries added before the white lists kept an index
+    IndexWhitelistEntries(Vec<silo::WhitelistArgs>),
+    /// Set the EVM gas limit of the ERC-20 deployments made by `deploy_erc20_token`
+    SetErc20DeployGasLimit(parameters::SetErc20DeployGasLimitArgs),
     /// Sentinel kind for cases where a NEAR receipt caused a
     /// change in Aurora state, but we failed to parse the Action.
     Unknown,
 }
 
 impl TransactionKind {
+    /// Returns the access list of the Ethereum transaction (EIP-2930 or EIP-1559) contained in
+    /// this transaction. Returns `None` if there is no Ethereum transaction, if it cannot be
+    /// decoded or if it is a legacy transaction (which does not have an access list).
+    #[must_use]
+    pub fn access_list(&self) -> Option<Vec<AccessTuple>> {
+        let decoded;
+        let eth_tx_kind = match self {
+            Self::Submit(eth_tx_kind) => eth_tx_kind,
+            Self::SubmitWithArgs(parameters::SubmitArgs { tx_data, .. })
+            | Self::SubmitWithArgsV2(parameters::SubmitArgsV2 { tx_data, .. }) => {
+                decoded = EthTransactionKind::try_from(tx_data.as_slice()).ok()?;
+                &decoded
+            }
+            _ => return None,
+        };
+
+        match eth_tx_kind {
+            EthTransactionKind::Legacy(_) => None,
+            EthTransactionKind::Eip2930(tx) => Some(tx.transaction.access_list.clone()),
+            EthTransactionKind::Eip1559(tx) => Some(tx.transaction.access_list.clone()),
+        }
+    }
+
     #[must_use]
     #[allow(clippy::too_many_lines)]
     pub fn eth_repr(
@@ -193,9 +303,12 @@ impl TransactionKind {
             Self::Submit(eth_tx_kind) => eth_tx_kind
                 .try_into()
                 .unwrap_or_else(|_| Self::no_evm_execution("submit")),
-            Self::SubmitWithArgs(args) => EthTransactionKind::try_from(args.tx_data.as_slice())
-                .and_then(TryInto::try_into)
-                .unwrap_or_else(|_| Self::no_evm_execution("submit_with_args")),
+            Self::SubmitWithArgs(parameters::SubmitArgs { tx_data, .. })
+            | Self::SubmitWithArgsV2(parameters::SubmitArgsV2 { tx_data, .. }) => {
+                EthTransactionKind::try_from(tx_data.as_slice())
+                    .and_then(TryInto::try_into)
+                    .unwrap_or_else(|_| Self::no_evm_execution("submit_with_args"))
+            }
             Self::Call(call_args) => {
                 let from = Self::get_implicit_address(caller);
                 let nonce =
@@ -242,7 +355,16 @@ impl TransactionKind {
                 let from = Self::get_implicit_address(caller);
                 let nonce =
                     Self::get_implicit_nonce(&from, block_height, transaction_position, storage);
-                let data = aurora_engine::engine::setup_deploy_erc20_input(engine_account, None);
+                let erc20_template = storage
+                    .with_engine_access(block_height, transaction_position, &[], |io| {
+                        aurora_engine::engine::get_erc20_template(&io)
+                    })
+                    .result;
+                let data = aurora_engine::engine::setup_deploy_erc20_input(
+                    engine_account,
+                    None,
+                    erc20_template.as_deref(),
+                );
                 NormalizedEthTransaction {
                     address: from,
                     chain_id: None,
@@ -451,6 +573,57 @@ impl TransactionKind {
             Self::MirrorErc20TokenCallback(_) => {
                 Self::no_evm_execution("mirror_erc20_token_callback")
             }
+            Self::SetRelaxedNonce(_) => Self::no_evm_execution("set_relaxed_nonce"),
+            Self::RegisterRelayersBatch(_) => Self::no_evm_execution("register_relayers_batch"),
+            Self::AddDeployer(_) => Self::no_evm_execution("add_deployer"),
+            Self::RemoveDeployer(_) => Self::no_evm_execution("remove_deployer"),
+            Self::SetDeployerAllowlistStatus(_) => {
+                Self::no_evm_execution("set_deployer_allowlist_status")
+            }
+            Self::WithdrawCollectedFees(_) => Self::no_evm_execution("withdraw_collected_fees"),
+            Self::SetMaxLogsPerTx(_) => Self::no_evm_execution("set_max_logs_per_tx"),
+            Self::SetEngineMetadata(_) => Self::no_evm_execution("set_engine_metadata"),
+            Self::SetGasRefundsEnabled(_) => Self::no_evm_execution("set_gas_refunds_enabled"),
+            Self::SetEvmMemoryLimit(_) => Self::no_evm_execution("set_evm_memory_limit"),
+            Self::SetTipRecipient(_) => Self::no_evm_execution("set_tip_recipient"),
+            Self::SetErc20Template(_) => Self::no_evm_execution("set_erc20_template"),
+            Self::ConfigureSilo(_) => Self::no_evm_execution("configure_silo"),
+            Self::SetLogLevel(_) => Self::no_evm_execution("set_log_level"),
+            Self::SetDiagnosticsEnabled(_) => Self::no_evm_execution("set_diagnostics_enabled"),
+            Self::SetModExpAlgorithm(_) => Self::no_evm_execution("set_modexp_algorithm"),
+            Self::SetAllowZeroGasPrice(_) => Self::no_evm_execution("set_allow_zero_gas_price"),
+            Self::SetExitFee(_) => Self::no_evm_execution("set_exit_fee"),
+            Self::PauseConnector => Self::no_evm_execution("pause_connector"),
+            Self::ResumeConnector => Self::no_evm_execution("resume_connector"),
+            Self::SetFtOnTransferGasLimit(_) => {
+                Self::no_evm_execution("set_ft_on_transfer_gas_limit")
+            }
+            Self::SetAllowChainlessTx(_) => Self::no_evm_execution("set_allow_chainless_tx"),
+            Self::SetAllowEmptyInitCode(_) => Self::no_evm_execution("set_allow_empty_init_code"),
+            Self::SetFeeDistribution(_) => Self::no_evm_execution("set_fee_distribution"),
+            Self::SetMaxNonceGap(_) => Self::no_evm_execution("set_max_nonce_gap"),
+            Self::SetXccNearGasPerEvmGas(_) => {
+                Self::no_evm_execution("set_xcc_near_gas_per_evm_gas")
+            }
+            Self::SetPaymaster(_) => Self::no_evm_execution("set_paymaster"),
+            Self::SetTxCountTrackingEnabled(_) => {
+                Self::no_evm_execution("set_tx_count_tracking_enabled")
+            }
+            Self::SetViewStepLimit(_) => Self::no_evm_execution("set_view_step_limit"),
+            Self::ImportWhitelists(_) => Self::no_evm_execution("import_whitelists"),
+            Self::SetReceiptsPersistenceEnabled(_) => {
+                Self::no_evm_execution("set_receipts_persistence_enabled")
+            }
+            Self::SetRandomSeedOverride(_) => Self::no_evm_execution("set_random_seed_override"),
+            // A batch contains several EVM transactions, which don't fit a single representation
+            Self::SubmitBatch(_) => Self::no_evm_execution("submit_batch"),
+            Self::SetMaxRefundQuotient(_) => Self::no_evm_execution("set_max_refund_quotient"),
+            Self::SetUnregisterFee(_) => Self::no_evm_execution("set_unregister_fee"),
+            Self::SetRejectUnknownPrecompiles(_) => {
+                Self::no_evm_execution("set_reject_unknown_precompiles")
+            }
+            Self::IndexWhitelistEntries(_) => Self::no_evm_execution("index_whitelist_entries"),
+            Self::SetErc20DeployGasLimit(_) => Self::no_evm_execution("set_erc20_deploy_gas_limit"),
         }
     }
 
@@ -581,6 +754,82 @@ pub enum TransactionKindTag {
     MirrorErc20TokenCallback,
     #[strum(serialize = "withdraw_wnear_to_router")]
     WithdrawWnearToRouter,
+    #[strum(serialize = "set_relaxed_nonce")]
+    SetRelaxedNonce,
+    #[strum(serialize = "register_relayers_batch")]
+    RegisterRelayersBatch,
+    #[strum(serialize = "add_deployer")]
+    AddDeployer,
+    #[strum(serialize = "remove_deployer")]
+    RemoveDeployer,
+    #[strum(serialize = "set_deployer_allowlist_status")]
+    SetDeployerAllowlistStatus,
+    #[strum(serialize = "withdraw_collected_fees")]
+    WithdrawCollectedFees,
+    #[strum(serialize = "set_max_logs_per_tx")]
+    SetMaxLogsPerTx,
+    #[strum(serialize = "set_engine_metadata")]
+    SetEngineMetadata,
+    #[strum(serialize = "set_gas_refunds_enabled")]
+    SetGasRefundsEnabled,
+    #[strum(serialize = "set_evm_memory_limit")]
+    SetEvmMemoryLimit,
+    #[strum(serialize = "set_tip_recipient")]
+    SetTipRecipient,
+    #[strum(serialize = "set_erc20_template")]
+    SetErc20Template,
+    #[strum(serialize = "configure_silo")]
+    ConfigureSilo,
+    #[strum(serialize = "set_log_level")]
+    SetLogLevel,
+    #[strum(serialize = "set_diagnostics_enabled")]
+    SetDiagnosticsEnabled,
+    #[strum(serialize = "set_modexp_algorithm")]
+    SetModExpAlgorithm,
+    #[strum(serialize = "set_allow_zero_gas_price")]
+    SetAllowZeroGasPrice,
+    #[strum(serialize = "set_exit_fee")]
+    SetExitFee,
+    #[strum(serialize = "pause_connector")]
+    PauseConnector,
+    #[strum(serialize = "resume_connector")]
+    ResumeConnector,
+    #[strum(serialize = "set_ft_on_transfer_gas_limit")]
+    SetFtOnTransferGasLimit,
+    #[strum(serialize = "set_allow_chainless_tx")]
+    SetAllowChainlessTx,
+    #[strum(serialize = "set_allow_empty_init_code")]
+    SetAllowEmptyInitCode,
+    #[strum(serialize = "set_fee_distribution")]
+    SetFeeDistribution,
+    #[strum(serialize = "set_max_nonce_gap")]
+    SetMaxNonceGap,
+    #[strum(serialize = "set_xcc_near_gas_per_evm_gas")]
+    SetXccNearGasPerEvmGas,
+    #[strum(serialize = "set_paymaster")]
+    SetPaymaster,
+    #[strum(serialize = "set_tx_count_tracking_enabled")]
+    SetTxCountTrackingEnabled,
+    #[strum(serialize = "set_view_step_limit")]
+    SetViewStepLimit,
+    #[strum(serialize = "import_whitelists")]
+    ImportWhitelists,
+    #[strum(serialize = "set_receipts_persistence_enabled")]
+    SetReceiptsPersistenceEnabled,
+    #[strum(serialize = "set_random_seed_override")]
+    SetRandomSeedOverride,
+    #[strum(serialize = "submit_batch")]
+    SubmitBatch,
+    #[strum(serialize = "set_max_refund_quotient")]
+    SetMaxRefundQuotient,
+    #[strum(serialize = "set_unregister_fee")]
+    SetUnregisterFee,
+    #[strum(serialize = "set_reject_unknown_precompiles")]
+    SetRejectUnknownPrecompiles,
+    #[strum(serialize = "index_whitelist_entries")]
+    IndexWhitelistEntries,
+    #[strum(serialize = "set_erc20_deploy_gas_limit")]
+    SetErc20DeployGasLimit,
     Unknown,
 }
 
@@ -590,12 +839,18 @@ impl TransactionKind {
         match self {
             Self::Submit(tx) => tx.into(),
             Self::SubmitWithArgs(args) => to_borsh(args),
+            Self::SubmitWithArgsV2(args) => to_borsh(args),
             Self::Call(args) => to_borsh(args),
             Self::PausePrecompiles(args) | Self::ResumePrecompiles(args) => to_borsh(args),
             Self::Deploy(bytes) | Self::Deposit(bytes) | Self::FactoryUpdate(bytes) => {
                 bytes.clone()
             }
-            Self::DeployErc20(args) => to_borsh(args),
+            Self::DeployErc20(args) => match args {
+                // Legacy arguments are passed to the contract without the enum wrapper.
+                parameters::DeployErc20TokenCallArgs::V1(args) => to_borsh(args),
+                parameters::DeployErc20TokenCallArgs::V2(_)
+                | parameters::DeployErc20TokenCallArgs::V3(_) => to_borsh(args),
+            },
             Self::FtOnTransfer(args) => to_json(args),
             Self::FtTransferCall(args) => to_json(args),
             Self::FinishDeposit(args) => to_borsh(args),
@@ -618,9 +873,17 @@ impl TransactionKind {
             Self::NewConnector(args) | Self::SetConnectorData(args) => to_borsh(args),
             Self::NewEngine(args) => to_borsh(args),
             Self::FactoryUpdateAddressVersion(args) => to_borsh(args),
-            Self::FundXccSubAccount(args) => to_borsh(args),
+            Self::FundXccSubAccount(args) => match args {
+                // Legacy arguments are passed to the contract without the enum wrapper.
+                FundXccCallArgs::V1(args) => to_borsh(args),
+                FundXccCallArgs::V2(_) => to_borsh(args),
+            },
             Self::WithdrawWnearToRouter(args) => to_borsh(args),
-            Self::PauseContract | Self::ResumeContract | Self::Unknown => Vec::new(),
+            Self::PauseContract
+            | Self::ResumeContract
+            | Self::PauseConnector
+            | Self::ResumeConnector
+            | Self::Unknown => Vec::new(),
             Self::SetKeyManager(args) => to_borsh(args),
             Self::AddRelayerKey(args) | Self::RemoveRelayerKey(args) => to_borsh(args),
             Self::StartHashchain(args) => to_borsh(args),
@@ -634,6 +897,42 @@ impl TransactionKind {
             Self::SetWhitelistStatus(args) => to_borsh(args),
             Self::SetEthConnectorContractAccount(args) => to_borsh(args),
             Self::MirrorErc20TokenCallback(args) => to_borsh(args),
+            Self::SetRelaxedNonce(args) => to_borsh(args),
+            Self::RegisterRelayersBatch(args) => to_borsh(args),
+            Self::AddDeployer(args) => to_borsh(args),
+            Self::RemoveDeployer(args) => to_borsh(args),
+            Self::SetDeployerAllowlistStatus(args) => to_borsh(args),
+            Self::WithdrawCollectedFees(args) => to_borsh(args),
+            Self::SetMaxLogsPerTx(args) => to_borsh(args),
+            Self::SetEngineMetadata(args) => to_borsh(args),
+            Self::SetGasRefundsEnabled(args) => to_borsh(args),
+            Self::SetEvmMemoryLimit(args) => to_borsh(args),
+            Self::SetTipRecipient(args) => to_borsh(args),
+            Self::SetErc20Template(args) => to_borsh(args),
+            Self::ConfigureSilo(args) => to_borsh(args),
+            Self::SetLogLevel(args) => to_borsh(args),
+            Self::SetDiagnosticsEnabled(args) => to_borsh(args),
+            Self::SetModExpAlgorithm(args) => to_borsh(args),
+            Self::SetAllowZeroGasPrice(args) => to_borsh(args),
+            Self::SetExitFee(args) => to_borsh(args),
+            Self::SetFtOnTransferGasLimit(args) => to_borsh(args),
+            Self::SetAllowChainlessTx(args) => to_borsh(args),
+            Self::SetAllowEmptyInitCode(args) => to_borsh(args),
+            Self::SetFeeDistribution(args) => to_borsh(args),
+            Self::SetMaxNonceGap(args) => to_borsh(args),
+            Self::SetXccNearGasPerEvmGas(args) => to_borsh(args),
+            Self::SetPaymaster(args) => to_borsh(args),
+            Self::SetTxCountTrackingEnabled(args) => to_borsh(args),
+            Self::SetViewStepLimit(args) => to_borsh(args),
+            Self::ImportWhitelists(args) => to_borsh(args),
+            Self::SetReceiptsPersistenceEnabled(args) => to_borsh(args),
+            Self::SetRandomSeedOverride(args) => to_borsh(args),
+            Self::SubmitBatch(args) => to_borsh(args),
+            Self::SetMaxRefundQuotient(args) => to_borsh(args),
+            Self::SetUnregisterFee(args) => to_borsh(args),
+            Self::SetRejectUnknownPrecompiles(args) => to_borsh(args),
+            Self::IndexWhitelistEntries(args) => to_borsh(args),
+            Self::SetErc20DeployGasLimit(args) => to_borsh(args),
         }
     }
 }
@@ -677,7 +976,9 @@ impl From<&TransactionKind> for TransactionKindTag {
             TransactionKind::FactorySetWNearAddress(_) => Self::FactorySetWNearAddress,
             TransactionKind::WithdrawWnearToRouter(_) => Self::WithdrawWnearToRouter,
             TransactionKind::SetOwner(_) => Self::SetOwner,
-            TransactionKind::SubmitWithArgs(_) => Self::SubmitWithArgs,
+            TransactionKind::SubmitWithArgs(_) | TransactionKind::SubmitWithArgsV2(_) => {
+                Self::SubmitWithArgs
+            }
             TransactionKind::SetUpgradeDelayBlocks(_) => Self::SetUpgradeDelayBlocks,
             TransactionKind::FundXccSubAccount(_) => Self::FundXccSubAccount,
             TransactionKind::PauseContract => Self::PauseContract,
@@ -698,6 +999,46 @@ impl From<&TransactionKind> for TransactionKindTag {
             TransactionKind::SetWhitelistStatus(_) => Self::SetWhitelistStatus,
             TransactionKind::Unknown => Self::Unknown,
             TransactionKind::MirrorErc20TokenCallback(_) => Self::MirrorErc20TokenCallback,
+            TransactionKind::SetRelaxedNonce(_) => Self::SetRelaxedNonce,
+            TransactionKind::RegisterRelayersBatch(_) => Self::RegisterRelayersBatch,
+            TransactionKind::AddDeployer(_) => Self::AddDeployer,
+            TransactionKind::RemoveDeployer(_) => Self::RemoveDeployer,
+            TransactionKind::SetDeployerAllowlistStatus(_) => Self::SetDeployerAllowlistStatus,
+            TransactionKind::WithdrawCollectedFees(_) => Self::WithdrawCollectedFees,
+            TransactionKind::SetMaxLogsPerTx(_) => Self::SetMaxLogsPerTx,
+            TransactionKind::SetEngineMetadata(_) => Self::SetEngineMetadata,
+            TransactionKind::SetGasRefundsEnabled(_) => Self::SetGasRefundsEnabled,
+            TransactionKind::SetEvmMemoryLimit(_) => Self::SetEvmMemoryLimit,
+            TransactionKind::SetTipRecipient(_) => Self::SetTipRecipient,
+            TransactionKind::SetErc20Template(_) => Self::SetErc20Template,
+            TransactionKind::ConfigureSilo(_) => Self::ConfigureSilo,
+            TransactionKind::SetLogLevel(_) => Self::SetLogLevel,
+            TransactionKind::SetDiagnosticsEnabled(_) => Self::SetDiagnosticsEnabled,
+            TransactionKind::SetModExpAlgorithm(_) => Self::SetModExpAlgorithm,
+            TransactionKind::SetAllowZeroGasPrice(_) => Self::SetAllowZeroGasPrice,
+            TransactionKind::SetExitFee(_) => Self::SetExitFee,
+            TransactionKind::PauseConnector => Self::PauseConnector,
+            TransactionKind::ResumeConnector => Self::ResumeConnector,
+            TransactionKind::SetFtOnTransferGasLimit(_) => Self::SetFtOnTransferGasLimit,
+            TransactionKind::SetAllowChainlessTx(_) => Self::SetAllowChainlessTx,
+            TransactionKind::SetAllowEmptyInitCode(_) => Self::SetAllowEmptyInitCode,
+            TransactionKind::SetFeeDistribution(_) => Self::SetFeeDistribution,
+            TransactionKind::SetMaxNonceGap(_) => Self::SetMaxNonceGap,
+            TransactionKind::SetXccNearGasPerEvmGas(_) => Self::SetXccNearGasPerEvmGas,
+            TransactionKind::SetPaymaster(_) => Self::SetPaymaster,
+            TransactionKind::SetTxCountTrackingEnabled(_) => Self::SetTxCountTrackingEnabled,
+            TransactionKind::SetViewStepLimit(_) => Self::SetViewStepLimit,
+            TransactionKind::ImportWhitelists(_) => Self::ImportWhitelists,
+            TransactionKind::SetReceiptsPersistenceEnabled(_) => {
+                Self::SetReceiptsPersistenceEnabled
+            }
+            TransactionKind::SetRandomSeedOverride(_) => Self::SetRandomSeedOverride,
+            TransactionKind::SubmitBatch(_) => Self::SubmitBatch,
+            TransactionKind::SetMaxRefundQuotient(_) => Self::SetMaxRefundQuotient,
+            TransactionKind::SetUnregisterFee(_) => Self::SetUnregisterFee,
+            TransactionKind::SetRejectUnknownPrecompiles(_) => Self::SetRejectUnknownPrecompiles,
+            TransactionKind::IndexWhitelistEntries(_) => Self::IndexWhitelistEntries,
+            TransactionKind::SetErc20DeployGasLimit(_) => Self::SetErc20DeployGasLimit,
         }
     }
 }
@@ -928,6 +1269,48 @@ enum BorshableTransactionKind<'a> {
     SetEthConnectorContractAccount(Cow<'a, parameters::SetEthConnectorContractAccountArgs>),
     MirrorErc20TokenCallback(Cow<'a, parameters::MirrorErc20TokenArgs>),
     WithdrawWnearToRouter(Cow<'a, WithdrawWnearToRouterArgs>),
+    SetRelaxedNonce(Cow<'a, parameters::SetRelaxedNonceArgs>),
+    RegisterRelayersBatch(Cow<'a, parameters::RegisterRelayersBatchArgs>),
+    AddDeployer(Cow<'a, parameters::DeployerArgs>),
+    RemoveDeployer(Cow<'a, parameters::DeployerArgs>),
+    SetDeployerAllowlistStatus(Cow<'a, parameters::DeployerAllowlistStatusArgs>),
+    DeployErc20V2(Cow<'a, parameters::DeployErc20TokenArgsV2>),
+    WithdrawCollectedFees(Cow<'a, parameters::WithdrawCollectedFeesArgs>),
+    SetMaxLogsPerTx(Cow<'a, parameters::SetMaxLogsPerTxArgs>),
+    SetEngineMetadata(Cow<'a, parameters::EngineMetadata>),
+    SetGasRefundsEnabled(Cow<'a, bool>),
+    SetEvmMemoryLimit(Cow<'a, parameters::SetEvmMemoryLimitArgs>),
+    SetTipRecipient(Cow<'a, parameters::SetTipRecipientArgs>),
+    SetErc20Template(Cow<'a, parameters::SetErc20TemplateArgs>),
+    ConfigureSilo(Cow<'a, silo::SiloConfigArgs>),
+    SetLogLevel(Cow<'a, parameters::SetLogLevelArgs>),
+    SetDiagnosticsEnabled(Cow<'a, bool>),
+    SetModExpAlgorithm(Cow<'a, parameters::SetModExpAlgorithmArgs>),
+    SetAllowZeroGasPrice(Cow<'a, bool>),
+    SetExitFee(Cow<'a, parameters::SetExitFeeArgs>),
+    PauseConnector,
+    ResumeConnector,
+    SetFtOnTransferGasLimit(Cow<'a, parameters::SetFtOnTransferGasLimitArgs>),
+    SetAllowChainlessTx(Cow<'a, bool>),
+    SetAllowEmptyInitCode(Cow<'a, bool>),
+    SetFeeDistribution(Cow<'a, parameters::SetFeeDistributionArgs>),
+    SetMaxNonceGap(Cow<'a, parameters::SetMaxNonceGapArgs>),
+    SetXccNearGasPerEvmGas(Cow<'a, SetNearGasPerEvmGasArgs>),
+    SetPaymaster(Cow<'a, parameters::SetPaymasterArgs>),
+    SetTxCountTrackingEnabled(Cow<'a, bool>),
+    FundXccSubAccountV2(Cow<'a, FundXccArgsV2>),
+    SetViewStepLimit(Cow<'a, parameters::SetViewStepLimitArgs>),
+    ImportWhitelists(Cow<'a, silo::WhitelistsArgs>),
+    SetReceiptsPersistenceEnabled(Cow<'a, bool>),
+    SetRandomSeedOverride(Cow<'a, parameters::SetRandomSeedOverrideArgs>),
+    SubmitBatch(Cow<'a, parameters::SubmitBatchArgs>),
+    SetMaxRefundQuotient(Cow<'a, parameters::SetMaxRefundQuotientArgs>),
+    SetUnregisterFee(Cow<'a, parameters::SetUnregisterFeeArgs>),
+    SetRejectUnknownPrecompiles(Cow<'a, bool>),
+    SubmitWithArgsV2(Cow<'a, parameters::SubmitArgsV2>),
+    DeployErc20V3(Cow<'a, parameters::DeployErc20TokenArgsV3>),
+    IndexWhitelistEntries(Cow<'a, Vec<silo::WhitelistArgs>>),
+    SetErc20DeployGasLimit(Cow<'a, parameters::SetErc20DeployGasLimitArgs>),
 }
 
 impl<'a> From<&'a TransactionKind> for BorshableTransactionKind<'a> {
@@ -938,9 +1321,18 @@ impl<'a> From<&'a TransactionKind> for BorshableTransactionKind<'a> {
                 Self::Submit(Cow::Owned(tx_bytes))
             }
             TransactionKind::SubmitWithArgs(x) => Self::SubmitWithArgs(Cow::Borrowed(x)),
+            TransactionKind::SubmitWithArgsV2(x) => Self::SubmitWithArgsV2(Cow::Borrowed(x)),
             TransactionKind::Call(x) => Self::Call(Cow::Borrowed(x)),
             TransactionKind::Deploy(x) => Self::Deploy(Cow::Borrowed(x)),
-            TransactionKind::DeployErc20(x) => Self::DeployErc20(Cow::Borrowed(x)),
+            TransactionKind::DeployErc20(parameters::DeployErc20TokenCallArgs::V1(x)) => {
+                Self::DeployErc20(Cow::Borrowed(x))
+            }
+            TransactionKind::DeployErc20(parameters::DeployErc20TokenCallArgs::V2(x)) => {
+                Self::DeployErc20V2(Cow::Borrowed(x))
+            }
+            TransactionKind::DeployErc20(parameters::DeployErc20TokenCallArgs::V3(x)) => {
+                Self::DeployErc20V3(Cow::Borrowed(x))
+            }
             TransactionKind::FtOnTransfer(x) => Self::FtOnTransfer(Cow::Borrowed(x)),
             TransactionKind::Deposit(x) => Self::Deposit(Cow::Borrowed(x)),
             TransactionKind::FtTransferCall(x) => Self::FtTransferCall(Cow::Borrowed(x)),
@@ -976,7 +1368,12 @@ impl<'a> From<&'a TransactionKind> for BorshableTransactionKind<'a> {
                 Self::SetEthConnectorContractAccount(Cow::Borrowed(x))
             }
             TransactionKind::SetOwner(x) => Self::SetOwner(Cow::Borrowed(x)),
-            TransactionKind::FundXccSubAccount(x) => Self::FundXccSubAccount(Cow::Borrowed(x)),
+            TransactionKind::FundXccSubAccount(FundXccCallArgs::V1(x)) => {
+                Self::FundXccSubAccount(Cow::Borrowed(x))
+            }
+            TransactionKind::FundXccSubAccount(FundXccCallArgs::V2(x)) => {
+                Self::FundXccSubAccountV2(Cow::Borrowed(x))
+            }
             TransactionKind::SetUpgradeDelayBlocks(x) => {
                 Self::SetUpgradeDelayBlocks(Cow::Borrowed(x))
             }
@@ -1000,6 +1397,76 @@ impl<'a> From<&'a TransactionKind> for BorshableTransactionKind<'a> {
             TransactionKind::MirrorErc20TokenCallback(x) => {
                 Self::MirrorErc20TokenCallback(Cow::Borrowed(x))
             }
+            TransactionKind::SetRelaxedNonce(x) => Self::SetRelaxedNonce(Cow::Borrowed(x)),
+            TransactionKind::RegisterRelayersBatch(x) => {
+                Self::RegisterRelayersBatch(Cow::Borrowed(x))
+            }
+            TransactionKind::AddDeployer(x) => Self::AddDeployer(Cow::Borrowed(x)),
+            TransactionKind::RemoveDeployer(x) => Self::RemoveDeployer(Cow::Borrowed(x)),
+            TransactionKind::SetDeployerAllowlistStatus(x) => {
+                Self::SetDeployerAllowlistStatus(Cow::Borrowed(x))
+            }
+            TransactionKind::WithdrawCollectedFees(x) => {
+                Self::WithdrawCollectedFees(Cow::Borrowed(x))
+            }
+            TransactionKind::SetMaxLogsPerTx(x) => Self::SetMaxLogsPerTx(Cow::Borrowed(x)),
+            TransactionKind::SetEngineMetadata(x) => Self::SetEngineMetadata(Cow::Borrowed(x)),
+            TransactionKind::SetGasRefundsEnabled(x) => {
+                Self::SetGasRefundsEnabled(Cow::Borrowed(x))
+            }
+            TransactionKind::SetEvmMemoryLimit(x) => Self::SetEvmMemoryLimit(Cow::Borrowed(x)),
+            TransactionKind::SetTipRecipient(x) => Self::SetTipRecipient(Cow::Borrowed(x)),
+            TransactionKind::SetErc20Template(x) => Self::SetErc20Template(Cow::Borrowed(x)),
+            TransactionKind::ConfigureSilo(x) => Self::ConfigureSilo(Cow::Borrowed(x)),
+            TransactionKind::SetLogLevel(x) => Self::SetLogLevel(Cow::Borrowed(x)),
+            TransactionKind::SetDiagnosticsEnabled(x) => {
+                Self::SetDiagnosticsEnabled(Cow::Borrowed(x))
+            }
+            TransactionKind::SetModExpAlgorithm(x) => Self::SetModExpAlgorithm(Cow::Borrowed(x)),
+            TransactionKind::SetAllowZeroGasPrice(x) => {
+                Self::SetAllowZeroGasPrice(Cow::Borrowed(x))
+            }
+            TransactionKind::SetExitFee(x) => Self::SetExitFee(Cow::Borrowed(x)),
+            TransactionKind::PauseConnector => Self::PauseConnector,
+            TransactionKind::ResumeConnector => Self::ResumeConnector,
+            TransactionKind::SetFtOnTransferGasLimit(x) => {
+                Self::SetFtOnTransferGasLimit(Cow::Borrowed(x))
+            }
+            TransactionKind::SetAllowChainlessTx(x) => Self::SetAllowChainlessTx(Cow::Borrowed(x)),
+            TransactionKind::SetAllowEmptyInitCode(x) => {
+                Self::SetAllowEmptyInitCode(Cow::Borrowed(x))
+            }
+            TransactionKind::SetFeeDistribution(x) => Self::SetFeeDistribution(Cow::Borrowed(x)),
+            TransactionKind::SetMaxNonceGap(x) => Self::SetMaxNonceGap(Cow::Borrowed(x)),
+            TransactionKind::SetXccNearGasPerEvmGas(x) => {
+                Self::SetXccNearGasPerEvmGas(Cow::Borrowed(x))
+            }
+            TransactionKind::SetPaymaster(x) => Self::SetPaymaster(Cow::Borrowed(x)),
+            TransactionKind::SetTxCountTrackingEnabled(x) => {
+                Self::SetTxCountTrackingEnabled(Cow::Borrowed(x))
+            }
+            TransactionKind::SetViewStepLimit(x) => Self::SetViewStepLimit(Cow::Borrowed(x)),
+            TransactionKind::ImportWhitelists(x) => Self::ImportWhitelists(Cow::Borrowed(x)),
+            TransactionKind::SetReceiptsPersistenceEnabled(x) => {
+                Self::SetReceiptsPersistenceEnabled(Cow::Borrowed(x))
+            }
+            TransactionKind::SetRandomSeedOverride(x) => {
+                Self::SetRandomSeedOverride(Cow::Borrowed(x))
+            }
+            TransactionKind::SubmitBatch(x) => Self::SubmitBatch(Cow::Borrowed(x)),
+            TransactionKind::SetMaxRefundQuotient(x) => {
+                Self::SetMaxRefundQuotient(Cow::Borrowed(x))
+            }
+            TransactionKind::SetUnregisterFee(x) => Self::SetUnregisterFee(Cow::Borrowed(x)),
+            TransactionKind::SetRejectUnknownPrecompiles(x) => {
+                Self::SetRejectUnknownPrecompiles(Cow::Borrowed(x))
+            }
+            TransactionKind::IndexWhitelistEntries(x) => {
+                Self::IndexWhitelistEntries(Cow::Borrowed(x))
+            }
+            TransactionKind::SetErc20DeployGasLimit(x) => {
+                Self::SetErc20DeployGasLimit(Cow::Borrowed(x))
+            }
         }
     }
 }
@@ -1017,9 +1484,17 @@ impl<'a> TryFrom<BorshableTransactionKind<'a>> for TransactionKind {
                 Ok(Self::Submit(eth_tx))
             }
             BorshableTransactionKind::SubmitWithArgs(x) => Ok(Self::SubmitWithArgs(x.into_owned())),
+            BorshableTransactionKind::SubmitWithArgsV2(x) => {
+                Ok(Self::SubmitWithArgsV2(x.into_owned()))
+            }
+            BorshableTransactionKind::DeployErc20V3(x) => Ok(Self::DeployErc20(
+                parameters::DeployErc20TokenCallArgs::V3(x.into_owned()),
+            )),
             BorshableTransactionKind::Call(x) => Ok(Self::Call(x.into_owned())),
             BorshableTransactionKind::Deploy(x) => Ok(Self::Deploy(x.into_owned())),
-            BorshableTransactionKind::DeployErc20(x) => Ok(Self::DeployErc20(x.into_owned())),
+            BorshableTransactionKind::DeployErc20(x) => Ok(Self::DeployErc20(
+                parameters::DeployErc20TokenCallArgs::V1(x.into_owned()),
+            )),
             BorshableTransactionKind::FtOnTransfer(x) => Ok(Self::FtOnTransfer(x.into_owned())),
             BorshableTransactionKind::Deposit(x) => Ok(Self::Deposit(x.into_owned())),
             BorshableTransactionKind::FtTransferCall(x) => Ok(Self::FtTransferCall(x.into_owned())),
@@ -1063,7 +1538,7 @@ impl<'a> TryFrom<BorshableTransactionKind<'a>> for TransactionKind {
             }
             BorshableTransactionKind::SetOwner(x) => Ok(Self::SetOwner(x.into_owned())),
             BorshableTransactionKind::FundXccSubAccount(x) => {
-                Ok(Self::FundXccSubAccount(x.into_owned()))
+                Ok(Self::FundXccSubAccount(FundXccCallArgs::V1(x.into_owned())))
             }
             BorshableTransactionKind::SetUpgradeDelayBlocks(x) => {
                 Ok(Self::SetUpgradeDelayBlocks(x.into_owned()))
@@ -1099,6 +1574,106 @@ impl<'a> TryFrom<BorshableTransactionKind<'a>> for TransactionKind {
             BorshableTransactionKind::WithdrawWnearToRouter(x) => {
                 Ok(Self::WithdrawWnearToRouter(x.into_owned()))
             }
+            BorshableTransactionKind::SetRelaxedNonce(x) => {
+                Ok(Self::SetRelaxedNonce(x.into_owned()))
+            }
+            BorshableTransactionKind::RegisterRelayersBatch(x) => {
+                Ok(Self::RegisterRelayersBatch(x.into_owned()))
+            }
+            BorshableTransactionKind::AddDeployer(x) => Ok(Self::AddDeployer(x.into_owned())),
+            BorshableTransactionKind::RemoveDeployer(x) => Ok(Self::RemoveDeployer(x.into_owned())),
+            BorshableTransactionKind::SetDeployerAllowlistStatus(x) => {
+                Ok(Self::SetDeployerAllowlistStatus(x.into_owned()))
+            }
+            BorshableTransactionKind::DeployErc20V2(x) => Ok(Self::DeployErc20(
+                parameters::DeployErc20TokenCallArgs::V2(x.into_owned()),
+            )),
+            BorshableTransactionKind::WithdrawCollectedFees(x) => {
+                Ok(Self::WithdrawCollectedFees(x.into_owned()))
+            }
+            BorshableTransactionKind::SetMaxLogsPerTx(x) => {
+                Ok(Self::SetMaxLogsPerTx(x.into_owned()))
+            }
+            BorshableTransactionKind::SetEngineMetadata(x) => {
+                Ok(Self::SetEngineMetadata(x.into_owned()))
+            }
+            BorshableTransactionKind::SetGasRefundsEnabled(x) => {
+                Ok(Self::SetGasRefundsEnabled(x.into_owned()))
+            }
+            BorshableTransactionKind::SetEvmMemoryLimit(x) => {
+                Ok(Self::SetEvmMemoryLimit(x.into_owned()))
+            }
+            BorshableTransactionKind::SetTipRecipient(x) => {
+                Ok(Self::SetTipRecipient(x.into_owned()))
+            }
+            BorshableTransactionKind::SetErc20Template(x) => {
+                Ok(Self::SetErc20Template(x.into_owned()))
+            }
+            BorshableTransactionKind::ConfigureSilo(x) => Ok(Self::ConfigureSilo(x.into_owned())),
+            BorshableTransactionKind::SetLogLevel(x) => Ok(Self::SetLogLevel(x.into_owned())),
+            BorshableTransactionKind::SetDiagnosticsEnabled(x) => {
+                Ok(Self::SetDiagnosticsEnabled(x.into_owned()))
+            }
+            BorshableTransactionKind::SetModExpAlgorithm(x) => {
+                Ok(Self::SetModExpAlgorithm(x.into_owned()))
+            }
+            BorshableTransactionKind::SetAllowZeroGasPrice(x) => {
+                Ok(Self::SetAllowZeroGasPrice(x.into_owned()))
+            }
+            BorshableTransactionKind::SetExitFee(x) => Ok(Self::SetExitFee(x.into_owned())),
+            BorshableTransactionKind::PauseConnector => Ok(Self::PauseConnector),
+            BorshableTransactionKind::ResumeConnector => Ok(Self::ResumeConnector),
+            BorshableTransactionKind::SetFtOnTransferGasLimit(x) => {
+                Ok(Self::SetFtOnTransferGasLimit(x.into_owned()))
+            }
+            BorshableTransactionKind::SetAllowChainlessTx(x) => {
+                Ok(Self::SetAllowChainlessTx(x.into_owned()))
+            }
+            BorshableTransactionKind::SetAllowEmptyInitCode(x) => {
+                Ok(Self::SetAllowEmptyInitCode(x.into_owned()))
+            }
+            BorshableTransactionKind::SetFeeDistribution(x) => {
+                Ok(Self::SetFeeDistribution(x.into_owned()))
+            }
+            BorshableTransactionKind::SetMaxNonceGap(x) => Ok(Self::SetMaxNonceGap(x.into_owned())),
+            BorshableTransactionKind::SetXccNearGasPerEvmGas(x) => {
+                Ok(Self::SetXccNearGasPerEvmGas(x.into_owned()))
+            }
+            BorshableTransactionKind::SetPaymaster(x) => Ok(Self::SetPaymaster(x.into_owned())),
+            BorshableTransactionKind::SetTxCountTrackingEnabled(x) => {
+                Ok(Self::SetTxCountTrackingEnabled(x.into_owned()))
+            }
+            BorshableTransactionKind::FundXccSubAccountV2(x) => {
+                Ok(Self::FundXccSubAccount(FundXccCallArgs::V2(x.into_owned())))
+            }
+            BorshableTransactionKind::SetViewStepLimit(x) => {
+                Ok(Self::SetViewStepLimit(x.into_owned()))
+            }
+            BorshableTransactionKind::ImportWhitelists(x) => {
+                Ok(Self::ImportWhitelists(x.into_owned()))
+            }
+            BorshableTransactionKind::SetReceiptsPersistenceEnabled(x) => {
+                Ok(Self::SetReceiptsPersistenceEnabled(x.into_owned()))
+            }
+            BorshableTransactionKind::SetRandomSeedOverride(x) => {
+                Ok(Self::SetRandomSeedOverride(x.into_owned()))
+            }
+            BorshableTransactionKind::SubmitBatch(x) => Ok(Self::SubmitBatch(x.into_owned())),
+            BorshableTransactionKind::SetMaxRefundQuotient(x) => {
+                Ok(Self::SetMaxRefundQuotient(x.into_owned()))
+            }
+            BorshableTransactionKind::SetUnregisterFee(x) => {
+                Ok(Self::SetUnregisterFee(x.into_owned()))
+            }
+            BorshableTransactionKind::SetRejectUnknownPrecompiles(x) => {
+                Ok(Self::SetRejectUnknownPrecompiles(x.into_owned()))
+            }
+            BorshableTransactionKind::IndexWhitelistEntries(x) => {
+                Ok(Self::IndexWhitelistEntries(x.into_owned()))
+            }
+            BorshableTransactionKind::SetErc20DeployGasLimit(x) => {
+                Ok(Self::SetErc20DeployGasLimit(x.into_owned()))
+            }
         }
     }
 }
@@ -5,6 +5,7 @@ mod eth_deploy_code;
 mod eth_erc20;
 mod eth_standard_precompiles;
 mod eth_transfer;
+mod modexp_dispatch;
 mod nft_pagination;
 mod uniswap;
 
@@ -18,6 +19,7 @@ fn benches() {
     eth_erc20::eth_erc20_benchmark(&mut c);
     eth_standard_precompiles::eth_standard_precompiles_benchmark(&mut c);
     eth_transfer::eth_transfer_benchmark(&mut c);
+    modexp_dispatch::modexp_dispatch_benchmark(&mut c);
 
     c.final_summary();
 }
@@ -0,0 +1,49 @@
+use aurora_engine_precompiles::modexp::{DynModExp, ModExp};
+use aurora_engine_precompiles::{Berlin, Precompile};
+use criterion::{BenchmarkId, Criterion};
+use rand::{Rng, SeedableRng};
+
+use crate::prelude::{H160, U256};
+
+/// Compares the statically dispatched modexp precompile (used for the default algorithm) with
+/// the one selecting the algorithm at runtime, both running the built-in algorithm.
+pub fn modexp_dispatch_benchmark(c: &mut Criterion) {
+    let context = evm::Context {
+        address: H160::default(),
+        caller: H160::default(),
+        apparent_value: U256::zero(),
+    };
+    let static_modexp = ModExp::<Berlin>::new();
+    let dyn_modexp = DynModExp::<Berlin>::new(aurora_engine_modexp::modexp);
+    let mut rng = rand::rngs::StdRng::seed_from_u64(314_159);
+
+    let mut group = c.benchmark_group("modexp_dispatch");
+
+    // The smaller the input, the bigger the relative cost of the indirect call.
+    for size in [1, 32, 256] {
+        let input = modexp_input(&mut rng, size);
+        group.bench_with_input(BenchmarkId::new("static", size), &input, |b, input| {
+            b.iter(|| static_modexp.run(input, None, &context, false).unwrap());
+        });
+        group.bench_with_input(BenchmarkId::new("dynamic", size), &input, |b, input| {
+            b.iter(|| dyn_modexp.run(input, None, &context, false).unwrap());
+        });
+    }
+
+    group.finish();
+}
+
+/// Random input of the modexp precompile with the base, the exponent and the modulus of `size`
+/// bytes each.
+fn modexp_input<R: Rng>(rng: &mut R, size: usize) -> Vec<u8> {
+    let mut len = [0; 32];
+    U256::from(size).to_big_endian(&mut len);
+    let mut input = Vec::with_capacity(96 + 3 * size);
+    for _ in 0..3 {
+        input.extend_from_slice(&len);
+    }
+    let mut values = vec![0; 3 * size];
+    rng.fill(values.as_mut_slice());
+    input.extend_from_slice(&values);
+    input
+}
@@ -127,6 +127,29 @@ fn withdraw() {
     }
 }
 
+#[test]
+fn withdraw_with_diagnostics() {
+    let (mut runner, mut signer, _token, tester) = setup_test();
+
+    // The created promises are not returned by default.
+    let withdraw_result = tester.withdraw(&mut runner, &mut signer, true).unwrap();
+    assert!(withdraw_result.promises.is_empty());
+
+    runner
+        .call(
+            "set_diagnostics_enabled",
+            DEFAULT_AURORA_ACCOUNT_ID,
+            borsh::to_vec(&true).unwrap(),
+        )
+        .unwrap();
+
+    let withdraw_result = tester.withdraw(&mut runner, &mut signer, true).unwrap();
+    assert_eq!(withdraw_result.promises.len(), 1);
+    let promise = &withdraw_result.promises[0];
+    assert_eq!(promise.target_account_id.as_ref(), "tt.testnet");
+    assert_eq!(promise.method, "ft_transfer");
+}
+
 #[test]
 fn withdraw_and_fail() {
     let (mut runner, mut signer, _token, tester) = setup_test();
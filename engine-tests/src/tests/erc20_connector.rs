@@ -1,10 +1,15 @@
 use crate::prelude::{Address, Balance, Wei, WeiU256, U256};
 use crate::utils::{self, create_eth_transaction, AuroraRunner, DEFAULT_AURORA_ACCOUNT_ID};
-use aurora_engine::engine::EngineError;
-use aurora_engine::parameters::{CallArgs, FunctionCallArgsV2};
+use aurora_engine::engine::{EngineError, EngineErrorKind};
+use aurora_engine::parameters::{
+    CallArgs, DeployErc20TokenArgsV2, DeployErc20TokenCallArgs, FunctionCallArgsV2,
+};
 use aurora_engine_transactions::legacy::LegacyEthSignedTransaction;
 use aurora_engine_types::borsh::BorshDeserialize;
-use aurora_engine_types::parameters::engine::{SubmitResult, TransactionStatus};
+use aurora_engine_types::parameters::engine::{
+    SetErc20DeployGasLimitArgs, SetErc20TemplateArgs, SetFtOnTransferGasLimitArgs, SubmitResult,
+    TransactionStatus,
+};
 use ethabi::Token;
 use libsecp256k1::SecretKey;
 use near_vm_runner::logic::VMOutcome;
@@ -105,6 +110,23 @@ impl AuroraRunner {
             .unwrap()
     }
 
+    pub fn deploy_erc20_token_with_args(
+        &mut self,
+        args: &DeployErc20TokenCallArgs,
+    ) -> Result<Address, EngineError> {
+        let result = self.make_call(
+            "deploy_erc20_token",
+            DEFAULT_AURORA_ACCOUNT_ID,
+            borsh::to_vec(args).unwrap(),
+        )?;
+
+        Ok(Vec::try_from_slice(&result.return_data.as_value().unwrap())
+            .unwrap()
+            .try_into()
+            .map(Address::from_array)
+            .unwrap())
+    }
+
     pub fn create_account(&mut self) -> EthereumAddress {
         let mut rng = rand::thread_rng();
         let source_account = SecretKey::random(&mut rng);
@@ -226,6 +248,198 @@ fn test_deploy_erc20_token() {
     runner.deploy_erc20_token("tt.testnet");
 }
 
+#[test]
+fn test_deploy_erc20_token_return_existing() {
+    let mut runner = AuroraRunner::new();
+    let deploy_args = |nep141: &str, return_existing| {
+        DeployErc20TokenCallArgs::V2(DeployErc20TokenArgsV2 {
+            nep141: nep141.parse().unwrap(),
+            return_existing,
+        })
+    };
+
+    // A new token is deployed regardless of the flag.
+    let token = runner
+        .deploy_erc20_token_with_args(&deploy_args("tt.testnet", true))
+        .unwrap();
+
+    // Without the flag deploying an already registered token fails, as before.
+    let error = runner
+        .deploy_erc20_token_with_args(&deploy_args("tt.testnet", false))
+        .unwrap_err();
+    assert!(matches!(
+        error.kind,
+        EngineErrorKind::EvmFatal(evm::ExitFatal::Other(e)) if e == "ERR_NEP141_TOKEN_ALREADY_REGISTERED"
+    ));
+
+    // With the flag the address of the existing token is returned.
+    let existing = runner
+        .deploy_erc20_token_with_args(&deploy_args("tt.testnet", true))
+        .unwrap();
+    assert_eq!(existing, token);
+
+    let other = runner
+        .deploy_erc20_token_with_args(&deploy_args("tt2.testnet", true))
+        .unwrap();
+    assert_ne!(other, token);
+}
+
+#[test]
+fn test_deploy_erc20_token_with_template() {
+    let mut runner = AuroraRunner::new();
+    let set_erc20_template = |runner: &mut AuroraRunner, code: Option<Vec<u8>>| {
+        runner.make_call(
+            "set_erc20_template",
+            DEFAULT_AURORA_ACCOUNT_ID,
+            borsh::to_vec(&SetErc20TemplateArgs { code }).unwrap(),
+        )
+    };
+    let get_erc20_template = |runner: &AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_erc20_template", "any.near", Vec::new())
+            .unwrap();
+        Option::<Vec<u8>>::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+    // Init code which deploys the runtime code returning 42, the constructor arguments are ignored.
+    let runtime_code = hex::decode("602a60005260206000f3").unwrap();
+    let template = [
+        hex::decode("600a600c600039600a6000f3").unwrap(),
+        runtime_code.clone(),
+    ]
+    .concat();
+
+    // Empty and reverting templates are rejected.
+    for code in [Vec::new(), hex::decode("60006000fd").unwrap()] {
+        let error = set_erc20_template(&mut runner, Some(code)).unwrap_err();
+        assert!(matches!(
+            error.kind,
+            EngineErrorKind::EvmFatal(evm::ExitFatal::Other(e)) if e == "ERR_INVALID_ERC20_TEMPLATE"
+        ));
+    }
+    assert_eq!(get_erc20_template(&runner), None);
+
+    set_erc20_template(&mut runner, Some(template.clone())).unwrap();
+    assert_eq!(get_erc20_template(&runner), Some(template));
+    let token = runner.deploy_erc20_token("tt.testnet");
+    assert_eq!(runner.get_code(token), runtime_code);
+
+    // The built-in binary is used once the template is unset.
+    set_erc20_template(&mut runner, None).unwrap();
+    assert_eq!(get_erc20_template(&runner), None);
+    let token = runner.deploy_erc20_token("tt2.testnet");
+    assert_ne!(runner.get_code(token), runtime_code);
+    assert_eq!(
+        runner.balance_of(token, Address::zero(), DEFAULT_AURORA_ACCOUNT_ID),
+        U256::zero()
+    );
+}
+
+/// Returns an ERC-20 template with a runtime code close to the EIP-170 limit of 24576 bytes,
+/// along with the runtime code.
+fn large_erc20_template() -> (Vec<u8>, Vec<u8>) {
+    const RUNTIME_CODE_LEN: usize = 24_000;
+
+    // Runtime code returning 42 padded with `STOP`s.
+    let mut runtime_code = hex::decode("602a60005260206000f3").unwrap();
+    runtime_code.resize(RUNTIME_CODE_LEN, 0);
+    // `PUSH2 len PUSH1 14 PUSH1 0 CODECOPY PUSH2 len PUSH1 0 RETURN`, 14 is the length of itself.
+    let len = hex::encode(u16::try_from(RUNTIME_CODE_LEN).unwrap().to_be_bytes());
+    let template = [
+        hex::decode(format!("61{len}600e60003961{len}6000f3")).unwrap(),
+        runtime_code.clone(),
+    ]
+    .concat();
+    (template, runtime_code)
+}
+
+#[test]
+fn test_deploy_erc20_token_with_large_template() {
+    let mut runner = AuroraRunner::new();
+    let (template, runtime_code) = large_erc20_template();
+
+    // The deployment is not bounded by an EVM gas allocation, only by the attached NEAR gas.
+    runner
+        .make_call(
+            "set_erc20_template",
+            DEFAULT_AURORA_ACCOUNT_ID,
+            borsh::to_vec(&SetErc20TemplateArgs {
+                code: Some(template),
+            })
+            .unwrap(),
+        )
+        .unwrap();
+    let token = runner.deploy_erc20_token("tt.testnet");
+    assert_eq!(runner.get_code(token), runtime_code);
+}
+
+#[test]
+fn test_erc20_deploy_gas_limit() {
+    let mut runner = AuroraRunner::new();
+    let set_gas_limit = |runner: &mut AuroraRunner, gas_limit: Option<u64>| {
+        runner.make_call(
+            "set_erc20_deploy_gas_limit",
+            DEFAULT_AURORA_ACCOUNT_ID,
+            borsh::to_vec(&SetErc20DeployGasLimitArgs { gas_limit }).unwrap(),
+        )
+    };
+    let get_gas_limit = |runner: &AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_erc20_deploy_gas_limit", "any.near", Vec::new())
+            .unwrap();
+        u64::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+    let deploy = |runner: &mut AuroraRunner, nep141: &str| {
+        runner.make_call(
+            "deploy_erc20_token",
+            DEFAULT_AURORA_ACCOUNT_ID,
+            borsh::to_vec(&nep141).unwrap(),
+        )
+    };
+    let (template, runtime_code) = large_erc20_template();
+    runner
+        .make_call(
+            "set_erc20_template",
+            DEFAULT_AURORA_ACCOUNT_ID,
+            borsh::to_vec(&SetErc20TemplateArgs {
+                code: Some(template),
+            })
+            .unwrap(),
+        )
+        .unwrap();
+
+    assert_eq!(get_gas_limit(&runner), u64::MAX);
+
+    // Limits below the cost of a contract creation are rejected.
+    let error = set_gas_limit(&mut runner, Some(52_999)).unwrap_err();
+    assert!(matches!(
+        error.kind,
+        EngineErrorKind::EvmFatal(evm::ExitFatal::Other(e)) if e == "ERR_INVALID_GAS_LIMIT"
+    ));
+    assert_eq!(get_gas_limit(&runner), u64::MAX);
+
+    // Storing the large runtime code alone costs 4.8M gas.
+    set_gas_limit(&mut runner, Some(1_000_000)).unwrap();
+    assert_eq!(get_gas_limit(&runner), 1_000_000);
+    assert!(deploy(&mut runner, "tt.testnet").is_err());
+
+    set_gas_limit(&mut runner, Some(10_000_000)).unwrap();
+    assert_eq!(get_gas_limit(&runner), 10_000_000);
+    let outcome = deploy(&mut runner, "tt.testnet").unwrap();
+    let token = Vec::try_from_slice(&outcome.return_data.as_value().unwrap())
+        .unwrap()
+        .try_into()
+        .map(Address::from_array)
+        .unwrap();
+    assert_eq!(runner.get_code(token), runtime_code);
+
+    // Once the limit is unset the deployments are bounded by the NEAR gas only.
+    set_gas_limit(&mut runner, None).unwrap();
+    assert_eq!(get_gas_limit(&runner), u64::MAX);
+    deploy(&mut runner, "tt2.testnet").unwrap();
+}
+
 #[test]
 fn test_mint() {
     let mut runner = AuroraRunner::new();
@@ -287,6 +501,58 @@ fn test_ft_on_transfer_fail() {
     assert_eq!(res, format!("\"{amount}\""));
 }
 
+#[test]
+fn test_ft_on_transfer_gas_limit() {
+    let mut runner = AuroraRunner::new();
+    // Standalone runner presently does not support ft_on_transfer
+    runner.standalone_runner = None;
+    let set_gas_limit = |runner: &mut AuroraRunner, gas_limit: Option<u64>| {
+        runner.make_call(
+            "set_ft_on_transfer_gas_limit",
+            DEFAULT_AURORA_ACCOUNT_ID,
+            borsh::to_vec(&SetFtOnTransferGasLimitArgs { gas_limit }).unwrap(),
+        )
+    };
+    let get_gas_limit = |runner: &AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_ft_on_transfer_gas_limit", "any.near", Vec::new())
+            .unwrap();
+        u64::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+    let nep141 = "tt.testnet";
+    let alice = "alice";
+    let token = runner.deploy_erc20_token(nep141);
+    let amount = Balance::new(10);
+    let recipient = runner.create_account().address;
+
+    assert_eq!(get_gas_limit(&runner), u64::MAX);
+
+    // Limits below the cost of a plain transfer are rejected.
+    let error = set_gas_limit(&mut runner, Some(20_000)).unwrap_err();
+    assert!(matches!(
+        error.kind,
+        EngineErrorKind::EvmFatal(evm::ExitFatal::Other(e)) if e == "ERR_INVALID_GAS_LIMIT"
+    ));
+    assert_eq!(get_gas_limit(&runner), u64::MAX);
+
+    // The mint runs out of gas, so the whole amount is returned to the sender.
+    set_gas_limit(&mut runner, Some(21_000)).unwrap();
+    assert_eq!(get_gas_limit(&runner), 21_000);
+    let res = runner.ft_on_transfer(nep141, alice, alice, amount, &recipient.encode());
+    assert_eq!(res, format!("\"{amount}\""));
+    let balance = runner.balance_of(token, recipient, DEFAULT_AURORA_ACCOUNT_ID);
+    assert_eq!(balance, U256::zero());
+
+    // Once the limit is unset the mint goes through.
+    set_gas_limit(&mut runner, None).unwrap();
+    assert_eq!(get_gas_limit(&runner), u64::MAX);
+    let res = runner.ft_on_transfer(nep141, alice, alice, amount, &recipient.encode());
+    assert_eq!(res, "\"0\"");
+    let balance = runner.balance_of(token, recipient, DEFAULT_AURORA_ACCOUNT_ID);
+    assert_eq!(balance, U256::from(amount.as_u128()));
+}
+
 #[ignore]
 #[test]
 fn test_relayer_charge_fee() {
@@ -394,6 +660,7 @@ pub mod workspace {
     };
     use aurora_engine::parameters::{CallArgs, FunctionCallArgsV2};
     use aurora_engine::proof::Proof;
+    use aurora_engine_types::parameters::connector::{ExitFee, ExitFeeConfig};
     use aurora_engine_types::parameters::engine::TransactionStatus;
     use aurora_engine_workspace::account::Account;
     use aurora_engine_workspace::types::{ExecutionFinalResult, NearToken};
@@ -407,6 +674,7 @@ pub mod workspace {
     const INITIAL_ETH_BALANCE: u64 = 777_777_777;
     const ETH_EXIT_AMOUNT: u64 = 111_111_111;
     const ETH_CUSTODIAN_ADDRESS: &str = "096de9c2b8a5b8c22cee3289b101f6960d68e51e";
+    const TREASURY: &str = "treasury.near";
     #[cfg(not(feature = "ext-connector"))]
     const ONE_YOCTO: NearToken = NearToken::from_yoctonear(1);
 
@@ -511,6 +779,129 @@ pub mod workspace {
         );
     }
 
+    #[tokio::test]
+    async fn test_exit_to_near_flat_fee() {
+        let TestExitToNearContext {
+            ft_owner,
+            ft_owner_address,
+            nep_141,
+            erc20,
+            aurora,
+            ..
+        } = test_exit_to_near_common().await.unwrap();
+        let fee = 1_000;
+        let config = ExitFeeConfig {
+            fee: ExitFee::Flat(fee),
+            treasury: TREASURY.parse().unwrap(),
+        };
+
+        // The treasury must be registered to receive the tokens.
+        let result = ft_owner
+            .call(&nep_141.id(), "storage_deposit")
+            .args_json(serde_json::json!({ "account_id": TREASURY }))
+            .deposit(NearToken::from_near(1))
+            .transact()
+            .await
+            .unwrap();
+        assert!(result.is_success());
+
+        let result = aurora
+            .set_exit_fee(erc20.0.address, Some(config.clone()))
+            .transact()
+            .await
+            .unwrap();
+        assert!(result.is_success());
+        let result = aurora.get_exit_fee(erc20.0.address).await.unwrap().result;
+        assert_eq!(result, Some(config));
+
+        exit_to_near(
+            &ft_owner,
+            ft_owner.id().as_ref(),
+            FT_EXIT_AMOUNT,
+            &erc20,
+            &aurora,
+        )
+        .await
+        .unwrap();
+
+        // The whole amount is burned, the fee goes to the treasury and the rest to the recipient.
+        assert_eq!(
+            nep_141_balance_of(&nep_141, &ft_owner.id()).await,
+            FT_TOTAL_SUPPLY - FT_TRANSFER_AMOUNT + FT_EXIT_AMOUNT - fee
+        );
+        assert_eq!(
+            nep_141_balance_of(&nep_141, &TREASURY.parse().unwrap()).await,
+            fee
+        );
+        assert_eq!(
+            nep_141_balance_of(&nep_141, &aurora.id()).await,
+            FT_TRANSFER_AMOUNT - FT_EXIT_AMOUNT
+        );
+        assert_eq!(
+            erc20_balance(&erc20, ft_owner_address, &aurora).await,
+            (FT_TRANSFER_AMOUNT - FT_EXIT_AMOUNT).into()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exit_to_near_fee_refund() {
+        let TestExitToNearContext {
+            ft_owner,
+            ft_owner_address,
+            nep_141,
+            erc20,
+            aurora,
+            ..
+        } = test_exit_to_near_common().await.unwrap();
+        let fee = 1_000;
+        // The transfer of the fee fails because the treasury isn't registered with the NEP-141.
+        let config = ExitFeeConfig {
+            fee: ExitFee::Flat(fee),
+            treasury: TREASURY.parse().unwrap(),
+        };
+        let result = aurora
+            .set_exit_fee(erc20.0.address, Some(config))
+            .transact()
+            .await
+            .unwrap();
+        assert!(result.is_success());
+
+        exit_to_near(
+            &ft_owner,
+            ft_owner.id().as_ref(),
+            FT_EXIT_AMOUNT,
+            &erc20,
+            &aurora,
+        )
+        .await
+        .unwrap();
+
+        // The exit itself succeeds, the fee stays locked in the engine.
+        assert_eq!(
+            nep_141_balance_of(&nep_141, &ft_owner.id()).await,
+            FT_TOTAL_SUPPLY - FT_TRANSFER_AMOUNT + FT_EXIT_AMOUNT - fee
+        );
+        assert_eq!(
+            nep_141_balance_of(&nep_141, &TREASURY.parse().unwrap()).await,
+            0
+        );
+        assert_eq!(
+            nep_141_balance_of(&nep_141, &aurora.id()).await,
+            FT_TRANSFER_AMOUNT - FT_EXIT_AMOUNT + fee
+        );
+
+        // The fee is credited back the same way as a failed exit.
+        #[cfg(feature = "error_refund")]
+        let balance = (FT_TRANSFER_AMOUNT - FT_EXIT_AMOUNT + fee).into();
+        #[cfg(not(feature = "error_refund"))]
+        let balance = (FT_TRANSFER_AMOUNT - FT_EXIT_AMOUNT).into();
+
+        assert_eq!(
+            erc20_balance(&erc20, ft_owner_address, &aurora).await,
+            balance
+        );
+    }
+
     #[tokio::test]
     async fn test_exit_to_near_wnear_unwrapped() {
         // Deploy Aurora; deploy wnear; bridge wnear to ERC-20 on Aurora
@@ -717,6 +1108,72 @@ pub mod workspace {
         );
     }
 
+    #[tokio::test]
+    async fn test_exit_to_near_eth_percentage_fee() {
+        let TestExitToNearEthContext {
+            signer,
+            signer_address,
+            chain_id,
+            tester_address,
+            aurora,
+        } = test_exit_to_near_eth_common().await.unwrap();
+        let exit_account_id = "any.near";
+        // 2.5% of the exited amount.
+        let fee = u128::from(ETH_EXIT_AMOUNT) * 250 / 10_000;
+
+        // The zero address stands for ETH.
+        let result = aurora
+            .set_exit_fee(
+                Address::zero(),
+                Some(ExitFeeConfig {
+                    fee: ExitFee::Percentage(250),
+                    treasury: TREASURY.parse().unwrap(),
+                }),
+            )
+            .transact()
+            .await
+            .unwrap();
+        assert!(result.is_success());
+
+        // call exit to near
+        let input = build_input(
+            "withdrawEthToNear(bytes)",
+            &[ethabi::Token::Bytes(exit_account_id.as_bytes().to_vec())],
+        );
+        let tx = utils::create_eth_transaction(
+            Some(tester_address),
+            Wei::new_u64(ETH_EXIT_AMOUNT),
+            input,
+            Some(chain_id),
+            &signer.secret_key,
+        );
+        let result = aurora
+            .submit(rlp::encode(&tx).to_vec())
+            .max_gas()
+            .transact()
+            .await
+            .unwrap();
+        assert!(result.is_success());
+
+        // check balances
+        assert_eq!(
+            nep_141_balance_of(aurora.as_raw_contract(), &aurora.id()).await,
+            u128::from(INITIAL_ETH_BALANCE - ETH_EXIT_AMOUNT)
+        );
+        assert_eq!(
+            nep_141_balance_of(aurora.as_raw_contract(), &exit_account_id.parse().unwrap()).await,
+            u128::from(ETH_EXIT_AMOUNT) - fee
+        );
+        assert_eq!(
+            nep_141_balance_of(aurora.as_raw_contract(), &TREASURY.parse().unwrap()).await,
+            fee
+        );
+        assert_eq!(
+            eth_balance_of(signer_address, &aurora).await,
+            Wei::new_u64(INITIAL_ETH_BALANCE - ETH_EXIT_AMOUNT)
+        );
+    }
+
     #[tokio::test]
     async fn test_exit_to_near_eth_refund() {
         // Test the case where the ft_transfer promise from the exit call fails;
@@ -783,6 +1240,75 @@ pub mod workspace {
         );
     }
 
+    #[cfg(not(feature = "ext-connector"))]
+    #[tokio::test]
+    async fn test_get_storage_deposit_amount() {
+        use aurora_engine::parameters::FungibleTokenMetadata;
+        use aurora_engine_types::account_id::AccountId;
+
+        let aurora = deploy_engine().await;
+        aurora
+            .set_eth_connector_contract_data(
+                aurora.id(),
+                ETH_CUSTODIAN_ADDRESS.to_string(),
+                FungibleTokenMetadata::default(),
+            )
+            .transact()
+            .await
+            .unwrap();
+
+        let amount = aurora.get_storage_deposit_amount().await.unwrap().result;
+        let accounts_counter = aurora.get_accounts_counter().await.unwrap().result;
+        let account_id = AccountId::new("new_account.near").unwrap();
+
+        let result = aurora
+            .storage_deposit(Some(account_id.clone()), None)
+            .deposit(NearToken::from_yoctonear(amount.as_u128()))
+            .transact()
+            .await
+            .unwrap();
+        assert!(result.is_success());
+
+        // The returned amount is exactly enough to register the account.
+        let balance = aurora.storage_balance_of(&account_id).await.unwrap().result;
+        assert_eq!(balance.total, amount);
+        assert_eq!(
+            aurora.get_accounts_counter().await.unwrap().result,
+            accounts_counter + 1
+        );
+    }
+
+    #[cfg(not(feature = "ext-connector"))]
+    #[tokio::test]
+    async fn test_get_used_proofs_count() {
+        use aurora_engine::parameters::FungibleTokenMetadata;
+
+        let aurora = deploy_engine().await;
+        aurora
+            .set_eth_connector_contract_data(
+                aurora.id(),
+                ETH_CUSTODIAN_ADDRESS.to_string(),
+                FungibleTokenMetadata::default(),
+            )
+            .transact()
+            .await
+            .unwrap();
+        assert_eq!(aurora.get_used_proofs_count().await.unwrap().result, 0);
+
+        deposit_balance(&aurora).await;
+        assert_eq!(aurora.get_used_proofs_count().await.unwrap().result, 1);
+
+        // The same proof can't be used twice, so the counter stays the same.
+        let proof = create_test_proof(
+            INITIAL_ETH_BALANCE,
+            aurora.id().as_ref(),
+            ETH_CUSTODIAN_ADDRESS,
+        );
+        let result = aurora.deposit(proof).max_gas().transact().await;
+        assert!(result.unwrap_err().to_string().contains("ERR_PROOF_EXIST"));
+        assert_eq!(aurora.get_used_proofs_count().await.unwrap().result, 1);
+    }
+
     #[cfg(not(feature = "ext-connector"))]
     #[tokio::test]
     async fn test_ft_balances_of() {
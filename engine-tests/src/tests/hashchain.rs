@@ -1,6 +1,9 @@
-use crate::utils;
-use aurora_engine::parameters::{StartHashchainArgs, SubmitResult, TransactionStatus};
-use aurora_engine_hashchain::bloom::Bloom;
+use crate::utils::{self, solidity::DeployedContract};
+use aurora_engine::engine::EngineErrorKind;
+use aurora_engine::parameters::{
+    NewCallArgs, NewCallArgsV4, StartHashchainArgs, SubmitResult, TransactionStatus,
+};
+use aurora_engine_hashchain::bloom::{self, Bloom};
 use aurora_engine_transactions::legacy::TransactionLegacy;
 use aurora_engine_types::{
     types::{Address, Wei},
@@ -91,6 +94,67 @@ fn test_hashchain() {
     assert_eq!(hc.hashchain, hex::encode(expected_hc));
 }
 
+#[test]
+fn test_initial_hashchain() {
+    let mut runner = utils::AuroraRunner::default();
+    let account_id = runner.aurora_account_id.clone();
+    let args = NewCallArgs::V4(NewCallArgsV4 {
+        chain_id: aurora_engine_types::types::u256_to_arr(&runner.chain_id.into()),
+        owner_id: account_id.parse().unwrap(),
+        upgrade_delay_blocks: 1,
+        key_manager: account_id.parse().unwrap(),
+        initial_hashchain: None,
+    });
+    runner
+        .call("new", &account_id, borsh::to_vec(&args).unwrap())
+        .unwrap();
+
+    // The engine was initialized without the hashchain.
+    assert_eq!(get_initial_hashchain(&runner), None);
+
+    runner
+        .call("pause_contract", &account_id, Vec::new())
+        .unwrap();
+    let args = StartHashchainArgs {
+        block_height: runner.context.block_height,
+        block_hashchain: [0x11; 32],
+    };
+    runner
+        .call(
+            "start_hashchain",
+            &account_id,
+            borsh::to_vec(&args).unwrap(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        get_initial_hashchain(&runner),
+        Some(HashchainView {
+            block_height: args.block_height,
+            hashchain: hex::encode(args.block_hashchain),
+        })
+    );
+
+    // The seed given to `new` belongs to the block preceding the initialization.
+    let runner = utils::deploy_runner();
+    let initial_hashchain = get_initial_hashchain(&runner).unwrap();
+    assert_eq!(
+        initial_hashchain.block_height,
+        runner.context.block_height - 2
+    );
+    assert_eq!(initial_hashchain.hashchain, hex::encode(H256::default()));
+}
+
+fn get_initial_hashchain(runner: &utils::AuroraRunner) -> Option<HashchainView> {
+    let outcome = runner
+        .one_shot()
+        .call("get_initial_hashchain", "any.near", Vec::new())
+        .unwrap();
+    let return_data = outcome.return_data.as_value().unwrap();
+    let result: HashchainViewResult = serde_json::from_slice(&return_data).unwrap();
+    result.result
+}
+
 fn get_latest_hashchain(runner: &utils::AuroraRunner) -> HashchainView {
     let outcome = runner
         .one_shot()
@@ -111,3 +175,87 @@ struct HashchainView {
     block_height: u64,
     hashchain: String,
 }
+
+#[test]
+fn test_block_logs_bloom() {
+    let (mut runner, mut signer, _) = crate::tests::sanity::initialize_transfer();
+    // Two transactions are included in the same block below, which is not supported by
+    // the standalone runner.
+    runner.standalone_runner = None;
+    let mut deploy_contract = |runner: &mut utils::AuroraRunner| {
+        let constructor = utils::solidity::ContractConstructor::compile_from_source(
+            "src/tests/res",
+            "target/solidity_build",
+            "log_order.sol",
+            "LogOrder",
+        );
+        let nonce = signer.use_nonce();
+        runner.deploy_contract(
+            &signer.secret_key,
+            |c| c.deploy_without_constructor(nonce.into()),
+            constructor,
+        )
+    };
+    // The blooms of the logs emitted by different contracts differ.
+    let first_contract = deploy_contract(&mut runner);
+    let second_contract = deploy_contract(&mut runner);
+    let mut emit_step = |runner: &mut utils::AuroraRunner, contract: &DeployedContract| {
+        let result = runner
+            .submit_with_signer(&mut signer, |nonce| {
+                contract.call_method_with_args(
+                    "emitStep",
+                    &[ethabi::Token::Uint(U256::one())],
+                    nonce,
+                )
+            })
+            .unwrap();
+        assert!(result.status.is_ok());
+        bloom::get_logs_bloom(&result.logs)
+    };
+    let get_block_logs_bloom = |runner: &utils::AuroraRunner, block_height: u64| {
+        runner
+            .one_shot()
+            .call(
+                "get_block_logs_bloom",
+                "any.near",
+                borsh::to_vec(&block_height).unwrap(),
+            )
+            .map(|outcome| Bloom::from_slice(&outcome.return_data.as_value().unwrap()))
+    };
+
+    // Include both transactions in the same block.
+    let block_height = runner.context.block_height + 1;
+    let first_bloom = emit_step(&mut runner, &first_contract);
+    runner.context.block_height -= 1;
+    let second_bloom = emit_step(&mut runner, &second_contract);
+    assert_eq!(runner.context.block_height, block_height);
+    assert_ne!(first_bloom, second_bloom);
+
+    let mut expected_bloom = first_bloom;
+    expected_bloom.accrue_bloom(&second_bloom);
+    assert_eq!(
+        get_block_logs_bloom(&runner, block_height).unwrap(),
+        expected_bloom
+    );
+
+    // The bloom is reset in the next block.
+    let bloom = emit_step(&mut runner, &second_contract);
+    assert_eq!(
+        get_block_logs_bloom(&runner, block_height + 1).unwrap(),
+        bloom
+    );
+    // The blocks without logs have an empty bloom.
+    assert_eq!(
+        get_block_logs_bloom(&runner, block_height + 2).unwrap(),
+        Bloom::default()
+    );
+
+    // The bloom of the previous blocks and the future blocks is unknown.
+    for block_height in [block_height, block_height + 3] {
+        let error = get_block_logs_bloom(&runner, block_height).unwrap_err();
+        assert!(matches!(
+            error.kind,
+            EngineErrorKind::EvmFatal(evm::ExitFatal::Other(e)) if e == "ERR_BLOCK_LOGS_BLOOM_NOT_FOUND"
+        ));
+    }
+}
@@ -1,10 +1,12 @@
-use aurora_engine::engine::EngineError;
+use aurora_engine::engine::{EngineError, EngineErrorKind};
+use aurora_engine::parameters::{ModExpAlgorithmKind, SetModExpAlgorithmArgs};
+use aurora_engine_types::borsh::BorshDeserialize;
 use near_vm_runner::ContractCode;
 use rand::{Rng, SeedableRng};
 
 use super::sanity::initialize_transfer;
 use crate::prelude::Wei;
-use crate::prelude::{make_address, Address, U256};
+use crate::prelude::{make_address, u256_to_arr, Address, U256};
 use crate::utils::{self, standalone::StandaloneRunner, AuroraRunner, Signer};
 
 const MODEXP_ADDRESS: Address = make_address(0, 5);
@@ -170,14 +172,64 @@ fn test_modexp_oom() {
     }
 }
 
+#[test]
+fn test_set_modexp_algorithm() {
+    let (mut runner, mut signer, _) = initialize_transfer();
+    let aurora = runner.aurora_account_id.clone();
+    let get_algorithm = |runner: &AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_modexp_algorithm", "any.near", Vec::new())
+            .unwrap();
+        ModExpAlgorithmKind::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+    let args = borsh::to_vec(&SetModExpAlgorithmArgs {
+        algorithm: ModExpAlgorithmKind::Num,
+    })
+    .unwrap();
+    // The algorithms compute the same result, but they don't cost the same amount of NEAR gas
+    // for a large exponent, which shows which one is used.
+    let base = vec![224, 6, 0, 0, 169, 33, 33, 33, 33, 33, 33, 33, 255, 0, 0, 33];
+    let exp = vec![35; 216];
+    let modulus = vec![
+        130, 130, 130, 130, 130, 130, 0, 255, 255, 40, 255, 43, 33, 130, 130, 0,
+    ];
+    let input = [
+        u256_to_arr(&base.len().into()).as_slice(),
+        &u256_to_arr(&exp.len().into()),
+        &u256_to_arr(&modulus.len().into()),
+        &base,
+        &exp,
+        &modulus,
+    ]
+    .concat();
+    let mut expected_output = aurora_engine_modexp::modexp(&base, &exp, &modulus);
+    let padding = modulus.len() - expected_output.len();
+    expected_output.splice(0..0, core::iter::repeat(0).take(padding));
+
+    assert_eq!(get_algorithm(&runner), ModExpAlgorithmKind::Aurora);
+    let aurora_gas = check_wasm_modexp(&mut runner, &mut signer, input.clone(), &expected_output);
+
+    // Only the owner can select the algorithm.
+    let error = runner
+        .call("set_modexp_algorithm", "some-account.near", args.clone())
+        .unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::NotAllowed));
+
+    runner.call("set_modexp_algorithm", &aurora, args).unwrap();
+    assert_eq!(get_algorithm(&runner), ModExpAlgorithmKind::Num);
+    let num_gas = check_wasm_modexp(&mut runner, &mut signer, input, &expected_output);
+    assert_ne!(aurora_gas, num_gas);
+}
+
 fn check_wasm_modexp(
     runner: &mut AuroraRunner,
     signer: &mut Signer,
     input: Vec<u8>,
     expected_output: &[u8],
-) {
-    let wasm_result = runner
-        .submit_with_signer(signer, |nonce| {
+) -> u64 {
+    let (wasm_result, profile) = runner
+        .submit_with_signer_profiled(signer, |nonce| {
             aurora_engine_transactions::legacy::TransactionLegacy {
                 nonce,
                 gas_price: U256::zero(),
@@ -189,6 +241,7 @@ fn check_wasm_modexp(
         })
         .unwrap();
     assert_eq!(expected_output, utils::unwrap_success_slice(&wasm_result));
+    profile.wasm_gas()
 }
 
 /// Input to the modexp call (base, exp, modulus in big-endian bytes).
@@ -119,3 +119,68 @@ fn test_resume_contract() {
     let result = runner.call("set_upgrade_delay_blocks", &aurora_account_id, set);
     assert!(result.is_ok());
 }
+
+#[cfg(not(feature = "ext-connector"))]
+#[test]
+fn test_pause_connector_require_owner() {
+    let mut runner = utils::deploy_runner();
+    let aurora_account_id = runner.aurora_account_id.clone();
+
+    let result = runner.call("pause_connector", "new_owner.near", vec![]);
+    assert!(result.is_err());
+
+    let result = runner.call("pause_connector", &aurora_account_id, vec![]);
+    assert!(result.is_ok());
+
+    let result = runner.call("resume_connector", "new_owner.near", vec![]);
+    assert!(result.is_err());
+
+    let result = runner.call("resume_connector", &aurora_account_id, vec![]);
+    assert!(result.is_ok());
+}
+
+#[cfg(not(feature = "ext-connector"))]
+#[test]
+fn test_pause_connector() {
+    use crate::prelude::{Address, Wei};
+    use aurora_engine::engine::EngineErrorKind;
+
+    let is_connector_paused_error = |kind: &EngineErrorKind| matches!(kind, EngineErrorKind::EvmFatal(evm::ExitFatal::Other(e)) if e == "ERR_CONNECTOR_PAUSED");
+    let mut runner = utils::deploy_runner();
+    let aurora_account_id = runner.aurora_account_id.clone();
+    let mut signer = utils::Signer::random();
+    let signer_address = utils::address_from_secret_key(&signer.secret_key);
+    runner.create_address(signer_address, Wei::new_u64(1_000_000), 0.into());
+
+    // the connector is running by default, resuming it fails
+    let result = runner.call("resume_connector", &aurora_account_id, vec![]);
+    assert!(result.is_err());
+
+    let result = runner.call("pause_connector", &aurora_account_id, vec![]);
+    assert!(result.is_ok());
+
+    // pausing the paused connector fails
+    let result = runner.call("pause_connector", &aurora_account_id, vec![]);
+    assert!(is_connector_paused_error(&result.unwrap_err().kind));
+
+    // deposits, withdrawals and transfers are rejected
+    for method in ["deposit", "withdraw", "ft_transfer", "ft_transfer_call"] {
+        let result = runner.call(method, &aurora_account_id, vec![]);
+        assert!(is_connector_paused_error(&result.unwrap_err().kind));
+    }
+
+    // the EVM execution is not affected
+    let result = runner
+        .submit_with_signer(&mut signer, |nonce| {
+            utils::transfer(Address::from_array([1; 20]), Wei::new_u64(1), nonce)
+        })
+        .unwrap();
+    assert!(result.status.is_ok());
+
+    let result = runner.call("resume_connector", &aurora_account_id, vec![]);
+    assert!(result.is_ok());
+
+    // the connector doesn't reject the calls after resuming
+    let result = runner.call("ft_transfer", &aurora_account_id, vec![]);
+    assert!(!is_connector_paused_error(&result.unwrap_err().kind));
+}
@@ -38,6 +38,34 @@ fn test_promise_results_precompile() {
     );
 }
 
+#[test]
+fn test_promise_results_count_precompile() {
+    let mut signer = utils::Signer::random();
+    let mut runner = utils::deploy_runner();
+
+    runner.promise_results = vec![
+        PromiseResult::Successful(hex::decode("deadbeef").unwrap()),
+        PromiseResult::Failed,
+    ];
+
+    let transaction = TransactionLegacy {
+        nonce: signer.use_nonce().into(),
+        gas_price: U256::zero(),
+        gas_limit: u64::MAX.into(),
+        to: Some(promise_result::COUNT_ADDRESS),
+        value: Wei::zero(),
+        data: Vec::new(),
+    };
+    let result = runner
+        .submit_transaction(&signer.secret_key, transaction)
+        .unwrap();
+
+    assert_eq!(
+        U256::from_big_endian(&utils::unwrap_success(result)),
+        U256::from(2)
+    );
+}
+
 #[test]
 fn test_promise_result_gas_cost() {
     let mut runner = utils::deploy_runner();
@@ -1,5 +1,11 @@
 use crate::utils;
 use crate::utils::solidity::random::{Random, RandomConstructor};
+#[cfg(feature = "random-seed-override")]
+use aurora_engine_types::borsh::BorshDeserialize;
+#[cfg(feature = "random-seed-override")]
+use aurora_engine_types::parameters::engine::SetRandomSeedOverrideArgs;
+#[cfg(feature = "random-seed-override")]
+use aurora_engine_types::types::RawH256;
 use aurora_engine_types::H256;
 use rand::SeedableRng;
 
@@ -28,4 +34,64 @@ fn test_random_number_precompile() {
 
     let counter_value = random.random_seed(&mut runner, &mut signer);
     assert_eq!(counter_value, expected_value);
+
+    // The view method returns the same seed the precompile sees.
+    let outcome = runner
+        .one_shot()
+        .call("get_random_seed", "any.near", Vec::new())
+        .unwrap();
+    assert_eq!(outcome.return_data.as_value().unwrap(), expected_value.0);
+}
+
+#[cfg(feature = "random-seed-override")]
+#[test]
+fn test_random_seed_override() {
+    let mut signer = utils::Signer::random();
+    let mut runner = utils::deploy_runner();
+    let aurora = runner.aurora_account_id.clone();
+
+    let random_ctr = RandomConstructor::load();
+    let nonce = signer.use_nonce();
+    let random: Random = runner
+        .deploy_contract(&signer.secret_key, |ctr| ctr.deploy(nonce), random_ctr)
+        .into();
+
+    let block_seed = H256::from_slice(&[3; 32]);
+    runner.context.random_seed = block_seed.0.to_vec();
+    assert_eq!(random.random_seed(&mut runner, &mut signer), block_seed);
+
+    // The pinned seed replaces the block seed until the override is removed.
+    let pinned_seed = [9; 32];
+    let args = SetRandomSeedOverrideArgs {
+        random_seed: Some(pinned_seed),
+    };
+    runner
+        .call(
+            "set_random_seed_override",
+            &aurora,
+            borsh::to_vec(&args).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(
+        random.random_seed(&mut runner, &mut signer),
+        H256(pinned_seed)
+    );
+
+    let outcome = runner
+        .one_shot()
+        .call("get_random_seed_override", "any.near", Vec::new())
+        .unwrap();
+    let random_seed =
+        Option::<RawH256>::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap();
+    assert_eq!(random_seed, Some(pinned_seed));
+
+    let args = SetRandomSeedOverrideArgs { random_seed: None };
+    runner
+        .call(
+            "set_random_seed_override",
+            &aurora,
+            borsh::to_vec(&args).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(random.random_seed(&mut runner, &mut signer), block_seed);
 }
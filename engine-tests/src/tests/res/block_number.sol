@@ -0,0 +1,8 @@
+// SPDX-License-Identifier: LGPL-3.0-only
+pragma solidity ^0.8.6;
+
+contract BlockNumber {
+    function getCurrentBlockNumber() public view returns (uint256 number) {
+        number = block.number;
+    }
+}
@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: Unlicense
+pragma solidity ^0.8.0;
+
+contract ChainId {
+  constructor() payable {}
+
+  function chainId() public view returns (uint256) {
+    return block.chainid;
+  }
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract LogOrder {
+    event Step(uint256 index);
+
+    function emitStep(uint256 index) public {
+        emit Step(index);
+    }
+
+    function emitStepAndRevert(uint256 index) public {
+        emit Step(index);
+        revert();
+    }
+
+    function run() public {
+        emit Step(1);
+        this.emitStep(2);
+        // The log of the reverted call is discarded.
+        try this.emitStepAndRevert(3) {} catch {}
+        emit Step(4);
+        this.emitStep(5);
+        emit Step(6);
+    }
+}
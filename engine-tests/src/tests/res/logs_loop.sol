@@ -0,0 +1,12 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.0;
+
+contract LogsLoop {
+    event Ping(uint256 index);
+
+    function emitLogs(uint256 count) public {
+        for (uint256 i = 0; i < count; i++) {
+            emit Ping(i);
+        }
+    }
+}
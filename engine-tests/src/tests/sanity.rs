@@ -1,8 +1,20 @@
-use crate::prelude::{Address, U256};
+use crate::prelude::{u256_to_arr, Address, H256, U256};
 use crate::prelude::{Wei, ERC20_MINT_SELECTOR};
 use crate::utils::{self, str_to_account_id};
 use aurora_engine::engine::{EngineErrorKind, GasPaymentError, ZERO_ADDRESS_FIX_HEIGHT};
-use aurora_engine::parameters::{SetOwnerArgs, SetUpgradeDelayBlocksArgs, TransactionStatus};
+#[cfg(feature = "view-step-limit")]
+use aurora_engine::parameters::SetViewStepLimitArgs;
+use aurora_engine::parameters::{
+    AccountInfo, DeployerAllowlistStatusArgs, DeployerArgs, EngineMetadata, EvmConfig, GenesisInfo,
+    GetDeployersArgs, GovernanceInfo, LogLevel, RegisterRelayersBatchArgs, RelayerKeyManagerArgs,
+    SetEvmMemoryLimitArgs, SetLogLevelArgs, SetMaxLogsPerTxArgs, SetMaxNonceGapArgs,
+    SetMaxRefundQuotientArgs, SetOwnerArgs, SetRelaxedNonceArgs, SetUpgradeDelayBlocksArgs,
+    SimulateDeployArgs, SimulateDeployResult, SubmitArgsV2, SubmitBatchArgs, SubmitBatchResult,
+    SubmitBatchTxResult, SubmitResult, TransactionReceipt, TransactionStatus, VerifySignatureArgs,
+    WithdrawCollectedFeesArgs,
+};
+#[cfg(feature = "fee-distribution")]
+use aurora_engine::parameters::{FeeShare, SetFeeDistributionArgs};
 use aurora_engine_sdk as sdk;
 use aurora_engine_types::borsh::BorshDeserialize;
 #[cfg(not(feature = "ext-connector"))]
@@ -153,8 +165,11 @@ fn test_total_supply_accounting() {
         Wei::new(U256::from(amount))
     };
 
-    // Self-destruct with some benefactor does not reduce the total supply
+    // Self-destruct with some benefactor does not reduce the total supply. The benefactor
+    // doesn't exist beforehand, so its account is created with the balance of the contract.
     let contract = deploy_contract(&mut runner, &mut signer);
+    assert_eq!(runner.get_balance(benefactor), Wei::zero());
+    assert_eq!(runner.get_nonce(benefactor), U256::zero());
     let _submit_result = runner
         .submit_with_signer(&mut signer, |nonce| {
             contract.call_method_with_args(
@@ -184,6 +199,43 @@ fn test_total_supply_accounting() {
     assert_eq!(get_total_supply(&mut runner), INITIAL_BALANCE);
 }
 
+#[cfg(feature = "selfdestruct-log")]
+#[test]
+fn test_selfdestruct_log() {
+    let (mut runner, mut signer, benefactor) = initialize_transfer();
+
+    let constructor = utils::solidity::ContractConstructor::compile_from_source(
+        "src/tests/res",
+        "target/solidity_build",
+        "self_destructor.sol",
+        "SelfDestruct",
+    );
+    let nonce = signer.use_nonce();
+    let contract = runner.deploy_contract(
+        &signer.secret_key,
+        |c| c.deploy_without_constructor(nonce.into()),
+        constructor,
+    );
+
+    let result = runner
+        .submit_with_signer(&mut signer, |nonce| {
+            contract.call_method_with_args(
+                "destruct",
+                &[ethabi::Token::Address(benefactor.raw())],
+                nonce,
+            )
+        })
+        .unwrap();
+    assert!(result.status.is_ok());
+
+    let expected = format!(
+        "SELFDESTRUCT is deprecated: contract 0x{} beneficiary 0x{}",
+        contract.address.encode(),
+        benefactor.encode()
+    );
+    assert!(runner.previous_logs.contains(&expected));
+}
+
 #[test]
 fn test_transaction_to_zero_address() {
     // Transactions that explicit list `0x0000...` as the `to` field in the transaction
@@ -279,6 +331,374 @@ fn test_deploy_contract() {
     assert_eq!(code, stored_code);
 }
 
+#[test]
+fn test_get_code_hash() {
+    let (mut runner, mut signer, dest_address) = initialize_transfer();
+    let source_address = utils::address_from_secret_key(&signer.secret_key);
+
+    let code = generate_code(567);
+    let result = runner
+        .submit_with_signer(&mut signer, |nonce| {
+            utils::create_deploy_transaction(code.clone(), nonce)
+        })
+        .unwrap();
+    let address = Address::try_from_slice(utils::unwrap_success_slice(&result)).unwrap();
+
+    // Contract account: hash of the deployed code.
+    assert_eq!(runner.get_code_hash(address), sdk::keccak(&code));
+    // Existing account without code: hash of the empty code.
+    assert_eq!(runner.get_code_hash(source_address), sdk::keccak(&[]));
+    // Non-existent account: zero.
+    assert_eq!(runner.get_code_hash(dest_address), H256::zero());
+}
+
+#[test]
+fn test_signer_from_seed() {
+    let address = |signer: &utils::Signer| utils::address_from_secret_key(&signer.secret_key);
+    let signer = utils::Signer::from_seed(42);
+    let same_signer = utils::Signer::from_seed(42);
+    let other_signer = utils::Signer::from_seed(43);
+
+    assert_eq!(signer.secret_key, same_signer.secret_key);
+    assert_eq!(address(&signer), address(&same_signer));
+    assert_ne!(signer.secret_key, other_signer.secret_key);
+    assert_ne!(address(&signer), address(&other_signer));
+}
+
+#[test]
+fn test_account_exists() {
+    let (mut runner, mut signer, dest_address) = initialize_transfer();
+    let source_address = utils::address_from_secret_key(&signer.secret_key);
+    let empty_address = Address::from_array([0x11; 20]);
+    runner.create_address(empty_address, Wei::zero(), U256::zero());
+
+    assert!(runner.account_exists(source_address));
+    // Never touched account doesn't exist.
+    assert!(!runner.account_exists(dest_address));
+    // An account can exist while having zero balance and nonce and no code.
+    assert!(runner.account_exists(empty_address));
+    assert_eq!(runner.get_balance(empty_address), Wei::zero());
+    assert!(runner.get_code(empty_address).is_empty());
+
+    // Touching the empty account clears it from the state (EIP-161).
+    let result = runner
+        .submit_with_signer(&mut signer, |nonce| {
+            utils::transfer(empty_address, Wei::zero(), nonce)
+        })
+        .unwrap();
+    assert!(matches!(result.status, TransactionStatus::Succeed(_)));
+    assert!(!runner.account_exists(empty_address));
+    assert_eq!(runner.get_code_hash(empty_address), H256::zero());
+}
+
+#[test]
+fn test_get_account() {
+    let (mut runner, mut signer, dest_address) = initialize_transfer();
+    let source_address = utils::address_from_secret_key(&signer.secret_key);
+
+    let code = generate_code(567);
+    let result = runner
+        .submit_with_signer(&mut signer, |nonce| {
+            utils::create_deploy_transaction(code.clone(), nonce)
+        })
+        .unwrap();
+    let contract_address = Address::try_from_slice(utils::unwrap_success_slice(&result)).unwrap();
+
+    // EOA: hash of the empty code.
+    let account = runner.get_account(source_address);
+    assert_eq!(
+        account,
+        AccountInfo {
+            balance: INITIAL_BALANCE.to_bytes(),
+            nonce: u256_to_arr(&(INITIAL_NONCE + 1).into()),
+            code_hash: sdk::keccak(&[]).0,
+            exists: true,
+        }
+    );
+
+    // Contract: hash of the deployed code and the nonce set by EIP-161.
+    let account = runner.get_account(contract_address);
+    assert_eq!(account.balance, Wei::zero().to_bytes());
+    assert_eq!(account.nonce, u256_to_arr(&U256::one()));
+    assert_eq!(account.code_hash, sdk::keccak(&code).0);
+    assert!(account.exists);
+
+    // Non-existent account: zero code hash.
+    let account = runner.get_account(dest_address);
+    assert_eq!(
+        account,
+        AccountInfo {
+            balance: Wei::zero().to_bytes(),
+            nonce: u256_to_arr(&U256::zero()),
+            code_hash: H256::zero().0,
+            exists: false,
+        }
+    );
+}
+
+#[test]
+fn test_verify_signature() {
+    let runner = utils::deploy_runner();
+    let verify = |hash: H256, signature: &[u8], address: Address| {
+        let args = VerifySignatureArgs {
+            hash: hash.0,
+            signature: signature.to_vec(),
+            address,
+        };
+        let outcome = runner
+            .one_shot()
+            .call(
+                "verify_signature",
+                "any.near",
+                borsh::to_vec(&args).unwrap(),
+            )
+            .unwrap();
+        outcome.return_data.as_value().unwrap()
+    };
+    let hash = H256([0x11; 32]);
+    let signature = hex::decode("b9f0bb08640d3c1c00761cdd0121209268f6fd3816bc98b9e6f3cc77bf82b69812ac7a61788a0fdc0e19180f14c945a8e1088a27d92a74dce81c0981fb6447441b").unwrap();
+    let signer = utils::address_from_hex("0x1563915e194D8CfBA1943570603F7606A3115508");
+
+    assert_eq!(verify(hash, &signature, signer), [1]);
+    // The recovery id can also be given as 0/1 instead of 27/28.
+    let mut normalized = signature.clone();
+    normalized[64] -= 27;
+    assert_eq!(verify(hash, &normalized, signer), [1]);
+
+    // Another address, hash or a tampered signature do not match.
+    assert_eq!(verify(hash, &signature, Address::zero()), [0]);
+    assert_eq!(verify(H256([0x22; 32]), &signature, signer), [0]);
+    let mut tampered = signature.clone();
+    tampered[10] ^= 0xff;
+    assert_eq!(verify(hash, &tampered, signer), [0]);
+
+    // Malformed signatures are a mismatch rather than an error.
+    let mut invalid_v = signature.clone();
+    invalid_v[64] = 5;
+    assert_eq!(verify(hash, &invalid_v, signer), [0]);
+    assert_eq!(verify(hash, &signature[..64], signer), [0]);
+    assert_eq!(verify(hash, &[0; 65], signer), [0]);
+}
+
+#[test]
+fn test_get_accounts() {
+    let (mut runner, mut signer, dest_address) = initialize_transfer();
+    let source_address = utils::address_from_secret_key(&signer.secret_key);
+    let result = runner
+        .submit_with_signer(&mut signer, |nonce| {
+            utils::create_deploy_transaction(generate_code(567), nonce)
+        })
+        .unwrap();
+    let contract_address = Address::try_from_slice(utils::unwrap_success_slice(&result)).unwrap();
+
+    // The accounts are returned in the order of the addresses, the non-existent one is zeroed.
+    let addresses = [dest_address, source_address, contract_address];
+    let accounts = runner.get_accounts(&addresses).unwrap();
+    assert_eq!(
+        accounts,
+        addresses
+            .iter()
+            .map(|address| runner.get_account(*address))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(accounts[0].balance, Wei::zero().to_bytes());
+    assert_eq!(accounts[0].nonce, u256_to_arr(&U256::zero()));
+    assert!(!accounts[0].exists);
+    assert_eq!(accounts[1].balance, INITIAL_BALANCE.to_bytes());
+    assert_eq!(accounts[1].nonce, u256_to_arr(&(INITIAL_NONCE + 1).into()));
+
+    assert!(runner.get_accounts(&[]).unwrap().is_empty());
+
+    let too_many = vec![dest_address; aurora_engine::engine::MAX_ACCOUNTS_PER_QUERY + 1];
+    let error = runner.get_accounts(&too_many).unwrap_err();
+    assert_eq!(
+        error.kind,
+        EngineErrorKind::EvmFatal(evm::ExitFatal::Other("ERR_TOO_MANY_ACCOUNTS".into()))
+    );
+}
+
+#[test]
+fn test_get_precompiles() {
+    use aurora_engine_precompiles::{
+        account_ids::{predecessor_account, CurrentAccount},
+        native::{exit_to_ethereum, exit_to_near},
+        prepaid_gas, promise_result,
+        random::RandomSeed,
+        xcc::cross_contract_call,
+    };
+
+    let (runner, _, _) = initialize_transfer();
+    let precompiles = runner.get_precompiles();
+
+    // Standard Ethereum precompiles available in the Berlin hard fork.
+    for i in 1..=9 {
+        assert!(precompiles.contains(&Address::from_array(H160::from_low_u64_be(i).0)));
+    }
+    // Aurora-specific precompiles.
+    for address in [
+        exit_to_near::ADDRESS,
+        exit_to_ethereum::ADDRESS,
+        cross_contract_call::ADDRESS,
+        predecessor_account::ADDRESS,
+        CurrentAccount::ADDRESS,
+        RandomSeed::ADDRESS,
+        prepaid_gas::ADDRESS,
+        promise_result::ADDRESS,
+        promise_result::COUNT_ADDRESS,
+    ] {
+        assert!(precompiles.contains(&address));
+    }
+    assert_eq!(precompiles.len(), 18);
+}
+
+#[test]
+fn test_get_precompile_info() {
+    use aurora_engine_precompiles::{
+        blake2::Blake2F, modexp::ModExp, secp256k1::ECRecover, Berlin,
+    };
+
+    let (runner, _, _) = initialize_transfer();
+    let precompiles = runner.get_precompiles();
+    let precompile_info = runner.get_precompile_info();
+
+    // Every active precompile is reported exactly once.
+    assert_eq!(precompile_info.len(), precompiles.len());
+    for info in &precompile_info {
+        assert!(precompiles.contains(&info.address));
+    }
+
+    let fork_of = |address: Address| {
+        precompile_info
+            .iter()
+            .find(|info| info.address == address)
+            .map(|info| info.fork.as_str())
+            .unwrap()
+    };
+    assert_eq!(fork_of(ECRecover::ADDRESS), "homestead");
+    assert_eq!(fork_of(ModExp::<Berlin>::ADDRESS), "byzantium");
+    assert_eq!(fork_of(Blake2F::ADDRESS), "istanbul");
+}
+
+#[test]
+fn test_reject_unknown_precompiles() {
+    let (mut runner, mut signer, _) = initialize_transfer();
+    let aurora = runner.aurora_account_id.clone();
+    // No precompile is implemented at `0x0a`, which is in the reserved range.
+    let unknown_precompile = Address::from_array({
+        let mut bytes = [0u8; 20];
+        bytes[19] = 0x0a;
+        bytes
+    });
+    let is_rejected = |runner: &utils::AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_reject_unknown_precompiles", "any.near", Vec::new())
+            .unwrap();
+        bool::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+    let call_unknown_precompile = |runner: &mut utils::AuroraRunner, signer: &mut utils::Signer| {
+        runner
+            .submit_with_signer(signer, |nonce| {
+                utils::transfer(unknown_precompile, Wei::zero(), nonce)
+            })
+            .unwrap()
+    };
+
+    // The calls succeed as calls to an empty account by default.
+    assert!(!is_rejected(&runner));
+    let result = call_unknown_precompile(&mut runner, &mut signer);
+    assert_eq!(result.status, TransactionStatus::Succeed(Vec::new()));
+
+    // Only the owner can change the flag.
+    let error = runner
+        .call(
+            "set_reject_unknown_precompiles",
+            "some-account.near",
+            borsh::to_vec(&true).unwrap(),
+        )
+        .unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::NotAllowed));
+
+    runner
+        .call(
+            "set_reject_unknown_precompiles",
+            &aurora,
+            borsh::to_vec(&true).unwrap(),
+        )
+        .unwrap();
+    assert!(is_rejected(&runner));
+    let result = call_unknown_precompile(&mut runner, &mut signer);
+    assert_eq!(
+        result.status,
+        TransactionStatus::Other("ERR_UNKNOWN_PRECOMPILE".into())
+    );
+
+    // The implemented precompiles and the addresses out of the reserved range aren't affected.
+    let result = runner
+        .submit_with_signer(&mut signer, |nonce| {
+            utils::transfer(
+                aurora_engine_precompiles::identity::Identity::ADDRESS,
+                Wei::zero(),
+                nonce,
+            )
+        })
+        .unwrap();
+    assert!(result.status.is_ok());
+    let result = runner
+        .submit_with_signer(&mut signer, |nonce| {
+            utils::transfer(Address::from_array([0x0a; 20]), Wei::zero(), nonce)
+        })
+        .unwrap();
+    assert!(result.status.is_ok());
+
+    runner
+        .call(
+            "set_reject_unknown_precompiles",
+            &aurora,
+            borsh::to_vec(&false).unwrap(),
+        )
+        .unwrap();
+    assert!(!is_rejected(&runner));
+    let result = call_unknown_precompile(&mut runner, &mut signer);
+    assert_eq!(result.status, TransactionStatus::Succeed(Vec::new()));
+}
+
+#[test]
+fn test_simulate_deploy() {
+    let (mut runner, mut signer, _) = initialize_transfer();
+    let sender = utils::address_from_secret_key(&signer.secret_key);
+    // Init code which sets the storage slot 0 to 1 and deploys `PUSH1 0 PUSH1 0 SSTORE STOP`.
+    let code = hex::decode("60016000556006601160003960066000f3600060005500").unwrap();
+
+    let args = SimulateDeployArgs {
+        sender,
+        code: code.clone(),
+    };
+    let outcome = runner
+        .one_shot()
+        .call("simulate_deploy", "getter", borsh::to_vec(&args).unwrap())
+        .unwrap();
+    let simulated =
+        SimulateDeployResult::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap();
+    // The simulation doesn't change the nonce of the sender.
+    assert_eq!(runner.get_nonce(sender), INITIAL_NONCE.into());
+
+    let result = runner
+        .submit_with_signer(&mut signer, |nonce| {
+            utils::create_deploy_transaction(code, nonce)
+        })
+        .unwrap();
+    let address = Address::try_from_slice(utils::unwrap_success_slice(&result)).unwrap();
+
+    assert_eq!(simulated.address, address);
+    assert_eq!(simulated.gas_used, result.gas_used);
+    assert_eq!(simulated.status, result.status);
+    assert_eq!(
+        runner.get_code(address),
+        hex::decode("600060005500").unwrap()
+    );
+}
+
 #[test]
 fn test_deploy_largest_contract() {
     // Check to see we can deploy the largest allowed contract size within the
@@ -345,6 +765,47 @@ fn test_log_address() {
     assert_eq!(log_address, greet_contract.address);
 }
 
+#[test]
+fn test_logs_order() {
+    let (mut runner, mut signer, _) = initialize_transfer();
+
+    let constructor = utils::solidity::ContractConstructor::force_compile(
+        "src/tests/res",
+        "target/solidity_build",
+        "log_order.sol",
+        "LogOrder",
+    );
+    let nonce = signer.use_nonce();
+    let contract = runner.deploy_contract(
+        &signer.secret_key,
+        |c| c.deploy_without_constructor(nonce.into()),
+        constructor,
+    );
+
+    // Execute the transaction in the wasm and standalone runners separately to compare the results.
+    let mut standalone_runner = runner.standalone_runner.take().unwrap();
+    let nonce = signer.use_nonce();
+    let result = runner
+        .submit_transaction(
+            &signer.secret_key,
+            contract.call_method_without_args("run", nonce.into()),
+        )
+        .unwrap();
+    let standalone_result = standalone_runner
+        .submit_raw(utils::SUBMIT, &runner.context, &[], None)
+        .unwrap();
+
+    // The logs of the nested calls are placed between the logs of the outer call
+    // in the order of execution, the log of the reverted call is discarded.
+    let steps: Vec<U256> = result
+        .logs
+        .iter()
+        .map(|log| U256::from_big_endian(&log.data))
+        .collect();
+    assert_eq!(steps, [1, 2, 4, 5, 6].map(U256::from));
+    assert_eq!(result.logs, standalone_result.logs);
+}
+
 #[test]
 fn test_is_contract() {
     let (mut runner, mut signer, _) = initialize_transfer();
@@ -819,54 +1280,1129 @@ fn test_eth_transfer_incorrect_nonce() {
 }
 
 #[test]
-fn test_tx_support_shanghai() {
-    let (mut runner, mut source_account, _) = initialize_transfer();
-    // Encoded EVM transaction with parameter: `evmVersion: 'shanghai'`.
-    let data = "6080604052348015600e575f80fd5b50607480601a5f395ff3fe6080604052348015600e575\
-    f80fd5b50600436106026575f3560e01c8063919840ad14602a575b5f80fd5b600560405190815260200160\
-    405180910390f3fea2646970667358221220cb01b9b9c75e5cd079a1980af2fe4397d2029888d12737d74cb\
-    bc10e0de65bd364736f6c63430008150033";
+fn test_get_relayer_account() {
+    let mut runner = utils::deploy_runner();
+    let relayer = "relayer.near";
+    let relayer_address = Address::from_array([0x11; 20]);
+    let get_relayer_account = |runner: &utils::AuroraRunner, address: Address| {
+        runner
+            .one_shot()
+            .call(
+                "get_relayer_account",
+                "any.near",
+                address.as_bytes().to_vec(),
+            )
+            .unwrap()
+            .return_data
+            .as_value()
+            .unwrap_or_default()
+    };
 
-    let result = runner
-        .submit_with_signer(&mut source_account, |nonce| {
-            aurora_engine_transactions::legacy::TransactionLegacy {
-                nonce,
-                gas_price: 0.into(),
-                gas_limit: u64::MAX.into(),
-                to: None,
-                value: Wei::zero(),
-                data: hex::decode(data).unwrap(),
-            }
-        })
-        .expect("Should be able to execute EVM bytecode including PUSH0");
+    runner
+        .call(
+            "register_relayer",
+            relayer,
+            relayer_address.as_bytes().to_vec(),
+        )
+        .unwrap();
+    assert_eq!(
+        get_relayer_account(&runner, relayer_address),
+        relayer.as_bytes()
+    );
 
-    assert!(result.status.is_ok());
+    // The address is not registered yet.
+    let other_address = Address::from_array([0x22; 20]);
+    assert!(get_relayer_account(&runner, other_address).is_empty());
+
+    // The previous address is no longer mapped after the relayer changes its address.
+    runner
+        .call(
+            "register_relayer",
+            relayer,
+            other_address.as_bytes().to_vec(),
+        )
+        .unwrap();
+    assert!(get_relayer_account(&runner, relayer_address).is_empty());
+    assert_eq!(
+        get_relayer_account(&runner, other_address),
+        relayer.as_bytes()
+    );
 }
 
 #[test]
-fn test_eth_transfer_not_enough_gas() {
-    let (mut runner, mut source_account, dest_address) = initialize_transfer();
+fn test_eth_transfer_relaxed_nonce() {
+    let (mut runner, source_account, dest_address) = initialize_transfer();
     let source_address = utils::address_from_secret_key(&source_account.secret_key);
-    let transaction = |nonce| {
-        let mut tx = utils::transfer(dest_address, TRANSFER_AMOUNT, nonce);
-        tx.gas_limit = 10_000.into(); // this is not enough gas
-        tx
-    };
-
-    // validate pre-state
-    utils::validate_address_balance_and_nonce(
-        &runner,
-        source_address,
-        INITIAL_BALANCE,
-        INITIAL_NONCE.into(),
-    )
-    .unwrap();
-    utils::validate_address_balance_and_nonce(&runner, dest_address, Wei::zero(), 0.into())
-        .unwrap();
+    // The account used as the relayer by `submit_transaction`.
+    let relayer = "some-account.near";
+    let aurora = runner.aurora_account_id.clone();
+    let gapped_nonce = U256::from(INITIAL_NONCE + 5);
 
-    // attempt transfer
+    // In strict mode (the default) a gap in the nonce is rejected.
     let error = runner
-        .submit_with_signer(&mut source_account, transaction)
+        .submit_transaction(
+            &source_account.secret_key,
+            utils::transfer(dest_address, TRANSFER_AMOUNT, gapped_nonce),
+        )
+        .unwrap_err();
+    assert!(
+        matches!(error.kind, EngineErrorKind::IncorrectNonce(msg) if &msg == "ERR_INCORRECT_NONCE: ac: 0, tx: 5")
+    );
+
+    // The relaxed mode can only be enabled for registered relayers.
+    let args = SetRelaxedNonceArgs {
+        relayer: str_to_account_id(relayer),
+        enabled: true,
+    };
+    let error = runner
+        .call("set_relaxed_nonce", &aurora, borsh::to_vec(&args).unwrap())
+        .unwrap_err();
+    assert!(
+        matches!(error.kind, EngineErrorKind::EvmFatal(evm::ExitFatal::Other(e)) if e == "ERR_RELAYER_NOT_REGISTERED")
+    );
+
+    let relayer_address = Address::from_array([0x11; 20]);
+    runner
+        .call(
+            "register_relayer",
+            relayer,
+            relayer_address.as_bytes().to_vec(),
+        )
+        .unwrap();
+    runner
+        .call("set_relaxed_nonce", &aurora, borsh::to_vec(&args).unwrap())
+        .unwrap();
+
+    // In relaxed mode the gapped nonce is accepted...
+    let result = runner
+        .submit_transaction(
+            &source_account.secret_key,
+            utils::transfer(dest_address, TRANSFER_AMOUNT, gapped_nonce),
+        )
+        .unwrap();
+    assert!(result.status.is_ok());
+    utils::validate_address_balance_and_nonce(
+        &runner,
+        source_address,
+        INITIAL_BALANCE - TRANSFER_AMOUNT,
+        gapped_nonce + 1,
+    )
+    .unwrap();
+
+    // ...but nonces which were skipped can not be used anymore.
+    let error = runner
+        .submit_transaction(
+            &source_account.secret_key,
+            utils::transfer(dest_address, TRANSFER_AMOUNT, gapped_nonce - 1),
+        )
+        .unwrap_err();
+    assert!(
+        matches!(error.kind, EngineErrorKind::IncorrectNonce(msg) if &msg == "ERR_INCORRECT_NONCE: ac: 6, tx: 4")
+    );
+
+    // The gap is unlimited unless the owner sets the maximum.
+    let max_gap = |runner: &utils::AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_max_nonce_gap", "any.near", Vec::new())
+            .unwrap();
+        Option::<u64>::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+    let args = SetMaxNonceGapArgs { max_gap: Some(2) };
+    assert_eq!(max_gap(&runner), None);
+    let error = runner
+        .call("set_max_nonce_gap", relayer, borsh::to_vec(&args).unwrap())
+        .unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::NotAllowed));
+    runner
+        .call("set_max_nonce_gap", &aurora, borsh::to_vec(&args).unwrap())
+        .unwrap();
+    assert_eq!(max_gap(&runner), Some(2));
+
+    let error = runner
+        .submit_transaction(
+            &source_account.secret_key,
+            utils::transfer(dest_address, TRANSFER_AMOUNT, gapped_nonce + 4),
+        )
+        .unwrap_err();
+    assert_eq!(error.kind, EngineErrorKind::NonceGapTooLarge);
+    let result = runner
+        .submit_transaction(
+            &source_account.secret_key,
+            utils::transfer(dest_address, TRANSFER_AMOUNT, gapped_nonce + 3),
+        )
+        .unwrap();
+    assert!(result.status.is_ok());
+    utils::validate_address_balance_and_nonce(
+        &runner,
+        source_address,
+        INITIAL_BALANCE - TRANSFER_AMOUNT - TRANSFER_AMOUNT,
+        gapped_nonce + 4,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_submit_with_deadline() {
+    let (mut runner, mut signer, dest_address) = initialize_transfer();
+    let source_address = utils::address_from_secret_key(&signer.secret_key);
+    let mut submit = |runner: &mut utils::AuroraRunner, valid_until_block: Option<u64>| {
+        let transaction = utils::transfer(dest_address, TRANSFER_AMOUNT, signer.nonce.into());
+        let signed_tx =
+            utils::sign_transaction(transaction, Some(runner.chain_id), &signer.secret_key);
+        let args = SubmitArgsV2 {
+            tx_data: rlp::encode(&signed_tx).to_vec(),
+            valid_until_block,
+            ..Default::default()
+        };
+        let result = runner.call(
+            utils::SUBMIT_WITH_ARGS,
+            "some-account.near",
+            borsh::to_vec(&args).unwrap(),
+        );
+        if result.is_ok() {
+            signer.use_nonce();
+        }
+        result
+    };
+
+    // The runner executes every call in the next block.
+    let expired_block = runner.context.block_height;
+    let error = submit(&mut runner, Some(expired_block)).unwrap_err();
+    assert_eq!(error.kind, EngineErrorKind::TxExpired);
+    assert_eq!(runner.get_nonce(source_address), U256::from(INITIAL_NONCE));
+
+    let current_block = runner.context.block_height + 1;
+    submit(&mut runner, Some(current_block)).unwrap();
+    assert_eq!(
+        runner.get_nonce(source_address),
+        U256::from(INITIAL_NONCE + 1)
+    );
+
+    // The transactions without a deadline never expire.
+    submit(&mut runner, None).unwrap();
+    assert_eq!(
+        runner.get_nonce(source_address),
+        U256::from(INITIAL_NONCE + 2)
+    );
+}
+
+#[test]
+fn test_submit_batch() {
+    let (mut runner, signer, dest_address) = initialize_transfer();
+    let source_address = utils::address_from_secret_key(&signer.secret_key);
+    let sign = |runner: &utils::AuroraRunner, nonce: u64, revert: bool| {
+        let transaction = if revert {
+            // Init code which reverts.
+            utils::create_deploy_transaction(vec![0x60, 0x00, 0x60, 0x00, 0xfd], nonce.into())
+        } else {
+            utils::transfer(dest_address, TRANSFER_AMOUNT, nonce.into())
+        };
+        let signed_tx =
+            utils::sign_transaction(transaction, Some(runner.chain_id), &signer.secret_key);
+        rlp::encode(&signed_tx).to_vec()
+    };
+    let submit_batch = |runner: &mut utils::AuroraRunner, transactions, atomic| {
+        let args = SubmitBatchArgs {
+            transactions,
+            atomic,
+        };
+        runner
+            .call(
+                "submit_batch",
+                "some-account.near",
+                borsh::to_vec(&args).unwrap(),
+            )
+            .map(|outcome| {
+                SubmitBatchResult::try_from_slice(&outcome.return_data.as_value().unwrap())
+                    .unwrap()
+                    .results
+            })
+    };
+
+    // The transactions of the batch use the consecutive nonces and the reverted one doesn't
+    // affect the others in the non-atomic mode.
+    let transactions = vec![
+        sign(&runner, INITIAL_NONCE, false),
+        sign(&runner, INITIAL_NONCE + 1, true),
+        sign(&runner, INITIAL_NONCE + 2, false),
+    ];
+    let results = submit_batch(&mut runner, transactions, false).unwrap();
+    assert_eq!(results.len(), 3);
+    assert!(unwrap_executed(&results[0]).status.is_ok());
+    assert!(unwrap_executed(&results[1]).status.is_revert());
+    assert!(unwrap_executed(&results[2]).status.is_ok());
+    assert_eq!(
+        runner.get_nonce(source_address),
+        U256::from(INITIAL_NONCE + 3)
+    );
+    assert_eq!(
+        runner.get_balance(dest_address),
+        TRANSFER_AMOUNT + TRANSFER_AMOUNT
+    );
+
+    // The whole batch is reverted in the atomic mode.
+    let transactions = vec![
+        sign(&runner, INITIAL_NONCE + 3, false),
+        sign(&runner, INITIAL_NONCE + 4, true),
+    ];
+    // NEAR reverts the state of a failed call, but the mocked external doesn't.
+    let trie = runner.ext.underlying.fake_trie.clone();
+    let error = submit_batch(&mut runner, transactions, true).unwrap_err();
+    runner.ext.underlying.fake_trie = trie;
+    assert!(matches!(
+        error.kind,
+        EngineErrorKind::EvmFatal(evm::ExitFatal::Other(e)) if e == "ERR_BATCH_TX_FAILED"
+    ));
+    assert_eq!(
+        runner.get_nonce(source_address),
+        U256::from(INITIAL_NONCE + 3)
+    );
+    assert_eq!(
+        runner.get_balance(dest_address),
+        TRANSFER_AMOUNT + TRANSFER_AMOUNT
+    );
+
+    let transactions = vec![
+        sign(&runner, INITIAL_NONCE + 3, false),
+        sign(&runner, INITIAL_NONCE + 4, false),
+    ];
+    let results = submit_batch(&mut runner, transactions, true).unwrap();
+    assert!(results
+        .iter()
+        .all(|result| unwrap_executed(result).status.is_ok()));
+    assert_eq!(
+        runner.get_nonce(source_address),
+        U256::from(INITIAL_NONCE + 5)
+    );
+    assert_eq!(
+        runner.get_balance(dest_address),
+        TRANSFER_AMOUNT + TRANSFER_AMOUNT + TRANSFER_AMOUNT + TRANSFER_AMOUNT
+    );
+
+    // The transactions which can't be executed are skipped in the non-atomic mode.
+    let transactions = vec![
+        sign(&runner, INITIAL_NONCE + 5, false),
+        sign(&runner, INITIAL_NONCE + 5, false),
+        vec![0xde, 0xad, 0xbe, 0xef],
+        sign(&runner, INITIAL_NONCE + 6, false),
+    ];
+    let results = submit_batch(&mut runner, transactions, false).unwrap();
+    assert_eq!(results.len(), 4);
+    assert!(unwrap_executed(&results[0]).status.is_ok());
+    assert_eq!(
+        results[1],
+        SubmitBatchTxResult::Rejected(format!(
+            "ERR_INCORRECT_NONCE: ac: {}, tx: {}",
+            INITIAL_NONCE + 6,
+            INITIAL_NONCE + 5
+        ))
+    );
+    assert!(matches!(results[2], SubmitBatchTxResult::Rejected(_)));
+    assert!(unwrap_executed(&results[3]).status.is_ok());
+    assert_eq!(
+        runner.get_nonce(source_address),
+        U256::from(INITIAL_NONCE + 7)
+    );
+    assert_eq!(
+        runner.get_balance(dest_address),
+        Wei::new(TRANSFER_AMOUNT.raw() * 6)
+    );
+
+    // But they fail the whole batch in the atomic mode.
+    let transactions = vec![
+        sign(&runner, INITIAL_NONCE + 7, false),
+        sign(&runner, INITIAL_NONCE + 9, false),
+    ];
+    let trie = runner.ext.underlying.fake_trie.clone();
+    let error = submit_batch(&mut runner, transactions, true).unwrap_err();
+    runner.ext.underlying.fake_trie = trie;
+    assert!(matches!(
+        error.kind,
+        EngineErrorKind::IncorrectNonce(msg)
+            if msg == format!("ERR_INCORRECT_NONCE: ac: {}, tx: {}", INITIAL_NONCE + 8, INITIAL_NONCE + 9)
+    ));
+    assert_eq!(
+        runner.get_nonce(source_address),
+        U256::from(INITIAL_NONCE + 7)
+    );
+}
+
+fn unwrap_executed(result: &SubmitBatchTxResult) -> &SubmitResult {
+    match result {
+        SubmitBatchTxResult::Executed(result) => result,
+        SubmitBatchTxResult::Rejected(error) => panic!("Transaction rejected: {error}"),
+    }
+}
+
+#[test]
+fn test_submit_batch_reports_each_status() {
+    let (mut runner, signer, dest_address) = initialize_transfer();
+    let source_address = utils::address_from_secret_key(&signer.secret_key);
+    let sign = |runner: &utils::AuroraRunner, transaction| {
+        let signed_tx =
+            utils::sign_transaction(transaction, Some(runner.chain_id), &signer.secret_key);
+        rlp::encode(&signed_tx).to_vec()
+    };
+    // Init code of a contract which always reverts with `0x2a` (as a 32 bytes word).
+    let init_code = hex::decode("600a600c600039600a6000f3602a60005260206000fd").unwrap();
+    let contract_address =
+        aurora_engine::engine::create_legacy_address(&source_address, &INITIAL_NONCE.into());
+
+    // The middle transaction calls the contract deployed by the first one and reverts.
+    let args = SubmitBatchArgs {
+        transactions: vec![
+            sign(
+                &runner,
+                utils::create_deploy_transaction(init_code, INITIAL_NONCE.into()),
+            ),
+            sign(
+                &runner,
+                utils::transfer(contract_address, Wei::zero(), (INITIAL_NONCE + 1).into()),
+            ),
+            sign(
+                &runner,
+                utils::transfer(dest_address, TRANSFER_AMOUNT, (INITIAL_NONCE + 2).into()),
+            ),
+        ],
+        atomic: false,
+    };
+    let outcome = runner
+        .call(
+            "submit_batch",
+            "some-account.near",
+            borsh::to_vec(&args).unwrap(),
+        )
+        .unwrap();
+    let results = SubmitBatchResult::try_from_slice(&outcome.return_data.as_value().unwrap())
+        .unwrap()
+        .results;
+    let results: Vec<_> = results.iter().map(unwrap_executed).collect();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(
+        results[0].status,
+        TransactionStatus::Succeed(contract_address.as_bytes().to_vec())
+    );
+    assert_eq!(
+        results[1].status,
+        TransactionStatus::Revert(u256_to_arr(&U256::from(0x2a)).to_vec())
+    );
+    assert!(results[1].gas_used > 0);
+    assert_eq!(results[2].status, TransactionStatus::Succeed(Vec::new()));
+
+    // The transactions around the reverted one have been executed.
+    assert!(!runner.get_code(contract_address).is_empty());
+    assert_eq!(runner.get_balance(dest_address), TRANSFER_AMOUNT);
+    assert_eq!(
+        runner.get_nonce(source_address),
+        U256::from(INITIAL_NONCE + 3)
+    );
+}
+
+#[test]
+fn test_tx_count_tracking() {
+    let (mut runner, source_account, dest_address) = initialize_transfer();
+    let source_address = utils::address_from_secret_key(&source_account.secret_key);
+    let aurora = runner.aurora_account_id.clone();
+    let transfer = |runner: &mut utils::AuroraRunner, nonce: U256| {
+        let result = runner
+            .submit_transaction(
+                &source_account.secret_key,
+                utils::transfer(dest_address, TRANSFER_AMOUNT, nonce),
+            )
+            .unwrap();
+        assert!(result.status.is_ok());
+    };
+    let tracking_enabled = |runner: &utils::AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_tx_count_tracking_enabled", "any.near", Vec::new())
+            .unwrap();
+        bool::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+
+    // The transactions are not counted by default.
+    assert!(!tracking_enabled(&runner));
+    transfer(&mut runner, U256::from(INITIAL_NONCE));
+    assert_eq!(runner.get_tx_count(source_address), U256::zero());
+
+    // Only the owner can enable the tracking.
+    let error = runner
+        .call(
+            "set_tx_count_tracking_enabled",
+            "some-account.near",
+            borsh::to_vec(&true).unwrap(),
+        )
+        .unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::NotAllowed));
+    runner
+        .call(
+            "set_tx_count_tracking_enabled",
+            &aurora,
+            borsh::to_vec(&true).unwrap(),
+        )
+        .unwrap();
+    assert!(tracking_enabled(&runner));
+
+    transfer(&mut runner, U256::from(INITIAL_NONCE + 1));
+    assert_eq!(runner.get_tx_count(source_address), U256::one());
+    assert_eq!(runner.get_nonce(source_address), U256::from(2));
+
+    // The count diverges from the nonce when the relaxed nonce mode skips some nonces.
+    let relayer = "some-account.near";
+    runner
+        .call(
+            "register_relayer",
+            relayer,
+            Address::from_array([0x11; 20]).as_bytes().to_vec(),
+        )
+        .unwrap();
+    let args = SetRelaxedNonceArgs {
+        relayer: str_to_account_id(relayer),
+        enabled: true,
+    };
+    runner
+        .call("set_relaxed_nonce", &aurora, borsh::to_vec(&args).unwrap())
+        .unwrap();
+    transfer(&mut runner, U256::from(10));
+    assert_eq!(runner.get_tx_count(source_address), U256::from(2));
+    assert_eq!(runner.get_nonce(source_address), U256::from(11));
+}
+
+#[test]
+fn test_receipts_persistence() {
+    let (mut runner, mut signer, dest_address) = initialize_transfer();
+    let aurora = runner.aurora_account_id.clone();
+    let mut submit = |runner: &mut utils::AuroraRunner, to: Option<Address>| {
+        let nonce = signer.use_nonce().into();
+        let transaction = to.map_or_else(
+            // Init code which deploys an empty contract.
+            || utils::create_deploy_transaction(vec![0x60, 0x00, 0x60, 0x00, 0xf3], nonce),
+            |to| utils::transfer(to, TRANSFER_AMOUNT, nonce),
+        );
+        let signed_tx =
+            utils::sign_transaction(transaction, Some(runner.chain_id), &signer.secret_key);
+        let tx_bytes = rlp::encode(&signed_tx).to_vec();
+        let outcome = runner
+            .call(utils::SUBMIT, "some-account.near", tx_bytes.clone())
+            .unwrap();
+        let result =
+            SubmitResult::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap();
+        (sdk::keccak(&tx_bytes), result)
+    };
+    let get_receipt = |runner: &utils::AuroraRunner, tx_hash: H256| {
+        let outcome = runner
+            .one_shot()
+            .call("get_receipt", "any.near", tx_hash.as_bytes().to_vec())
+            .unwrap();
+        Option::<TransactionReceipt>::try_from_slice(&outcome.return_data.as_value().unwrap())
+            .unwrap()
+    };
+
+    // The receipts are not persisted by default.
+    let (tx_hash, _) = submit(&mut runner, Some(dest_address));
+    assert_eq!(get_receipt(&runner, tx_hash), None);
+
+    // Only the owner can enable the persistence.
+    let error = runner
+        .call(
+            "set_receipts_persistence_enabled",
+            "some-account.near",
+            borsh::to_vec(&true).unwrap(),
+        )
+        .unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::NotAllowed));
+    runner
+        .call(
+            "set_receipts_persistence_enabled",
+            &aurora,
+            borsh::to_vec(&true).unwrap(),
+        )
+        .unwrap();
+
+    let (tx_hash, result) = submit(&mut runner, Some(dest_address));
+    let receipt = get_receipt(&runner, tx_hash).unwrap();
+    assert_eq!(receipt.status, result.status);
+    assert_eq!(receipt.gas_used, result.gas_used);
+    assert!(receipt.logs.is_empty());
+    assert_eq!(receipt.contract_address, None);
+
+    let (tx_hash, result) = submit(&mut runner, None);
+    let receipt = get_receipt(&runner, tx_hash).unwrap();
+    let contract_address = Address::try_from_slice(&utils::unwrap_success(result)).unwrap();
+    assert_eq!(receipt.contract_address, Some(contract_address));
+
+    // The persisted receipts are kept after the persistence is disabled.
+    runner
+        .call(
+            "set_receipts_persistence_enabled",
+            &aurora,
+            borsh::to_vec(&false).unwrap(),
+        )
+        .unwrap();
+    assert!(get_receipt(&runner, tx_hash).is_some());
+    let (tx_hash, _) = submit(&mut runner, Some(dest_address));
+    assert_eq!(get_receipt(&runner, tx_hash), None);
+}
+
+#[test]
+fn test_genesis_info() {
+    let (mut runner, mut signer, dest_address) = initialize_transfer();
+    let get_genesis_info = |runner: &utils::AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_genesis_info", "any.near", Vec::new())
+            .unwrap();
+        Option::<GenesisInfo>::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+
+    assert_eq!(get_genesis_info(&runner), None);
+
+    runner
+        .submit_with_signer(&mut signer, |nonce| {
+            utils::transfer(dest_address, TRANSFER_AMOUNT, nonce)
+        })
+        .unwrap();
+    let expected = GenesisInfo {
+        block_height: runner.context.block_height,
+        block_timestamp: runner.context.block_timestamp,
+    };
+    assert_eq!(get_genesis_info(&runner), Some(expected));
+
+    // The following transactions, executed in later blocks, don't overwrite it.
+    for _ in 0..2 {
+        runner
+            .submit_with_signer(&mut signer, |nonce| {
+                utils::transfer(dest_address, TRANSFER_AMOUNT, nonce)
+            })
+            .unwrap();
+        assert!(runner.context.block_height > expected.block_height);
+        assert_eq!(get_genesis_info(&runner), Some(expected));
+    }
+}
+
+#[test]
+fn test_register_relayers_batch() {
+    let mut runner = utils::deploy_runner();
+    let aurora = runner.aurora_account_id.clone();
+    let mut args = RegisterRelayersBatchArgs {
+        relayers: vec![
+            (
+                str_to_account_id("relayer1.near"),
+                Address::from_array([0x01; 20]),
+            ),
+            (
+                str_to_account_id("relayer2.near"),
+                Address::from_array([0x02; 20]),
+            ),
+        ],
+    };
+
+    // Only the owner or the key manager can register relayers in batch.
+    let error = runner
+        .call(
+            "register_relayers_batch",
+            "some-account.near",
+            borsh::to_vec(&args).unwrap(),
+        )
+        .unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::NotAllowed));
+
+    let outcome = runner
+        .call(
+            "register_relayers_batch",
+            &aurora,
+            borsh::to_vec(&args).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(outcome.return_data.as_value().unwrap(), 2u32.to_le_bytes());
+
+    // The key manager can register relayers too, and already registered ones are skipped.
+    let key_manager = "key-manager.near";
+    let key_manager_args = RelayerKeyManagerArgs {
+        key_manager: Some(str_to_account_id(key_manager)),
+    };
+    runner
+        .call(
+            "set_key_manager",
+            &aurora,
+            serde_json::to_vec(&key_manager_args).unwrap(),
+        )
+        .unwrap();
+    args.relayers.push((
+        str_to_account_id("relayer3.near"),
+        Address::from_array([0x03; 20]),
+    ));
+    let outcome = runner
+        .call(
+            "register_relayers_batch",
+            key_manager,
+            borsh::to_vec(&args).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(outcome.return_data.as_value().unwrap(), 1u32.to_le_bytes());
+}
+
+#[test]
+fn test_deployer_allowlist() {
+    let (mut runner, source_account, dest_address) = initialize_transfer();
+    let source_address = utils::address_from_secret_key(&source_account.secret_key);
+    let aurora = runner.aurora_account_id.clone();
+    // Init code which deploys a contract with empty code.
+    let contract_bytes = vec![0x60, 0x00, 0x60, 0x00, 0xf3];
+    let set_status = |runner: &mut utils::AuroraRunner, enabled: bool| {
+        let args = DeployerAllowlistStatusArgs { enabled };
+        runner.call(
+            "set_deployer_allowlist_status",
+            &aurora,
+            borsh::to_vec(&args).unwrap(),
+        )
+    };
+
+    // Deployment is open to everyone by default.
+    let result = runner
+        .submit_transaction(
+            &source_account.secret_key,
+            utils::create_deploy_transaction(contract_bytes.clone(), U256::from(INITIAL_NONCE)),
+        )
+        .unwrap();
+    assert!(result.status.is_ok());
+
+    // Only the owner can manage the allowlist.
+    let error = runner
+        .call(
+            "set_deployer_allowlist_status",
+            "some-account.near",
+            borsh::to_vec(&DeployerAllowlistStatusArgs { enabled: true }).unwrap(),
+        )
+        .unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::NotAllowed));
+    set_status(&mut runner, true).unwrap();
+
+    // Contract creation from non-allowlisted senders is rejected...
+    let error = runner
+        .submit_transaction(
+            &source_account.secret_key,
+            utils::create_deploy_transaction(contract_bytes.clone(), U256::from(INITIAL_NONCE + 1)),
+        )
+        .unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::DeployNotAllowed));
+
+    // ...while plain calls are still allowed.
+    let result = runner
+        .submit_transaction(
+            &source_account.secret_key,
+            utils::transfer(dest_address, TRANSFER_AMOUNT, U256::from(INITIAL_NONCE + 1)),
+        )
+        .unwrap();
+    assert!(result.status.is_ok());
+
+    let args = DeployerArgs {
+        address: source_address,
+    };
+    runner
+        .call("add_deployer", &aurora, borsh::to_vec(&args).unwrap())
+        .unwrap();
+    let result = runner
+        .submit_transaction(
+            &source_account.secret_key,
+            utils::create_deploy_transaction(contract_bytes.clone(), U256::from(INITIAL_NONCE + 2)),
+        )
+        .unwrap();
+    assert!(result.status.is_ok());
+
+    runner
+        .call("remove_deployer", &aurora, borsh::to_vec(&args).unwrap())
+        .unwrap();
+    let error = runner
+        .submit_transaction(
+            &source_account.secret_key,
+            utils::create_deploy_transaction(contract_bytes.clone(), U256::from(INITIAL_NONCE + 3)),
+        )
+        .unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::DeployNotAllowed));
+
+    // Disabling the allowlist opens deployment again.
+    set_status(&mut runner, false).unwrap();
+    let result = runner
+        .submit_transaction(
+            &source_account.secret_key,
+            utils::create_deploy_transaction(contract_bytes, U256::from(INITIAL_NONCE + 3)),
+        )
+        .unwrap();
+    assert!(result.status.is_ok());
+}
+
+#[test]
+fn test_get_deployers() {
+    let mut runner = utils::deploy_runner();
+    let aurora = runner.aurora_account_id.clone();
+    let deployers: Vec<_> = (1..=3).map(|i| Address::from_array([i; 20])).collect();
+    let call = |runner: &mut utils::AuroraRunner, method: &str, address: Address| {
+        let args = DeployerArgs { address };
+        runner
+            .call(method, &aurora, borsh::to_vec(&args).unwrap())
+            .unwrap();
+    };
+    let get_deployers = |runner: &utils::AuroraRunner, from_index: u32, limit: u32| {
+        let args = GetDeployersArgs { from_index, limit };
+        let outcome = runner
+            .one_shot()
+            .call("get_deployers", "any.near", borsh::to_vec(&args).unwrap())
+            .unwrap();
+        Vec::<Address>::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+
+    assert!(get_deployers(&runner, 0, 10).is_empty());
+    for deployer in &deployers {
+        call(&mut runner, "add_deployer", *deployer);
+    }
+    // Adding an address twice doesn't duplicate it.
+    call(&mut runner, "add_deployer", deployers[0]);
+    assert_eq!(get_deployers(&runner, 0, 10), deployers);
+
+    // The allowlist is paginated.
+    assert_eq!(get_deployers(&runner, 0, 2), deployers[..2]);
+    assert_eq!(get_deployers(&runner, 2, 2), deployers[2..]);
+    assert!(get_deployers(&runner, 3, 2).is_empty());
+    assert!(get_deployers(&runner, u32::MAX, u32::MAX).is_empty());
+
+    // The last address takes the position of the removed one.
+    call(&mut runner, "remove_deployer", deployers[0]);
+    assert_eq!(
+        get_deployers(&runner, 0, 10),
+        vec![deployers[2], deployers[1]]
+    );
+}
+
+#[test]
+fn test_set_log_level() {
+    let (mut runner, mut signer, dest_address) = initialize_transfer();
+    let aurora = runner.aurora_account_id.clone();
+    let get_log_level = |runner: &utils::AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_log_level", "any.near", Vec::new())
+            .unwrap();
+        LogLevel::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+    let mut submit_logs = |runner: &mut utils::AuroraRunner| {
+        let transaction = utils::transfer(dest_address, Wei::zero(), signer.use_nonce().into());
+        let signed_tx =
+            utils::sign_transaction(transaction, Some(runner.chain_id), &signer.secret_key);
+        runner
+            .call(
+                utils::SUBMIT,
+                "some-account.near",
+                rlp::encode(&signed_tx).to_vec(),
+            )
+            .unwrap()
+            .logs
+    };
+    let args = SetLogLevelArgs {
+        log_level: LogLevel::Debug,
+    };
+
+    // Debug logs are skipped by default.
+    assert_eq!(get_log_level(&runner), LogLevel::Error);
+    let logs = submit_logs(&mut runner);
+    assert!(!logs.iter().any(|log| log.starts_with("signer_address")));
+
+    // Only the owner can change the log level.
+    let error = runner
+        .call(
+            "set_log_level",
+            "some-account.near",
+            borsh::to_vec(&args).unwrap(),
+        )
+        .unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::NotAllowed));
+
+    runner
+        .call("set_log_level", &aurora, borsh::to_vec(&args).unwrap())
+        .unwrap();
+    assert_eq!(get_log_level(&runner), LogLevel::Debug);
+    let logs = submit_logs(&mut runner);
+    assert!(logs.iter().any(|log| log.starts_with("signer_address")));
+}
+
+#[test]
+fn test_get_governance() {
+    let mut runner = utils::deploy_runner();
+    let aurora = runner.aurora_account_id.clone();
+    let get_governance = |runner: &utils::AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_governance", "any.near", Vec::new())
+            .unwrap();
+        GovernanceInfo::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+
+    assert_eq!(
+        get_governance(&runner),
+        GovernanceInfo {
+            owner: str_to_account_id(&aurora),
+            key_manager: None,
+            upgrade_delay_blocks: 1,
+        }
+    );
+
+    let key_manager = str_to_account_id("key-manager.near");
+    let args = RelayerKeyManagerArgs {
+        key_manager: Some(key_manager.clone()),
+    };
+    runner
+        .call(
+            "set_key_manager",
+            &aurora,
+            serde_json::to_vec(&args).unwrap(),
+        )
+        .unwrap();
+    let args = SetUpgradeDelayBlocksArgs {
+        upgrade_delay_blocks: 5,
+    };
+    runner
+        .call(
+            "set_upgrade_delay_blocks",
+            &aurora,
+            borsh::to_vec(&args).unwrap(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        get_governance(&runner),
+        GovernanceInfo {
+            owner: str_to_account_id(&aurora),
+            key_manager: Some(key_manager),
+            upgrade_delay_blocks: 5,
+        }
+    );
+}
+
+#[test]
+fn test_submit_result_intrinsic_gas() {
+    let (mut runner, mut signer, dest_address) = initialize_transfer();
+    let data = vec![0, 1, 2, 3];
+    let mut transfer_with_data = |runner: &mut utils::AuroraRunner| {
+        runner
+            .submit_with_signer(&mut signer, |nonce| {
+                aurora_engine_transactions::legacy::TransactionLegacy {
+                    data: data.clone(),
+                    ..utils::transfer(dest_address, Wei::zero(), nonce)
+                }
+            })
+            .unwrap()
+    };
+
+    // The intrinsic gas is only reported with the diagnostics enabled.
+    let result = transfer_with_data(&mut runner);
+    assert_eq!(result.intrinsic_gas, None);
+
+    runner
+        .call(
+            "set_diagnostics_enabled",
+            &runner.aurora_account_id.clone(),
+            borsh::to_vec(&true).unwrap(),
+        )
+        .unwrap();
+
+    // Base cost, one zero byte and three non-zero bytes of calldata; nothing is executed
+    // for a transfer to an account without code.
+    let result = transfer_with_data(&mut runner);
+    let expected_intrinsic_gas = 21_000 + 4 + 3 * 16;
+    assert_eq!(result.intrinsic_gas, Some(expected_intrinsic_gas));
+    assert_eq!(result.gas_used, expected_intrinsic_gas);
+}
+
+#[test]
+fn test_withdraw_collected_fees() {
+    let (mut runner, source_account, dest_address) = initialize_transfer();
+    let aurora = runner.aurora_account_id.clone();
+    let treasury = Address::from_array([0x77; 20]);
+    let args = WithdrawCollectedFeesArgs { treasury };
+
+    // Base fee is zero, so transactions do not add to the collected fees.
+    runner
+        .submit_transaction(
+            &source_account.secret_key,
+            utils::transfer(dest_address, TRANSFER_AMOUNT, INITIAL_NONCE.into()),
+        )
+        .unwrap();
+    let outcome = runner
+        .one_shot()
+        .call("get_collected_fees", "any.near", Vec::new())
+        .unwrap();
+    assert_eq!(outcome.return_data.as_value().unwrap(), [0u8; 32]);
+
+    // Only the owner can withdraw the collected fees.
+    let error = runner
+        .call(
+            "withdraw_collected_fees",
+            "some-account.near",
+            borsh::to_vec(&args).unwrap(),
+        )
+        .unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::NotAllowed));
+
+    // Without a fee distribution the fees can't be withdrawn to the zero address.
+    let error = runner
+        .call(
+            "withdraw_collected_fees",
+            &aurora,
+            borsh::to_vec(&WithdrawCollectedFeesArgs {
+                treasury: Address::zero(),
+            })
+            .unwrap(),
+        )
+        .unwrap_err();
+    assert_eq!(
+        error.kind,
+        EngineErrorKind::EvmFatal(evm::ExitFatal::Other("ERR_ZERO_TREASURY".into()))
+    );
+
+    let outcome = runner
+        .call(
+            "withdraw_collected_fees",
+            &aurora,
+            borsh::to_vec(&args).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(outcome.return_data.as_value().unwrap(), [0u8; 32]);
+    assert_eq!(
+        outcome.logs,
+        vec![format!(
+            "Withdrawn collected fees: 0 to {}",
+            treasury.encode()
+        )]
+    );
+    assert_eq!(runner.get_balance(treasury), Wei::zero());
+}
+
+#[cfg(feature = "fee-distribution")]
+#[test]
+fn test_set_fee_distribution() {
+    let (mut runner, _, _) = initialize_transfer();
+    let aurora = runner.aurora_account_id.clone();
+    let share = |address: u8, basis_points: u16| FeeShare {
+        address: Address::from_array([address; 20]),
+        basis_points,
+    };
+    let set_distribution = |runner: &mut utils::AuroraRunner, account_id: &str, shares| {
+        runner.call(
+            "set_fee_distribution",
+            account_id,
+            borsh::to_vec(&SetFeeDistributionArgs { shares }).unwrap(),
+        )
+    };
+    let get_distribution = |runner: &utils::AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_fee_distribution", "any.near", Vec::new())
+            .unwrap();
+        Vec::<FeeShare>::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+    let shares = vec![share(0x70, 7_000), share(0x30, 3_000)];
+
+    assert!(get_distribution(&runner).is_empty());
+
+    // Only the owner can set the distribution.
+    let error = set_distribution(&mut runner, "some-account.near", shares.clone()).unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::NotAllowed));
+
+    // The shares must sum to 100%.
+    for invalid in [
+        vec![share(0x70, 7_000)],
+        vec![share(0x70, 7_000), share(0x30, 3_001)],
+    ] {
+        let error = set_distribution(&mut runner, &aurora, invalid).unwrap_err();
+        assert_eq!(
+            error.kind,
+            EngineErrorKind::EvmFatal(evm::ExitFatal::Other("ERR_INVALID_FEE_DISTRIBUTION".into()))
+        );
+    }
+
+    set_distribution(&mut runner, &aurora, shares.clone()).unwrap();
+    assert_eq!(get_distribution(&runner), shares);
+
+    // The treasury can't be given together with the distribution.
+    let withdraw = |runner: &mut utils::AuroraRunner, treasury| {
+        runner.call(
+            "withdraw_collected_fees",
+            &aurora,
+            borsh::to_vec(&WithdrawCollectedFeesArgs { treasury }).unwrap(),
+        )
+    };
+    let error = withdraw(&mut runner, Address::from_array([0x77; 20])).unwrap_err();
+    assert_eq!(
+        error.kind,
+        EngineErrorKind::EvmFatal(evm::ExitFatal::Other(
+            "ERR_TREASURY_WITH_FEE_DISTRIBUTION".into()
+        ))
+    );
+
+    let outcome = withdraw(&mut runner, Address::zero()).unwrap();
+    assert_eq!(
+        outcome.logs,
+        shares
+            .iter()
+            .map(|share| format!("Withdrawn collected fees: 0 to {}", share.address.encode()))
+            .collect::<Vec<_>>()
+    );
+
+    set_distribution(&mut runner, &aurora, Vec::new()).unwrap();
+    assert!(get_distribution(&runner).is_empty());
+}
+
+#[test]
+fn test_tx_support_shanghai() {
+    let (mut runner, mut source_account, _) = initialize_transfer();
+    // Encoded EVM transaction with parameter: `evmVersion: 'shanghai'`.
+    let data = "6080604052348015600e575f80fd5b50607480601a5f395ff3fe6080604052348015600e575\
+    f80fd5b50600436106026575f3560e01c8063919840ad14602a575b5f80fd5b600560405190815260200160\
+    405180910390f3fea2646970667358221220cb01b9b9c75e5cd079a1980af2fe4397d2029888d12737d74cb\
+    bc10e0de65bd364736f6c63430008150033";
+
+    let result = runner
+        .submit_with_signer(&mut source_account, |nonce| {
+            aurora_engine_transactions::legacy::TransactionLegacy {
+                nonce,
+                gas_price: 0.into(),
+                gas_limit: u64::MAX.into(),
+                to: None,
+                value: Wei::zero(),
+                data: hex::decode(data).unwrap(),
+            }
+        })
+        .expect("Should be able to execute EVM bytecode including PUSH0");
+
+    assert!(result.status.is_ok());
+}
+
+#[test]
+fn test_eth_transfer_not_enough_gas() {
+    let (mut runner, mut source_account, dest_address) = initialize_transfer();
+    let source_address = utils::address_from_secret_key(&source_account.secret_key);
+    let transaction = |nonce| {
+        let mut tx = utils::transfer(dest_address, TRANSFER_AMOUNT, nonce);
+        tx.gas_limit = 10_000.into(); // this is not enough gas
+        tx
+    };
+
+    // validate pre-state
+    utils::validate_address_balance_and_nonce(
+        &runner,
+        source_address,
+        INITIAL_BALANCE,
+        INITIAL_NONCE.into(),
+    )
+    .unwrap();
+    utils::validate_address_balance_and_nonce(&runner, dest_address, Wei::zero(), 0.into())
+        .unwrap();
+
+    // attempt transfer
+    let error = runner
+        .submit_with_signer(&mut source_account, transaction)
         .unwrap_err();
     assert_eq!(error.kind, EngineErrorKind::IntrinsicGasNotMet);
 
@@ -1028,52 +2564,412 @@ fn test_block_hash() {
         hex::encode(block_hash.0).as_str(),
         "c4a46f076b64877cbd8c5dbfd7bfbbea21a5653b79e3b6d06b6dfb5c88f1c384",
     );
-}
+}
+
+#[test]
+fn test_block_hash_api() {
+    let runner = utils::deploy_runner();
+    let block_height: u64 = 10;
+    let outcome = runner
+        .one_shot()
+        .call(
+            "get_block_hash",
+            "any.near",
+            borsh::to_vec(&block_height).unwrap(),
+        )
+        .unwrap();
+    let block_hash = outcome.return_data.as_value().unwrap();
+
+    assert_eq!(
+        hex::encode(block_hash).as_str(),
+        "c4a46f076b64877cbd8c5dbfd7bfbbea21a5653b79e3b6d06b6dfb5c88f1c384",
+    );
+}
+
+#[test]
+fn test_block_hash_contract() {
+    let (mut runner, mut source_account, _) = initialize_transfer();
+    let test_constructor = utils::solidity::ContractConstructor::compile_from_source(
+        ["src", "tests", "res"].iter().collect::<PathBuf>(),
+        Path::new("target").join("solidity_build"),
+        "blockhash.sol",
+        "BlockHash",
+    );
+    let nonce = source_account.use_nonce();
+    let test_contract = runner.deploy_contract(
+        &source_account.secret_key,
+        |c| c.deploy_without_args(nonce.into()),
+        test_constructor,
+    );
+
+    let result = runner
+        .submit_with_signer(&mut source_account, |nonce| {
+            test_contract.call_method_without_args("test", nonce)
+        })
+        .unwrap();
+
+    let res = utils::panic_on_fail(result.status);
+    assert!(res.is_none(), "Status: {res:?}");
+}
+
+#[test]
+fn test_chain_id_u256() {
+    let (mut runner, mut source_account, _) = initialize_transfer();
+    let test_constructor = utils::solidity::ContractConstructor::compile_from_source(
+        ["src", "tests", "res"].iter().collect::<PathBuf>(),
+        Path::new("target").join("solidity_build"),
+        "chainid.sol",
+        "ChainId",
+    );
+    let nonce = source_account.use_nonce();
+    let test_contract = runner.deploy_contract(
+        &source_account.secret_key,
+        |c| c.deploy_without_args(nonce.into()),
+        test_constructor,
+    );
+
+    let result = runner
+        .submit_with_signer(&mut source_account, |nonce| {
+            test_contract.call_method_without_args("chainId", nonce)
+        })
+        .unwrap();
+    let evm_chain_id = utils::unwrap_success(result);
+
+    let outcome = runner
+        .one_shot()
+        .call("get_chain_id_u256", "any.near", Vec::new())
+        .unwrap();
+    let chain_id = outcome.return_data.as_value().unwrap();
+
+    assert_eq!(chain_id, evm_chain_id);
+    assert_eq!(
+        U256::from_big_endian(&chain_id),
+        U256::from(runner.chain_id)
+    );
+}
+
+#[test]
+fn test_engine_metadata() {
+    let mut runner = utils::deploy_runner();
+    let aurora = runner.aurora_account_id.clone();
+    let get_metadata = |runner: &utils::AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_engine_metadata", "any.near", Vec::new())
+            .unwrap();
+        Option::<EngineMetadata>::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+    let metadata = EngineMetadata {
+        name: "silo-1".to_string(),
+        description: "Test silo".to_string(),
+        operator_url: "https://example.com".to_string(),
+    };
+
+    assert_eq!(get_metadata(&runner), None);
+
+    // Only the owner can set the metadata.
+    let error = runner
+        .call(
+            "set_engine_metadata",
+            "some-account.near",
+            borsh::to_vec(&metadata).unwrap(),
+        )
+        .unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::NotAllowed));
+
+    // The size of the fields is bounded.
+    let too_long = EngineMetadata {
+        name: "a".repeat(EngineMetadata::MAX_NAME_LEN + 1),
+        ..metadata.clone()
+    };
+    let error = runner
+        .call(
+            "set_engine_metadata",
+            &aurora,
+            borsh::to_vec(&too_long).unwrap(),
+        )
+        .unwrap_err();
+    assert!(
+        matches!(error.kind, EngineErrorKind::EvmFatal(evm::ExitFatal::Other(e)) if e == "ERR_ENGINE_METADATA_TOO_LONG")
+    );
+    assert_eq!(get_metadata(&runner), None);
 
-#[test]
-fn test_block_hash_api() {
-    let runner = utils::deploy_runner();
-    let block_height: u64 = 10;
-    let outcome = runner
-        .one_shot()
+    runner
         .call(
-            "get_block_hash",
-            "any.near",
-            borsh::to_vec(&block_height).unwrap(),
+            "set_engine_metadata",
+            &aurora,
+            borsh::to_vec(&metadata).unwrap(),
         )
         .unwrap();
-    let block_hash = outcome.return_data.as_value().unwrap();
-
-    assert_eq!(
-        hex::encode(block_hash).as_str(),
-        "c4a46f076b64877cbd8c5dbfd7bfbbea21a5653b79e3b6d06b6dfb5c88f1c384",
-    );
+    assert_eq!(get_metadata(&runner), Some(metadata));
 }
 
 #[test]
-fn test_block_hash_contract() {
+fn test_max_logs_per_tx() {
     let (mut runner, mut source_account, _) = initialize_transfer();
-    let test_constructor = utils::solidity::ContractConstructor::compile_from_source(
+    let aurora = runner.aurora_account_id.clone();
+    let constructor = utils::solidity::ContractConstructor::compile_from_source(
         ["src", "tests", "res"].iter().collect::<PathBuf>(),
         Path::new("target").join("solidity_build"),
-        "blockhash.sol",
-        "BlockHash",
+        "logs_loop.sol",
+        "LogsLoop",
     );
     let nonce = source_account.use_nonce();
-    let test_contract = runner.deploy_contract(
+    let contract = runner.deploy_contract(
         &source_account.secret_key,
         |c| c.deploy_without_args(nonce.into()),
-        test_constructor,
+        constructor,
+    );
+    let emit_logs = |count: u64, nonce: u64| {
+        contract.call_method_with_args(
+            "emitLogs",
+            &[ethabi::Token::Uint(count.into())],
+            nonce.into(),
+        )
+    };
+    let get_max_logs = |runner: &utils::AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_max_logs_per_tx", "any.near", Vec::new())
+            .unwrap();
+        Option::<u64>::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+
+    // Unlimited by default.
+    assert_eq!(get_max_logs(&runner), None);
+    let result = runner
+        .submit_transaction(&source_account.secret_key, emit_logs(10, 1))
+        .unwrap();
+    assert_eq!(result.logs.len(), 10);
+
+    let args = SetMaxLogsPerTxArgs { max_logs: Some(3) };
+    runner
+        .call(
+            "set_max_logs_per_tx",
+            &aurora,
+            borsh::to_vec(&args).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(get_max_logs(&runner), Some(3));
+
+    // Emitting exactly the maximum number of logs is fine...
+    let result = runner
+        .submit_transaction(&source_account.secret_key, emit_logs(3, 2))
+        .unwrap();
+    assert_eq!(result.logs.len(), 3);
+
+    // ...but one more log fails the transaction. It's still charged and bumps the nonce,
+    // so it can't be resubmitted for free.
+    let sender = utils::address_from_secret_key(&source_account.secret_key);
+    let balance_before = runner.get_balance(sender);
+    let mut tx = emit_logs(4, 3);
+    tx.gas_price = U256::one();
+    tx.gas_limit = 200_000.into();
+    let result = runner
+        .submit_transaction(&source_account.secret_key, tx)
+        .unwrap();
+    assert_eq!(
+        result.status,
+        TransactionStatus::Other("ERR_TOO_MANY_LOGS".into())
+    );
+    assert!(result.logs.is_empty());
+    assert!(result.gas_used > 0);
+    assert_eq!(runner.get_nonce(sender), 4.into());
+    assert_eq!(
+        runner.get_balance(sender),
+        balance_before - Wei::new_u64(result.gas_used)
     );
 
+    let args = SetMaxLogsPerTxArgs { max_logs: None };
+    runner
+        .call(
+            "set_max_logs_per_tx",
+            &aurora,
+            borsh::to_vec(&args).unwrap(),
+        )
+        .unwrap();
     let result = runner
-        .submit_with_signer(&mut source_account, |nonce| {
-            test_contract.call_method_without_args("test", nonce)
+        .submit_transaction(&source_account.secret_key, emit_logs(4, 4))
+        .unwrap();
+    assert_eq!(result.logs.len(), 4);
+}
+
+#[cfg(feature = "view-step-limit")]
+#[test]
+fn test_view_step_limit() {
+    let (mut runner, mut signer, _) = initialize_transfer();
+    let aurora = runner.aurora_account_id.clone();
+    let viewer = utils::address_from_secret_key(&signer.secret_key);
+    let mut deploy = |runner: &mut utils::AuroraRunner, code: &str| {
+        let code = hex::decode(code).unwrap();
+        let result = runner
+            .submit_with_signer(&mut signer, |nonce| {
+                utils::create_deploy_transaction(code, nonce)
+            })
+            .unwrap();
+        Address::try_from_slice(utils::unwrap_success_slice(&result)).unwrap()
+    };
+    // Runtime code `JUMPDEST PUSH1 0 JUMP` which loops forever.
+    let infinite_loop = deploy(&mut runner, "6004600c60003960046000f35b600056");
+    // Runtime code which returns 42 after executing 6 opcodes.
+    let return_42 = deploy(&mut runner, "600a600c600039600a6000f3602a60005260206000f3");
+    let view = |runner: &utils::AuroraRunner, address: Address| {
+        runner.view_call(&utils::as_view_call(
+            utils::transfer(address, Wei::zero(), U256::zero()),
+            viewer,
+        ))
+    };
+    let get_step_limit = |runner: &utils::AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_view_step_limit", "any.near", Vec::new())
+            .unwrap();
+        Option::<u64>::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+
+    // Unlimited by default.
+    assert_eq!(get_step_limit(&runner), None);
+    assert!(matches!(
+        view(&runner, return_42).unwrap(),
+        TransactionStatus::Succeed(_)
+    ));
+
+    let args = SetViewStepLimitArgs {
+        step_limit: Some(1000),
+    };
+    runner
+        .call(
+            "set_view_step_limit",
+            &aurora,
+            borsh::to_vec(&args).unwrap(),
+        )
+        .unwrap();
+    assert_eq!(get_step_limit(&runner), Some(1000));
+
+    // Short executions are not affected by the limit.
+    assert_eq!(
+        view(&runner, return_42).unwrap(),
+        TransactionStatus::Succeed(u256_to_arr(&U256::from(42)).to_vec())
+    );
+    let error = view(&runner, infinite_loop).unwrap_err();
+    assert_eq!(
+        error.kind,
+        EngineErrorKind::EvmFatal(evm::ExitFatal::Other("ERR_VIEW_STEP_LIMIT".into()))
+    );
+}
+
+#[test]
+fn test_evm_memory_limit() {
+    let (mut runner, mut signer, _) = initialize_transfer();
+    let aurora = runner.aurora_account_id.clone();
+    // Init code which deploys `PUSH1 1 PUSH3 0x010000 MSTORE STOP`, the runtime code expands
+    // the memory to more than 64 KiB.
+    let code = hex::decode("6008600c60003960086000f36001620100005200").unwrap();
+    let result = runner
+        .submit_with_signer(&mut signer, |nonce| {
+            utils::create_deploy_transaction(code, nonce)
         })
         .unwrap();
+    let address = Address::try_from_slice(utils::unwrap_success_slice(&result)).unwrap();
+    let mut expand_memory = |runner: &mut utils::AuroraRunner| {
+        runner
+            .submit_with_signer(&mut signer, |nonce| {
+                utils::transfer(address, Wei::zero(), nonce)
+            })
+            .unwrap()
+            .status
+    };
+    let set_memory_limit = |runner: &mut utils::AuroraRunner, memory_limit: Option<u64>| {
+        let args = SetEvmMemoryLimitArgs { memory_limit };
+        runner
+            .call(
+                "set_evm_memory_limit",
+                &aurora,
+                borsh::to_vec(&args).unwrap(),
+            )
+            .unwrap();
+    };
 
-    let res = utils::panic_on_fail(result.status);
-    assert!(res.is_none(), "Status: {res:?}");
+    // Unlimited by default.
+    assert!(matches!(
+        expand_memory(&mut runner),
+        TransactionStatus::Succeed(_)
+    ));
+
+    set_memory_limit(&mut runner, Some(1024));
+    let outcome = runner
+        .one_shot()
+        .call("get_evm_memory_limit", "any.near", Vec::new())
+        .unwrap();
+    let memory_limit =
+        Option::<u64>::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap();
+    assert_eq!(memory_limit, Some(1024));
+    assert_eq!(expand_memory(&mut runner), TransactionStatus::OutOfGas);
+
+    set_memory_limit(&mut runner, None);
+    assert!(matches!(
+        expand_memory(&mut runner),
+        TransactionStatus::Succeed(_)
+    ));
+}
+
+#[test]
+fn test_get_evm_config() {
+    let mut runner = utils::deploy_runner();
+    let aurora = runner.aurora_account_id.clone();
+    let get_evm_config = |runner: &utils::AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_evm_config", "any.near", Vec::new())
+            .unwrap();
+        EvmConfig::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+
+    let config = get_evm_config(&runner);
+    assert_eq!(config.fork, "cancun");
+    assert_eq!(config.gas_transaction_call, 21_000);
+    assert_eq!(config.gas_transaction_create, 53_000);
+    assert_eq!(config.call_stipend, 2_300);
+    assert_eq!(config.create_contract_limit, Some(0x6000));
+    assert_eq!(config.max_initcode_size, Some(0xc000));
+    assert_eq!(config.memory_limit, None);
+    assert_eq!(config.max_refund_quotient, 5);
+    assert!(config.gas_refunds_enabled);
+    assert!(config
+        .enabled_opcodes
+        .iter()
+        .any(|opcode| opcode == "PUSH0"));
+
+    // The values overridden by the owner are reflected.
+    runner
+        .call(
+            "set_evm_memory_limit",
+            &aurora,
+            borsh::to_vec(&SetEvmMemoryLimitArgs {
+                memory_limit: Some(1024),
+            })
+            .unwrap(),
+        )
+        .unwrap();
+    runner
+        .call(
+            "set_max_refund_quotient",
+            &aurora,
+            borsh::to_vec(&SetMaxRefundQuotientArgs { quotient: Some(2) }).unwrap(),
+        )
+        .unwrap();
+
+    let overridden_config = get_evm_config(&runner);
+    assert_eq!(overridden_config.memory_limit, Some(1024));
+    assert_eq!(overridden_config.max_refund_quotient, 2);
+    assert_eq!(
+        overridden_config,
+        EvmConfig {
+            memory_limit: Some(1024),
+            max_refund_quotient: 2,
+            ..config
+        }
+    );
 }
 
 #[cfg(not(feature = "ext-connector"))]
@@ -1092,6 +2988,28 @@ fn test_ft_metadata() {
     assert_eq!(metadata, FungibleTokenMetadata::default());
 }
 
+#[cfg(not(feature = "ext-connector"))]
+#[test]
+fn test_get_bridge_prover() {
+    let runner = utils::deploy_runner();
+    let account_id: String = runner.context.signer_account_id.clone().into();
+    let outcome = runner
+        .one_shot()
+        .call("get_bridge_prover", &account_id, Vec::new())
+        .unwrap();
+    assert_eq!(outcome.return_data.as_value().unwrap(), b"prover.near");
+
+    // The eth-connector is not initialized.
+    let runner = utils::AuroraRunner::default();
+    let error = runner
+        .one_shot()
+        .call("get_bridge_prover", &account_id, Vec::new())
+        .unwrap_err();
+    assert!(
+        matches!(error.kind, EngineErrorKind::EvmFatal(evm::ExitFatal::Other(e)) if e == "ERR_CONNECTOR_STORAGE_KEY_NOT_FOUND")
+    );
+}
+
 /// Tests transfer Eth from one account to another with custom argument `max_gas_price`.
 #[test]
 fn test_eth_transfer_with_max_gas_price() {
@@ -1133,6 +3051,242 @@ fn test_eth_transfer_with_max_gas_price() {
         .unwrap();
 }
 
+#[test]
+fn test_allow_zero_gas_price() {
+    let (mut runner, mut signer, dest_address) = initialize_transfer();
+    let aurora = runner.aurora_account_id.clone();
+    let is_allowed = |runner: &utils::AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_allow_zero_gas_price", "any.near", Vec::new())
+            .unwrap();
+        bool::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+    let transaction = |gas_price: u64| {
+        move |nonce| {
+            let mut tx =
+                utils::transfer_with_price(dest_address, TRANSFER_AMOUNT, nonce, gas_price.into());
+            tx.gas_limit = 30_000.into();
+            tx
+        }
+    };
+
+    // The transactions with a zero gas price are allowed by default.
+    assert!(is_allowed(&runner));
+    let result = runner
+        .submit_with_signer(&mut signer, transaction(0))
+        .unwrap();
+    assert!(result.status.is_ok());
+
+    // Only the owner can change the flag.
+    let error = runner
+        .call(
+            "set_allow_zero_gas_price",
+            "some-account.near",
+            borsh::to_vec(&false).unwrap(),
+        )
+        .unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::NotAllowed));
+
+    runner
+        .call(
+            "set_allow_zero_gas_price",
+            &aurora,
+            borsh::to_vec(&false).unwrap(),
+        )
+        .unwrap();
+    assert!(!is_allowed(&runner));
+    // The rejected transaction doesn't consume the nonce.
+    let error = runner
+        .submit_transaction(&signer.secret_key, transaction(0)(signer.nonce.into()))
+        .unwrap_err();
+    assert_eq!(error.kind, EngineErrorKind::ZeroGasPrice);
+    // Neither is a transaction whose gas price is capped to zero by the submit arguments.
+    let error = runner
+        .submit_transaction_with_args(
+            &signer.secret_key,
+            transaction(1)(signer.nonce.into()),
+            0,
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(error.kind, EngineErrorKind::ZeroGasPrice);
+    let result = runner
+        .submit_with_signer(&mut signer, transaction(1))
+        .unwrap();
+    assert!(result.status.is_ok());
+
+    runner
+        .call(
+            "set_allow_zero_gas_price",
+            &aurora,
+            borsh::to_vec(&true).unwrap(),
+        )
+        .unwrap();
+    assert!(is_allowed(&runner));
+    let result = runner
+        .submit_with_signer(&mut signer, transaction(0))
+        .unwrap();
+    assert!(result.status.is_ok());
+}
+
+#[test]
+fn test_allow_empty_init_code() {
+    let (mut runner, mut signer, _) = initialize_transfer();
+    let aurora = runner.aurora_account_id.clone();
+    let is_allowed = |runner: &utils::AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_allow_empty_init_code", "any.near", Vec::new())
+            .unwrap();
+        bool::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+    let set_allowed = |runner: &mut utils::AuroraRunner, account_id: &str, allowed: bool| {
+        runner.call(
+            "set_allow_empty_init_code",
+            account_id,
+            borsh::to_vec(&allowed).unwrap(),
+        )
+    };
+
+    // The creations with an empty init code are allowed by default, as in the EVM.
+    assert!(is_allowed(&runner));
+    let result = runner
+        .submit_with_signer(&mut signer, |nonce| {
+            utils::create_deploy_transaction(Vec::new(), nonce)
+        })
+        .unwrap();
+    assert!(result.status.is_ok());
+    let result = runner
+        .call("deploy_code", "some-account.near", Vec::new())
+        .unwrap();
+    let result = SubmitResult::try_from_slice(&result.return_data.as_value().unwrap()).unwrap();
+    assert!(result.status.is_ok());
+
+    // Only the owner can change the flag.
+    let error = set_allowed(&mut runner, "some-account.near", false).unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::NotAllowed));
+
+    set_allowed(&mut runner, &aurora, false).unwrap();
+    assert!(!is_allowed(&runner));
+    let error = runner
+        .submit_transaction(
+            &signer.secret_key,
+            utils::create_deploy_transaction(Vec::new(), signer.nonce.into()),
+        )
+        .unwrap_err();
+    assert_eq!(error.kind, EngineErrorKind::EmptyInitCode);
+    let error = runner
+        .call("deploy_code", "some-account.near", Vec::new())
+        .unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::EmptyInitCode));
+    // The creations with a non-empty init code are not affected.
+    let result = runner
+        .submit_with_signer(&mut signer, |nonce| {
+            utils::create_deploy_transaction(vec![0x00], nonce)
+        })
+        .unwrap();
+    assert!(result.status.is_ok());
+
+    set_allowed(&mut runner, &aurora, true).unwrap();
+    assert!(is_allowed(&runner));
+    let result = runner
+        .submit_with_signer(&mut signer, |nonce| {
+            utils::create_deploy_transaction(Vec::new(), nonce)
+        })
+        .unwrap();
+    assert!(result.status.is_ok());
+}
+
+#[test]
+fn test_allow_chainless_tx() {
+    let (mut runner, mut signer, dest_address) = initialize_transfer();
+    let aurora = runner.aurora_account_id.clone();
+    let is_allowed = |runner: &utils::AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_allow_chainless_tx", "any.near", Vec::new())
+            .unwrap();
+        bool::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+    let submit_chainless = |runner: &mut utils::AuroraRunner, signer: &utils::Signer| {
+        let transaction = utils::transfer(dest_address, TRANSFER_AMOUNT, signer.nonce.into());
+        let signed_tx = utils::sign_transaction(transaction, None, &signer.secret_key);
+        runner.call(
+            utils::SUBMIT,
+            "some-account.near",
+            rlp::encode(&signed_tx).to_vec(),
+        )
+    };
+
+    // The transactions without a chain ID are allowed by default.
+    assert!(is_allowed(&runner));
+    let outcome = submit_chainless(&mut runner, &signer).unwrap();
+    signer.use_nonce();
+    let result = SubmitResult::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap();
+    assert!(result.status.is_ok());
+    assert_eq!(runner.get_balance(dest_address), TRANSFER_AMOUNT);
+
+    // Only the owner can change the flag.
+    let error = runner
+        .call(
+            "set_allow_chainless_tx",
+            "some-account.near",
+            borsh::to_vec(&false).unwrap(),
+        )
+        .unwrap_err();
+    assert!(matches!(error.kind, EngineErrorKind::NotAllowed));
+
+    runner
+        .call(
+            "set_allow_chainless_tx",
+            &aurora,
+            borsh::to_vec(&false).unwrap(),
+        )
+        .unwrap();
+    assert!(!is_allowed(&runner));
+    let error = submit_chainless(&mut runner, &signer).unwrap_err();
+    assert_eq!(error.kind, EngineErrorKind::InvalidChainId);
+
+    // The transactions with a chain ID are still accepted.
+    let result = runner
+        .submit_with_signer(&mut signer, |nonce| {
+            utils::transfer(dest_address, TRANSFER_AMOUNT, nonce)
+        })
+        .unwrap();
+    assert!(result.status.is_ok());
+
+    runner
+        .call(
+            "set_allow_chainless_tx",
+            &aurora,
+            borsh::to_vec(&true).unwrap(),
+        )
+        .unwrap();
+    assert!(is_allowed(&runner));
+    let outcome = submit_chainless(&mut runner, &signer).unwrap();
+    signer.use_nonce();
+    let result = SubmitResult::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap();
+    assert!(result.status.is_ok());
+    assert_eq!(
+        runner.get_balance(dest_address),
+        Wei::new(TRANSFER_AMOUNT.raw() * 3)
+    );
+
+    // The transactions with a wrong chain ID are still rejected.
+    let transaction = utils::transfer(dest_address, TRANSFER_AMOUNT, signer.nonce.into());
+    let signed_tx =
+        utils::sign_transaction(transaction, Some(runner.chain_id + 1), &signer.secret_key);
+    let error = runner
+        .call(
+            utils::SUBMIT,
+            "some-account.near",
+            rlp::encode(&signed_tx).to_vec(),
+        )
+        .unwrap_err();
+    assert_eq!(error.kind, EngineErrorKind::InvalidChainId);
+}
+
 #[test]
 fn test_set_owner() {
     let mut runner = utils::deploy_runner();
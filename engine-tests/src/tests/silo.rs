@@ -1,11 +1,13 @@
 use aurora_engine::engine::EngineErrorKind;
 use aurora_engine_sdk as sdk;
 use aurora_engine_types::account_id::AccountId;
-use aurora_engine_types::borsh::BorshSerialize;
-use aurora_engine_types::parameters::engine::TransactionStatus;
+use aurora_engine_types::borsh::{BorshDeserialize, BorshSerialize};
+use aurora_engine_types::parameters::engine::{
+    EngineFeatures, SetMaxRefundQuotientArgs, TransactionStatus,
+};
 use aurora_engine_types::parameters::silo::{
-    FixedGasArgs, SiloParamsArgs, WhitelistAccountArgs, WhitelistAddressArgs, WhitelistArgs,
-    WhitelistKind, WhitelistStatusArgs,
+    FeeRegime, FixedGasArgs, FixedGasInfo, SiloConfigArgs, SiloParamsArgs, WhitelistAccountArgs,
+    WhitelistAddressArgs, WhitelistArgs, WhitelistKind, WhitelistStatusArgs, WhitelistsArgs,
 };
 use aurora_engine_types::types::EthGas;
 use libsecp256k1::SecretKey;
@@ -13,7 +15,7 @@ use rand::{rngs::ThreadRng, Rng, RngCore};
 use std::fmt::Debug;
 
 use crate::{
-    prelude::{Address, Wei},
+    prelude::{Address, Wei, H256},
     utils::{self, validate_address_balance_and_nonce, AuroraRunner},
 };
 
@@ -71,6 +73,56 @@ fn test_address_transfer_success() {
         .unwrap();
 }
 
+#[test]
+fn test_effective_gas_price_with_fixed_gas() {
+    let (mut runner, mut source_account, receiver) = initialize_transfer();
+    let sender = utils::address_from_secret_key(&source_account.secret_key);
+    let caller: AccountId = CALLER_ACCOUNT_ID.parse().unwrap();
+
+    set_silo_params(&mut runner, Some(SILO_PARAMS_ARGS));
+    add_account_to_whitelist(&mut runner, caller);
+    add_address_to_whitelist(&mut runner, sender);
+
+    let result = runner
+        .submit_with_signer(&mut source_account, |nonce| {
+            utils::transfer_with_price(receiver, TRANSFER_AMOUNT, nonce, TWO_GAS_PRICE.raw())
+        })
+        .unwrap();
+
+    // The price is charged for the fixed gas instead of the used gas.
+    assert_eq!(result.effective_gas_price, TWO_GAS_PRICE.raw().as_u128());
+    validate_address_balance_and_nonce(
+        &runner,
+        sender,
+        INITIAL_BALANCE - FIXED_GAS * TWO_GAS_PRICE - TRANSFER_AMOUNT,
+        (INITIAL_NONCE + 1).into(),
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_get_features() {
+    let (mut runner, _, _) = initialize_transfer();
+    let get_features = |runner: &AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_features", "any.near", Vec::new())
+            .unwrap();
+        EngineFeatures::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+
+    let features = get_features(&runner);
+    assert_eq!(features.ext_connector, cfg!(feature = "ext-connector"));
+    assert!(!features.silo_enabled);
+    assert_eq!(features.fork, "cancun");
+
+    set_silo_params(&mut runner, Some(SILO_PARAMS_ARGS));
+    assert!(get_features(&runner).silo_enabled);
+
+    set_silo_params(&mut runner, None);
+    assert!(!get_features(&runner).silo_enabled);
+}
+
 #[test]
 fn test_transfer_insufficient_balance() {
     let (mut runner, mut source_account, receiver) = initialize_transfer();
@@ -768,6 +820,153 @@ fn test_switch_between_fix_gas() {
         .unwrap();
 }
 
+#[test]
+fn test_configure_silo() {
+    let (mut runner, signer, receiver) = initialize_transfer();
+    let sender = utils::address_from_secret_key(&signer.secret_key);
+    let caller: AccountId = CALLER_ACCOUNT_ID.parse().unwrap();
+    let transaction = utils::transfer_with_price(
+        receiver,
+        TRANSFER_AMOUNT,
+        INITIAL_NONCE.into(),
+        ONE_GAS_PRICE.raw(),
+    );
+    let mut config = SiloConfigArgs {
+        silo_params: SILO_PARAMS_ARGS,
+        whitelist_statuses: vec![
+            WhitelistStatusArgs {
+                kind: WhitelistKind::Account,
+                active: true,
+            },
+            WhitelistStatusArgs {
+                kind: WhitelistKind::Address,
+                active: true,
+            },
+        ],
+        // An account can't be added to the white list of addresses.
+        whitelist_entries: vec![WhitelistArgs::WhitelistAccountArgs(WhitelistAccountArgs {
+            kind: WhitelistKind::Address,
+            account_id: caller.clone(),
+        })],
+    };
+
+    // Nothing is applied if a part of the configuration is invalid.
+    let error = runner
+        .call(
+            "configure_silo",
+            &runner.aurora_account_id.clone(),
+            borsh::to_vec(&config).unwrap(),
+        )
+        .unwrap_err();
+    assert!(matches!(
+        error.kind,
+        EngineErrorKind::EvmFatal(evm::ExitFatal::Other(e)) if e == "ERR_INVALID_WHITELIST_ENTRY"
+    ));
+    assert_eq!(get_silo_params(&runner), None);
+
+    config.whitelist_entries = vec![
+        WhitelistArgs::WhitelistAccountArgs(WhitelistAccountArgs {
+            kind: WhitelistKind::Account,
+            account_id: caller,
+        }),
+        WhitelistArgs::WhitelistAddressArgs(WhitelistAddressArgs {
+            kind: WhitelistKind::Address,
+            address: sender,
+        }),
+    ];
+    call_function(&mut runner, "configure_silo", config);
+    assert_eq!(get_silo_params(&runner), Some(SILO_PARAMS_ARGS));
+
+    // The caller and the signer are allowed to submit transactions and the fixed gas is charged.
+    let result = runner
+        .submit_transaction(&signer.secret_key, transaction)
+        .unwrap();
+    assert!(matches!(result.status, TransactionStatus::Succeed(_)));
+    validate_address_balance_and_nonce(
+        &runner,
+        sender,
+        INITIAL_BALANCE - TRANSFER_AMOUNT - FIXED_GAS * ONE_GAS_PRICE,
+        (INITIAL_NONCE + 1).into(),
+    )
+    .unwrap();
+
+    // Other signers are rejected because the white list of addresses is active.
+    let (other_address, other_key) = keys(&mut rand::thread_rng());
+    runner.create_address(other_address, INITIAL_BALANCE, INITIAL_NONCE.into());
+    let err = runner
+        .submit_transaction(
+            &other_key,
+            utils::transfer_with_price(
+                receiver,
+                TRANSFER_AMOUNT,
+                INITIAL_NONCE.into(),
+                ONE_GAS_PRICE.raw(),
+            ),
+        )
+        .unwrap_err();
+    assert_eq!(err.kind, EngineErrorKind::NotAllowed);
+}
+
+#[test]
+fn test_export_import_whitelists() {
+    let mut runner = utils::deploy_runner();
+    let mut rng = rand::thread_rng();
+    let (address, _) = keys(&mut rng);
+    let (evm_admin, _) = keys(&mut rng);
+    let caller: AccountId = CALLER_ACCOUNT_ID.parse().unwrap();
+    let admin: AccountId = "admin.near".parse().unwrap();
+
+    set_silo_params(&mut runner, Some(SILO_PARAMS_ARGS));
+    add_admin(&mut runner, admin.clone());
+    add_evm_admin(&mut runner, evm_admin);
+    add_account_to_whitelist(&mut runner, caller.clone());
+    add_account_to_whitelist(&mut runner, admin);
+    add_address_to_whitelist(&mut runner, address);
+    disable_whitelist(&mut runner, WhitelistKind::EvmAdmin);
+    remove_account_from_whitelist(&mut runner, caller);
+
+    let exported = export_whitelists(&runner);
+    assert_eq!(exported.whitelist_statuses.len(), 4);
+    assert_eq!(exported.whitelist_entries.len(), 4);
+
+    // The import into another silo replaces its white lists with the exported ones.
+    let mut other_runner = utils::deploy_runner();
+    let (other_address, _) = keys(&mut rng);
+    set_silo_params(&mut other_runner, Some(SILO_PARAMS_ARGS));
+    add_address_to_whitelist(&mut other_runner, other_address);
+    call_function(&mut other_runner, "import_whitelists", exported.clone());
+    assert_eq!(export_whitelists(&other_runner), exported);
+
+    // Nothing is applied if a part of the blob is invalid.
+    let mut invalid = exported.clone();
+    invalid.whitelist_entries = vec![WhitelistArgs::WhitelistAddressArgs(WhitelistAddressArgs {
+        kind: WhitelistKind::Admin,
+        address: other_address,
+    })];
+    let error = other_runner
+        .call(
+            "import_whitelists",
+            &other_runner.aurora_account_id.clone(),
+            borsh::to_vec(&invalid).unwrap(),
+        )
+        .unwrap_err();
+    assert!(matches!(
+        error.kind,
+        EngineErrorKind::EvmFatal(evm::ExitFatal::Other(e)) if e == "ERR_INVALID_WHITELIST_ENTRY"
+    ));
+    assert_eq!(export_whitelists(&other_runner), exported);
+
+    // Only the owner is allowed to import the white lists.
+    let error = other_runner
+        .call(
+            "import_whitelists",
+            "any.near",
+            borsh::to_vec(&exported).unwrap(),
+        )
+        .unwrap_err();
+    assert_eq!(error.kind, EngineErrorKind::NotAllowed);
+}
+
 #[test]
 #[should_panic(expected = "SILO_MODE_IS_OFF")]
 fn test_set_fixed_gas_in_disabled_silo_mode() {
@@ -789,6 +988,159 @@ fn test_set_fixed_gas() {
     set_fixed_gas(&mut runner, Some(FIXED_GAS));
 }
 
+#[test]
+fn test_get_fixed_gas_info() {
+    let mut runner = utils::deploy_runner();
+    let zero = Wei::zero().to_bytes();
+
+    // The fixed gas isn't set while the silo mode is off.
+    let info = get_fixed_gas_info(&runner);
+    assert_eq!(info.fixed_gas, None);
+    assert_eq!(info.gas_price, zero);
+
+    set_silo_params(&mut runner, Some(SILO_PARAMS_ARGS));
+    let info = get_fixed_gas_info(&runner);
+    assert_eq!(info.fixed_gas, Some(FIXED_GAS));
+    assert_eq!(info.gas_price, zero);
+}
+
+#[test]
+fn test_get_fee_regime() {
+    let mut runner = utils::deploy_runner();
+    let zero = Wei::zero().to_bytes();
+
+    // The gas market is active while the silo mode is off.
+    assert_eq!(
+        get_fee_regime(&runner),
+        FeeRegime::Market {
+            base_fee_per_gas: zero,
+            zero_gas_price_allowed: true,
+        }
+    );
+
+    call_function(&mut runner, "set_allow_zero_gas_price", false);
+    assert_eq!(
+        get_fee_regime(&runner),
+        FeeRegime::Market {
+            base_fee_per_gas: zero,
+            zero_gas_price_allowed: false,
+        }
+    );
+
+    // The fixed gas takes precedence over the gas market.
+    set_silo_params(&mut runner, Some(SILO_PARAMS_ARGS));
+    assert_eq!(
+        get_fee_regime(&runner),
+        FeeRegime::FixedGas {
+            fixed_gas: FIXED_GAS,
+            base_fee_per_gas: zero,
+            fixed_fee: zero,
+            zero_gas_price_allowed: false,
+        }
+    );
+
+    call_function(&mut runner, "set_allow_zero_gas_price", true);
+    set_silo_params(&mut runner, None);
+    assert_eq!(
+        get_fee_regime(&runner),
+        FeeRegime::Market {
+            base_fee_per_gas: zero,
+            zero_gas_price_allowed: true,
+        }
+    );
+}
+
+#[test]
+fn test_disabled_gas_refunds() {
+    let (mut runner, mut signer, _) = initialize_transfer();
+
+    let (address_with_refunds, gas_used_with_refunds) = deploy_and_clear(&mut runner, &mut signer);
+    call_function(&mut runner, "set_gas_refunds_enabled", false);
+    let (address_without_refunds, gas_used_without_refunds) =
+        deploy_and_clear(&mut runner, &mut signer);
+
+    // The storage is cleared in both cases, but the refund for clearing it is only applied
+    // when the refunds are enabled.
+    assert_eq!(
+        runner.get_storage(address_with_refunds, H256::zero()),
+        H256::zero()
+    );
+    assert_eq!(
+        runner.get_storage(address_without_refunds, H256::zero()),
+        H256::zero()
+    );
+    assert!(gas_used_with_refunds < gas_used_without_refunds);
+}
+
+#[test]
+fn test_max_refund_quotient() {
+    let (mut runner, mut signer, _) = initialize_transfer();
+    let get_quotient = |runner: &AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_max_refund_quotient", "any.near", Vec::new())
+            .unwrap();
+        u64::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+
+    assert_eq!(get_quotient(&runner), 5);
+    let (_, gas_used_default) = deploy_and_clear(&mut runner, &mut signer);
+
+    call_function(
+        &mut runner,
+        "set_max_refund_quotient",
+        SetMaxRefundQuotientArgs { quotient: Some(0) },
+    );
+    assert_eq!(get_quotient(&runner), 0);
+    let (_, gas_used_without_refunds) = deploy_and_clear(&mut runner, &mut signer);
+
+    // The refund for clearing the slot is larger than a tenth of the used gas.
+    call_function(
+        &mut runner,
+        "set_max_refund_quotient",
+        SetMaxRefundQuotientArgs { quotient: Some(10) },
+    );
+    let (_, gas_used_capped) = deploy_and_clear(&mut runner, &mut signer);
+    assert_eq!(
+        gas_used_capped,
+        gas_used_without_refunds - gas_used_without_refunds / 10
+    );
+    assert!(gas_used_default < gas_used_capped);
+
+    call_function(
+        &mut runner,
+        "set_max_refund_quotient",
+        SetMaxRefundQuotientArgs { quotient: None },
+    );
+    assert_eq!(get_quotient(&runner), 5);
+    let (_, gas_used) = deploy_and_clear(&mut runner, &mut signer);
+    assert_eq!(gas_used, gas_used_default);
+}
+
+/// Deploys a contract which sets its storage slot 0 and calls it to clear the slot, returns the
+/// gas used by the call.
+fn deploy_and_clear(runner: &mut AuroraRunner, signer: &mut utils::Signer) -> (Address, u64) {
+    // Init code which sets the storage slot 0 to 1 and deploys the runtime code below.
+    // Runtime code which clears the storage slot 0: `PUSH1 0 PUSH1 0 SSTORE STOP`.
+    let code = hex::decode("60016000556006601160003960066000f3600060005500").unwrap();
+    let result = runner
+        .submit_with_signer(signer, |nonce| {
+            utils::create_deploy_transaction(code, nonce)
+        })
+        .unwrap();
+    let address = Address::try_from_slice(utils::unwrap_success_slice(&result)).unwrap();
+    assert_eq!(
+        runner.get_storage(address, H256::zero()),
+        H256::from_low_u64_be(1)
+    );
+
+    let result = runner
+        .submit_with_signer(signer, |nonce| utils::transfer(address, Wei::zero(), nonce))
+        .unwrap();
+    assert!(matches!(result.status, TransactionStatus::Succeed(_)));
+    (address, result.gas_used)
+}
+
 fn initialize_transfer() -> (AuroraRunner, utils::Signer, Address) {
     // set up Aurora runner and accounts
     let mut runner = utils::deploy_runner();
@@ -830,7 +1182,6 @@ fn enable_whitelist(runner: &mut AuroraRunner, kind: WhitelistKind) {
     call_function(runner, "set_whitelist_status", args);
 }
 
-#[allow(dead_code)]
 fn disable_whitelist(runner: &mut AuroraRunner, kind: WhitelistKind) {
     let args = WhitelistStatusArgs {
         kind,
@@ -880,6 +1231,38 @@ fn set_silo_params(runner: &mut AuroraRunner, silo_params: Option<SiloParamsArgs
     call_function(runner, "set_silo_params", silo_params);
 }
 
+fn get_silo_params(runner: &AuroraRunner) -> Option<SiloParamsArgs> {
+    let result = runner
+        .one_shot()
+        .call("get_silo_params", "any.near", Vec::new())
+        .unwrap();
+    BorshDeserialize::try_from_slice(&result.return_data.as_value().unwrap()).unwrap()
+}
+
+fn get_fixed_gas_info(runner: &AuroraRunner) -> FixedGasInfo {
+    let result = runner
+        .one_shot()
+        .call("get_fixed_gas_info", "any.near", Vec::new())
+        .unwrap();
+    FixedGasInfo::try_from_slice(&result.return_data.as_value().unwrap()).unwrap()
+}
+
+fn get_fee_regime(runner: &AuroraRunner) -> FeeRegime {
+    let result = runner
+        .one_shot()
+        .call("get_fee_regime", "any.near", Vec::new())
+        .unwrap();
+    FeeRegime::try_from_slice(&result.return_data.as_value().unwrap()).unwrap()
+}
+
+fn export_whitelists(runner: &AuroraRunner) -> WhitelistsArgs {
+    let result = runner
+        .one_shot()
+        .call("export_whitelists", "any.near", Vec::new())
+        .unwrap();
+    WhitelistsArgs::try_from_slice(&result.return_data.as_value().unwrap()).unwrap()
+}
+
 fn call_function<T: BorshSerialize + Debug>(runner: &mut AuroraRunner, func: &str, args: T) {
     let input = borsh::to_vec(&args).unwrap();
     let result = runner.call(func, &runner.aurora_account_id.clone(), input);
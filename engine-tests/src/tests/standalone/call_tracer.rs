@@ -1,5 +1,6 @@
 use crate::prelude::{H160, H256};
 use crate::utils::solidity::erc20::{ERC20Constructor, ERC20};
+use crate::utils::standalone::diagnose;
 use crate::utils::{self, standalone, Signer};
 use aurora_engine_modexp::AuroraModExp;
 use aurora_engine_types::parameters::engine::TransactionStatus;
@@ -154,6 +155,61 @@ fn test_trace_contract_single_call() {
     runner.close();
 }
 
+#[test]
+fn test_diagnose_transaction() {
+    let mut runner = standalone::StandaloneRunner::default();
+    let mut signer = Signer::random();
+    let signer_address = utils::address_from_secret_key(&signer.secret_key);
+
+    runner.init_evm();
+
+    let constructor = ERC20Constructor::load();
+    let deploy_tx = constructor.deploy("Test", "TST", signer.use_nonce().into());
+    let deploy_result = runner
+        .submit_transaction(&signer.secret_key, deploy_tx)
+        .unwrap();
+    let contract_address = {
+        let bytes = utils::unwrap_success_slice(&deploy_result);
+        Address::try_from_slice(bytes).unwrap()
+    };
+    let contract = ERC20(constructor.0.deployed_at(contract_address));
+
+    let tx = contract.mint(signer_address, 100.into(), signer.nonce.into());
+    let signed_tx = utils::sign_transaction(tx.clone(), Some(runner.chain_id), &signer.secret_key);
+    let raw_tx = rlp::encode(&signed_tx).to_vec();
+
+    let diagnostics =
+        diagnose::diagnose_transaction::<AuroraModExp>(&runner.storage, &raw_tx, true).unwrap();
+    assert_eq!(diagnostics.tx_hash, aurora_engine_sdk::keccak(&raw_tx));
+    let result = diagnostics.result.unwrap();
+    assert!(result.status.is_ok());
+    assert!(result.gas_used > 0);
+    // The `Transfer` event of the minted tokens.
+    assert_eq!(result.logs.len(), 1);
+    assert_eq!(result.logs[0].address, contract_address);
+    let structured_diff = diagnostics.diff.to_structured();
+    assert!(structured_diff.nonces.contains_key(&signer_address));
+    assert!(!structured_diff.storage.is_empty());
+    let trace = diagnostics.trace.unwrap();
+    assert_eq!(trace.from, signer_address);
+    assert_eq!(trace.to, Some(contract_address));
+    assert_eq!(trace.input, tx.data);
+
+    // The trace is optional and nothing is persisted, so the same transaction can be replayed.
+    let diagnostics =
+        diagnose::diagnose_transaction::<AuroraModExp>(&runner.storage, &raw_tx, false).unwrap();
+    assert!(diagnostics.result.unwrap().status.is_ok());
+    assert!(diagnostics.trace.is_none());
+    assert_eq!(runner.get_nonce(&signer_address), U256::from(signer.nonce));
+
+    assert!(matches!(
+        diagnose::diagnose_transaction::<AuroraModExp>(&runner.storage, &[], false),
+        Err(diagnose::DiagnoseError::InvalidTransaction(_))
+    ));
+
+    runner.close();
+}
+
 #[test]
 fn test_trace_contract_with_sub_call() {
     use crate::tests::uniswap::UniswapTestContext;
@@ -342,9 +398,11 @@ fn test_trace_precompiles_with_subcalls() {
         let env = &runner.env;
 
         let tx_kind = sync::types::TransactionKind::DeployErc20(
-            aurora_engine::parameters::DeployErc20TokenArgs {
-                nep141: "wrap.near".parse().unwrap(),
-            },
+            aurora_engine::parameters::DeployErc20TokenCallArgs::V1(
+                aurora_engine::parameters::DeployErc20TokenArgs {
+                    nep141: "wrap.near".parse().unwrap(),
+                },
+            ),
         );
         let mut tx = standalone::StandaloneRunner::template_tx_msg(
             storage,
@@ -355,7 +413,8 @@ fn test_trace_precompiles_with_subcalls() {
             tx_kind.raw_bytes(),
         );
         tx.transaction = tx_kind;
-        let mut outcome = sync::execute_transaction_message::<AuroraModExp>(storage, tx).unwrap();
+        let mut outcome =
+            sync::execute_transaction_message::<AuroraModExp>(storage, tx, None, None).unwrap();
         let key = storage::bytes_to_key(storage::KeyPrefix::Nep141Erc20Map, b"wrap.near");
         outcome.diff.modify(key, wnear_address.as_bytes().to_vec());
         let key =
@@ -385,8 +444,13 @@ fn test_trace_precompiles_with_subcalls() {
         tx.transaction = tx_kind;
         tx
     };
-    let outcome =
-        sync::execute_transaction_message::<AuroraModExp>(&runner.storage, factory_update).unwrap();
+    let outcome = sync::execute_transaction_message::<AuroraModExp>(
+        &runner.storage,
+        factory_update,
+        None,
+        None,
+    )
+    .unwrap();
     standalone::storage::commit(&mut runner.storage, &outcome);
     let set_wnear_address = {
         runner.env.block_height += 1;
@@ -405,9 +469,13 @@ fn test_trace_precompiles_with_subcalls() {
         tx.transaction = tx_kind;
         tx
     };
-    let outcome =
-        sync::execute_transaction_message::<AuroraModExp>(&runner.storage, set_wnear_address)
-            .unwrap();
+    let outcome = sync::execute_transaction_message::<AuroraModExp>(
+        &runner.storage,
+        set_wnear_address,
+        None,
+        None,
+    )
+    .unwrap();
     standalone::storage::commit(&mut runner.storage, &outcome);
 
     // User calls XCC precompile
@@ -1,8 +1,10 @@
 use crate::utils::{self, standalone};
+use aurora_engine_types::borsh::BorshDeserialize;
 use aurora_engine_types::storage::{self, KeyPrefix};
 use aurora_engine_types::types::{Address, Wei};
-use aurora_engine_types::{H160, U256};
+use aurora_engine_types::{H160, H256, U256};
 use engine_standalone_storage::json_snapshot;
+use std::collections::HashMap;
 
 const NONCE_PREFIX: [u8; 2] = [0x07, 0x01];
 const BALANCE_PREFIX: [u8; 2] = [0x07, 0x02];
@@ -138,6 +140,84 @@ fn test_produce_snapshot() {
     runner.close();
 }
 
+#[test]
+fn test_produce_snapshot_for_addresses() {
+    let mut runner = standalone::StandaloneRunner::default();
+    runner.init_evm();
+
+    let sk = libsecp256k1::SecretKey::parse(&[0x77; 32]).unwrap();
+    let mut signer = utils::Signer::new(sk);
+    let signer_address = utils::address_from_secret_key(&signer.secret_key);
+    let dest = Address::from_array([0x11; 20]);
+    runner.mint_account(
+        signer_address,
+        Wei::from_eth(U256::one()).unwrap(),
+        U256::zero(),
+        None,
+    );
+    runner
+        .transfer_with_signer(&mut signer, Wei::new_u64(100_000), dest)
+        .unwrap();
+    let transfer_height = runner.env.block_height;
+
+    // Deploy a contract which writes to its storage: `PUSH1 0x2a PUSH1 0x01 SSTORE STOP`.
+    let contract_bytes = vec![0x60, 0x2a, 0x60, 0x01, 0x55, 0x00];
+    let nonce = signer.use_nonce();
+    let result = runner
+        .submit_transaction(
+            &signer.secret_key,
+            utils::create_deploy_transaction(contract_bytes, nonce.into()),
+        )
+        .unwrap();
+    let contract_address = Address::try_from_slice(&utils::unwrap_success(result)).unwrap();
+
+    let addresses = [signer_address, contract_address];
+    let full_snapshot = runner
+        .storage
+        .get_snapshot(runner.env.block_height)
+        .unwrap();
+    let partial_snapshot = runner
+        .storage
+        .get_snapshot_for_addresses(&addresses, runner.env.block_height)
+        .unwrap();
+
+    // The partial snapshot is exactly the subset of the full one belonging to the addresses.
+    let account_prefixes = [
+        KeyPrefix::Balance,
+        KeyPrefix::Nonce,
+        KeyPrefix::Code,
+        KeyPrefix::Generation,
+        KeyPrefix::Storage,
+    ]
+    .map(u8::from);
+    let expected: HashMap<_, _> = full_snapshot
+        .into_iter()
+        .filter(|(key, _)| {
+            account_prefixes.contains(&key[1]) && addresses.contains(&address_from_key(key))
+        })
+        .collect();
+    assert_eq!(partial_snapshot, expected);
+    let storage_key = storage::storage_to_key(&contract_address, &H256::from_low_u64_be(1), 0);
+    assert_eq!(
+        partial_snapshot.get(storage_key.as_ref()).unwrap(),
+        &H256::from_low_u64_be(0x2a).0.to_vec()
+    );
+    assert!(partial_snapshot
+        .keys()
+        .all(|key| address_from_key(key) != dest));
+
+    // Keys created after the requested height are not included.
+    let partial_snapshot = runner
+        .storage
+        .get_snapshot_for_addresses(&addresses, transfer_height)
+        .unwrap();
+    assert!(partial_snapshot
+        .keys()
+        .all(|key| address_from_key(key) == signer_address));
+
+    runner.close();
+}
+
 fn address_from_key(key: &[u8]) -> Address {
     let mut result = [0u8; 20];
     result.copy_from_slice(&key[2..22]);
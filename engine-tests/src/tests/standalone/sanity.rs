@@ -21,6 +21,7 @@ fn test_deploy_code() {
         upgrade_delay_blocks: 0,
         is_paused: false,
         key_manager: None,
+        state_version: state::CURRENT_STATE_VERSION,
     };
     let origin = Address::new(H160([0u8; 20]));
     let storage = RefCell::new(Storage::default());
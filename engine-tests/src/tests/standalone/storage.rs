@@ -1,9 +1,23 @@
+use aurora_engine_hashchain::bloom::Bloom;
+use aurora_engine_hashchain::hashchain::{BlockTx, HashchainBuilder};
 use aurora_engine_sdk::env::Timestamp;
+use aurora_engine_transactions::eip_2930::{AccessTuple, Transaction2930};
+use aurora_engine_transactions::EthTransactionKind;
+use aurora_engine_types::account_id::AccountId;
+use aurora_engine_types::parameters::engine::{
+    DeployErc20TokenArgs, DeployErc20TokenArgsV2, DeployErc20TokenArgsV3, DeployErc20TokenCallArgs,
+    SubmitArgs, SubmitArgsV2,
+};
+use aurora_engine_types::storage::{address_to_key, bytes_to_key, storage_to_key, KeyPrefix};
 use aurora_engine_types::types::{Address, Wei};
 use aurora_engine_types::{H256, U256};
 use engine_standalone_storage::{
-    sync::types::{TransactionKind, TransactionMessage},
-    BlockMetadata,
+    sync::{
+        self,
+        types::{TransactionKind, TransactionMessage},
+    },
+    BlockMetadata, Diff, DiffValue, IntegrityReport, IntegrityViolation, SnapshotCompression,
+    StorageDiff,
 };
 
 use crate::utils::standalone::{mocks, storage::create_db};
@@ -253,6 +267,43 @@ fn test_block_index() {
     temp_dir.close().unwrap();
 }
 
+#[test]
+fn test_recent_blocks() {
+    let (temp_dir, mut storage) = create_db();
+
+    assert!(storage.get_recent_blocks(3).unwrap().is_empty());
+
+    let block = |block_height: u64| {
+        let block_hash = mocks::compute_block_hash(block_height);
+        let block_metadata = BlockMetadata {
+            timestamp: Timestamp::new(block_height * 1_000),
+            random_seed: H256([u8::try_from(block_height).unwrap(); 32]),
+        };
+        (block_height, block_hash, block_metadata)
+    };
+    for block_height in [5, 7, 6] {
+        let (_, block_hash, block_metadata) = block(block_height);
+        storage
+            .set_block_data(block_hash, block_height, &block_metadata)
+            .unwrap();
+    }
+
+    // newest first
+    assert_eq!(
+        storage.get_recent_blocks(2).unwrap(),
+        vec![block(7), block(6)]
+    );
+    // fewer blocks than requested
+    assert_eq!(
+        storage.get_recent_blocks(10).unwrap(),
+        vec![block(7), block(6), block(5)]
+    );
+    assert!(storage.get_recent_blocks(0).unwrap().is_empty());
+
+    drop(storage);
+    temp_dir.close().unwrap();
+}
+
 #[test]
 fn test_transaction_index() {
     let (temp_dir, mut storage) = create_db();
@@ -281,7 +332,7 @@ fn test_transaction_index() {
     };
     let diff = {
         let mut tmp = engine_standalone_storage::Diff::default();
-        let key = aurora_engine_types::storage::bytes_to_key(
+        let key = aurora_engine_types::bytes_to_key(
             aurora_engine_types::storage::KeyPrefix::Balance,
             &[1u8; 20],
         );
@@ -290,11 +341,14 @@ fn test_transaction_index() {
         tmp
     };
 
+    assert!(!storage.is_transaction_included(tx_hash).unwrap());
+
     // write transaction association
     storage
         .set_transaction_included(tx_hash, &tx_msg, &diff)
         .unwrap();
     // read it back
+    assert!(storage.is_transaction_included(tx_hash).unwrap());
     assert_eq!(tx_msg, storage.get_transaction_data(tx_hash).unwrap(),);
     assert_eq!(
         tx_hash,
@@ -316,6 +370,7 @@ fn test_transaction_index() {
         position: 0,
     };
     let missing_tx_hash = H256([13u8; 32]);
+    assert!(!storage.is_transaction_included(missing_tx_hash).unwrap());
     match storage.get_transaction_data(missing_tx_hash) {
         Err(engine_standalone_storage::Error::TransactionHashNotFound(h))
             if h == missing_tx_hash => {}
@@ -334,6 +389,530 @@ fn test_transaction_index() {
     temp_dir.close().unwrap();
 }
 
+#[test]
+fn test_transaction_access_list() {
+    let (temp_dir, mut storage) = create_db();
+
+    let block_height = 37u64;
+    mocks::insert_block(&mut storage, block_height);
+    let block_hash = mocks::compute_block_hash(block_height);
+    let signer = Signer::random();
+    let access_list = vec![
+        AccessTuple {
+            address: Address::from_array([1u8; 20]).raw(),
+            storage_keys: vec![H256::zero(), H256([1u8; 32])],
+        },
+        AccessTuple {
+            address: Address::from_array([2u8; 20]).raw(),
+            storage_keys: vec![H256([2u8; 32])],
+        },
+    ];
+    let transaction = Transaction2930 {
+        chain_id: 1_313_161_556,
+        nonce: U256::zero(),
+        gas_price: U256::zero(),
+        gas_limit: U256::from(u64::MAX),
+        to: Some(Address::from_array([3u8; 20])),
+        value: Wei::zero(),
+        data: Vec::new(),
+        access_list: access_list.clone(),
+    };
+    let signed_tx = utils::sign_access_list_transaction(transaction, &signer.secret_key);
+    let tx_msg = TransactionMessage {
+        block_hash,
+        near_receipt_id: H256::zero(),
+        position: 0,
+        succeeded: true,
+        signer: "placeholder.near".parse().unwrap(),
+        caller: "placeholder.near".parse().unwrap(),
+        attached_near: 0,
+        transaction: TransactionKind::Submit(EthTransactionKind::Eip2930(signed_tx)),
+        promise_data: Vec::new(),
+        raw_input: Vec::new(),
+        action_hash: H256::default(),
+    };
+    let tx_hash = H256([77u8; 32]);
+    storage
+        .set_transaction_included(
+            tx_hash,
+            &tx_msg,
+            &engine_standalone_storage::Diff::default(),
+        )
+        .unwrap();
+
+    let stored_msg = storage.get_transaction_data(tx_hash).unwrap();
+    assert_eq!(stored_msg.access_list(), Some(access_list));
+
+    // Non-Ethereum transactions do not have an access list
+    let tx_msg = TransactionMessage {
+        transaction: TransactionKind::Unknown,
+        ..stored_msg
+    };
+    assert_eq!(tx_msg.access_list(), None);
+
+    drop(storage);
+    temp_dir.close().unwrap();
+}
+
+#[test]
+fn test_transaction_message_submit_with_args_round_trip() {
+    let legacy_args = SubmitArgs {
+        tx_data: vec![1, 2, 3],
+        max_gas_price: Some(4),
+        gas_token_address: None,
+    };
+    let args = SubmitArgsV2::from(legacy_args.clone());
+    let args_with_deadline = SubmitArgsV2 {
+        valid_until_block: Some(100),
+        ..args.clone()
+    };
+
+    for transaction in [
+        TransactionKind::SubmitWithArgs(legacy_args),
+        TransactionKind::SubmitWithArgsV2(args),
+        TransactionKind::SubmitWithArgsV2(args_with_deadline),
+    ] {
+        let raw_input = transaction.raw_bytes();
+        // The input of `submit_with_args` is parsed back to the same kind of arguments.
+        let parsed =
+            sync::parse_transaction_kind("submit_with_args", raw_input.clone(), &[]).unwrap();
+        assert_eq!(parsed, transaction);
+
+        // The fields stored after the transaction kind are not consumed by its arguments.
+        let tx_msg = TransactionMessage {
+            block_hash: H256([1u8; 32]),
+            near_receipt_id: H256([2u8; 32]),
+            position: 3,
+            succeeded: true,
+            signer: "signer.near".parse().unwrap(),
+            caller: "caller.near".parse().unwrap(),
+            attached_near: 4,
+            transaction,
+            promise_data: vec![Some(vec![5, 6]), None],
+            raw_input,
+            action_hash: H256([7u8; 32]),
+        };
+        let bytes = tx_msg.to_bytes();
+        assert_eq!(TransactionMessage::try_from_slice(&bytes).unwrap(), tx_msg);
+    }
+}
+
+#[test]
+fn test_transaction_message_deploy_erc20_round_trip() {
+    let nep141: AccountId = "token.near".parse().unwrap();
+
+    for args in [
+        DeployErc20TokenCallArgs::V1(DeployErc20TokenArgs {
+            nep141: nep141.clone(),
+        }),
+        DeployErc20TokenCallArgs::V2(DeployErc20TokenArgsV2 {
+            nep141: nep141.clone(),
+            return_existing: true,
+        }),
+        DeployErc20TokenCallArgs::V3(DeployErc20TokenArgsV3 {
+            nep141: nep141.clone(),
+            return_existing: false,
+            deterministic: true,
+        }),
+    ] {
+        let transaction = TransactionKind::DeployErc20(args);
+        let raw_input = transaction.raw_bytes();
+        let parsed =
+            sync::parse_transaction_kind("deploy_erc20_token", raw_input.clone(), &[]).unwrap();
+        assert_eq!(parsed, transaction);
+
+        let tx_msg = TransactionMessage {
+            block_hash: H256([1u8; 32]),
+            near_receipt_id: H256([2u8; 32]),
+            position: 3,
+            succeeded: true,
+            signer: "signer.near".parse().unwrap(),
+            caller: "caller.near".parse().unwrap(),
+            attached_near: 4,
+            transaction,
+            promise_data: vec![Some(vec![5, 6]), None],
+            raw_input,
+            action_hash: H256([7u8; 32]),
+        };
+        let bytes = tx_msg.to_bytes();
+        assert_eq!(TransactionMessage::try_from_slice(&bytes).unwrap(), tx_msg);
+    }
+}
+
+#[test]
+fn test_verify_integrity() {
+    let (temp_dir, mut storage) = create_db();
+
+    let block_height = 37u64;
+    mocks::insert_block(&mut storage, block_height);
+    let block_hash = mocks::compute_block_hash(block_height);
+    let tx_msg = TransactionMessage {
+        block_hash,
+        near_receipt_id: H256::zero(),
+        position: 0,
+        succeeded: true,
+        signer: "placeholder.near".parse().unwrap(),
+        caller: "placeholder.near".parse().unwrap(),
+        attached_near: 0,
+        transaction: TransactionKind::Unknown,
+        promise_data: Vec::new(),
+        raw_input: Vec::new(),
+        action_hash: H256::default(),
+    };
+    let mut diff = engine_standalone_storage::Diff::default();
+    diff.modify(vec![1u8; 21], vec![2u8; 32]);
+    storage
+        .set_transaction_included(H256([77u8; 32]), &tx_msg, &diff)
+        .unwrap();
+
+    assert_eq!(
+        storage.verify_integrity().unwrap(),
+        IntegrityReport::default()
+    );
+
+    // Corrupt the block index: the same block hash is now also stored for the next height,
+    // so the original height no longer maps back to itself.
+    let block_metadata = BlockMetadata {
+        timestamp: Timestamp::new(0),
+        random_seed: H256::zero(),
+    };
+    storage
+        .set_block_data(block_hash, block_height + 1, &block_metadata)
+        .unwrap();
+
+    let report = storage.verify_integrity().unwrap();
+    assert_eq!(
+        report.violations,
+        vec![IntegrityViolation::BlockHeightMismatch {
+            block_height,
+            block_hash,
+            stored_height: Some(block_height + 1),
+        }]
+    );
+
+    drop(storage);
+    temp_dir.close().unwrap();
+}
+
+#[test]
+fn test_structured_diff() {
+    let address = Address::from_array([1u8; 20]);
+    let slot = H256([2u8; 32]);
+    let config_key = bytes_to_key(KeyPrefix::Config, b"STATE");
+    let connector_key = bytes_to_key(KeyPrefix::EthConnector, &[0x1]);
+    let custom_key = b"custom_data".to_vec();
+
+    let mut diff = Diff::default();
+    diff.modify(
+        address_to_key(KeyPrefix::Balance, &address).to_vec(),
+        vec![3u8; 32],
+    );
+    diff.modify(
+        address_to_key(KeyPrefix::Nonce, &address).to_vec(),
+        vec![4u8; 32],
+    );
+    diff.delete(address_to_key(KeyPrefix::Code, &address).to_vec());
+    diff.modify(
+        storage_to_key(&address, &slot, 0).as_ref().to_vec(),
+        vec![5u8; 32],
+    );
+    diff.delete(storage_to_key(&address, &slot, 7).as_ref().to_vec());
+    diff.modify(config_key.clone(), vec![6u8]);
+    diff.modify(connector_key.clone(), vec![7u8]);
+    diff.modify(custom_key.clone(), vec![8u8]);
+
+    let structured = diff.to_structured();
+
+    assert_eq!(
+        structured.balances.get(&address),
+        Some(&DiffValue::Modified(vec![3u8; 32]))
+    );
+    assert_eq!(
+        structured.nonces.get(&address),
+        Some(&DiffValue::Modified(vec![4u8; 32]))
+    );
+    assert_eq!(structured.codes.get(&address), Some(&DiffValue::Deleted));
+    assert_eq!(
+        structured.storage,
+        vec![
+            StorageDiff {
+                address,
+                generation: 0,
+                slot,
+                value: DiffValue::Modified(vec![5u8; 32]),
+            },
+            StorageDiff {
+                address,
+                generation: 7,
+                slot,
+                value: DiffValue::Deleted,
+            },
+        ]
+    );
+
+    let mut other = Diff::default();
+    other.modify(config_key, vec![6u8]);
+    other.modify(connector_key, vec![7u8]);
+    other.modify(custom_key, vec![8u8]);
+    assert_eq!(structured.other, other);
+}
+
+#[test]
+fn test_reverted_transaction_diff() {
+    let mut signer = Signer::random();
+    let address = utils::address_from_secret_key(&signer.secret_key);
+    let initial_balance = Wei::new_u64(10_000_000);
+    let contract_address = Address::from_array([0xcc; 20]);
+    let gas_price = U256::from(2);
+    let mut runner = utils::standalone::StandaloneRunner::default();
+    runner.init_evm();
+
+    runner.mint_account(address, initial_balance, U256::zero(), None);
+    // Runtime code which always reverts: `PUSH1 0 DUP1 REVERT`.
+    runner.mint_account(
+        contract_address,
+        Wei::zero(),
+        U256::zero(),
+        Some(hex::decode("600080fd").unwrap()),
+    );
+
+    let mut tx = utils::transfer_with_price(
+        contract_address,
+        Wei::zero(),
+        signer.use_nonce().into(),
+        gas_price,
+    );
+    tx.gas_limit = 100_000.into();
+    let signed_tx = utils::sign_transaction(tx, Some(runner.chain_id), &signer.secret_key);
+    let tx_bytes = rlp::encode(&signed_tx).to_vec();
+    let result = runner.submit_raw_transaction_bytes(&tx_bytes).unwrap();
+    assert!(matches!(
+        result.status,
+        aurora_engine_types::parameters::engine::TransactionStatus::Revert(_)
+    ));
+
+    // The revert discards the changes of the call, but the nonce increment
+    // and the gas fee are still recorded in the diff of the transaction.
+    let expected_nonce = U256::one();
+    let expected_balance = initial_balance
+        .checked_sub(Wei::new(gas_price * U256::from(result.gas_used)))
+        .unwrap();
+    let tx_msg = runner
+        .storage
+        .get_transaction_data(aurora_engine_sdk::keccak(&tx_bytes))
+        .unwrap();
+    let tx_included = engine_standalone_storage::TransactionIncluded {
+        block_hash: tx_msg.block_hash,
+        position: tx_msg.position,
+    };
+    let diff = runner
+        .storage
+        .get_transaction_diff(tx_included)
+        .unwrap()
+        .to_structured();
+    assert_eq!(
+        diff.nonces.get(&address),
+        Some(&DiffValue::Modified(
+            aurora_engine_types::types::u256_to_arr(&expected_nonce).to_vec()
+        ))
+    );
+    assert_eq!(
+        diff.balances.get(&address),
+        Some(&DiffValue::Modified(expected_balance.to_bytes().to_vec()))
+    );
+    assert!(diff.storage.is_empty());
+
+    // Replaying the diffs reconstructs the post-state.
+    assert_eq!(runner.get_nonce(&address), expected_nonce);
+    assert_eq!(runner.get_balance(&address), expected_balance);
+
+    runner.close();
+}
+
+#[test]
+fn test_get_storage_at_height() {
+    let mut signer = Signer::random();
+    let mut runner = utils::standalone::StandaloneRunner::default();
+    runner.init_evm();
+
+    // Init code which deploys `PUSH1 0 CALLDATALOAD PUSH1 0 SSTORE`, i.e. the runtime code stores
+    // the first word of the input in the slot 0.
+    let code = hex::decode("6006600c60003960066000f3600035600055").unwrap();
+    let result = runner
+        .submit_transaction(
+            &signer.secret_key,
+            utils::create_deploy_transaction(code, signer.use_nonce().into()),
+        )
+        .unwrap();
+    let address = Address::try_from_slice(utils::unwrap_success_slice(&result)).unwrap();
+    let deploy_height = runner.env.block_height;
+    let slot = H256::zero();
+
+    let mut set_slot = |runner: &mut utils::standalone::StandaloneRunner, value: H256| {
+        let mut tx = utils::transfer(address, Wei::zero(), signer.use_nonce().into());
+        tx.data = value.as_bytes().to_vec();
+        let result = runner.submit_transaction(&signer.secret_key, tx).unwrap();
+        assert!(result.status.is_ok());
+        runner.env.block_height
+    };
+    let first_height = set_slot(&mut runner, H256([1u8; 32]));
+    let second_height = set_slot(&mut runner, H256([2u8; 32]));
+
+    let storage = &runner.storage;
+    assert_eq!(
+        storage
+            .get_storage_at_height(&address, &slot, deploy_height)
+            .unwrap(),
+        H256::zero()
+    );
+    assert_eq!(
+        storage
+            .get_storage_at_height(&address, &slot, first_height)
+            .unwrap(),
+        H256([1u8; 32])
+    );
+    assert_eq!(
+        storage
+            .get_storage_at_height(&address, &slot, second_height)
+            .unwrap(),
+        H256([2u8; 32])
+    );
+
+    // Heights before the earliest block are not available.
+    let (_, earliest_height) = storage.get_earliest_block().unwrap();
+    match storage.get_storage_at_height(&address, &slot, earliest_height - 1) {
+        Err(engine_standalone_storage::Error::BlockPruned(h)) if h == earliest_height - 1 => (), // ok
+        other => panic!("Unexpected response: {other:?}"),
+    }
+
+    runner.close();
+}
+
+#[test]
+fn test_prune_before() {
+    let mut signer = Signer::random();
+    let mut runner = utils::standalone::StandaloneRunner::default();
+    runner.init_evm();
+
+    // Same contract as in `test_get_storage_at_height`: stores the first word of the input in the slot 0.
+    let code = hex::decode("6006600c60003960066000f3600035600055").unwrap();
+    let result = runner
+        .submit_transaction(
+            &signer.secret_key,
+            utils::create_deploy_transaction(code, signer.use_nonce().into()),
+        )
+        .unwrap();
+    let address = Address::try_from_slice(utils::unwrap_success_slice(&result)).unwrap();
+    let slot = H256::zero();
+
+    let mut set_slot = |runner: &mut utils::standalone::StandaloneRunner, value: H256| {
+        let mut tx = utils::transfer(address, Wei::zero(), signer.use_nonce().into());
+        tx.data = value.as_bytes().to_vec();
+        let result = runner.submit_transaction(&signer.secret_key, tx).unwrap();
+        assert!(result.status.is_ok());
+        runner.env.block_height
+    };
+    let first_height = set_slot(&mut runner, H256([1u8; 32]));
+    let second_height = set_slot(&mut runner, H256([2u8; 32]));
+    let third_height = set_slot(&mut runner, H256([3u8; 32]));
+
+    let storage = &mut runner.storage;
+    assert_eq!(storage.get_pruning_watermark().unwrap(), None);
+    assert_eq!(
+        storage.get_earliest_queryable_block().unwrap(),
+        storage.get_earliest_block().unwrap()
+    );
+
+    let removed = storage.prune_before(third_height).unwrap();
+    assert!(removed > 0);
+    assert_eq!(storage.get_pruning_watermark().unwrap(), Some(third_height));
+    let (_, earliest_height) = storage.get_earliest_queryable_block().unwrap();
+    assert_eq!(earliest_height, third_height);
+
+    // The state at and after the watermark is still available.
+    assert_eq!(
+        storage
+            .get_storage_at_height(&address, &slot, third_height)
+            .unwrap(),
+        H256([3u8; 32])
+    );
+    assert_eq!(
+        storage
+            .get_storage_at_height(&address, &slot, second_height)
+            .unwrap_err(),
+        engine_standalone_storage::Error::BlockPruned(second_height),
+    );
+    assert!(matches!(
+        storage.get_storage_at_height(&address, &slot, first_height),
+        Err(engine_standalone_storage::Error::BlockPruned(h)) if h == first_height
+    ));
+
+    // The watermark never moves back, and there is nothing left to remove below it.
+    assert_eq!(storage.prune_before(first_height).unwrap(), 0);
+    assert_eq!(storage.get_pruning_watermark().unwrap(), Some(third_height));
+
+    runner.close();
+}
+
+#[test]
+fn test_verify_integrity_non_monotonic_engine_key() {
+    let (temp_dir, mut storage) = create_db();
+
+    let engine_key = vec![1u8; 21];
+    for (block_height, value) in [(10u64, 2u8), (11, 3)] {
+        mocks::insert_block(&mut storage, block_height);
+        let tx_msg = TransactionMessage {
+            block_hash: mocks::compute_block_hash(block_height),
+            near_receipt_id: H256::zero(),
+            position: 0,
+            succeeded: true,
+            signer: "placeholder.near".parse().unwrap(),
+            caller: "placeholder.near".parse().unwrap(),
+            attached_near: 0,
+            transaction: TransactionKind::Unknown,
+            promise_data: Vec::new(),
+            raw_input: Vec::new(),
+            action_hash: H256::default(),
+        };
+        let mut diff = Diff::default();
+        diff.modify(engine_key.clone(), vec![value; 32]);
+        storage
+            .set_transaction_included(H256([value; 32]), &tx_msg, &diff)
+            .unwrap();
+    }
+    assert!(storage.verify_integrity().unwrap().is_ok());
+
+    // Move the block of the second version before the block of the first one.
+    let block_hash = mocks::compute_block_hash(11);
+    let block_metadata = BlockMetadata {
+        timestamp: Timestamp::new(0),
+        random_seed: H256::zero(),
+    };
+    storage
+        .set_block_data(block_hash, 9, &block_metadata)
+        .unwrap();
+
+    let report = storage.verify_integrity().unwrap();
+    assert_eq!(
+        report.violations,
+        vec![
+            IntegrityViolation::BlockHeightMismatch {
+                block_height: 11,
+                block_hash,
+                stored_height: Some(9),
+            },
+            IntegrityViolation::NonMonotonicEngineKey {
+                engine_key,
+                block_height: 9,
+                previous_block_height: 10,
+            },
+        ]
+    );
+
+    drop(storage);
+    temp_dir.close().unwrap();
+}
+
 #[test]
 fn test_track_key() {
     // Set up the test
@@ -362,7 +941,7 @@ fn test_track_key() {
     // 1. Account minted
     // 2. Transfer to dest1
     // 3. Transfer to dest2
-    let balance_key = aurora_engine_types::storage::address_to_key(
+    let balance_key = aurora_engine_types::address_to_key(
         aurora_engine_types::storage::KeyPrefix::Balance,
         &signer_address,
     );
@@ -394,3 +973,165 @@ fn test_track_key() {
 
     runner.close();
 }
+
+#[test]
+fn test_binary_snapshot_round_trip() {
+    let mut signer = Signer::random();
+    let signer_address = utils::address_from_secret_key(&signer.secret_key);
+    let mut runner = utils::standalone::StandaloneRunner::default();
+
+    runner.init_evm();
+    runner.mint_account(
+        signer_address,
+        Wei::new_u64(1000),
+        signer.nonce.into(),
+        None,
+    );
+    for dest in [
+        Address::from_array([0x11; 20]),
+        Address::from_array([0x22; 20]),
+    ] {
+        let result = runner
+            .transfer_with_signer(&mut signer, Wei::new_u64(37), dest)
+            .unwrap();
+        assert!(result.status.is_ok());
+    }
+    let block_height = runner.env.block_height;
+    let expected_snapshot = runner.storage.get_snapshot(block_height).unwrap();
+
+    let export = |compression| {
+        let mut bytes = Vec::new();
+        runner
+            .storage
+            .export_binary_snapshot(block_height, &mut bytes, compression)
+            .unwrap();
+        bytes
+    };
+    let uncompressed = export(SnapshotCompression::None);
+    let compressed = export(SnapshotCompression::Zstd);
+    assert!(compressed.len() < uncompressed.len());
+
+    for bytes in [uncompressed.as_slice(), compressed.as_slice()] {
+        let (temp_dir, storage) = create_db();
+        assert_eq!(storage.import_binary_snapshot(bytes).unwrap(), block_height);
+        assert_eq!(
+            storage.get_snapshot(block_height).unwrap(),
+            expected_snapshot
+        );
+
+        drop(storage);
+        temp_dir.close().unwrap();
+    }
+
+    // Snapshots of the unknown versions are rejected.
+    let mut future_version = uncompressed;
+    future_version[0] += 1;
+    let (temp_dir, storage) = create_db();
+    assert_eq!(
+        storage.import_binary_snapshot(future_version.as_slice()),
+        Err(engine_standalone_storage::Error::UnsupportedSnapshotVersion(1))
+    );
+    drop(storage);
+    temp_dir.close().unwrap();
+
+    runner.close();
+}
+
+#[test]
+fn test_verify_block_hashchain() {
+    let (temp_dir, mut storage) = create_db();
+
+    let block_height = 37u64;
+    mocks::insert_block(&mut storage, block_height);
+    let block_hash = mocks::compute_block_hash(block_height);
+    let bloom = {
+        let mut bloom = Bloom::default();
+        bloom.accrue(&[0xde, 0xad, 0xbe, 0xef]);
+        bloom
+    };
+    let txs = [
+        BlockTx {
+            method_name: "submit",
+            input: b"submit_input",
+            output: b"submit_output",
+            log_bloom: &bloom,
+        },
+        BlockTx {
+            method_name: "set_owner",
+            input: b"set_owner_input",
+            output: &[],
+            log_bloom: &Bloom::default(),
+        },
+    ];
+
+    // The hashchain state is saved by every transaction of the block.
+    let hashchain_key = aurora_engine_types::bytes_to_key(
+        aurora_engine_types::storage::KeyPrefix::Hashchain,
+        aurora_engine::hashchain::HASHCHAIN_STATE,
+    );
+    let mut hashchain = HashchainBuilder::default()
+        .with_account_id("aurora".parse().unwrap())
+        .with_u64_chain_id(1_313_161_554)
+        .with_current_block_height(block_height)
+        .with_previous_hashchain([5; 32])
+        .build();
+    for (position, tx) in (0u16..).zip(txs) {
+        hashchain
+            .add_block_tx(
+                block_height,
+                tx.method_name,
+                tx.input,
+                tx.output,
+                tx.log_bloom,
+            )
+            .unwrap();
+        let tx_msg = TransactionMessage {
+            block_hash,
+            near_receipt_id: H256::zero(),
+            position,
+            succeeded: true,
+            signer: "placeholder.near".parse().unwrap(),
+            caller: "placeholder.near".parse().unwrap(),
+            attached_near: 0,
+            transaction: TransactionKind::Unknown,
+            promise_data: Vec::new(),
+            raw_input: tx.input.to_vec(),
+            action_hash: H256::default(),
+        };
+        let mut diff = engine_standalone_storage::Diff::default();
+        diff.modify(hashchain_key.to_vec(), hashchain.try_serialize().unwrap());
+        storage
+            .set_transaction_included(H256::from_low_u64_be(position.into()), &tx_msg, &diff)
+            .unwrap();
+    }
+    hashchain.move_to_block(block_height + 1).unwrap();
+    let expected_hashchain = hashchain.get_previous_block_hashchain();
+
+    let verification = storage
+        .verify_block_hashchain(block_height, txs)
+        .unwrap()
+        .unwrap();
+    assert!(verification.is_valid());
+    assert_eq!(verification.computed, expected_hashchain);
+
+    // A missing transaction is reported with the value computed without it.
+    let verification = storage
+        .verify_block_hashchain(block_height, txs.into_iter().skip(1))
+        .unwrap()
+        .unwrap();
+    assert!(!verification.is_valid());
+    assert_ne!(verification.computed, expected_hashchain);
+    assert_eq!(verification.stored, expected_hashchain);
+
+    // Blocks before the hashchain was started can't be verified.
+    mocks::insert_block(&mut storage, block_height - 1);
+    assert_eq!(
+        storage
+            .verify_block_hashchain(block_height - 1, txs)
+            .unwrap(),
+        None
+    );
+
+    drop(storage);
+    temp_dir.close().unwrap();
+}
@@ -214,9 +214,11 @@ fn test_consume_deploy_erc20_message() {
     let mint_amount: u128 = 555_555;
     let dest_address = Address::new(H160([170u8; 20]));
 
-    let args = aurora_engine::parameters::DeployErc20TokenArgs {
-        nep141: token.clone(),
-    };
+    let args = aurora_engine::parameters::DeployErc20TokenCallArgs::V1(
+        aurora_engine::parameters::DeployErc20TokenArgs {
+            nep141: token.clone(),
+        },
+    );
     let tx_kind = sync::types::TransactionKind::DeployErc20(args);
     let raw_input = tx_kind.raw_bytes();
     let transaction_message = sync::types::TransactionMessage {
@@ -536,6 +538,162 @@ fn sample_block() -> sync::types::BlockMessage {
     }
 }
 
+#[test]
+fn test_execute_transaction_with_timestamp_override() {
+    let mut runner = StandaloneRunner::default();
+    runner.init_evm();
+    let mut signer = utils::Signer::random();
+    let address = utils::address_from_secret_key(&signer.secret_key);
+    runner.mint_account(address, Wei::zero(), U256::zero(), None);
+
+    let constructor = utils::solidity::ContractConstructor::compile_from_source(
+        "src/tests/res",
+        "target/solidity_build",
+        "timestamp.sol",
+        "Timestamp",
+    );
+    let deploy_tx = constructor.deploy_without_constructor(signer.use_nonce().into());
+    let result = runner
+        .submit_transaction(&signer.secret_key, deploy_tx)
+        .unwrap();
+    let contract_address = Address::try_from_slice(&utils::unwrap_success(result)).unwrap();
+    let contract = constructor.deployed_at(contract_address);
+
+    runner.env.block_height += 1;
+    runner.env.block_timestamp = Timestamp::new(1_000_000_000_000);
+    let tx =
+        contract.call_method_without_args("getCurrentBlockTimestamp", signer.use_nonce().into());
+    let signed_tx = utils::sign_transaction(tx, Some(runner.chain_id), &signer.secret_key);
+    let tx_bytes = rlp::encode(&signed_tx).to_vec();
+    let mut tx_msg = StandaloneRunner::template_tx_msg(
+        &mut runner.storage,
+        &runner.env,
+        0,
+        aurora_engine_sdk::keccak(&tx_bytes),
+        &[],
+        tx_bytes.clone(),
+    );
+    tx_msg.transaction =
+        sync::types::TransactionKind::Submit(tx_bytes.as_slice().try_into().unwrap());
+
+    let get_timestamp =
+        |outcome: sync::TransactionIncludedOutcome| match outcome.maybe_result.unwrap().unwrap() {
+            sync::TransactionExecutionResult::Submit(result) => {
+                U256::from_big_endian(&utils::unwrap_success(result.unwrap()))
+            }
+            _ => unreachable!(),
+        };
+
+    // Without the override the timestamp comes from the stored block metadata.
+    let outcome = sync::execute_transaction_message::<AuroraModExp>(
+        &runner.storage,
+        tx_msg.clone(),
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(get_timestamp(outcome), U256::from(1_000));
+
+    let outcome = sync::execute_transaction_message::<AuroraModExp>(
+        &runner.storage,
+        tx_msg.clone(),
+        Some(Timestamp::new(2_000_000_000_000)),
+        None,
+    )
+    .unwrap();
+    assert_eq!(get_timestamp(outcome), U256::from(2_000));
+
+    // The override is not persisted.
+    assert_eq!(
+        runner
+            .storage
+            .get_block_metadata(tx_msg.block_hash)
+            .unwrap()
+            .timestamp,
+        Timestamp::new(1_000_000_000_000),
+    );
+
+    runner.close();
+}
+
+#[test]
+fn test_execute_transaction_with_block_height_override() {
+    let mut runner = StandaloneRunner::default();
+    runner.init_evm();
+    let mut signer = utils::Signer::random();
+    let address = utils::address_from_secret_key(&signer.secret_key);
+    runner.mint_account(address, Wei::zero(), U256::zero(), None);
+
+    let constructor = utils::solidity::ContractConstructor::compile_from_source(
+        "src/tests/res",
+        "target/solidity_build",
+        "block_number.sol",
+        "BlockNumber",
+    );
+    let deploy_tx = constructor.deploy_without_constructor(signer.use_nonce().into());
+    let result = runner
+        .submit_transaction(&signer.secret_key, deploy_tx)
+        .unwrap();
+    let contract_address = Address::try_from_slice(&utils::unwrap_success(result)).unwrap();
+    let contract = constructor.deployed_at(contract_address);
+
+    runner.env.block_height += 1;
+    let block_height = runner.env.block_height;
+    let tx = contract.call_method_without_args("getCurrentBlockNumber", signer.use_nonce().into());
+    let signed_tx = utils::sign_transaction(tx, Some(runner.chain_id), &signer.secret_key);
+    let tx_bytes = rlp::encode(&signed_tx).to_vec();
+    let mut tx_msg = StandaloneRunner::template_tx_msg(
+        &mut runner.storage,
+        &runner.env,
+        0,
+        aurora_engine_sdk::keccak(&tx_bytes),
+        &[],
+        tx_bytes.clone(),
+    );
+    tx_msg.transaction =
+        sync::types::TransactionKind::Submit(tx_bytes.as_slice().try_into().unwrap());
+
+    let get_block_number =
+        |outcome: &sync::TransactionIncludedOutcome| match outcome.maybe_result.as_ref().unwrap() {
+            Some(sync::TransactionExecutionResult::Submit(Ok(result))) => {
+                U256::from_big_endian(utils::unwrap_success_slice(result))
+            }
+            _ => unreachable!(),
+        };
+
+    // Without the override the block number is the height of the block containing the transaction.
+    let outcome = sync::execute_transaction_message::<AuroraModExp>(
+        &runner.storage,
+        tx_msg.clone(),
+        None,
+        None,
+    )
+    .unwrap();
+    assert_eq!(get_block_number(&outcome), U256::from(block_height));
+
+    let outcome = sync::execute_transaction_message::<AuroraModExp>(
+        &runner.storage,
+        tx_msg.clone(),
+        None,
+        Some(1_000_000),
+    )
+    .unwrap();
+    // Only the EVM view is affected: the contract is still found because the engine state is
+    // read at the actual height.
+    assert_eq!(get_block_number(&outcome), U256::from(1_000_000));
+
+    // The override is not persisted.
+    assert_eq!(
+        runner
+            .storage
+            .get_block_height_by_hash(tx_msg.block_hash)
+            .unwrap(),
+        block_height
+    );
+
+    runner.close();
+}
+
 fn initialize() -> (StandaloneRunner, sync::types::BlockMessage) {
     let mut runner = StandaloneRunner::default();
     runner.init_evm();
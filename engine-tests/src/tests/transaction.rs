@@ -1,13 +1,15 @@
 use crate::prelude::transactions::eip_1559::{self, SignedTransaction1559, Transaction1559};
 use crate::prelude::transactions::eip_2930::AccessTuple;
 use crate::prelude::transactions::EthTransactionKind;
-use crate::prelude::Wei;
+use crate::prelude::{Address, Wei};
 use crate::prelude::{H256, U256};
 use crate::utils;
-use aurora_engine::parameters::SubmitResult;
+use aurora_engine::engine::{EngineError, EngineErrorKind, GasPaymentError};
+use aurora_engine::parameters::{SubmitArgs, SubmitResult};
 use aurora_engine_transactions::eip_2930;
 use aurora_engine_transactions::eip_2930::Transaction2930;
-use aurora_engine_types::borsh::BorshDeserialize;
+use aurora_engine_types::borsh::{self, BorshDeserialize};
+use aurora_engine_types::parameters::engine::{SetPaymasterArgs, SetTipRecipientArgs};
 use std::convert::TryFrom;
 use std::iter;
 
@@ -85,6 +87,7 @@ fn test_eip_1559_example() {
         .unwrap();
     let result = SubmitResult::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap();
     assert_eq!(result.gas_used, 0xb8d2);
+    assert_eq!(result.effective_gas_price, 0x0a);
 
     // Check post state:
     // signer spent some ETH on gas fees and incremented nonce for submitting transaction
@@ -107,6 +110,293 @@ fn test_eip_1559_example() {
     assert_eq!(runner.get_balance(coinbase), Wei::new_u64(0x73834));
 }
 
+#[test]
+fn test_eip_1559_tip_recipient() {
+    let mut runner = utils::deploy_runner();
+    let mut signer = example_signer();
+    let signer_address = utils::address_from_secret_key(&signer.secret_key);
+    let contract_address = utils::address_from_hex(CONTRACT_ADDRESS);
+    let tip_recipient = utils::address_from_hex("0x1111111111111111111111111111111111111111");
+    let aurora = runner.aurora_account_id.clone();
+
+    runner.create_address(signer_address, INITIAL_BALANCE, signer.nonce.into());
+    runner.create_address_with_code(
+        contract_address,
+        CONTRACT_BALANCE,
+        CONTRACT_NONCE.into(),
+        hex::decode(CONTRACT_CODE).unwrap(),
+    );
+
+    let set_tip_recipient = |runner: &mut utils::AuroraRunner, tip_recipient| {
+        let args = SetTipRecipientArgs { tip_recipient };
+        runner
+            .call("set_tip_recipient", &aurora, borsh::to_vec(&args).unwrap())
+            .unwrap();
+    };
+    let submit = |runner: &mut utils::AuroraRunner, signer: &mut utils::Signer| {
+        let mut transaction = example_transaction();
+        transaction.chain_id = runner.chain_id;
+        transaction.nonce = signer.use_nonce().into();
+        let signed_tx = utils::sign_eip_1559_transaction(transaction, &signer.secret_key);
+        let outcome = runner
+            .call(utils::SUBMIT, "relay.aurora", encode_tx(&signed_tx))
+            .unwrap();
+        SubmitResult::try_from_slice(&outcome.return_data.as_value().unwrap())
+            .unwrap()
+            .gas_used
+    };
+    let coinbase = aurora_engine_sdk::types::near_account_to_evm_address(b"relay.aurora");
+    let priority_fee_per_gas = example_transaction().max_priority_fee_per_gas;
+
+    set_tip_recipient(&mut runner, Some(tip_recipient));
+    let outcome = runner
+        .one_shot()
+        .call("get_tip_recipient", "any.near", Vec::new())
+        .unwrap();
+    let stored_tip_recipient =
+        Option::<Address>::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap();
+    assert_eq!(stored_tip_recipient, Some(tip_recipient));
+
+    // The priority fee is credited to the tip recipient instead of the relayer.
+    let gas_used = submit(&mut runner, &mut signer);
+    let expected_tip = Wei::new(priority_fee_per_gas * U256::from(gas_used));
+    assert_eq!(runner.get_balance(tip_recipient), expected_tip);
+    assert_eq!(runner.get_balance(coinbase), Wei::zero());
+    // The base fee is always 0, so there is nothing collected by the engine.
+    let outcome = runner
+        .one_shot()
+        .call("get_collected_fees", "any.near", Vec::new())
+        .unwrap();
+    assert_eq!(outcome.return_data.as_value().unwrap(), [0u8; 32]);
+
+    // The zero address means the priority fee is burned.
+    set_tip_recipient(&mut runner, Some(Address::zero()));
+    let balance_before = runner.get_balance(signer_address);
+    let gas_used = submit(&mut runner, &mut signer);
+    let spent = Wei::new(priority_fee_per_gas * U256::from(gas_used));
+    assert_eq!(
+        runner.get_balance(signer_address),
+        balance_before.checked_sub(spent).unwrap()
+    );
+    assert_eq!(runner.get_balance(tip_recipient), expected_tip);
+    assert_eq!(runner.get_balance(coinbase), Wei::zero());
+    assert_eq!(runner.get_balance(Address::zero()), Wei::zero());
+}
+
+#[test]
+fn test_paymaster() {
+    // Runtime code of the paymasters which return `true` and `false` from `sponsor` respectively:
+    // `PUSH1 x PUSH1 0 MSTORE PUSH1 0x20 PUSH1 0 RETURN`.
+    const APPROVING_CODE: &str = "600160005260206000f3";
+    const DENYING_CODE: &str = "600060005260206000f3";
+
+    let mut runner = utils::deploy_runner();
+    let mut signer = example_signer();
+    let signer_address = utils::address_from_secret_key(&signer.secret_key);
+    let approving = utils::address_from_hex("0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa");
+    let denying = utils::address_from_hex("0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb");
+    let broke = utils::address_from_hex("0xdddddddddddddddddddddddddddddddddddddddd");
+    let aurora = runner.aurora_account_id.clone();
+
+    runner.create_address(signer_address, INITIAL_BALANCE, signer.nonce.into());
+    runner.create_address_with_code(
+        utils::address_from_hex(CONTRACT_ADDRESS),
+        CONTRACT_BALANCE,
+        CONTRACT_NONCE.into(),
+        hex::decode(CONTRACT_CODE).unwrap(),
+    );
+    for (address, balance, code) in [
+        (approving, INITIAL_BALANCE, APPROVING_CODE),
+        (denying, INITIAL_BALANCE, DENYING_CODE),
+        (broke, Wei::zero(), APPROVING_CODE),
+    ] {
+        runner.create_address_with_code(address, balance, U256::zero(), hex::decode(code).unwrap());
+    }
+
+    let set_paymaster = |runner: &mut utils::AuroraRunner, paymaster| {
+        let args = SetPaymasterArgs { paymaster };
+        runner
+            .call("set_paymaster", &aurora, borsh::to_vec(&args).unwrap())
+            .unwrap();
+    };
+    let submit = |runner: &mut utils::AuroraRunner,
+                  signer: &mut utils::Signer|
+     -> Result<Wei, EngineError> {
+        let mut transaction = example_transaction();
+        transaction.chain_id = runner.chain_id;
+        transaction.nonce = signer.nonce.into();
+        let signed_tx = utils::sign_eip_1559_transaction(transaction, &signer.secret_key);
+        let outcome = runner.call(utils::SUBMIT, "relay.aurora", encode_tx(&signed_tx))?;
+        signer.use_nonce();
+        let result =
+            SubmitResult::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap();
+        Ok(Wei::new(
+            example_transaction().max_priority_fee_per_gas * U256::from(result.gas_used),
+        ))
+    };
+
+    set_paymaster(&mut runner, Some(approving));
+    let outcome = runner
+        .one_shot()
+        .call("get_paymaster", "any.near", Vec::new())
+        .unwrap();
+    let stored_paymaster =
+        Option::<Address>::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap();
+    assert_eq!(stored_paymaster, Some(approving));
+
+    // The approved transaction is paid by the paymaster.
+    let spent = submit(&mut runner, &mut signer).unwrap();
+    assert_eq!(runner.get_balance(signer_address), INITIAL_BALANCE);
+    assert_eq!(
+        runner.get_balance(approving),
+        INITIAL_BALANCE.checked_sub(spent).unwrap()
+    );
+
+    // The denied transaction is paid by the sender.
+    set_paymaster(&mut runner, Some(denying));
+    let spent = submit(&mut runner, &mut signer).unwrap();
+    assert_eq!(
+        runner.get_balance(signer_address),
+        INITIAL_BALANCE.checked_sub(spent).unwrap()
+    );
+    assert_eq!(runner.get_balance(denying), INITIAL_BALANCE);
+
+    // The sponsored transaction fails if the paymaster cannot cover the gas,
+    // the sender is not charged instead.
+    set_paymaster(&mut runner, Some(broke));
+    let balance_before = runner.get_balance(signer_address);
+    let error = submit(&mut runner, &mut signer).unwrap_err();
+    assert_eq!(
+        error.kind,
+        EngineErrorKind::GasPayment(GasPaymentError::PaymasterOutOfFund)
+    );
+    assert_eq!(runner.get_balance(signer_address), balance_before);
+
+    // Without a paymaster the sender pays as usual.
+    set_paymaster(&mut runner, None);
+    let spent = submit(&mut runner, &mut signer).unwrap();
+    assert_eq!(
+        runner.get_balance(signer_address),
+        balance_before.checked_sub(spent).unwrap()
+    );
+}
+
+#[test]
+fn test_paymaster_denied_value_transfer() {
+    // Runtime code of a paymaster which returns `false` from `sponsor`.
+    const DENYING_CODE: &str = "600060005260206000f3";
+
+    let mut runner = utils::deploy_runner();
+    let signer = example_signer();
+    let signer_address = utils::address_from_secret_key(&signer.secret_key);
+    let contract_address = utils::address_from_hex(CONTRACT_ADDRESS);
+    let denying = utils::address_from_hex("0xbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb");
+    let value = Wei::new_u64(1_000_000);
+
+    runner.create_address(signer_address, INITIAL_BALANCE, signer.nonce.into());
+    runner.create_address_with_code(
+        contract_address,
+        CONTRACT_BALANCE,
+        CONTRACT_NONCE.into(),
+        hex::decode(CONTRACT_CODE).unwrap(),
+    );
+    runner.create_address_with_code(
+        denying,
+        INITIAL_BALANCE,
+        U256::zero(),
+        hex::decode(DENYING_CODE).unwrap(),
+    );
+    let args = SetPaymasterArgs {
+        paymaster: Some(denying),
+    };
+    let aurora = runner.aurora_account_id.clone();
+    runner
+        .call("set_paymaster", &aurora, borsh::to_vec(&args).unwrap())
+        .unwrap();
+
+    let mut transaction = example_transaction();
+    transaction.chain_id = runner.chain_id;
+    transaction.nonce = signer.nonce.into();
+    transaction.value = value;
+    let signed_tx = utils::sign_eip_1559_transaction(transaction, &signer.secret_key);
+    let outcome = runner
+        .call(utils::SUBMIT, "relay.aurora", encode_tx(&signed_tx))
+        .unwrap();
+    let result = SubmitResult::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap();
+    let spent =
+        Wei::new(example_transaction().max_priority_fee_per_gas * U256::from(result.gas_used));
+
+    // The sender pays both the value and the gas, the paymaster is not charged.
+    assert_eq!(
+        runner.get_balance(signer_address),
+        INITIAL_BALANCE
+            .checked_sub(spent)
+            .and_then(|balance| balance.checked_sub(value))
+            .unwrap()
+    );
+    assert_eq!(
+        runner.get_balance(contract_address),
+        CONTRACT_BALANCE.checked_add(value).unwrap()
+    );
+    assert_eq!(runner.get_balance(denying), INITIAL_BALANCE);
+    assert_eq!(
+        runner.get_nonce(signer_address),
+        U256::from(INITIAL_NONCE + 1)
+    );
+}
+
+#[test]
+fn test_eip_1559_effective_gas_price() {
+    let mut runner = utils::deploy_runner();
+    let mut signer = example_signer();
+    let signer_address = utils::address_from_secret_key(&signer.secret_key);
+
+    runner.create_address(signer_address, INITIAL_BALANCE, signer.nonce.into());
+    runner.create_address_with_code(
+        utils::address_from_hex(CONTRACT_ADDRESS),
+        CONTRACT_BALANCE,
+        CONTRACT_NONCE.into(),
+        hex::decode(CONTRACT_CODE).unwrap(),
+    );
+
+    let mut submit = |max_gas_price: Option<u128>| {
+        let mut transaction = example_transaction();
+        transaction.chain_id = runner.chain_id;
+        transaction.nonce = signer.use_nonce().into();
+        let signed_tx = utils::sign_eip_1559_transaction(transaction, &signer.secret_key);
+        let args = SubmitArgs {
+            tx_data: encode_tx(&signed_tx),
+            max_gas_price,
+            gas_token_address: None,
+        };
+        let balance_before = runner.get_balance(signer_address);
+        let outcome = runner
+            .call(
+                utils::SUBMIT_WITH_ARGS,
+                "relay.aurora",
+                borsh::to_vec(&args).unwrap(),
+            )
+            .unwrap();
+        let output = outcome.return_data.as_value().unwrap();
+        let result = SubmitResult::try_from_slice(&output).unwrap();
+        // The fee paid by the sender matches the reported price.
+        let fee = Wei::new(U256::from(result.gas_used) * U256::from(result.effective_gas_price));
+        assert_eq!(
+            runner.get_balance(signer_address),
+            balance_before.checked_sub(fee).unwrap()
+        );
+        (result.effective_gas_price, output.len())
+    };
+
+    // The base fee is always 0, so only the priority fee is charged.
+    let priority_fee_per_gas = example_transaction().max_priority_fee_per_gas.as_u128();
+    let (price, output_len) = submit(None);
+    assert_eq!(price, priority_fee_per_gas);
+    // The price can be capped by the relayer. The price doesn't change the layout of the result.
+    assert_eq!(submit(Some(4)), (4, output_len));
+    assert_eq!(submit(Some(1_000)), (priority_fee_per_gas, output_len));
+}
+
 // Test taken from https://github.com/ethereum/tests/blob/develop/GeneralStateTests/stExample/accessListExample.json
 // TODO(#170): generally support Ethereum tests
 #[test]
@@ -161,6 +451,37 @@ fn test_access_list_tx_encoding_decoding() {
     );
 }
 
+#[test]
+fn test_tx_hash() {
+    let runner = utils::deploy_runner();
+    // Expected hashes are the ones reported by ethers.js (`Transaction.from(raw).hash`).
+    let legacy_tx = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+    let access_list_tx = "01f8f901800a83061a8094095e7baea6a6c7c4c2dfeb977efac326af552d87830186a000f893f85994095e7baea6a6c7c4c2dfeb977efac326af552d87f842a00000000000000000000000000000000000000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000001f794195e7baea6a6c7c4c2dfeb977efac326af552d87e1a0000000000000000000000000000000000000000000000000000000000000000080a011c97e0bb8a356fe4f49b37863d059c6fe8cd3214a6ac06a8387a2f6f0b75f60a0212368a1097da30806edfd13d9c35662e1baee939235eb25de867980bd0eda26";
+
+    for (raw, expected_hash) in [
+        (
+            legacy_tx,
+            "33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788",
+        ),
+        (
+            access_list_tx,
+            "b7a343072cc709a17dcb9305f435af6812f4d76002729b48d9c4028aa0e3924b",
+        ),
+        (
+            EXAMPLE_TX_HEX,
+            "fb18421827800adcf465688e303cc9863045fdb96971473a114677916a3a08a4",
+        ),
+    ] {
+        let outcome = runner
+            .one_shot()
+            .call("tx_hash", "any.near", hex::decode(raw).unwrap())
+            .unwrap();
+        let tx_hash = outcome.return_data.as_value().unwrap();
+
+        assert_eq!(hex::encode(tx_hash), expected_hash);
+    }
+}
+
 fn encode_tx(signed_tx: &SignedTransaction1559) -> Vec<u8> {
     iter::once(eip_1559::TYPE_BYTE)
         .chain(rlp::encode(signed_tx))
@@ -1,6 +1,13 @@
 use std::{fs, path::Path};
 
+use aurora_engine::parameters::{NewCallArgs, NewCallArgsV5};
+use aurora_engine_sdk as sdk;
+use aurora_engine_types::borsh::BorshDeserialize;
+use aurora_engine_types::types::RawH256;
+
+use crate::prelude::U256;
 use crate::utils::workspace::deploy_engine;
+use crate::utils::{str_to_account_id, AuroraRunner};
 
 #[tokio::test]
 async fn test_code_upgrade() {
@@ -51,6 +58,149 @@ async fn test_code_upgrade_with_stage() {
     assert_eq!(output, [3, 1, 4, 1, 5, 9, 2]);
 }
 
+#[tokio::test]
+async fn test_code_upgrade_with_chunked_stage() {
+    let aurora = deploy_engine().await;
+    let code = contract_bytes();
+    let chunk_size = code.len() / 3 + 1;
+
+    for (index, chunk) in code.chunks(chunk_size).enumerate() {
+        let result = aurora
+            .stage_upgrade_chunk(u32::try_from(index).unwrap(), chunk.to_vec())
+            .max_gas()
+            .transact()
+            .await
+            .unwrap();
+        assert!(result.is_success());
+    }
+
+    let is_staged = aurora
+        .finalize_upgrade_stage(code.len() as u64, sdk::keccak(&code))
+        .max_gas()
+        .transact()
+        .await
+        .unwrap()
+        .into_value();
+    assert!(is_staged);
+
+    let result = aurora.deploy_upgrade().max_gas().transact().await.unwrap();
+    assert!(result.is_success());
+
+    // call a new method
+    let result = aurora
+        .as_raw_contract()
+        .view("some_new_fancy_function")
+        .await
+        .unwrap();
+
+    let output: [u32; 7] = result.borsh().unwrap();
+    assert_eq!(output, [3, 1, 4, 1, 5, 9, 2]);
+}
+
+#[tokio::test]
+async fn test_chunked_stage_with_wrong_hash_clears_chunks() {
+    let aurora = deploy_engine().await;
+    let code = contract_bytes();
+    let (first, second) = code.split_at(code.len() / 2);
+
+    for (index, chunk) in [first, second].into_iter().enumerate() {
+        let result = aurora
+            .stage_upgrade_chunk(u32::try_from(index).unwrap(), chunk.to_vec())
+            .max_gas()
+            .transact()
+            .await
+            .unwrap();
+        assert!(result.is_success());
+    }
+
+    let is_staged = aurora
+        .finalize_upgrade_stage(code.len() as u64, sdk::keccak(first))
+        .max_gas()
+        .transact()
+        .await
+        .unwrap()
+        .into_value();
+    assert!(!is_staged);
+
+    // The chunks have been cleared, so neither finalizing again nor deploying is possible.
+    let result = aurora
+        .finalize_upgrade_stage(code.len() as u64, sdk::keccak(&code))
+        .max_gas()
+        .transact()
+        .await;
+    assert!(result.is_err());
+
+    let result = aurora.deploy_upgrade().max_gas().transact().await;
+    assert!(result.is_err());
+
+    // Uploading a chunk out of order is rejected as well.
+    let result = aurora
+        .stage_upgrade_chunk(1, second.to_vec())
+        .max_gas()
+        .transact()
+        .await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_get_code_hash_self() {
+    let aurora = deploy_engine().await;
+    // The hash of the initially deployed code is unknown.
+    let code_hash = aurora.get_code_hash_self().await.unwrap().result;
+    assert_eq!(code_hash, None);
+
+    let code = AuroraRunner::get_engine_code();
+    let result = aurora
+        .upgrade(code.clone())
+        .max_gas()
+        .transact()
+        .await
+        .unwrap();
+    assert!(result.is_success());
+
+    let code_hash = aurora.get_code_hash_self().await.unwrap().result;
+    assert_eq!(code_hash, Some(sdk::keccak(&code).0));
+
+    // Staged code is not reflected until it is deployed.
+    let result = aurora
+        .stage_upgrade(contract_bytes())
+        .max_gas()
+        .transact()
+        .await
+        .unwrap();
+    assert!(result.is_success());
+
+    let code_hash = aurora.get_code_hash_self().await.unwrap().result;
+    assert_eq!(code_hash, Some(sdk::keccak(&code).0));
+}
+
+#[test]
+fn test_get_code_hash_self_recorded_by_new() {
+    let mut runner = AuroraRunner::default();
+    let account_id = runner.aurora_account_id.clone();
+    let aurora_account_id = str_to_account_id(&account_id);
+    let code_hash = sdk::keccak(&AuroraRunner::get_engine_code()).0;
+    let args = NewCallArgs::V5(NewCallArgsV5 {
+        chain_id: crate::prelude::u256_to_arr(&U256::from(runner.chain_id)),
+        owner_id: aurora_account_id.clone(),
+        upgrade_delay_blocks: 1,
+        key_manager: aurora_account_id,
+        initial_hashchain: None,
+        code_hash: Some(code_hash),
+    });
+    runner
+        .call("new", &account_id, borsh::to_vec(&args).unwrap())
+        .unwrap();
+
+    let outcome = runner
+        .one_shot()
+        .call("get_code_hash_self", "any.near", Vec::new())
+        .unwrap();
+    let result =
+        Option::<RawH256>::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap();
+    assert_eq!(result, Some(code_hash));
+}
+
 // TODO: Should be reworked with `upgrade_delay_blocks` more then one to check that
 // we get the TOO EARLY error.
 #[tokio::test]
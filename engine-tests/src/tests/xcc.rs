@@ -4,6 +4,7 @@ use aurora_engine_precompiles::xcc::{costs, cross_contract_call};
 use aurora_engine_transactions::legacy::TransactionLegacy;
 use aurora_engine_types::account_id::AccountId;
 use aurora_engine_types::borsh::BorshDeserialize;
+use aurora_engine_types::parameters::xcc::SetNearGasPerEvmGasArgs;
 use aurora_engine_types::parameters::{
     CrossContractCallArgs, NearPromise, PromiseArgs, PromiseCreateArgs, PromiseWithCallbackArgs,
     SimpleNearPromise,
@@ -166,6 +167,48 @@ fn test_xcc_eth_gas_cost() {
     );
 }
 
+#[test]
+fn test_xcc_near_gas_per_evm_gas() {
+    let mut runner = utils::deploy_runner();
+    let get_value = |runner: &AuroraRunner| {
+        let outcome = runner
+            .one_shot()
+            .call("get_xcc_near_gas_per_evm_gas", "any.near", Vec::new())
+            .unwrap();
+        u64::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    };
+    let set_value = |runner: &mut AuroraRunner, account_id: &str, value: Option<u64>| {
+        let args = SetNearGasPerEvmGasArgs {
+            near_gas_per_evm_gas: value,
+        };
+        runner.call(
+            "set_xcc_near_gas_per_evm_gas",
+            account_id,
+            borsh::to_vec(&args).unwrap(),
+        )
+    };
+
+    assert_eq!(get_value(&runner), costs::CROSS_CONTRACT_CALL_NEAR_GAS);
+
+    let error = set_value(&mut runner, "some-account.near", Some(100)).unwrap_err();
+    assert!(matches!(
+        error.kind,
+        aurora_engine::engine::EngineErrorKind::NotAllowed
+    ));
+    let error = set_value(&mut runner, DEFAULT_AURORA_ACCOUNT_ID, Some(0)).unwrap_err();
+    assert_eq!(
+        error.kind,
+        aurora_engine::engine::EngineErrorKind::EvmFatal(evm::ExitFatal::Other(
+            "ERR_INVALID_NEAR_GAS_PER_EVM_GAS".into()
+        ))
+    );
+
+    set_value(&mut runner, DEFAULT_AURORA_ACCOUNT_ID, Some(100_000_000)).unwrap();
+    assert_eq!(get_value(&runner), 100_000_000);
+    set_value(&mut runner, DEFAULT_AURORA_ACCOUNT_ID, None).unwrap();
+    assert_eq!(get_value(&runner), costs::CROSS_CONTRACT_CALL_NEAR_GAS);
+}
+
 fn check_fib_result(output: &serde_json::Value, n: usize) {
     let fib_numbers: [u8; 8] = [0, 1, 1, 2, 3, 5, 8, 13];
     let get_number = |field_name: &str| -> u8 {
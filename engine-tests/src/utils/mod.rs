@@ -8,7 +8,9 @@ use aurora_engine_types::parameters::connector::FungibleTokenMetadata;
 use aurora_engine_types::parameters::connector::{
     SetEthConnectorContractAccountArgs, WithdrawSerializeType,
 };
-use aurora_engine_types::parameters::engine::{NewCallArgs, NewCallArgsV4};
+use aurora_engine_types::parameters::engine::{
+    AccountInfo, NewCallArgs, NewCallArgsV4, PrecompileInfo,
+};
 use aurora_engine_types::parameters::silo::FixedGasArgs;
 use aurora_engine_types::types::{EthGas, PromiseResult};
 use evm::ExitFatal;
@@ -21,6 +23,7 @@ use near_vm_runner::logic::mocks::mock_external::MockedExternal;
 use near_vm_runner::logic::types::ReturnData;
 use near_vm_runner::logic::{Config, HostError, VMContext, VMOutcome};
 use near_vm_runner::{ContractCode, MockContractRuntimeCache, ProfileDataV3};
+use rand::SeedableRng;
 use rlp::RlpStream;
 use std::borrow::Cow;
 use std::sync::Arc;
@@ -72,6 +75,14 @@ impl Signer {
         Self::new(sk)
     }
 
+    /// Derives the secret key from the seed, so a failing test can be reproduced with the same
+    /// signer.
+    pub fn from_seed(seed: u64) -> Self {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+        let sk = SecretKey::random(&mut rng);
+        Self::new(sk)
+    }
+
     pub fn use_nonce(&mut self) -> u64 {
         let nonce = self.nonce;
         self.nonce += 1;
@@ -245,13 +256,14 @@ impl AuroraRunner {
         self.previous_logs.clone_from(&outcome.logs);
 
         if let Some(standalone_runner) = &mut self.standalone_runner {
-            standalone_runner.submit_raw(
+            let standalone_result = standalone_runner.submit_raw(
                 method_name,
                 &self.context,
                 &self.promise_results,
                 self.block_random_value,
             )?;
             self.validate_standalone();
+            Self::validate_standalone_logs(method_name, &outcome, &standalone_result);
         }
 
         Ok(outcome)
@@ -514,10 +526,49 @@ impl AuroraRunner {
         self.u256_getter_method_call("get_nonce", address)
     }
 
+    pub fn get_tx_count(&self, address: Address) -> U256 {
+        self.u256_getter_method_call("get_tx_count", address)
+    }
+
     pub fn get_code(&self, address: Address) -> Vec<u8> {
         self.getter_method_call("get_code", address)
     }
 
+    pub fn get_code_hash(&self, address: Address) -> H256 {
+        H256::from_slice(&self.getter_method_call("get_code_hash", address))
+    }
+
+    pub fn account_exists(&self, address: Address) -> bool {
+        bool::try_from_slice(&self.getter_method_call("account_exists", address)).unwrap()
+    }
+
+    pub fn get_account(&self, address: Address) -> AccountInfo {
+        AccountInfo::try_from_slice(&self.getter_method_call("get_account", address)).unwrap()
+    }
+
+    pub fn get_accounts(&self, addresses: &[Address]) -> Result<Vec<AccountInfo>, EngineError> {
+        let outcome =
+            self.one_shot()
+                .call("get_accounts", "getter", borsh::to_vec(addresses).unwrap())?;
+        Ok(Vec::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap())
+    }
+
+    pub fn get_precompiles(&self) -> Vec<Address> {
+        let outcome = self
+            .one_shot()
+            .call("get_precompiles", "getter", vec![])
+            .unwrap();
+        Vec::<Address>::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    }
+
+    pub fn get_precompile_info(&self) -> Vec<PrecompileInfo> {
+        let outcome = self
+            .one_shot()
+            .call("get_precompile_info", "getter", vec![])
+            .unwrap();
+        Vec::<PrecompileInfo>::try_from_slice(&outcome.return_data.as_value().unwrap()).unwrap()
+    }
+
     pub fn get_fixed_gas(&self) -> Option<EthGas> {
         let outcome = self
             .one_shot()
@@ -562,6 +613,29 @@ impl AuroraRunner {
         self
     }
 
+    /// The logs of the transactions must be the same (including their order) in both runners.
+    fn validate_standalone_logs(
+        method_name: &str,
+        outcome: &VMOutcome,
+        standalone_result: &SubmitResult,
+    ) {
+        if method_name != SUBMIT && method_name != SUBMIT_WITH_ARGS {
+            return;
+        }
+
+        if let Some(result) = outcome
+            .return_data
+            .clone()
+            .as_value()
+            .and_then(|bytes| SubmitResult::try_from_slice(&bytes).ok())
+        {
+            assert_eq!(
+                result.logs, standalone_result.logs,
+                "The logs of the wasm and standalone runners differ"
+            );
+        }
+    }
+
     fn validate_standalone(&self) {
         if let Some(standalone_runner) = &self.standalone_runner {
             let standalone_state = standalone_runner.get_current_state();
@@ -1055,9 +1129,17 @@ fn into_engine_error(gas_used: u64, aborted: &FunctionCallError) -> EngineError
             match panic_msg.as_str() {
                 "ERR_INVALID_CHAIN_ID" => EngineErrorKind::InvalidChainId,
                 "ERR_OUT_OF_FUND" => EngineErrorKind::GasPayment(GasPaymentError::OutOfFund),
+                "ERR_PAYMASTER_OUT_OF_FUND" => {
+                    EngineErrorKind::GasPayment(GasPaymentError::PaymasterOutOfFund)
+                }
                 "ERR_GAS_OVERFLOW" => EngineErrorKind::GasOverflow,
                 "ERR_INTRINSIC_GAS" => EngineErrorKind::IntrinsicGasNotMet,
                 "ERR_NOT_ALLOWED" => EngineErrorKind::NotAllowed,
+                "ERR_DEPLOY_NOT_ALLOWED" => EngineErrorKind::DeployNotAllowed,
+                "ERR_ZERO_GAS_PRICE" => EngineErrorKind::ZeroGasPrice,
+                "ERR_EMPTY_INIT_CODE" => EngineErrorKind::EmptyInitCode,
+                "ERR_NONCE_GAP_TOO_LARGE" => EngineErrorKind::NonceGapTooLarge,
+                "ERR_TX_EXPIRED" => EngineErrorKind::TxExpired,
                 "ERR_SAME_OWNER" => EngineErrorKind::SameOwner,
                 "ERR_FIXED_GAS_OVERFLOW" => EngineErrorKind::FixedGasOverflow,
                 "ERR_PAUSED" => EngineErrorKind::EvmFatal(ExitFatal::Other("ERR_PAUSED".into())),
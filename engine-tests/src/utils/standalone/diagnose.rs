@@ -0,0 +1,96 @@
+//! Replays a single signed transaction against the latest state of the standalone storage and
+//! collects everything useful for debugging it in one place.
+
+use aurora_engine_modexp::ModExpAlgorithm;
+use aurora_engine_transactions::EthTransactionKind;
+use aurora_engine_types::parameters::engine::SubmitResult;
+use aurora_engine_types::H256;
+use engine_standalone_storage::sync::{
+    self,
+    types::{TransactionKind, TransactionMessage},
+    TransactionExecutionResult,
+};
+use engine_standalone_storage::{Diff, Storage};
+use engine_standalone_tracing::sputnik;
+use engine_standalone_tracing::types::call_tracer::{CallFrame, CallTracer};
+
+/// Result of replaying a transaction with `diagnose_transaction`.
+#[derive(Debug)]
+pub struct TransactionDiagnostics {
+    /// Hash of the replayed transaction.
+    pub tx_hash: H256,
+    /// Status, gas used and logs of the execution, or the error which prevented it.
+    pub result: Result<SubmitResult, sync::error::Error>,
+    /// Changes of the engine state made by the transaction. They are never written to the storage.
+    pub diff: Diff,
+    /// Top level call frame of the execution, only present if the trace was requested.
+    pub trace: Option<CallFrame>,
+}
+
+#[derive(Debug)]
+pub enum DiagnoseError {
+    /// The raw bytes are not a valid signed Ethereum transaction.
+    InvalidTransaction(aurora_engine_transactions::Error),
+    Storage(engine_standalone_storage::Error),
+    /// The execution of the transaction did not produce the result of a `submit`.
+    UnexpectedOutcome,
+}
+
+impl From<engine_standalone_storage::Error> for DiagnoseError {
+    fn from(e: engine_standalone_storage::Error) -> Self {
+        Self::Storage(e)
+    }
+}
+
+/// Executes the signed transaction on top of the latest block in the storage without persisting
+/// anything. The transaction is submitted by the engine account itself. Collecting the trace
+/// requires running the EVM with a listener, so it is only done if `with_trace` is set.
+pub fn diagnose_transaction<M: ModExpAlgorithm + 'static>(
+    storage: &Storage,
+    raw_tx: &[u8],
+    with_trace: bool,
+) -> Result<TransactionDiagnostics, DiagnoseError> {
+    let eth_tx = EthTransactionKind::try_from(raw_tx).map_err(DiagnoseError::InvalidTransaction)?;
+    let (block_hash, _) = storage.get_latest_block()?;
+    let engine_account_id = storage.get_engine_account_id()?;
+    let transaction_message = TransactionMessage {
+        block_hash,
+        near_receipt_id: H256::zero(),
+        // Executing after all the transactions of the block gives the latest state.
+        position: u16::MAX,
+        succeeded: true,
+        signer: engine_account_id.clone(),
+        caller: engine_account_id,
+        attached_near: 0,
+        transaction: TransactionKind::Submit(eth_tx),
+        promise_data: Vec::new(),
+        raw_input: raw_tx.to_vec(),
+        action_hash: H256::zero(),
+    };
+    let execute =
+        || sync::execute_transaction_message::<M>(storage, transaction_message, None, None);
+
+    let (outcome, trace) = if with_trace {
+        let mut listener = CallTracer::default();
+        let outcome = sputnik::traced_call(&mut listener, execute)?;
+        (outcome, listener.call_stack.pop())
+    } else {
+        (execute()?, None)
+    };
+
+    let result = match outcome.maybe_result {
+        Ok(Some(TransactionExecutionResult::Submit(result))) => result.map_err(Into::into),
+        Ok(Some(TransactionExecutionResult::SubmitError(e))) => {
+            Err(sync::error::Error::ContractError(e.message.into()))
+        }
+        Ok(_) => return Err(DiagnoseError::UnexpectedOutcome),
+        Err(e) => Err(e),
+    };
+
+    Ok(TransactionDiagnostics {
+        tx_hash: outcome.hash,
+        result,
+        diff: outcome.diff,
+        trace,
+    })
+}
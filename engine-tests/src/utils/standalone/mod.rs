@@ -17,6 +17,7 @@ use tempfile::TempDir;
 
 use crate::utils;
 
+pub mod diagnose;
 pub mod mocks;
 pub mod storage;
 
@@ -178,7 +179,8 @@ impl StandaloneRunner {
         tx_msg.position = transaction_position;
         tx_msg.transaction =
             TransactionKind::Submit(transaction_bytes.as_slice().try_into().unwrap());
-        let outcome = sync::execute_transaction_message::<AuroraModExp>(storage, tx_msg).unwrap();
+        let outcome =
+            sync::execute_transaction_message::<AuroraModExp>(storage, tx_msg, None, None).unwrap();
 
         match outcome.maybe_result.as_ref().unwrap().as_ref().unwrap() {
             sync::TransactionExecutionResult::Submit(result) => {
@@ -223,10 +225,10 @@ impl StandaloneRunner {
             sync::parse_transaction_kind(method_name, ctx.input.clone(), &promise_data)
                 .expect("All method names must be known by standalone");
 
-        let transaction_hash = if let TransactionKind::SubmitWithArgs(args) = &transaction_kind {
-            aurora_engine_sdk::keccak(&args.tx_data)
-        } else {
-            aurora_engine_sdk::keccak(&ctx.input)
+        let transaction_hash = match &transaction_kind {
+            TransactionKind::SubmitWithArgs(args) => aurora_engine_sdk::keccak(&args.tx_data),
+            TransactionKind::SubmitWithArgsV2(args) => aurora_engine_sdk::keccak(&args.tx_data),
+            _ => aurora_engine_sdk::keccak(&ctx.input),
         };
 
         let storage = &mut self.storage;
@@ -253,7 +255,8 @@ impl StandaloneRunner {
             );
         }
 
-        let outcome = sync::execute_transaction_message::<AuroraModExp>(storage, tx_msg).unwrap();
+        let outcome =
+            sync::execute_transaction_message::<AuroraModExp>(storage, tx_msg, None, None).unwrap();
         self.cumulative_diff.append(outcome.diff.clone());
         storage::commit(storage, &outcome);
 
@@ -369,7 +372,8 @@ impl StandaloneRunner {
         );
         tx_msg.transaction = TransactionKind::Submit(transaction_bytes.try_into().unwrap());
 
-        let outcome = sync::execute_transaction_message::<AuroraModExp>(storage, tx_msg).unwrap();
+        let outcome =
+            sync::execute_transaction_message::<AuroraModExp>(storage, tx_msg, None, None).unwrap();
         cumulative_diff.append(outcome.diff.clone());
         storage::commit(storage, &outcome);
 
@@ -384,6 +388,8 @@ fn unwrap_result(
         sync::TransactionExecutionResult::Submit(result) => result.map_err(Into::into),
         sync::TransactionExecutionResult::Promise(_) => panic!("Unexpected promise."),
         sync::TransactionExecutionResult::DeployErc20(_) => panic!("Unexpected DeployErc20."),
+        sync::TransactionExecutionResult::SubmitBatch(_) => panic!("Unexpected SubmitBatch."),
+        sync::TransactionExecutionResult::SubmitError(_) => panic!("Unexpected SubmitError."),
     }
 }
 
@@ -9,7 +9,7 @@
 #![forbid(unsafe_code)]
 
 use aurora_engine_types::types::{Address, Wei};
-use aurora_engine_types::{vec, Vec, H160, U256};
+use aurora_engine_types::{vec, Vec, H160, H256, U256};
 use eip_2930::AccessTuple;
 use rlp::{Decodable, DecoderError, Rlp};
 
@@ -44,7 +44,7 @@ impl TryFrom<&[u8]> for EthTransactionKind {
         } else if bytes[0] == eip_4844::TYPE_BYTE {
             Err(Error::UnsupportedTransactionEip4844)
         } else if bytes[0] <= 0x7f {
-            Err(Error::UnknownTransactionType)
+            Err(Error::UnknownTransactionType(bytes[0]))
         } else if bytes[0] == 0xff {
             Err(Error::ReservedSentinel)
         } else {
@@ -54,6 +54,11 @@ impl TryFrom<&[u8]> for EthTransactionKind {
     }
 }
 
+/// Handler used to decode transaction types which are not natively supported.
+/// It receives the EIP-2718 type byte and the remaining payload of the transaction.
+pub type PassthroughHandler = fn(u8, &[u8]) -> Result<EthTransactionKind, Error>;
+
+/// Canonical encoding of the transaction as defined by EIP-2718.
 impl From<&EthTransactionKind> for Vec<u8> {
     fn from(tx: &EthTransactionKind) -> Self {
         let mut stream = rlp::RlpStream::new();
@@ -74,6 +79,30 @@ impl From<&EthTransactionKind> for Vec<u8> {
     }
 }
 
+impl EthTransactionKind {
+    /// Same as `TryFrom<&[u8]>`, but transactions of an unknown type are decoded (on a
+    /// best-effort basis) by the given `passthrough` handler instead of being rejected.
+    /// Strict rejection via `TryFrom<&[u8]>` remains the default behaviour.
+    pub fn try_from_with_passthrough(
+        bytes: &[u8],
+        passthrough: PassthroughHandler,
+    ) -> Result<Self, Error> {
+        match Self::try_from(bytes) {
+            Err(Error::UnknownTransactionType(type_byte)) => passthrough(type_byte, &bytes[1..]),
+            result => result,
+        }
+    }
+
+    /// Returns the transaction hash, i.e. the keccak256 of the canonical encoding of the
+    /// transaction. For typed transactions this is the whole envelope (type byte followed
+    /// by the RLP payload), while for legacy transactions it is the plain RLP encoding.
+    /// This matches the hash displayed by wallets and block explorers.
+    #[must_use]
+    pub fn hash(&self) -> H256 {
+        aurora_engine_sdk::keccak(&Vec::<u8>::from(self))
+    }
+}
+
 /// A normalized Ethereum transaction which can be created from older
 /// transactions.
 pub struct NormalizedEthTransaction {
@@ -205,7 +234,8 @@ fn init_code_cost(config: &evm::Config, data: &[u8]) -> Result<u64, Error> {
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Error {
-    UnknownTransactionType,
+    /// Contains the unrecognized EIP-2718 type byte.
+    UnknownTransactionType(u8),
     EmptyInput,
     // Per the EIP-2718 spec 0xff is a reserved value
     ReservedSentinel,
@@ -227,7 +257,12 @@ impl Error {
     #[must_use]
     pub const fn as_str(&self) -> &str {
         match self {
-            Self::UnknownTransactionType => "ERR_UNKNOWN_TX_TYPE",
+            Self::UnknownTransactionType(type_byte) => {
+                match core::str::from_utf8(&UNKNOWN_TX_TYPE_MESSAGES[*type_byte as usize]) {
+                    Ok(message) => message,
+                    Err(_) => "ERR_UNKNOWN_TX_TYPE",
+                }
+            }
             Self::EmptyInput => "ERR_EMPTY_TX_INPUT",
             Self::ReservedSentinel => "ERR_RESERVED_LEADING_TX_BYTE",
             Self::InvalidV => "ERR_INVALID_V",
@@ -240,6 +275,32 @@ impl Error {
     }
 }
 
+const UNKNOWN_TX_TYPE_PREFIX: &[u8] = b"ERR_UNKNOWN_TX_TYPE_0x";
+const UNKNOWN_TX_TYPE_MESSAGE_LEN: usize = UNKNOWN_TX_TYPE_PREFIX.len() + 2;
+
+/// Messages of the `UnknownTransactionType` errors, e.g. `ERR_UNKNOWN_TX_TYPE_0x05`, indexed by
+/// the type byte. They are built at compile time so the errors stay allocation-free.
+const UNKNOWN_TX_TYPE_MESSAGES: [[u8; UNKNOWN_TX_TYPE_MESSAGE_LEN]; 256] =
+    unknown_tx_type_messages();
+
+const fn unknown_tx_type_messages() -> [[u8; UNKNOWN_TX_TYPE_MESSAGE_LEN]; 256] {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let mut messages = [[0; UNKNOWN_TX_TYPE_MESSAGE_LEN]; 256];
+    let mut type_byte = 0;
+    while type_byte < 256 {
+        let mut i = 0;
+        while i < UNKNOWN_TX_TYPE_PREFIX.len() {
+            messages[type_byte][i] = UNKNOWN_TX_TYPE_PREFIX[i];
+            i += 1;
+        }
+        messages[type_byte][i] = HEX_DIGITS[type_byte >> 4];
+        messages[type_byte][i + 1] = HEX_DIGITS[type_byte & 0x0f];
+        type_byte += 1;
+    }
+    messages
+}
+
 impl From<DecoderError> for Error {
     fn from(e: DecoderError) -> Self {
         Self::RlpDecodeError(e)
@@ -279,6 +340,11 @@ fn vrs_to_arr(v: u8, r: U256, s: U256) -> [u8; 65] {
 mod tests {
     use super::{Error, EthTransactionKind};
     use crate::{eip_1559, eip_2930};
+    use aurora_engine_types::{Vec, H256};
+
+    const LEGACY_TX: &str = "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83";
+    const EIP_2930_TX: &str = "01f8cd01078506fc23ac0082c35094353535353535353535353535353535353535353587038d7ea4c6800084deadbeeff85bf859940000000000000000000000000000000000000001f842a00000000000000000000000000000000000000000000000000000000000000000a0000000000000000000000000000000000000000000000000000000000000000101a023dc8c9a4452589f34679531ff9bde2ada111d0aee11ffd99eb850f5ca6f024da046e09c64bf35ab13a2b017afa02902057f12ad12dca33ef003bb199eaf33b09c";
+    const EIP_1559_TX: &str = "02f8730108847735940085174876e800825208943535353535353535353535353535353535353535880de0b6b3a764000080c001a0a51c3ccfed8d6c00e58fe0924eba77d23a8c93c42530ac855d9e8b0e06c2762ea06e0c008faacad00214ab26a0b1741f41a26a6795a59d530089c51f0c240154ae";
 
     #[test]
     fn test_try_parse_empty_input() {
@@ -302,4 +368,66 @@ mod tests {
             Err(Error::RlpDecodeError(_))
         ));
     }
+
+    #[test]
+    fn test_unknown_transaction_type() {
+        fn passthrough(type_byte: u8, payload: &[u8]) -> Result<EthTransactionKind, Error> {
+            match type_byte {
+                0x05 => Ok(EthTransactionKind::Eip1559(rlp::decode(payload)?)),
+                _ => Err(Error::UnknownTransactionType(type_byte)),
+            }
+        }
+
+        let bytes = hex::decode(EIP_1559_TX).unwrap();
+        let mut unknown = bytes.clone();
+        unknown[0] = 0x05;
+
+        // Strict mode by default, the error carries the type byte.
+        let error = EthTransactionKind::try_from(unknown.as_slice()).unwrap_err();
+        assert_eq!(error, Error::UnknownTransactionType(0x05));
+        assert_eq!(error.as_str(), "ERR_UNKNOWN_TX_TYPE_0x05");
+        assert_eq!(
+            Error::UnknownTransactionType(0x7f).as_ref(),
+            b"ERR_UNKNOWN_TX_TYPE_0x7f"
+        );
+
+        // The passthrough handler decodes unknown types.
+        assert_eq!(
+            EthTransactionKind::try_from_with_passthrough(&unknown, passthrough),
+            EthTransactionKind::try_from(bytes.as_slice())
+        );
+        unknown[0] = 0x06;
+        assert_eq!(
+            EthTransactionKind::try_from_with_passthrough(&unknown, passthrough),
+            Err(Error::UnknownTransactionType(0x06))
+        );
+    }
+
+    #[test]
+    fn test_transaction_hash() {
+        // Expected hashes are the ones reported by ethers.js (`Transaction.from(raw).hash`).
+        for (raw, expected_hash) in [
+            (
+                LEGACY_TX,
+                "33469b22e9f636356c4160a87eb19df52b7412e8eac32a4a55ffe88ea8350788",
+            ),
+            (
+                EIP_2930_TX,
+                "fbc8f917774a393b24c135ddf329ed3b84534a726812835d45b4d364ab51632c",
+            ),
+            (
+                EIP_1559_TX,
+                "3a3bf0e334338cd8ba61669bed55d21a1cb4978708c8499ed55538e08b52728e",
+            ),
+        ] {
+            let bytes = hex::decode(raw).unwrap();
+            let tx = EthTransactionKind::try_from(bytes.as_slice()).unwrap();
+            // The canonical encoding round-trips to the original bytes.
+            assert_eq!(Vec::<u8>::from(&tx), bytes);
+            assert_eq!(
+                tx.hash(),
+                H256::from_slice(&hex::decode(expected_hash).unwrap())
+            );
+        }
+    }
 }
@@ -307,6 +307,64 @@ impl Default for Erc20Metadata {
     }
 }
 
+/// Fee charged by the exit precompiles on the exits of a token.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize, Serialize, Deserialize,
+)]
+pub enum ExitFee {
+    /// Fixed amount of the token charged on every exit.
+    Flat(u128),
+    /// Share of the exited amount in basis points (hundredths of a percent).
+    Percentage(u16),
+}
+
+impl ExitFee {
+    /// Basis points of the whole amount.
+    pub const MAX_BASIS_POINTS: u16 = 10_000;
+
+    /// Returns the fee charged on the exit of the `amount`,
+    /// or `None` if the fee is greater than the amount.
+    #[must_use]
+    pub fn compute(&self, amount: u128) -> Option<u128> {
+        let fee = match *self {
+            Self::Flat(fee) => fee,
+            Self::Percentage(basis_points) => {
+                let basis_points = u128::from(basis_points);
+                let max = u128::from(Self::MAX_BASIS_POINTS);
+                // Split the amount to avoid the overflow of the multiplication.
+                (amount / max) * basis_points + (amount % max) * basis_points / max
+            }
+        };
+        (fee <= amount).then_some(fee)
+    }
+
+    /// Checks that the percentage doesn't exceed the whole amount.
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        match *self {
+            Self::Flat(_) => true,
+            Self::Percentage(basis_points) => basis_points <= Self::MAX_BASIS_POINTS,
+        }
+    }
+}
+
+/// Exit fee of a token together with the account credited with the fees.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct ExitFeeConfig {
+    pub fee: ExitFee,
+    /// NEAR account receiving the fees in the NEP-141 token.
+    pub treasury: AccountId,
+}
+
+/// Borsh-encoded parameters for the `set_exit_fee` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct SetExitFeeArgs {
+    /// Address of the ERC-20 token, or the zero address for ETH.
+    pub token: Address,
+    /// New fee of the token, `None` to stop charging the fee.
+    pub config: Option<ExitFeeConfig>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -333,4 +391,24 @@ mod tests {
 
         assert_eq!(expected_log_entry, actual_log_entry);
     }
+
+    #[test]
+    fn test_exit_fee_compute() {
+        assert_eq!(ExitFee::Flat(10).compute(100), Some(10));
+        assert_eq!(ExitFee::Flat(100).compute(100), Some(100));
+        assert_eq!(ExitFee::Flat(101).compute(100), None);
+
+        // 2.5%
+        let fee = ExitFee::Percentage(250);
+        assert_eq!(fee.compute(1_000), Some(25));
+        assert_eq!(fee.compute(39), Some(0));
+        assert_eq!(fee.compute(u128::MAX), Some(u128::MAX / 40));
+        assert_eq!(
+            ExitFee::Percentage(10_000).compute(u128::MAX),
+            Some(u128::MAX)
+        );
+
+        assert!(fee.is_valid());
+        assert!(!ExitFee::Percentage(10_001).is_valid());
+    }
 }
@@ -1,5 +1,6 @@
 use crate::{
     account_id::AccountId,
+    parameters::promise::PromiseCreateArgs,
     public_key::PublicKey,
     types::{Address, RawH256, RawU256, WeiU256, Yocto},
     Vec,
@@ -14,6 +15,7 @@ pub enum NewCallArgs {
     V2(NewCallArgsV2),
     V3(NewCallArgsV3),
     V4(NewCallArgsV4),
+    V5(NewCallArgsV5),
 }
 
 impl NewCallArgs {
@@ -33,10 +35,20 @@ impl NewCallArgs {
     pub const fn initial_hashchain(&self) -> Option<RawH256> {
         match self {
             Self::V4(args) => args.initial_hashchain,
+            Self::V5(args) => args.initial_hashchain,
             Self::V1(_) | Self::V2(_) | Self::V3(_) => None,
         }
     }
 
+    /// Returns the keccak256 hash of the deployed engine code if present.
+    #[must_use]
+    pub const fn code_hash(&self) -> Option<RawH256> {
+        match self {
+            Self::V5(args) => args.code_hash,
+            Self::V1(_) | Self::V2(_) | Self::V3(_) | Self::V4(_) => None,
+        }
+    }
+
     fn try_from_json(bytes: &[u8]) -> Result<Self, serde_json::Error> {
         serde_json::from_slice::<NewCallJsonArgs>(bytes).map(Into::into)
     }
@@ -113,6 +125,25 @@ pub struct NewCallArgsV4 {
     pub initial_hashchain: Option<RawH256>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct NewCallArgsV5 {
+    /// Chain id, according to the EIP-115 / ethereum-lists spec.
+    pub chain_id: RawU256,
+    /// Account which can upgrade this contract.
+    /// Use empty to disable updatability.
+    pub owner_id: AccountId,
+    /// How many blocks after staging upgrade can deploy it.
+    pub upgrade_delay_blocks: u64,
+    /// Relayer keys manager.
+    pub key_manager: AccountId,
+    /// Initial value of the hashchain.
+    /// If none is provided then the hashchain will start disabled.
+    pub initial_hashchain: Option<RawH256>,
+    /// Keccak256 hash of the deployed engine code, returned by `get_code_hash_self`.
+    /// It is provided by the deployer and isn't verified by the engine.
+    pub code_hash: Option<RawH256>,
+}
+
 /// Borsh-encoded parameters for the `set_owner` function.
 #[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 #[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
@@ -127,6 +158,369 @@ pub struct SetUpgradeDelayBlocksArgs {
     pub upgrade_delay_blocks: u64,
 }
 
+/// Borsh-encoded result of the `get_governance` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct GovernanceInfo {
+    /// Account which can upgrade and administer the contract.
+    pub owner: AccountId,
+    /// Account which manages the relayer keys, if any.
+    pub key_manager: Option<AccountId>,
+    /// How many blocks after staging upgrade can deploy it.
+    pub upgrade_delay_blocks: u64,
+}
+
+/// Borsh-encoded parameters for the `verify_signature` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct VerifySignatureArgs {
+    /// Hash of the signed message.
+    pub hash: RawH256,
+    /// 65 bytes `r || s || v`, where `v` is either 0/1 or 27/28.
+    pub signature: Vec<u8>,
+    /// Address expected to have produced the signature.
+    pub address: Address,
+}
+
+/// Borsh-encoded result of the `get_account` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct AccountInfo {
+    pub balance: WeiU256,
+    pub nonce: RawU256,
+    /// Hash of the code following EIP-1052: zero for the non-existent accounts.
+    pub code_hash: RawH256,
+    /// Whether the account exists in the state (see EIP-161).
+    pub exists: bool,
+}
+
+/// Borsh-encoded parameters for the `set_relaxed_nonce` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetRelaxedNonceArgs {
+    pub relayer: AccountId,
+    pub enabled: bool,
+}
+
+/// Borsh-encoded parameters for the `register_relayers_batch` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct RegisterRelayersBatchArgs {
+    /// Pairs of a relayer account and the EVM address receiving its fees.
+    pub relayers: Vec<(AccountId, Address)>,
+}
+
+/// Borsh-encoded parameters for the `add_deployer` and `remove_deployer` functions.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct DeployerArgs {
+    pub address: Address,
+}
+
+/// Borsh-encoded parameters for the `get_deployers` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct GetDeployersArgs {
+    /// Position of the first returned address in the allowlist.
+    pub from_index: u32,
+    /// Maximum number of the returned addresses.
+    pub limit: u32,
+}
+
+/// Borsh-encoded parameters for the `set_deployer_allowlist_status` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct DeployerAllowlistStatusArgs {
+    pub enabled: bool,
+}
+
+/// Borsh-encoded parameters for the `withdraw_collected_fees` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct WithdrawCollectedFeesArgs {
+    /// Address credited with the collected fees, it can't be the zero address. It must be the
+    /// zero address while a fee distribution is set, since the fees are split according to the
+    /// distribution instead.
+    pub treasury: Address,
+}
+
+/// Sum of the basis points of all shares in a fee distribution.
+pub const FEE_DISTRIBUTION_TOTAL_BASIS_POINTS: u16 = 10_000;
+
+/// Part of the collected fees credited to a single address.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct FeeShare {
+    pub address: Address,
+    /// Share of the fees in basis points (1/100 of a percent).
+    pub basis_points: u16,
+}
+
+/// Borsh-encoded parameters for the `set_fee_distribution` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetFeeDistributionArgs {
+    /// Shares summing to `FEE_DISTRIBUTION_TOTAL_BASIS_POINTS`, an empty list removes the distribution.
+    pub shares: Vec<FeeShare>,
+}
+
+impl SetFeeDistributionArgs {
+    /// Returns `true` if the list is empty or the shares sum to `FEE_DISTRIBUTION_TOTAL_BASIS_POINTS`.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        let total: u32 = self
+            .shares
+            .iter()
+            .map(|share| u32::from(share.basis_points))
+            .sum();
+        self.shares.is_empty() || total == u32::from(FEE_DISTRIBUTION_TOTAL_BASIS_POINTS)
+    }
+}
+
+/// Borsh-encoded parameters for the `set_max_logs_per_tx` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetMaxLogsPerTxArgs {
+    /// Maximum number of logs a transaction may emit, `None` means unlimited. A transaction
+    /// emitting more logs fails with the `ERR_TOO_MANY_LOGS` status, its state changes are
+    /// discarded, but it's charged for the gas and bumps the nonce.
+    pub max_logs: Option<u64>,
+}
+
+/// Borsh-encoded parameters for the `set_max_refund_quotient` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetMaxRefundQuotientArgs {
+    /// Quotient of the refund cap, `None` restores the quotient of EIP-3529 and zero disables
+    /// the refunds.
+    pub quotient: Option<u64>,
+}
+
+/// Borsh-encoded parameters for the `set_max_nonce_gap` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetMaxNonceGapArgs {
+    /// Maximum gap above the account nonce allowed in the relaxed nonce mode,
+    /// `None` means unlimited.
+    pub max_gap: Option<u64>,
+}
+
+/// Borsh-encoded parameters for the `set_evm_memory_limit` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetEvmMemoryLimitArgs {
+    /// Maximum size of the EVM memory in bytes, `None` means unlimited.
+    pub memory_limit: Option<u64>,
+}
+
+/// Borsh-encoded parameters for the `set_view_step_limit` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetViewStepLimitArgs {
+    /// Maximum number of EVM opcodes a view call may execute, `None` means unlimited.
+    pub step_limit: Option<u64>,
+}
+
+/// Borsh-encoded parameters for the `set_random_seed_override` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetRandomSeedOverrideArgs {
+    /// Seed returned by the random seed precompile, `None` restores the NEAR block seed.
+    pub random_seed: Option<RawH256>,
+}
+
+/// Borsh-encoded parameters for the `set_ft_on_transfer_gas_limit` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetFtOnTransferGasLimitArgs {
+    /// EVM gas limit of the call minting the ERC-20 tokens, `None` restores the default.
+    /// The default leaves the call bounded by the NEAR gas only, so the limit can only lower
+    /// the gas available to the call. It can't be lower than the cost of a plain transfer.
+    pub gas_limit: Option<u64>,
+}
+
+/// Borsh-encoded parameters for the `set_erc20_deploy_gas_limit` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetErc20DeployGasLimitArgs {
+    /// EVM gas limit of the ERC-20 deployments, `None` restores the default. The default
+    /// leaves the deployments bounded by the NEAR gas only. It can't be lower than the cost
+    /// of a contract creation.
+    pub gas_limit: Option<u64>,
+}
+
+/// Borsh-encoded parameters for the `set_unregister_fee` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetUnregisterFeeArgs {
+    /// Fee withheld from the storage deposit refunded by `storage_unregister`, `None` restores
+    /// the full refund.
+    pub fee: Option<Yocto>,
+}
+
+/// Borsh-encoded parameters for the `set_erc20_template` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetErc20TemplateArgs {
+    /// Bytecode used to deploy the bridged ERC-20 tokens, `None` means the built-in one.
+    pub code: Option<Vec<u8>>,
+}
+
+/// Borsh-encoded parameters for the `set_tip_recipient` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetTipRecipientArgs {
+    /// Address which receives the priority fees, `None` means the relayer receives them
+    /// and the zero address means they are burned.
+    pub tip_recipient: Option<Address>,
+}
+
+/// Borsh-encoded parameters for the `set_paymaster` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetPaymasterArgs {
+    /// Address of the contract which may sponsor the gas of the transactions,
+    /// `None` means the senders always pay for themselves.
+    pub paymaster: Option<Address>,
+}
+
+/// Verbosity of the engine logs. The levels are ordered, so enabling a level also enables
+/// all the levels below it.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, BorshSerialize, BorshDeserialize,
+)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+#[borsh(use_discriminant = true)]
+#[repr(u8)]
+pub enum LogLevel {
+    #[default]
+    Error = 0,
+    Info = 1,
+    Debug = 2,
+}
+
+/// Borsh-encoded parameters for the `set_log_level` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetLogLevelArgs {
+    pub log_level: LogLevel,
+}
+
+/// Implementation of the modexp precompile. The built-in one is used by default.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+#[borsh(use_discriminant = true)]
+#[repr(u8)]
+pub enum ModExpAlgorithmKind {
+    /// `aurora_engine_modexp::modexp`.
+    #[default]
+    Aurora = 0,
+    /// `aurora_engine_modexp::modexp_num`, based on the `num` crate.
+    Num = 1,
+}
+
+/// Borsh-encoded parameters for the `set_modexp_algorithm` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SetModExpAlgorithmArgs {
+    pub algorithm: ModExpAlgorithmKind,
+}
+
+/// Human-readable description of the engine instance, set by the `set_engine_metadata` function.
+/// It's purely informational and intended for explorers and dashboards.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct EngineMetadata {
+    pub name: String,
+    pub description: String,
+    pub operator_url: String,
+}
+
+impl EngineMetadata {
+    pub const MAX_NAME_LEN: usize = 64;
+    pub const MAX_DESCRIPTION_LEN: usize = 256;
+    pub const MAX_OPERATOR_URL_LEN: usize = 256;
+
+    /// Checks that the lengths (in bytes) of the fields don't exceed the limits.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.name.len() <= Self::MAX_NAME_LEN
+            && self.description.len() <= Self::MAX_DESCRIPTION_LEN
+            && self.operator_url.len() <= Self::MAX_OPERATOR_URL_LEN
+    }
+}
+
+/// Capabilities of the engine instance, returned by the `get_features` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct EngineFeatures {
+    /// The eth-connector is a separate contract rather than a part of the engine
+    /// (the engine is built with the `ext-connector` feature).
+    pub ext_connector: bool,
+    /// The silo mode is on, i.e. the fixed gas per transaction is set.
+    pub silo_enabled: bool,
+    /// Name of the Ethereum hard fork the EVM follows, e.g. `cancun`.
+    pub fork: String,
+    /// The engine is built with the support of the EVM tracing (the `tracing` feature).
+    pub tracing: bool,
+}
+
+/// Borsh-encoded result of the `get_evm_config` function: the parameters of the EVM
+/// configuration in effect, including the values overridden by the owner.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct EvmConfig {
+    /// Name of the Ethereum hard fork the EVM follows, e.g. `cancun`.
+    pub fork: String,
+    /// Base gas cost of a transaction creating a contract.
+    pub gas_transaction_create: u64,
+    /// Base gas cost of a transaction calling an account.
+    pub gas_transaction_call: u64,
+    /// Gas cost of a zero byte of the transaction data.
+    pub gas_transaction_zero_data: u64,
+    /// Gas cost of a non-zero byte of the transaction data.
+    pub gas_transaction_non_zero_data: u64,
+    /// Base gas cost of the `CALL` opcodes.
+    pub gas_call: u64,
+    /// Gas given to the callee of a call transferring a value.
+    pub call_stipend: u64,
+    /// Maximum size of the code of a contract, `None` if unlimited.
+    pub create_contract_limit: Option<u64>,
+    /// Maximum size of the init code of a contract creation, `None` if unlimited.
+    pub max_initcode_size: Option<u64>,
+    /// Maximum depth of the EVM stack.
+    pub stack_limit: u64,
+    /// Maximum depth of the call stack.
+    pub call_stack_limit: u64,
+    /// Maximum size of the EVM memory in bytes, `None` if unlimited. Set by `set_evm_memory_limit`.
+    pub memory_limit: Option<u64>,
+    /// Quotient of the refund cap. Set by `set_max_refund_quotient`.
+    pub max_refund_quotient: u64,
+    /// Whether the gas refunds are applied. They can be disabled in the silo mode.
+    pub gas_refunds_enabled: bool,
+    /// Names of the opcodes introduced by the hard forks which are enabled, e.g. `PUSH0`.
+    pub enabled_opcodes: Vec<String>,
+}
+
+/// Borsh-encoded parameters for the `stage_upgrade_chunk` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct StageUpgradeChunkArgs {
+    /// Position of the chunk in the upload. Index `0` starts a new upload.
+    pub index: u32,
+    pub bytes: Vec<u8>,
+}
+
+/// Borsh-encoded parameters for the `finalize_upgrade_stage` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct FinalizeUpgradeStageArgs {
+    /// Expected length of the assembled code.
+    pub total_len: u64,
+    /// Expected keccak256 hash of the assembled code.
+    pub hash: RawH256,
+}
+
 /// Borsh-encoded submit arguments used by the `submit_with_args` function.
 #[derive(Default, Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct SubmitArgs {
@@ -138,6 +532,114 @@ pub struct SubmitArgs {
     pub gas_token_address: Option<Address>,
 }
 
+/// Borsh-encoded submit arguments used by the `submit_with_args` function, with the deadline
+/// of the transaction.
+#[derive(Default, Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct SubmitArgsV2 {
+    /// Bytes of the transaction.
+    pub tx_data: Vec<u8>,
+    /// Max gas price the user is ready to pay for the transaction.
+    pub max_gas_price: Option<u128>,
+    /// Address of the `ERC20` token the user prefers to pay in.
+    pub gas_token_address: Option<Address>,
+    /// Height of the last block the transaction can be executed in. The transaction is
+    /// rejected with `ERR_TX_EXPIRED` in the later blocks. It never expires if not set.
+    pub valid_until_block: Option<u64>,
+}
+
+impl SubmitArgsV2 {
+    /// Deserializes the input of the `submit_with_args` function, which is either
+    /// `SubmitArgsV2` or the legacy `SubmitArgs` without the deadline.
+    #[must_use]
+    pub fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Self::try_from_slice(bytes).map_or_else(
+            |_| SubmitArgs::try_from_slice(bytes).map_or(None, |value| Some(value.into())),
+            Some,
+        )
+    }
+}
+
+impl From<SubmitArgs> for SubmitArgsV2 {
+    fn from(args: SubmitArgs) -> Self {
+        Self {
+            tx_data: args.tx_data,
+            max_gas_price: args.max_gas_price,
+            gas_token_address: args.gas_token_address,
+            valid_until_block: None,
+        }
+    }
+}
+
+impl From<SubmitArgsV2> for SubmitArgs {
+    fn from(args: SubmitArgsV2) -> Self {
+        Self {
+            tx_data: args.tx_data,
+            max_gas_price: args.max_gas_price,
+            gas_token_address: args.gas_token_address,
+        }
+    }
+}
+
+/// Borsh-encoded error of a failed `submit` call. The engines built with the `verbose-errors`
+/// feature don't panic when a transaction fails, `submit` returns the borsh-encoded
+/// `Result<SubmitResult, SubmitError>` instead. The state changes of a transaction which
+/// wasn't executed are discarded, as if the call had panicked.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SubmitError {
+    /// Status of the transaction, `None` if it wasn't executed (e.g. because of an incorrect nonce).
+    pub status: Option<TransactionStatus>,
+    /// Error message, e.g. `ERR_INCORRECT_NONCE: ac: 1, tx: 0`.
+    pub message: String,
+    /// Gas used by the transaction, zero if it wasn't executed.
+    pub gas_used: u64,
+    /// Reason of the revert decoded from the `Error(string)` revert data, if any.
+    pub revert_reason: Option<String>,
+}
+
+/// Borsh-encoded parameters for the `submit_batch` function.
+#[derive(Default, Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SubmitBatchArgs {
+    /// Bytes of the transactions, executed in order.
+    pub transactions: Vec<Vec<u8>>,
+    /// Whether the whole batch is reverted if one of the transactions doesn't succeed.
+    pub atomic: bool,
+}
+
+/// Result of a transaction of the batch executed by the `submit_batch` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub enum SubmitBatchTxResult {
+    /// The transaction was executed, its status tells whether it succeeded.
+    Executed(SubmitResult),
+    /// The transaction was rejected before its execution (e.g. because it couldn't be parsed
+    /// or its nonce is invalid) with the given error message, it didn't change the state.
+    Rejected(String),
+}
+
+/// Borsh-encoded result of the `submit_batch` function with a result for every transaction
+/// in the order of the batch.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SubmitBatchResult {
+    version: u8,
+    pub results: Vec<SubmitBatchTxResult>,
+}
+
+impl SubmitBatchResult {
+    /// Must be incremented when making breaking changes to the `SubmitBatchResult` ABI.
+    const VERSION: u8 = 1;
+
+    #[must_use]
+    pub const fn new(results: Vec<SubmitBatchTxResult>) -> Self {
+        Self {
+            version: Self::VERSION,
+            results,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 #[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
 pub struct StartHashchainArgs {
@@ -241,6 +743,22 @@ impl TransactionStatus {
     pub const fn is_fail(&self) -> bool {
         !matches!(*self, Self::Succeed(_) | Self::Revert(_))
     }
+
+    /// Same as `is_ok`: returns `true` only if the transaction succeeded.
+    #[must_use]
+    pub const fn is_success(&self) -> bool {
+        self.is_ok()
+    }
+
+    /// Returns the data returned by the EVM: the output of a succeeded transaction or
+    /// the revert data. The other statuses have no data, so an empty slice is returned.
+    #[must_use]
+    pub fn output(&self) -> &[u8] {
+        match self {
+            Self::Succeed(data) | Self::Revert(data) => data,
+            _ => &[],
+        }
+    }
 }
 
 impl AsRef<[u8]> for TransactionStatus {
@@ -272,22 +790,52 @@ impl AsRef<[u8]> for TransactionStatus {
 
 /// Borsh-encoded parameters for the `call`, `call_with_args`, `deploy_code`,
 /// and `deploy_with_input` methods.
-#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+///
+/// The layout of the encoding is given by the leading version byte. The results of the
+/// version 7 only have the status, the used gas and the logs, the other fields have
+/// their default values when such a result is decoded.
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
 pub struct SubmitResult {
     version: u8,
     pub status: TransactionStatus,
     pub gas_used: u64,
     pub logs: Vec<ResultLog>,
+    /// Promises created by the transaction (e.g. by the XCC or exit precompiles) in the order
+    /// of creation. Relayers can use them to correlate the follow-up receipts with the
+    /// transaction which scheduled them. Only recorded if the diagnostics are enabled.
+    #[cfg_attr(
+        feature = "impl-serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub promises: Vec<PromiseCreateArgs>,
+    /// Part of `gas_used` charged before the execution: the base cost of the transaction,
+    /// its calldata, the contract creation and the access list. Only recorded if the
+    /// diagnostics are enabled.
+    #[cfg_attr(
+        feature = "impl-serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub intrinsic_gas: Option<u64>,
+    /// Price per unit of gas actually charged for the transaction. It can be lower than
+    /// the price specified in the transaction (e.g. for EIP-1559 transactions or if capped
+    /// by the relayer). In silo mode the fee is this price multiplied by the fixed gas.
+    /// Zero if the transaction was free.
+    #[cfg_attr(feature = "impl-serde", serde(default))]
+    pub effective_gas_price: u128,
 }
 
 impl SubmitResult {
     /// Must be incremented when making breaking changes to the `SubmitResult` ABI.
-    /// The current value of 7 is chosen because previously a `TransactionStatus` object
+    /// The value of 7 was chosen because previously a `TransactionStatus` object
     /// was first in the serialization, which is an enum with less than 7 variants.
     /// Therefore, no previous `SubmitResult` would have begun with a leading 7 byte,
     /// and this can be used to distinguish the new ABI (with version byte) from the old.
-    const VERSION: u8 = 7;
+    /// The version 8 added the promises, the intrinsic gas and the effective gas price.
+    const VERSION: u8 = 8;
+    /// Version of the results without the promises, the intrinsic gas and the effective
+    /// gas price.
+    const LEGACY_VERSION: u8 = 7;
 
     #[must_use]
     pub const fn new(status: TransactionStatus, gas_used: u64, logs: Vec<ResultLog>) -> Self {
@@ -296,10 +844,86 @@ impl SubmitResult {
             status,
             gas_used,
             logs,
+            promises: Vec::new(),
+            intrinsic_gas: None,
+            effective_gas_price: 0,
+        }
+    }
+}
+
+impl BorshSerialize for SubmitResult {
+    fn serialize<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        self.version.serialize(writer)?;
+        self.status.serialize(writer)?;
+        self.gas_used.serialize(writer)?;
+        self.logs.serialize(writer)?;
+        if self.version != Self::LEGACY_VERSION {
+            self.promises.serialize(writer)?;
+            self.intrinsic_gas.serialize(writer)?;
+            self.effective_gas_price.serialize(writer)?;
         }
+        Ok(())
     }
 }
 
+impl BorshDeserialize for SubmitResult {
+    fn deserialize_reader<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let version = u8::deserialize_reader(reader)?;
+        let status = TransactionStatus::deserialize_reader(reader)?;
+        let gas_used = u64::deserialize_reader(reader)?;
+        let logs = Vec::<ResultLog>::deserialize_reader(reader)?;
+        let mut result = Self {
+            version,
+            status,
+            gas_used,
+            logs,
+            promises: Vec::new(),
+            intrinsic_gas: None,
+            effective_gas_price: 0,
+        };
+
+        match version {
+            Self::LEGACY_VERSION => {}
+            Self::VERSION => {
+                result.promises = BorshDeserialize::deserialize_reader(reader)?;
+                result.intrinsic_gas = BorshDeserialize::deserialize_reader(reader)?;
+                result.effective_gas_price = BorshDeserialize::deserialize_reader(reader)?;
+            }
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "unknown SubmitResult version",
+                ))
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Receipt of a transaction executed by `submit`. The receipts are only persisted while
+/// enabled by the owner and are returned by the `get_receipt` view keyed by the transaction hash.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct TransactionReceipt {
+    pub status: TransactionStatus,
+    pub gas_used: u64,
+    pub logs: Vec<ResultLog>,
+    /// Address of the created contract if the transaction is a deployment.
+    pub contract_address: Option<Address>,
+    /// Height of the NEAR block in which the transaction was executed.
+    pub block_height: u64,
+}
+
+/// Block of the first transaction executed by `submit`, returned by the `get_genesis_info` view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct GenesisInfo {
+    pub block_height: u64,
+    /// Timestamp of the block in nanoseconds.
+    pub block_timestamp: u64,
+}
+
 /// Borsh-encoded parameters for the engine `call` function.
 #[derive(BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq, Clone)]
 pub struct FunctionCallArgsV2 {
@@ -346,12 +970,124 @@ pub struct ViewCallArgs {
     pub input: Vec<u8>,
 }
 
+/// Borsh-encoded parameters for the `simulate_deploy` function.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Eq, PartialEq)]
+pub struct SimulateDeployArgs {
+    pub sender: Address,
+    pub code: Vec<u8>,
+}
+
+/// Borsh-encoded result of the `simulate_deploy` function.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct SimulateDeployResult {
+    /// Address of the contract, computed from the current nonce of the sender.
+    pub address: Address,
+    pub gas_used: u64,
+    pub status: TransactionStatus,
+}
+
+/// Borsh-encoded element of the result of the `get_precompile_info` function.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "impl-serde", derive(Serialize, Deserialize))]
+pub struct PrecompileInfo {
+    pub address: Address,
+    /// Name of the hard fork which introduced the precompile, e.g. `berlin`.
+    pub fork: String,
+}
+
 /// Borsh-encoded parameters for `deploy_erc20_token` function.
 #[derive(BorshSerialize, BorshDeserialize, Debug, Eq, PartialEq, Clone)]
 pub struct DeployErc20TokenArgs {
     pub nep141: AccountId,
 }
 
+/// Borsh-encoded parameters for `deploy_erc20_token` function with an opt-in flag
+/// which makes the call idempotent.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Eq, PartialEq, Clone)]
+pub struct DeployErc20TokenArgsV2 {
+    pub nep141: AccountId,
+    /// If `true` and the NEP-141 token is already registered, the address of the existing
+    /// ERC-20 token is returned instead of failing.
+    pub return_existing: bool,
+}
+
+/// Borsh-encoded parameters for `deploy_erc20_token` function with the opt-in flags
+/// which make the call idempotent and the address of the ERC-20 token deterministic.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Eq, PartialEq, Clone)]
+pub struct DeployErc20TokenArgsV3 {
+    pub nep141: AccountId,
+    /// If `true` and the NEP-141 token is already registered, the address of the existing
+    /// ERC-20 token is returned instead of failing.
+    pub return_existing: bool,
+    /// If `true`, the ERC-20 token is deployed with `CREATE2` at an address which only depends
+    /// on the NEP-141 account id and the ERC-20 code, so it's the same on every silo using
+    /// this scheme. Otherwise, the address depends on the nonce of the deployer, as with `CREATE`.
+    pub deterministic: bool,
+}
+
+/// Deserialized values from bytes to current or legacy Borsh-encoded parameters
+/// for passing to the engine `deploy_erc20_token` function, and to provide backward type compatibility
+#[derive(BorshSerialize, BorshDeserialize, Debug, Eq, PartialEq, Clone)]
+pub enum DeployErc20TokenCallArgs {
+    V2(DeployErc20TokenArgsV2),
+    V1(DeployErc20TokenArgs),
+    V3(DeployErc20TokenArgsV3),
+}
+
+impl DeployErc20TokenCallArgs {
+    #[must_use]
+    pub fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Self::try_from_slice(bytes).map_or_else(
+            |_| {
+                DeployErc20TokenArgs::try_from_slice(bytes)
+                    .map_or(None, |value| Some(Self::V1(value)))
+            },
+            Some,
+        )
+    }
+
+    #[must_use]
+    pub const fn nep141(&self) -> &AccountId {
+        match self {
+            Self::V2(args) => &args.nep141,
+            Self::V1(args) => &args.nep141,
+            Self::V3(args) => &args.nep141,
+        }
+    }
+
+    #[must_use]
+    pub const fn return_existing(&self) -> bool {
+        match self {
+            Self::V2(args) => args.return_existing,
+            Self::V1(_) => false,
+            Self::V3(args) => args.return_existing,
+        }
+    }
+
+    #[must_use]
+    pub const fn deterministic(&self) -> bool {
+        match self {
+            Self::V3(args) => args.deterministic,
+            Self::V1(_) | Self::V2(_) => false,
+        }
+    }
+}
+
+impl From<DeployErc20TokenCallArgs> for DeployErc20TokenArgs {
+    fn from(args: DeployErc20TokenCallArgs) -> Self {
+        match args {
+            DeployErc20TokenCallArgs::V2(args) => Self {
+                nep141: args.nep141,
+            },
+            DeployErc20TokenCallArgs::V1(args) => args,
+            DeployErc20TokenCallArgs::V3(args) => Self {
+                nep141: args.nep141,
+            },
+        }
+    }
+}
+
 /// Borsh-encoded parameters for `get_erc20_from_nep141` function.
 pub type GetErc20FromNep141CallArgs = DeployErc20TokenArgs;
 
@@ -491,6 +1227,119 @@ mod tests {
         assert_eq!(x, res);
     }
 
+    #[test]
+    fn test_transaction_status_output() {
+        let status = TransactionStatus::Succeed(vec![1, 2, 3]);
+        assert!(status.is_success());
+        assert_eq!(status.output(), &[1, 2, 3]);
+
+        let status = TransactionStatus::Revert(vec![4, 5]);
+        assert!(!status.is_success());
+        assert_eq!(status.output(), &[4, 5]);
+
+        for status in [
+            TransactionStatus::OutOfGas,
+            TransactionStatus::InvalidCode(0xef),
+            TransactionStatus::Other("ERR_FATAL".into()),
+        ] {
+            assert!(!status.is_success());
+            assert!(status.output().is_empty());
+        }
+    }
+
+    #[test]
+    fn test_submit_result_borsh_compatibility() {
+        #[derive(BorshSerialize)]
+        struct LegacySubmitResult {
+            version: u8,
+            status: TransactionStatus,
+            gas_used: u64,
+            logs: Vec<ResultLog>,
+        }
+
+        let logs = vec![ResultLog {
+            address: Address::from_array([1; 20]),
+            topics: vec![[2; 32]],
+            data: vec![3, 4, 5],
+        }];
+        let legacy = LegacySubmitResult {
+            version: SubmitResult::LEGACY_VERSION,
+            status: TransactionStatus::Succeed(vec![6]),
+            gas_used: 21_000,
+            logs: logs.clone(),
+        };
+
+        // The results of the legacy version are decoded without the details and are
+        // encoded back the same way.
+        let legacy_bytes = borsh::to_vec(&legacy).unwrap();
+        let legacy_result = SubmitResult::try_from_slice(&legacy_bytes).unwrap();
+        assert_eq!(legacy_result.status, legacy.status);
+        assert_eq!(legacy_result.gas_used, legacy.gas_used);
+        assert_eq!(legacy_result.logs, legacy.logs);
+        assert!(legacy_result.promises.is_empty());
+        assert_eq!(legacy_result.intrinsic_gas, None);
+        assert_eq!(legacy_result.effective_gas_price, 0);
+        assert_eq!(borsh::to_vec(&legacy_result).unwrap(), legacy_bytes);
+
+        // The new results always have the details, even if they are empty.
+        let mut result = SubmitResult::new(TransactionStatus::Succeed(vec![6]), 21_000, logs);
+        let bytes = borsh::to_vec(&result).unwrap();
+        assert_eq!(bytes[0], SubmitResult::VERSION);
+        assert_eq!(bytes.len(), legacy_bytes.len() + 4 + 1 + 16);
+        assert_eq!(SubmitResult::try_from_slice(&bytes).unwrap(), result);
+
+        result.promises = vec![PromiseCreateArgs {
+            target_account_id: "some-account.near".parse().unwrap(),
+            method: "some_method".into(),
+            args: vec![7, 8],
+            attached_balance: Yocto::new(9),
+            attached_gas: crate::types::NearGas::new(10),
+        }];
+        result.intrinsic_gas = Some(21_000);
+        result.effective_gas_price = 1_000;
+        let bytes = borsh::to_vec(&result).unwrap();
+        assert_eq!(SubmitResult::try_from_slice(&bytes).unwrap(), result);
+
+        // The results can be nested in other borsh values.
+        let results = vec![
+            result,
+            legacy_result,
+            SubmitResult::new(TransactionStatus::OutOfGas, 1, Vec::new()),
+        ];
+        let bytes = borsh::to_vec(&results).unwrap();
+        assert_eq!(
+            Vec::<SubmitResult>::try_from_slice(&bytes).unwrap(),
+            results
+        );
+
+        let mut unknown_version = legacy_bytes;
+        unknown_version[0] = SubmitResult::VERSION + 1;
+        assert!(SubmitResult::try_from_slice(&unknown_version).is_err());
+    }
+
+    #[test]
+    fn test_submit_args_deserialize() {
+        let legacy_args = SubmitArgs {
+            tx_data: vec![1, 2, 3],
+            max_gas_price: Some(4),
+            gas_token_address: Some(Address::from_array([5; 20])),
+        };
+        let mut args = SubmitArgsV2::from(legacy_args.clone());
+
+        // The legacy arguments are parsed without a deadline.
+        let bytes = borsh::to_vec(&legacy_args).unwrap();
+        assert_eq!(SubmitArgsV2::deserialize(&bytes), Some(args.clone()));
+
+        let bytes = borsh::to_vec(&args).unwrap();
+        assert_eq!(SubmitArgsV2::deserialize(&bytes), Some(args.clone()));
+
+        args.valid_until_block = Some(100);
+        let bytes = borsh::to_vec(&args).unwrap();
+        assert_eq!(SubmitArgsV2::deserialize(&bytes), Some(args));
+
+        assert_eq!(SubmitArgsV2::deserialize(&[1, 2, 3]), None);
+    }
+
     #[test]
     fn test_call_args_deserialize() {
         let new_input = FunctionCallArgsV2 {
@@ -536,6 +1385,42 @@ mod tests {
         assert_eq!(parsed_data, None);
     }
 
+    #[test]
+    fn test_deploy_erc20_token_args_deserialize() {
+        let nep141: AccountId = "wrap.near".parse().unwrap();
+        let legacy_input = DeployErc20TokenArgs {
+            nep141: nep141.clone(),
+        };
+
+        // New input format wrapped into the enum.
+        let args = DeployErc20TokenCallArgs::V2(DeployErc20TokenArgsV2 {
+            nep141: nep141.clone(),
+            return_existing: true,
+        });
+        let input_bytes = borsh::to_vec(&args).unwrap();
+        let parsed_data = DeployErc20TokenCallArgs::deserialize(&input_bytes).unwrap();
+        assert!(parsed_data.return_existing());
+        assert!(!parsed_data.deterministic());
+        assert_eq!(parsed_data, args);
+
+        let deterministic_args = DeployErc20TokenCallArgs::V3(DeployErc20TokenArgsV3 {
+            nep141,
+            return_existing: false,
+            deterministic: true,
+        });
+        let deterministic_bytes = borsh::to_vec(&deterministic_args).unwrap();
+        let parsed_data = DeployErc20TokenCallArgs::deserialize(&deterministic_bytes).unwrap();
+        assert!(!parsed_data.return_existing());
+        assert!(parsed_data.deterministic());
+        assert_eq!(parsed_data, deterministic_args);
+
+        // Old input format is still accepted and is not idempotent.
+        let input_bytes = borsh::to_vec(&legacy_input).unwrap();
+        let parsed_data = DeployErc20TokenCallArgs::deserialize(&input_bytes).unwrap();
+        assert!(!parsed_data.return_existing());
+        assert_eq!(parsed_data, DeployErc20TokenCallArgs::V1(legacy_input));
+    }
+
     #[test]
     fn test_deserialize_relayer_key_args() {
         let json = r#"{"public_key": "ed25519:DcA2MzgpJbrUATQLLceocVckhhAqrkingax4oJ9kZ847"}"#;
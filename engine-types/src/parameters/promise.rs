@@ -6,7 +6,7 @@ use crate::{Box, String, Vec};
 use borsh::{io, BorshDeserialize, BorshSerialize};
 
 #[must_use]
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
+#[derive(Debug, BorshSerialize, BorshDeserialize, Clone, PartialEq, Eq)]
 pub enum PromiseArgs {
     Create(PromiseCreateArgs),
     Callback(PromiseWithCallbackArgs),
@@ -209,6 +209,7 @@ impl BorshDeserialize for NearPromise {
 
 #[must_use]
 #[derive(Debug, BorshSerialize, BorshDeserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "impl-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PromiseCreateArgs {
     pub target_account_id: AccountId,
     pub method: String,
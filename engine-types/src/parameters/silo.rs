@@ -1,12 +1,53 @@
 use crate::account_id::AccountId;
 use crate::borsh::{self, BorshDeserialize, BorshSerialize};
-use crate::types::{Address, EthGas};
+use crate::types::{Address, EthGas, WeiU256};
 
 #[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct FixedGasArgs {
     pub fixed_gas: Option<EthGas>,
 }
 
+/// Borsh-encoded result of the `get_fixed_gas_info` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FixedGasInfo {
+    /// Fixed amount of gas per transaction, `None` if the fixed gas isn't set (silo mode is off).
+    pub fixed_gas: Option<EthGas>,
+    /// Current base fee per gas in Wei. A transaction is charged for the fixed gas at this base
+    /// fee plus the priority fee it offers.
+    pub gas_price: WeiU256,
+}
+
+/// Borsh-encoded result of the `get_fee_regime` function, which describes how the transactions
+/// executed by `submit` are charged for gas.
+///
+/// The fixed gas takes precedence over the gas market: while it is set (the silo mode is on),
+/// a transaction is charged for the fixed gas instead of the gas it uses. In both regimes the
+/// gas price is the base fee per gas plus the priority fee offered by the transaction, capped by
+/// the `max_gas_price` of the submit arguments, and the transactions with a zero gas price are
+/// rejected if they are disallowed. The gas is always paid in ETH: the `gas_token_address` of
+/// the submit arguments isn't taken into account.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum FeeRegime {
+    /// A transaction is charged for the gas it uses.
+    Market {
+        /// Base fee per gas in Wei.
+        base_fee_per_gas: WeiU256,
+        /// Whether the transactions with a zero gas price are accepted.
+        zero_gas_price_allowed: bool,
+    },
+    /// A transaction is charged for a fixed amount of gas, no matter how much gas it uses.
+    FixedGas {
+        /// Fixed amount of gas per transaction.
+        fixed_gas: EthGas,
+        /// Base fee per gas in Wei.
+        base_fee_per_gas: WeiU256,
+        /// Fee in Wei charged for the fixed gas at the base fee per gas.
+        fixed_fee: WeiU256,
+        /// Whether the transactions with a zero gas price are accepted.
+        zero_gas_price_allowed: bool,
+    },
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct SiloParamsArgs {
     /// Fixed amount of gas per transaction.
@@ -18,6 +59,28 @@ pub struct SiloParamsArgs {
     pub erc20_fallback_address: Address,
 }
 
+/// Borsh-encoded parameters for the `configure_silo` function. The whole bundle is validated
+/// before any of it is applied.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct SiloConfigArgs {
+    /// Fixed amount of gas per transaction and ERC-20 fallback address.
+    pub silo_params: SiloParamsArgs,
+    /// Statuses of the white lists, each kind can be present at most once.
+    pub whitelist_statuses: Vec<WhitelistStatusArgs>,
+    /// Initial entries of the white lists.
+    pub whitelist_entries: Vec<WhitelistArgs>,
+}
+
+/// Borsh-encoded result of the `export_whitelists` function and parameters of the
+/// `import_whitelists` function, i.e. the statuses and the entries of all the white lists.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct WhitelistsArgs {
+    /// Statuses of the white lists, each kind can be present at most once.
+    pub whitelist_statuses: Vec<WhitelistStatusArgs>,
+    /// Entries of the white lists.
+    pub whitelist_entries: Vec<WhitelistArgs>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 #[cfg_attr(
     feature = "impl-serde",
@@ -14,12 +14,60 @@ pub struct FundXccArgs {
     pub wnear_account_id: Option<AccountId>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct FundXccArgsV2 {
+    pub target: Address,
+    pub wnear_account_id: Option<AccountId>,
+    /// If `true` the router does not register itself with the wNEAR contract
+    /// when it is created. Only useful for silos where wNEAR is not used.
+    pub skip_wnear_registration: bool,
+}
+
+/// Deserialized values from bytes to current or legacy Borsh-encoded parameters
+/// for passing to the engine `fund_xcc_sub_account` function, and to provide backward type compatibility
+#[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum FundXccCallArgs {
+    V2(FundXccArgsV2),
+    V1(FundXccArgs),
+}
+
+impl FundXccCallArgs {
+    #[must_use]
+    pub fn deserialize(bytes: &[u8]) -> Option<Self> {
+        Self::try_from_slice(bytes).map_or_else(
+            |_| FundXccArgs::try_from_slice(bytes).map_or(None, |value| Some(Self::V1(value))),
+            Some,
+        )
+    }
+}
+
+impl From<FundXccCallArgs> for FundXccArgsV2 {
+    fn from(args: FundXccCallArgs) -> Self {
+        match args {
+            FundXccCallArgs::V2(args) => args,
+            FundXccCallArgs::V1(args) => Self {
+                target: args.target,
+                wnear_account_id: args.wnear_account_id,
+                skip_wnear_registration: false,
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
 pub struct WithdrawWnearToRouterArgs {
     pub target: Address,
     pub amount: Yocto,
 }
 
+/// Borsh-encoded parameters for the `set_xcc_near_gas_per_evm_gas` function.
+#[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub struct SetNearGasPerEvmGasArgs {
+    /// NEAR gas attached to the cross-contract calls charged as one EVM gas,
+    /// `None` restores the default.
+    pub near_gas_per_evm_gas: Option<u64>,
+}
+
 /// Type wrapper for version of router contracts.
 #[derive(
     Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, BorshDeserialize, BorshSerialize,
@@ -34,6 +34,12 @@ pub enum KeyPrefix {
     Hashchain = 0xc,
     Silo = 0xd,
     Whitelist = 0xe,
+    RelaxedNonce = 0xf,
+    DeployerAllowlist = 0x10,
+    RelayerAccountIdMap = 0x11,
+    ExitFee = 0x12,
+    TxCount = 0x13,
+    Receipt = 0x14,
 }
 
 impl From<KeyPrefix> for u8 {
@@ -54,6 +60,12 @@ impl From<KeyPrefix> for u8 {
             KeyPrefix::Hashchain => 0xc,
             KeyPrefix::Silo => 0xd,
             KeyPrefix::Whitelist => 0xe,
+            KeyPrefix::RelaxedNonce => 0xf,
+            KeyPrefix::DeployerAllowlist => 0x10,
+            KeyPrefix::RelayerAccountIdMap => 0x11,
+            KeyPrefix::ExitFee => 0x12,
+            KeyPrefix::TxCount => 0x13,
+            KeyPrefix::Receipt => 0x14,
         }
     }
 }
@@ -70,6 +82,8 @@ pub enum EthConnectorStorageId {
     FungibleTokenMetadata = 0x5,
     EthConnectorAccount = 0x6,
     WithdrawSerializationType = 0x7,
+    ConnectorPaused = 0x8,
+    UsedProofsCounter = 0x9,
 }
 
 impl From<EthConnectorStorageId> for u8 {
@@ -83,6 +97,8 @@ impl From<EthConnectorStorageId> for u8 {
             EthConnectorStorageId::FungibleTokenMetadata => 0x5,
             EthConnectorStorageId::EthConnectorAccount => 0x6,
             EthConnectorStorageId::WithdrawSerializationType => 0x7,
+            EthConnectorStorageId::ConnectorPaused => 0x8,
+            EthConnectorStorageId::UsedProofsCounter => 0x9,
         }
     }
 }
@@ -109,6 +125,12 @@ impl From<KeyPrefixU8> for KeyPrefix {
             0xc => Self::Hashchain,
             0xd => Self::Silo,
             0xe => Self::Whitelist,
+            0xf => Self::RelaxedNonce,
+            0x10 => Self::DeployerAllowlist,
+            0x11 => Self::RelayerAccountIdMap,
+            0x12 => Self::ExitFee,
+            0x13 => Self::TxCount,
+            0x14 => Self::Receipt,
             _ => unreachable!("Unknown key prefix"),
         }
     }
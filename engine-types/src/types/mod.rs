@@ -34,6 +34,9 @@ pub const ERC20_SYMBOL_SELECTOR: &[u8] = &[149, 216, 155, 65];
 /// Selector to call `digits` function in ERC-20 contact.
 /// `keccak(b"digits()")[..4];`
 pub const ERC20_DIGITS_SELECTOR: &[u8] = &[49, 60, 229, 103];
+/// Selector to call `sponsor` function in the paymaster contract.
+/// `keccak(b"sponsor(address,address,uint256)")[..4];`
+pub const PAYMASTER_SPONSOR_SELECTOR: &[u8] = &[96, 246, 183, 42];
 
 #[derive(Debug)]
 pub enum AddressValidationError {
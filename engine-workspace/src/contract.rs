@@ -1,40 +1,73 @@
 use crate::account::Account;
 use crate::node::Node;
 use crate::operation::{
-    CallAddEntryToWhitelist, CallAddEntryToWhitelistBatch, CallAddRelayerKey,
-    CallAttachFullAccessKey, CallCall, CallDeployCode, CallDeployErc20Token, CallDeployUpgrade,
-    CallDeposit, CallFactorySetWNearAddress, CallFactoryUpdate, CallFactoryUpdateAddressVersion,
-    CallFtOnTransfer, CallFtTransfer, CallFtTransferCall, CallFundXccSubAccount, CallMintAccount,
-    CallMirrorErc20Token, CallNew, CallNewEthConnector, CallPauseContract, CallPausePrecompiles,
-    CallRefundOnError, CallRegisterRelayer, CallRemoveEntryFromWhitelist, CallRemoveRelayerKey,
-    CallResumeContract, CallResumePrecompiles, CallSetErc20Metadata,
-    CallSetEthConnectorContractAccount, CallSetEthConnectorContractData, CallSetFixedGas,
-    CallSetKeyManager, CallSetOwner, CallSetPausedFlags, CallSetSiloParams, CallSetWhitelistStatus,
-    CallStageUpgrade, CallStateMigration, CallStorageDeposit, CallStorageUnregister,
-    CallStorageWithdraw, CallSubmit, CallUpgrade, CallWithdraw, ViewAccountsCounter, ViewBalance,
-    ViewBlockHash, ViewBridgeProver, ViewChainId, ViewCode, ViewErc20FromNep141,
-    ViewFactoryWnearAddress, ViewFtBalanceOf, ViewFtBalanceOfEth, ViewFtBalancesOf, ViewFtMetadata,
+    CallAddDeployer, CallAddEntryToWhitelist, CallAddEntryToWhitelistBatch, CallAddRelayerKey,
+    CallAttachFullAccessKey, CallCall, CallConfigureSilo, CallDeployCode, CallDeployErc20Token,
+    CallDeployUpgrade, CallDeposit, CallFactorySetWNearAddress, CallFactoryUpdate,
+    CallFactoryUpdateAddressVersion, CallFinalizeUpgradeStage, CallFtOnTransfer, CallFtTransfer,
+    CallFtTransferCall, CallFundXccSubAccount, CallImportWhitelists, CallIndexWhitelistEntries,
+    CallMintAccount, CallMirrorErc20Token, CallNew, CallNewEthConnector, CallPauseConnector,
+    CallPauseContract, CallPausePrecompiles, CallRefundOnError, CallRegisterRelayer,
+    CallRegisterRelayersBatch, CallRemoveDeployer, CallRemoveEntryFromWhitelist,
+    CallRemoveRelayerKey, CallResumeConnector, CallResumeContract, CallResumePrecompiles,
+    CallSetAllowChainlessTx, CallSetAllowEmptyInitCode, CallSetAllowZeroGasPrice,
+    CallSetDeployerAllowlistStatus, CallSetDiagnosticsEnabled, CallSetEngineMetadata,
+    CallSetErc20DeployGasLimit, CallSetErc20Metadata, CallSetErc20Template,
+    CallSetEthConnectorContractAccount, CallSetEthConnectorContractData, CallSetEvmMemoryLimit,
+    CallSetExitFee, CallSetFeeDistribution, CallSetFixedGas, CallSetFtOnTransferGasLimit,
+    CallSetGasRefundsEnabled, CallSetKeyManager, CallSetLogLevel, CallSetMaxLogsPerTx,
+    CallSetMaxNonceGap, CallSetMaxRefundQuotient, CallSetModExpAlgorithm, CallSetOwner,
+    CallSetPausedFlags, CallSetPaymaster, CallSetRandomSeedOverride,
+    CallSetReceiptsPersistenceEnabled, CallSetRejectUnknownPrecompiles, CallSetRelaxedNonce,
+    CallSetSiloParams, CallSetTipRecipient, CallSetTxCountTrackingEnabled, CallSetUnregisterFee,
+    CallSetViewStepLimit, CallSetWhitelistStatus, CallSetXccNearGasPerEvmGas, CallStageUpgrade,
+    CallStageUpgradeChunk, CallStateMigration, CallStorageDeposit, CallStorageUnregister,
+    CallStorageWithdraw, CallSubmit, CallSubmitBatch, CallUpgrade, CallWithdraw,
+    CallWithdrawCollectedFees, ViewAccount, ViewAccountExists, ViewAccounts, ViewAccountsCounter,
+    ViewAllowChainlessTx, ViewAllowEmptyInitCode, ViewAllowZeroGasPrice, ViewBalance,
+    ViewBlockHash, ViewBlockLogsBloom, ViewBridgeProver, ViewChainId, ViewChainIdU256, ViewCode,
+    ViewCodeHash, ViewCodeHashSelf, ViewCollectedFees, ViewDeployers, ViewDiagnosticsEnabled,
+    ViewEngineMetadata, ViewErc20DeployGasLimit, ViewErc20FromNep141, ViewErc20Generation,
+    ViewErc20Template, ViewEvmConfig, ViewEvmMemoryLimit, ViewExitFee, ViewExportWhitelists,
+    ViewFactoryWnearAddress, ViewFeatures, ViewFeeDistribution, ViewFeeRegime, ViewFtBalanceOf,
+    ViewFtBalanceOfEth, ViewFtBalancesOf, ViewFtMetadata, ViewFtOnTransferGasLimit,
     ViewFtTotalEthSupplyOnAurora, ViewFtTotalEthSupplyOnNear, ViewFtTotalSupply,
-    ViewGetErc20Metadata, ViewGetEthConnectorContractAccount, ViewGetFixedGas, ViewGetSiloParams,
-    ViewGetWhitelistStatus, ViewIsUsedProof, ViewNep141FromErc20, ViewNonce, ViewOwner,
-    ViewPausedFlags, ViewPausedPrecompiles, ViewStorageAt, ViewStorageBalanceOf, ViewUpgradeIndex,
-    ViewVersion, ViewView,
+    ViewGasRefundsEnabled, ViewGenesisInfo, ViewGetErc20Metadata,
+    ViewGetEthConnectorContractAccount, ViewGetFixedGas, ViewGetFixedGasInfo, ViewGetSiloParams,
+    ViewGetWhitelistStatus, ViewGovernance, ViewIsUsedProof, ViewLogLevel, ViewMaxLogsPerTx,
+    ViewMaxNonceGap, ViewMaxRefundQuotient, ViewModExpAlgorithm, ViewNep141FromErc20, ViewNonce,
+    ViewOwner, ViewPausedFlags, ViewPausedPrecompiles, ViewPaymaster, ViewPrecompileInfo,
+    ViewPrecompiles, ViewRandomSeed, ViewRandomSeedOverride, ViewReceipt,
+    ViewReceiptsPersistenceEnabled, ViewRejectUnknownPrecompiles, ViewRelayerAccount,
+    ViewSimulateDeploy, ViewStorageAt, ViewStorageBalanceOf, ViewStorageDepositAmount,
+    ViewTipRecipient, ViewTxCount, ViewTxCountTrackingEnabled, ViewTxHash, ViewUnregisterFee,
+    ViewUpgradeIndex, ViewUsedProofsCount, ViewVerifySignature, ViewVersion, ViewView,
+    ViewViewStepLimit, ViewXccNearGasPerEvmGas,
 };
 use crate::transaction::{CallTransaction, ViewTransaction};
 use aurora_engine_types::account_id::AccountId;
 use aurora_engine_types::parameters::connector::{
-    Erc20Identifier, FungibleTokenMetadata, MirrorErc20TokenArgs, PausedMask, Proof,
-    SetErc20MetadataArgs, SetEthConnectorContractAccountArgs, WithdrawSerializeType,
+    Erc20Identifier, ExitFeeConfig, FungibleTokenMetadata, MirrorErc20TokenArgs, PausedMask, Proof,
+    SetErc20MetadataArgs, SetEthConnectorContractAccountArgs, SetExitFeeArgs,
+    WithdrawSerializeType,
 };
 use aurora_engine_types::parameters::engine::{
-    CallArgs, FullAccessKeyArgs, FunctionCallArgsV2, NewCallArgs, NewCallArgsV2, RelayerKeyArgs,
-    RelayerKeyManagerArgs,
+    CallArgs, DeployerAllowlistStatusArgs, DeployerArgs, EngineMetadata, FeeShare,
+    FinalizeUpgradeStageArgs, FullAccessKeyArgs, FunctionCallArgsV2, GetDeployersArgs, LogLevel,
+    ModExpAlgorithmKind, NewCallArgs, NewCallArgsV2, RegisterRelayersBatchArgs, RelayerKeyArgs,
+    RelayerKeyManagerArgs, SetErc20DeployGasLimitArgs, SetErc20TemplateArgs, SetEvmMemoryLimitArgs,
+    SetFeeDistributionArgs, SetFtOnTransferGasLimitArgs, SetLogLevelArgs, SetMaxLogsPerTxArgs,
+    SetMaxNonceGapArgs, SetMaxRefundQuotientArgs, SetModExpAlgorithmArgs, SetPaymasterArgs,
+    SetRandomSeedOverrideArgs, SetRelaxedNonceArgs, SetTipRecipientArgs, SetUnregisterFeeArgs,
+    SetViewStepLimitArgs, StageUpgradeChunkArgs, SubmitBatchArgs, VerifySignatureArgs,
+    WithdrawCollectedFeesArgs,
 };
 use aurora_engine_types::parameters::silo::{
-    FixedGasArgs, SiloParamsArgs, WhitelistArgs, WhitelistKindArgs, WhitelistStatusArgs,
+    FixedGasArgs, SiloConfigArgs, SiloParamsArgs, WhitelistArgs, WhitelistKindArgs,
+    WhitelistStatusArgs, WhitelistsArgs,
 };
-use aurora_engine_types::parameters::xcc::FundXccArgs;
-use aurora_engine_types::types::{Address, RawU256, WeiU256};
+use aurora_engine_types::parameters::xcc::{FundXccArgs, SetNearGasPerEvmGasArgs};
+use aurora_engine_types::types::{Address, RawH256, RawU256, WeiU256, Yocto};
 use aurora_engine_types::{H256, U256};
 use near_sdk::json_types::U128;
 use near_workspaces::types::SecretKey;
@@ -230,10 +263,24 @@ impl EngineContract {
         CallSubmit::call(&self.contract).args(input)
     }
 
+    pub fn submit_batch(&self, transactions: Vec<Vec<u8>>, atomic: bool) -> CallSubmitBatch {
+        CallSubmitBatch::call(&self.contract).args_borsh(SubmitBatchArgs {
+            transactions,
+            atomic,
+        })
+    }
+
     pub fn register_relayer(&self, address: Address) -> CallRegisterRelayer {
         CallRegisterRelayer::call(&self.contract).args_borsh(address)
     }
 
+    pub fn register_relayers_batch(
+        &self,
+        args: RegisterRelayersBatchArgs,
+    ) -> CallRegisterRelayersBatch {
+        CallRegisterRelayersBatch::call(&self.contract).args_borsh(args)
+    }
+
     pub fn ft_on_transfer(
         &self,
         sender_id: AccountId,
@@ -267,6 +314,15 @@ impl EngineContract {
         CallFactorySetWNearAddress::call(&self.contract).args_borsh(address)
     }
 
+    pub fn set_xcc_near_gas_per_evm_gas(
+        &self,
+        near_gas_per_evm_gas: Option<u64>,
+    ) -> CallSetXccNearGasPerEvmGas {
+        CallSetXccNearGasPerEvmGas::call(&self.contract).args_borsh(SetNearGasPerEvmGasArgs {
+            near_gas_per_evm_gas,
+        })
+    }
+
     pub fn upgrade(&self, bytes: Vec<u8>) -> CallUpgrade {
         CallUpgrade::call(&self.contract).args(bytes)
     }
@@ -275,6 +331,18 @@ impl EngineContract {
         CallStageUpgrade::call(&self.contract).args(bytes)
     }
 
+    pub fn stage_upgrade_chunk(&self, index: u32, bytes: Vec<u8>) -> CallStageUpgradeChunk {
+        CallStageUpgradeChunk::call(&self.contract)
+            .args_borsh(StageUpgradeChunkArgs { index, bytes })
+    }
+
+    pub fn finalize_upgrade_stage(&self, total_len: u64, hash: H256) -> CallFinalizeUpgradeStage {
+        CallFinalizeUpgradeStage::call(&self.contract).args_borsh(FinalizeUpgradeStageArgs {
+            total_len,
+            hash: hash.0,
+        })
+    }
+
     pub fn deploy_upgrade(&self) -> CallDeployUpgrade {
         CallDeployUpgrade::call(&self.contract)
     }
@@ -324,14 +392,34 @@ impl EngineContract {
         CallResumeContract::call(&self.contract)
     }
 
+    pub fn pause_connector(&self) -> CallPauseConnector {
+        CallPauseConnector::call(&self.contract)
+    }
+
+    pub fn resume_connector(&self) -> CallResumeConnector {
+        CallResumeConnector::call(&self.contract)
+    }
+
     pub fn set_fixed_gas(&self, cost: FixedGasArgs) -> CallSetFixedGas {
         CallSetFixedGas::call(&self.contract).args_borsh(cost)
     }
 
+    pub fn set_gas_refunds_enabled(&self, enabled: bool) -> CallSetGasRefundsEnabled {
+        CallSetGasRefundsEnabled::call(&self.contract).args_borsh(enabled)
+    }
+
     pub fn set_silo_params(&self, params: Option<SiloParamsArgs>) -> CallSetSiloParams {
         CallSetSiloParams::call(&self.contract).args_borsh(params)
     }
 
+    pub fn configure_silo(&self, config: SiloConfigArgs) -> CallConfigureSilo {
+        CallConfigureSilo::call(&self.contract).args_borsh(config)
+    }
+
+    pub fn import_whitelists(&self, args: WhitelistsArgs) -> CallImportWhitelists {
+        CallImportWhitelists::call(&self.contract).args_borsh(args)
+    }
+
     pub fn set_whitelist_status(&self, status: WhitelistStatusArgs) -> CallSetWhitelistStatus {
         CallSetWhitelistStatus::call(&self.contract).args_borsh(status)
     }
@@ -347,6 +435,13 @@ impl EngineContract {
         CallAddEntryToWhitelistBatch::call(&self.contract).args_borsh(batch)
     }
 
+    pub fn index_whitelist_entries(
+        &self,
+        entries: Vec<WhitelistArgs>,
+    ) -> CallIndexWhitelistEntries {
+        CallIndexWhitelistEntries::call(&self.contract).args_borsh(entries)
+    }
+
     pub fn remove_entry_from_whitelist(
         &self,
         entry: WhitelistArgs,
@@ -365,6 +460,142 @@ impl EngineContract {
     pub fn set_owner(&self, account: &AccountId) -> CallSetOwner {
         CallSetOwner::call(&self.contract).args_borsh(account)
     }
+
+    pub fn set_relaxed_nonce(&self, args: SetRelaxedNonceArgs) -> CallSetRelaxedNonce {
+        CallSetRelaxedNonce::call(&self.contract).args_borsh(args)
+    }
+
+    pub fn add_deployer(&self, address: Address) -> CallAddDeployer {
+        CallAddDeployer::call(&self.contract).args_borsh(DeployerArgs { address })
+    }
+
+    pub fn remove_deployer(&self, address: Address) -> CallRemoveDeployer {
+        CallRemoveDeployer::call(&self.contract).args_borsh(DeployerArgs { address })
+    }
+
+    pub fn set_deployer_allowlist_status(&self, enabled: bool) -> CallSetDeployerAllowlistStatus {
+        CallSetDeployerAllowlistStatus::call(&self.contract)
+            .args_borsh(DeployerAllowlistStatusArgs { enabled })
+    }
+
+    pub fn withdraw_collected_fees(&self, treasury: Address) -> CallWithdrawCollectedFees {
+        CallWithdrawCollectedFees::call(&self.contract)
+            .args_borsh(WithdrawCollectedFeesArgs { treasury })
+    }
+
+    pub fn set_max_logs_per_tx(&self, max_logs: Option<u64>) -> CallSetMaxLogsPerTx {
+        CallSetMaxLogsPerTx::call(&self.contract).args_borsh(SetMaxLogsPerTxArgs { max_logs })
+    }
+
+    pub fn set_max_nonce_gap(&self, max_gap: Option<u64>) -> CallSetMaxNonceGap {
+        CallSetMaxNonceGap::call(&self.contract).args_borsh(SetMaxNonceGapArgs { max_gap })
+    }
+
+    pub fn set_max_refund_quotient(&self, quotient: Option<u64>) -> CallSetMaxRefundQuotient {
+        CallSetMaxRefundQuotient::call(&self.contract)
+            .args_borsh(SetMaxRefundQuotientArgs { quotient })
+    }
+
+    pub fn set_evm_memory_limit(&self, memory_limit: Option<u64>) -> CallSetEvmMemoryLimit {
+        CallSetEvmMemoryLimit::call(&self.contract)
+            .args_borsh(SetEvmMemoryLimitArgs { memory_limit })
+    }
+
+    pub fn set_view_step_limit(&self, step_limit: Option<u64>) -> CallSetViewStepLimit {
+        CallSetViewStepLimit::call(&self.contract).args_borsh(SetViewStepLimitArgs { step_limit })
+    }
+
+    pub fn set_ft_on_transfer_gas_limit(
+        &self,
+        gas_limit: Option<u64>,
+    ) -> CallSetFtOnTransferGasLimit {
+        CallSetFtOnTransferGasLimit::call(&self.contract)
+            .args_borsh(SetFtOnTransferGasLimitArgs { gas_limit })
+    }
+
+    pub fn set_unregister_fee(&self, fee: Option<Yocto>) -> CallSetUnregisterFee {
+        CallSetUnregisterFee::call(&self.contract).args_borsh(SetUnregisterFeeArgs { fee })
+    }
+
+    pub fn set_erc20_deploy_gas_limit(&self, gas_limit: Option<u64>) -> CallSetErc20DeployGasLimit {
+        CallSetErc20DeployGasLimit::call(&self.contract)
+            .args_borsh(SetErc20DeployGasLimitArgs { gas_limit })
+    }
+
+    pub fn set_erc20_template(&self, code: Option<Vec<u8>>) -> CallSetErc20Template {
+        CallSetErc20Template::call(&self.contract).args_borsh(SetErc20TemplateArgs { code })
+    }
+
+    pub fn set_fee_distribution(&self, shares: Vec<FeeShare>) -> CallSetFeeDistribution {
+        CallSetFeeDistribution::call(&self.contract).args_borsh(SetFeeDistributionArgs { shares })
+    }
+
+    pub fn set_tip_recipient(&self, tip_recipient: Option<Address>) -> CallSetTipRecipient {
+        CallSetTipRecipient::call(&self.contract).args_borsh(SetTipRecipientArgs { tip_recipient })
+    }
+
+    pub fn set_paymaster(&self, paymaster: Option<Address>) -> CallSetPaymaster {
+        CallSetPaymaster::call(&self.contract).args_borsh(SetPaymasterArgs { paymaster })
+    }
+
+    pub fn set_log_level(&self, log_level: LogLevel) -> CallSetLogLevel {
+        CallSetLogLevel::call(&self.contract).args_borsh(SetLogLevelArgs { log_level })
+    }
+
+    pub fn set_modexp_algorithm(&self, algorithm: ModExpAlgorithmKind) -> CallSetModExpAlgorithm {
+        CallSetModExpAlgorithm::call(&self.contract)
+            .args_borsh(SetModExpAlgorithmArgs { algorithm })
+    }
+
+    pub fn set_diagnostics_enabled(&self, enabled: bool) -> CallSetDiagnosticsEnabled {
+        CallSetDiagnosticsEnabled::call(&self.contract).args_borsh(enabled)
+    }
+
+    pub fn set_tx_count_tracking_enabled(&self, enabled: bool) -> CallSetTxCountTrackingEnabled {
+        CallSetTxCountTrackingEnabled::call(&self.contract).args_borsh(enabled)
+    }
+
+    pub fn set_receipts_persistence_enabled(
+        &self,
+        enabled: bool,
+    ) -> CallSetReceiptsPersistenceEnabled {
+        CallSetReceiptsPersistenceEnabled::call(&self.contract).args_borsh(enabled)
+    }
+
+    pub fn set_random_seed_override(
+        &self,
+        random_seed: Option<RawH256>,
+    ) -> CallSetRandomSeedOverride {
+        CallSetRandomSeedOverride::call(&self.contract)
+            .args_borsh(SetRandomSeedOverrideArgs { random_seed })
+    }
+
+    pub fn set_allow_zero_gas_price(&self, allowed: bool) -> CallSetAllowZeroGasPrice {
+        CallSetAllowZeroGasPrice::call(&self.contract).args_borsh(allowed)
+    }
+
+    pub fn set_reject_unknown_precompiles(
+        &self,
+        rejected: bool,
+    ) -> CallSetRejectUnknownPrecompiles {
+        CallSetRejectUnknownPrecompiles::call(&self.contract).args_borsh(rejected)
+    }
+
+    pub fn set_allow_empty_init_code(&self, allowed: bool) -> CallSetAllowEmptyInitCode {
+        CallSetAllowEmptyInitCode::call(&self.contract).args_borsh(allowed)
+    }
+
+    pub fn set_allow_chainless_tx(&self, allowed: bool) -> CallSetAllowChainlessTx {
+        CallSetAllowChainlessTx::call(&self.contract).args_borsh(allowed)
+    }
+
+    pub fn set_exit_fee(&self, token: Address, config: Option<ExitFeeConfig>) -> CallSetExitFee {
+        CallSetExitFee::call(&self.contract).args_borsh(SetExitFeeArgs { token, config })
+    }
+
+    pub fn set_engine_metadata(&self, metadata: EngineMetadata) -> CallSetEngineMetadata {
+        CallSetEngineMetadata::call(&self.contract).args_borsh(metadata)
+    }
 }
 
 /// View functions
@@ -385,6 +616,10 @@ impl EngineContract {
         ViewStorageBalanceOf::view(&self.contract).args_json(json!({ "account_id": account_id }))
     }
 
+    pub fn get_storage_deposit_amount(&self) -> ViewStorageDepositAmount {
+        ViewStorageDepositAmount::view(&self.contract)
+    }
+
     pub fn ft_metadata(&self) -> ViewFtMetadata {
         ViewFtMetadata::view(&self.contract)
     }
@@ -393,10 +628,18 @@ impl EngineContract {
         ViewVersion::view(&self.contract)
     }
 
+    pub fn get_code_hash_self(&self) -> ViewCodeHashSelf {
+        ViewCodeHashSelf::view(&self.contract)
+    }
+
     pub fn get_owner(&self) -> ViewOwner {
         ViewOwner::view(&self.contract)
     }
 
+    pub fn get_governance(&self) -> ViewGovernance {
+        ViewGovernance::view(&self.contract)
+    }
+
     pub fn get_bridge_prover(&self) -> ViewBridgeProver {
         ViewBridgeProver::view(&self.contract)
     }
@@ -405,6 +648,10 @@ impl EngineContract {
         ViewChainId::view(&self.contract)
     }
 
+    pub fn get_chain_id_u256(&self) -> ViewChainIdU256 {
+        ViewChainIdU256::view(&self.contract)
+    }
+
     pub fn get_upgrade_index(&self) -> ViewUpgradeIndex {
         ViewUpgradeIndex::view(&self.contract)
     }
@@ -417,23 +664,200 @@ impl EngineContract {
         ViewBlockHash::view(&self.contract).args_borsh(block_height)
     }
 
+    pub fn get_block_logs_bloom(&self, block_height: u64) -> ViewBlockLogsBloom {
+        ViewBlockLogsBloom::view(&self.contract).args_borsh(block_height)
+    }
+
     pub fn get_code(&self, address: Address) -> ViewCode {
         ViewCode::view(&self.contract).args_borsh(address)
     }
 
+    pub fn get_code_hash(&self, address: Address) -> ViewCodeHash {
+        ViewCodeHash::view(&self.contract).args(address.as_bytes().to_vec())
+    }
+
+    pub fn account_exists(&self, address: Address) -> ViewAccountExists {
+        ViewAccountExists::view(&self.contract).args(address.as_bytes().to_vec())
+    }
+
+    pub fn get_account(&self, address: Address) -> ViewAccount {
+        ViewAccount::view(&self.contract).args(address.as_bytes().to_vec())
+    }
+
+    pub fn verify_signature(
+        &self,
+        hash: H256,
+        signature: Vec<u8>,
+        address: Address,
+    ) -> ViewVerifySignature {
+        ViewVerifySignature::view(&self.contract).args_borsh(VerifySignatureArgs {
+            hash: hash.0,
+            signature,
+            address,
+        })
+    }
+
+    pub fn get_accounts(&self, addresses: Vec<Address>) -> ViewAccounts {
+        ViewAccounts::view(&self.contract).args_borsh(addresses)
+    }
+
+    pub fn get_relayer_account(&self, address: Address) -> ViewRelayerAccount {
+        ViewRelayerAccount::view(&self.contract).args(address.as_bytes().to_vec())
+    }
+
     pub fn get_balance(&self, address: Address) -> ViewBalance {
         ViewBalance::view(&self.contract).args(address.as_bytes().to_vec())
     }
 
+    pub fn get_deployers(&self, from_index: u32, limit: u32) -> ViewDeployers {
+        ViewDeployers::view(&self.contract).args_borsh(GetDeployersArgs { from_index, limit })
+    }
+
+    pub fn get_collected_fees(&self) -> ViewCollectedFees {
+        ViewCollectedFees::view(&self.contract)
+    }
+
+    pub fn get_max_logs_per_tx(&self) -> ViewMaxLogsPerTx {
+        ViewMaxLogsPerTx::view(&self.contract)
+    }
+
+    pub fn get_max_nonce_gap(&self) -> ViewMaxNonceGap {
+        ViewMaxNonceGap::view(&self.contract)
+    }
+
+    pub fn get_max_refund_quotient(&self) -> ViewMaxRefundQuotient {
+        ViewMaxRefundQuotient::view(&self.contract)
+    }
+
+    pub fn get_evm_memory_limit(&self) -> ViewEvmMemoryLimit {
+        ViewEvmMemoryLimit::view(&self.contract)
+    }
+
+    pub fn get_view_step_limit(&self) -> ViewViewStepLimit {
+        ViewViewStepLimit::view(&self.contract)
+    }
+
+    pub fn get_ft_on_transfer_gas_limit(&self) -> ViewFtOnTransferGasLimit {
+        ViewFtOnTransferGasLimit::view(&self.contract)
+    }
+
+    pub fn get_unregister_fee(&self) -> ViewUnregisterFee {
+        ViewUnregisterFee::view(&self.contract)
+    }
+
+    pub fn get_erc20_deploy_gas_limit(&self) -> ViewErc20DeployGasLimit {
+        ViewErc20DeployGasLimit::view(&self.contract)
+    }
+
+    pub fn get_erc20_template(&self) -> ViewErc20Template {
+        ViewErc20Template::view(&self.contract)
+    }
+
+    pub fn get_fee_distribution(&self) -> ViewFeeDistribution {
+        ViewFeeDistribution::view(&self.contract)
+    }
+
+    pub fn get_tip_recipient(&self) -> ViewTipRecipient {
+        ViewTipRecipient::view(&self.contract)
+    }
+
+    pub fn get_paymaster(&self) -> ViewPaymaster {
+        ViewPaymaster::view(&self.contract)
+    }
+
+    pub fn get_log_level(&self) -> ViewLogLevel {
+        ViewLogLevel::view(&self.contract)
+    }
+
+    pub fn get_modexp_algorithm(&self) -> ViewModExpAlgorithm {
+        ViewModExpAlgorithm::view(&self.contract)
+    }
+
+    pub fn get_diagnostics_enabled(&self) -> ViewDiagnosticsEnabled {
+        ViewDiagnosticsEnabled::view(&self.contract)
+    }
+
+    pub fn get_tx_count_tracking_enabled(&self) -> ViewTxCountTrackingEnabled {
+        ViewTxCountTrackingEnabled::view(&self.contract)
+    }
+
+    pub fn get_receipts_persistence_enabled(&self) -> ViewReceiptsPersistenceEnabled {
+        ViewReceiptsPersistenceEnabled::view(&self.contract)
+    }
+
+    pub fn get_random_seed_override(&self) -> ViewRandomSeedOverride {
+        ViewRandomSeedOverride::view(&self.contract)
+    }
+
+    pub fn get_allow_zero_gas_price(&self) -> ViewAllowZeroGasPrice {
+        ViewAllowZeroGasPrice::view(&self.contract)
+    }
+
+    pub fn get_reject_unknown_precompiles(&self) -> ViewRejectUnknownPrecompiles {
+        ViewRejectUnknownPrecompiles::view(&self.contract)
+    }
+
+    pub fn get_allow_empty_init_code(&self) -> ViewAllowEmptyInitCode {
+        ViewAllowEmptyInitCode::view(&self.contract)
+    }
+
+    pub fn get_allow_chainless_tx(&self) -> ViewAllowChainlessTx {
+        ViewAllowChainlessTx::view(&self.contract)
+    }
+
+    pub fn get_exit_fee(&self, token: Address) -> ViewExitFee {
+        ViewExitFee::view(&self.contract).args(token.as_bytes().to_vec())
+    }
+
+    pub fn get_features(&self) -> ViewFeatures {
+        ViewFeatures::view(&self.contract)
+    }
+
+    pub fn get_evm_config(&self) -> ViewEvmConfig {
+        ViewEvmConfig::view(&self.contract)
+    }
+
+    pub fn get_engine_metadata(&self) -> ViewEngineMetadata {
+        ViewEngineMetadata::view(&self.contract)
+    }
+
+    pub fn get_precompiles(&self) -> ViewPrecompiles {
+        ViewPrecompiles::view(&self.contract)
+    }
+
+    pub fn get_precompile_info(&self) -> ViewPrecompileInfo {
+        ViewPrecompileInfo::view(&self.contract)
+    }
+
     pub fn get_nonce(&self, address: Address) -> ViewNonce {
         ViewNonce::view(&self.contract).args(address.as_bytes().to_vec())
     }
 
+    pub fn get_tx_count(&self, address: Address) -> ViewTxCount {
+        ViewTxCount::view(&self.contract).args(address.as_bytes().to_vec())
+    }
+
+    pub fn get_genesis_info(&self) -> ViewGenesisInfo {
+        ViewGenesisInfo::view(&self.contract)
+    }
+
+    pub fn get_receipt(&self, tx_hash: H256) -> ViewReceipt {
+        ViewReceipt::view(&self.contract).args(tx_hash.as_bytes().to_vec())
+    }
+
     pub fn get_storage_at(&self, address: Address, key: H256) -> ViewStorageAt {
         let raw_key = <H256 as Into<aurora_engine_types::types::RawH256>>::into(key);
         ViewStorageAt::view(&self.contract).args_borsh((address, raw_key))
     }
 
+    pub fn tx_hash(&self, tx_bytes: Vec<u8>) -> ViewTxHash {
+        ViewTxHash::view(&self.contract).args(tx_bytes)
+    }
+
+    pub fn get_random_seed(&self) -> ViewRandomSeed {
+        ViewRandomSeed::view(&self.contract)
+    }
+
     pub fn get_view(
         &self,
         sender: Address,
@@ -446,6 +870,10 @@ impl EngineContract {
         ViewView::view(&self.contract).args_borsh((sender, address, raw_amount, input))
     }
 
+    pub fn simulate_deploy(&self, sender: Address, code: Vec<u8>) -> ViewSimulateDeploy {
+        ViewSimulateDeploy::view(&self.contract).args_borsh((sender, code))
+    }
+
     pub fn is_used_proof(&self, proof: Proof) -> ViewIsUsedProof {
         ViewIsUsedProof::view(&self.contract).args_borsh(proof)
     }
@@ -478,6 +906,10 @@ impl EngineContract {
         ViewAccountsCounter::view(&self.contract)
     }
 
+    pub fn get_used_proofs_count(&self) -> ViewUsedProofsCount {
+        ViewUsedProofsCount::view(&self.contract)
+    }
+
     pub fn get_eth_connector_contract_account(&self) -> ViewGetEthConnectorContractAccount {
         ViewGetEthConnectorContractAccount::view(&self.contract)
     }
@@ -486,6 +918,18 @@ impl EngineContract {
         ViewGetFixedGas::view(&self.contract)
     }
 
+    pub fn get_fixed_gas_info(&self) -> ViewGetFixedGasInfo {
+        ViewGetFixedGasInfo::view(&self.contract)
+    }
+
+    pub fn get_fee_regime(&self) -> ViewFeeRegime {
+        ViewFeeRegime::view(&self.contract)
+    }
+
+    pub fn get_gas_refunds_enabled(&self) -> ViewGasRefundsEnabled {
+        ViewGasRefundsEnabled::view(&self.contract)
+    }
+
     pub fn get_silo_params(&self) -> ViewGetSiloParams {
         ViewGetSiloParams::view(&self.contract)
     }
@@ -494,13 +938,25 @@ impl EngineContract {
         ViewGetWhitelistStatus::view(&self.contract).args_borsh(args)
     }
 
+    pub fn export_whitelists(&self) -> ViewExportWhitelists {
+        ViewExportWhitelists::view(&self.contract)
+    }
+
     pub fn factory_get_wnear_address(&self) -> ViewFactoryWnearAddress {
         ViewFactoryWnearAddress::view(&self.contract)
     }
 
+    pub fn get_xcc_near_gas_per_evm_gas(&self) -> ViewXccNearGasPerEvmGas {
+        ViewXccNearGasPerEvmGas::view(&self.contract)
+    }
+
     pub fn get_erc20_metadata(&self, identifier: Erc20Identifier) -> ViewGetErc20Metadata {
         ViewGetErc20Metadata::view(&self.contract).args_json(identifier)
     }
+
+    pub fn get_erc20_generation(&self, identifier: Erc20Identifier) -> ViewErc20Generation {
+        ViewErc20Generation::view(&self.contract).args_json(identifier)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -1,10 +1,16 @@
 use aurora_engine_types::account_id::AccountId;
 use aurora_engine_types::parameters::connector::{
-    Erc20Metadata, FungibleTokenMetadata, WithdrawResult,
+    Erc20Metadata, ExitFeeConfig, FungibleTokenMetadata, WithdrawResult,
 };
-use aurora_engine_types::parameters::engine::{StorageBalance, SubmitResult, TransactionStatus};
-use aurora_engine_types::parameters::silo::{FixedGasArgs, SiloParamsArgs, WhitelistStatusArgs};
-use aurora_engine_types::types::Address;
+use aurora_engine_types::parameters::engine::{
+    AccountInfo, EngineFeatures, EngineMetadata, EvmConfig, FeeShare, GenesisInfo, GovernanceInfo,
+    LogLevel, ModExpAlgorithmKind, PrecompileInfo, SimulateDeployResult, StorageBalance,
+    SubmitBatchResult, SubmitResult, TransactionReceipt, TransactionStatus,
+};
+use aurora_engine_types::parameters::silo::{
+    FeeRegime, FixedGasArgs, FixedGasInfo, SiloParamsArgs, WhitelistStatusArgs, WhitelistsArgs,
+};
+use aurora_engine_types::types::{Address, RawH256, Yocto};
 use aurora_engine_types::{HashMap, H256, U256};
 use near_sdk::json_types::U128;
 use near_sdk::PromiseOrValue;
@@ -57,7 +63,56 @@ impl_call_return![
     (CallAddEntryToWhitelistBatch, Call::AddEntryToWhitelistBatch),
     (CallRemoveEntryFromWhitelist, Call::RemoveEntryFromWhitelist),
     (CallSetErc20Metadata, Call::SetErc20Metadata),
-    (CallAttachFullAccessKey, Call::AttachFullAccessKey)
+    (CallAttachFullAccessKey, Call::AttachFullAccessKey),
+    (CallSetRelaxedNonce, Call::SetRelaxedNonce),
+    (CallAddDeployer, Call::AddDeployer),
+    (CallRemoveDeployer, Call::RemoveDeployer),
+    (
+        CallSetDeployerAllowlistStatus,
+        Call::SetDeployerAllowlistStatus
+    ),
+    (CallWithdrawCollectedFees, Call::WithdrawCollectedFees),
+    (CallSetMaxLogsPerTx, Call::SetMaxLogsPerTx),
+    (CallSetEngineMetadata, Call::SetEngineMetadata),
+    (CallStageUpgradeChunk, Call::StageUpgradeChunk),
+    (CallSetGasRefundsEnabled, Call::SetGasRefundsEnabled),
+    (CallSetEvmMemoryLimit, Call::SetEvmMemoryLimit),
+    (CallSetTipRecipient, Call::SetTipRecipient),
+    (CallSetErc20Template, Call::SetErc20Template),
+    (CallConfigureSilo, Call::ConfigureSilo),
+    (CallSetLogLevel, Call::SetLogLevel),
+    (CallSetDiagnosticsEnabled, Call::SetDiagnosticsEnabled),
+    (CallSetModExpAlgorithm, Call::SetModExpAlgorithm),
+    (CallSetAllowZeroGasPrice, Call::SetAllowZeroGasPrice),
+    (CallSetExitFee, Call::SetExitFee),
+    (CallPauseConnector, Call::PauseConnector),
+    (CallResumeConnector, Call::ResumeConnector),
+    (CallSetFtOnTransferGasLimit, Call::SetFtOnTransferGasLimit),
+    (CallSetAllowChainlessTx, Call::SetAllowChainlessTx),
+    (CallSetAllowEmptyInitCode, Call::SetAllowEmptyInitCode),
+    (CallSetFeeDistribution, Call::SetFeeDistribution),
+    (CallSetMaxNonceGap, Call::SetMaxNonceGap),
+    (CallSetXccNearGasPerEvmGas, Call::SetXccNearGasPerEvmGas),
+    (CallSetPaymaster, Call::SetPaymaster),
+    (
+        CallSetTxCountTrackingEnabled,
+        Call::SetTxCountTrackingEnabled
+    ),
+    (CallSetViewStepLimit, Call::SetViewStepLimit),
+    (CallImportWhitelists, Call::ImportWhitelists),
+    (
+        CallSetReceiptsPersistenceEnabled,
+        Call::SetReceiptsPersistenceEnabled
+    ),
+    (CallSetRandomSeedOverride, Call::SetRandomSeedOverride),
+    (CallSetMaxRefundQuotient, Call::SetMaxRefundQuotient),
+    (CallSetUnregisterFee, Call::SetUnregisterFee),
+    (
+        CallSetRejectUnknownPrecompiles,
+        Call::SetRejectUnknownPrecompiles
+    ),
+    (CallIndexWhitelistEntries, Call::IndexWhitelistEntries),
+    (CallSetErc20DeployGasLimit, Call::SetErc20DeployGasLimit)
 ];
 
 impl_call_return![
@@ -72,6 +127,9 @@ impl_call_return![
     (CallCall => SubmitResult, Call::Call, borsh),
     (CallSubmit => SubmitResult, Call::Submit, borsh),
     (CallFtOnTransfer => U128, Call::FtOnTransfer, json),
+    (CallRegisterRelayersBatch => u32, Call::RegisterRelayersBatch, borsh),
+    (CallFinalizeUpgradeStage => bool, Call::FinalizeUpgradeStage, borsh),
+    (CallSubmitBatch => SubmitBatchResult, Call::SubmitBatch, borsh),
 ];
 
 impl_view_return![
@@ -91,6 +149,7 @@ impl_view_return![
     (ViewBalance => U256, View::Balance, borsh_U256),
     (ViewNonce => U256, View::Nonce, borsh_U256),
     (ViewStorageAt => H256, View::StorageAt, borsh_H256),
+    (ViewTxHash => H256, View::TxHash, borsh_H256),
     (ViewView => TransactionStatus, View::View, borsh),
     (ViewIsUsedProof => bool, View::IsUsedProof, borsh),
     (ViewFtTotalEthSupplyOnAurora => U128, View::FtTotalEthSupplyOnAurora, json),
@@ -105,7 +164,60 @@ impl_view_return![
     (ViewGetSiloParams => SiloParamsArgs, View::GetSiloParams, borsh),
     (ViewGetWhitelistStatus => WhitelistStatusArgs, View::GetWhitelistStatus, borsh),
     (ViewFactoryWnearAddress => Address, View::FactoryWnearAddress, borsh),
-    (ViewGetErc20Metadata => Erc20Metadata, View::GetErc20Metadata, json)
+    (ViewGetErc20Metadata => Erc20Metadata, View::GetErc20Metadata, json),
+    (ViewChainIdU256 => U256, View::ChainIdU256, borsh_U256),
+    (ViewRandomSeed => H256, View::RandomSeed, borsh_H256),
+    (ViewCollectedFees => U256, View::CollectedFees, borsh_U256),
+    (ViewMaxLogsPerTx => Option<u64>, View::MaxLogsPerTx, borsh),
+    (ViewCodeHash => H256, View::CodeHash, borsh_H256),
+    (ViewEngineMetadata => Option<EngineMetadata>, View::EngineMetadata, borsh),
+    (ViewRelayerAccount => Vec<u8>, View::RelayerAccount, vec),
+    (ViewPrecompiles => Vec<Address>, View::Precompiles, borsh),
+    (ViewGasRefundsEnabled => bool, View::GasRefundsEnabled, borsh),
+    (ViewSimulateDeploy => SimulateDeployResult, View::SimulateDeploy, borsh),
+    (ViewEvmMemoryLimit => Option<u64>, View::EvmMemoryLimit, borsh),
+    (ViewCodeHashSelf => Option<RawH256>, View::CodeHashSelf, borsh),
+    (ViewTipRecipient => Option<Address>, View::TipRecipient, borsh),
+    (ViewErc20Template => Option<Vec<u8>>, View::Erc20Template, borsh),
+    (ViewAccountExists => bool, View::AccountExists, borsh),
+    (ViewLogLevel => LogLevel, View::LogLevel, borsh),
+    (ViewDiagnosticsEnabled => bool, View::DiagnosticsEnabled, borsh),
+    (ViewGetFixedGasInfo => FixedGasInfo, View::GetFixedGasInfo, borsh),
+    (ViewGovernance => GovernanceInfo, View::Governance, borsh),
+    (ViewModExpAlgorithm => ModExpAlgorithmKind, View::ModExpAlgorithm, borsh),
+    (ViewAllowZeroGasPrice => bool, View::AllowZeroGasPrice, borsh),
+    (ViewAccount => AccountInfo, View::Account, borsh),
+    (ViewExitFee => Option<ExitFeeConfig>, View::ExitFee, borsh),
+    (ViewStorageDepositAmount => Yocto, View::StorageDepositAmount, json),
+    (ViewFtOnTransferGasLimit => u64, View::FtOnTransferGasLimit, borsh),
+    (ViewUsedProofsCount => u64, View::UsedProofsCount, borsh),
+    (ViewAllowChainlessTx => bool, View::AllowChainlessTx, borsh),
+    (ViewBlockLogsBloom => Vec<u8>, View::BlockLogsBloom, vec),
+    (ViewFeatures => EngineFeatures, View::Features, borsh),
+    (ViewAllowEmptyInitCode => bool, View::AllowEmptyInitCode, borsh),
+    (ViewFeeDistribution => Vec<FeeShare>, View::FeeDistribution, borsh),
+    (ViewMaxNonceGap => Option<u64>, View::MaxNonceGap, borsh),
+    (ViewXccNearGasPerEvmGas => u64, View::XccNearGasPerEvmGas, borsh),
+    (ViewPaymaster => Option<Address>, View::Paymaster, borsh),
+    (ViewTxCountTrackingEnabled => bool, View::TxCountTrackingEnabled, borsh),
+    (ViewTxCount => U256, View::TxCount, borsh_U256),
+    (ViewAccounts => Vec<AccountInfo>, View::Accounts, borsh),
+    (ViewVerifySignature => u8, View::VerifySignature, borsh),
+    (ViewViewStepLimit => Option<u64>, View::ViewStepLimit, borsh),
+    (ViewExportWhitelists => WhitelistsArgs, View::ExportWhitelists, borsh),
+    (ViewReceiptsPersistenceEnabled => bool, View::ReceiptsPersistenceEnabled, borsh),
+    (ViewReceipt => Option<TransactionReceipt>, View::Receipt, borsh),
+    (ViewDeployers => Vec<Address>, View::Deployers, borsh),
+    (ViewRandomSeedOverride => Option<RawH256>, View::RandomSeedOverride, borsh),
+    (ViewMaxRefundQuotient => u64, View::MaxRefundQuotient, borsh),
+    (ViewPrecompileInfo => Vec<PrecompileInfo>, View::PrecompileInfo, borsh),
+    (ViewUnregisterFee => Yocto, View::UnregisterFee, borsh),
+    (ViewErc20Generation => u64, View::Erc20Generation, json),
+    (ViewGenesisInfo => Option<GenesisInfo>, View::GenesisInfo, borsh),
+    (ViewRejectUnknownPrecompiles => bool, View::RejectUnknownPrecompiles, borsh),
+    (ViewFeeRegime => FeeRegime, View::FeeRegime, borsh),
+    (ViewEvmConfig => EvmConfig, View::EvmConfig, borsh),
+    (ViewErc20DeployGasLimit => u64, View::Erc20DeployGasLimit, borsh)
 ];
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -156,6 +268,46 @@ pub(crate) enum Call {
     RemoveEntryFromWhitelist,
     SetErc20Metadata,
     AttachFullAccessKey,
+    SetRelaxedNonce,
+    RegisterRelayersBatch,
+    AddDeployer,
+    RemoveDeployer,
+    SetDeployerAllowlistStatus,
+    WithdrawCollectedFees,
+    SetMaxLogsPerTx,
+    SetEngineMetadata,
+    StageUpgradeChunk,
+    FinalizeUpgradeStage,
+    SetGasRefundsEnabled,
+    SetEvmMemoryLimit,
+    SetTipRecipient,
+    SetErc20Template,
+    ConfigureSilo,
+    SetLogLevel,
+    SetDiagnosticsEnabled,
+    SetModExpAlgorithm,
+    SetAllowZeroGasPrice,
+    SetExitFee,
+    PauseConnector,
+    ResumeConnector,
+    SetFtOnTransferGasLimit,
+    SetAllowChainlessTx,
+    SetAllowEmptyInitCode,
+    SetFeeDistribution,
+    SetMaxNonceGap,
+    SetXccNearGasPerEvmGas,
+    SetPaymaster,
+    SetTxCountTrackingEnabled,
+    SetViewStepLimit,
+    ImportWhitelists,
+    SetReceiptsPersistenceEnabled,
+    SetRandomSeedOverride,
+    SubmitBatch,
+    SetMaxRefundQuotient,
+    SetUnregisterFee,
+    SetRejectUnknownPrecompiles,
+    IndexWhitelistEntries,
+    SetErc20DeployGasLimit,
 }
 
 impl AsRef<str> for Call {
@@ -206,6 +358,46 @@ impl AsRef<str> for Call {
             Call::RemoveEntryFromWhitelist => "remove_entry_from_whitelist",
             Call::SetErc20Metadata => "set_erc20_metadata",
             Call::AttachFullAccessKey => "attach_full_access_key",
+            Call::SetRelaxedNonce => "set_relaxed_nonce",
+            Call::RegisterRelayersBatch => "register_relayers_batch",
+            Call::AddDeployer => "add_deployer",
+            Call::RemoveDeployer => "remove_deployer",
+            Call::SetDeployerAllowlistStatus => "set_deployer_allowlist_status",
+            Call::WithdrawCollectedFees => "withdraw_collected_fees",
+            Call::SetMaxLogsPerTx => "set_max_logs_per_tx",
+            Call::SetEngineMetadata => "set_engine_metadata",
+            Call::StageUpgradeChunk => "stage_upgrade_chunk",
+            Call::FinalizeUpgradeStage => "finalize_upgrade_stage",
+            Call::SetGasRefundsEnabled => "set_gas_refunds_enabled",
+            Call::SetEvmMemoryLimit => "set_evm_memory_limit",
+            Call::SetTipRecipient => "set_tip_recipient",
+            Call::SetErc20Template => "set_erc20_template",
+            Call::ConfigureSilo => "configure_silo",
+            Call::SetLogLevel => "set_log_level",
+            Call::SetDiagnosticsEnabled => "set_diagnostics_enabled",
+            Call::SetModExpAlgorithm => "set_modexp_algorithm",
+            Call::SetAllowZeroGasPrice => "set_allow_zero_gas_price",
+            Call::SetExitFee => "set_exit_fee",
+            Call::PauseConnector => "pause_connector",
+            Call::ResumeConnector => "resume_connector",
+            Call::SetFtOnTransferGasLimit => "set_ft_on_transfer_gas_limit",
+            Call::SetAllowChainlessTx => "set_allow_chainless_tx",
+            Call::SetAllowEmptyInitCode => "set_allow_empty_init_code",
+            Call::SetFeeDistribution => "set_fee_distribution",
+            Call::SetMaxNonceGap => "set_max_nonce_gap",
+            Call::SetXccNearGasPerEvmGas => "set_xcc_near_gas_per_evm_gas",
+            Call::SetPaymaster => "set_paymaster",
+            Call::SetTxCountTrackingEnabled => "set_tx_count_tracking_enabled",
+            Call::SetViewStepLimit => "set_view_step_limit",
+            Call::ImportWhitelists => "import_whitelists",
+            Call::SetReceiptsPersistenceEnabled => "set_receipts_persistence_enabled",
+            Call::SetRandomSeedOverride => "set_random_seed_override",
+            Call::SubmitBatch => "submit_batch",
+            Call::SetMaxRefundQuotient => "set_max_refund_quotient",
+            Call::SetUnregisterFee => "set_unregister_fee",
+            Call::SetRejectUnknownPrecompiles => "set_reject_unknown_precompiles",
+            Call::IndexWhitelistEntries => "index_whitelist_entries",
+            Call::SetErc20DeployGasLimit => "set_erc20_deploy_gas_limit",
         }
     }
 }
@@ -223,6 +415,7 @@ pub enum View {
     Balance,
     Nonce,
     StorageAt,
+    TxHash,
     View,
     IsUsedProof,
     FtTotalSupply,
@@ -243,6 +436,59 @@ pub enum View {
     GetWhitelistStatus,
     FactoryWnearAddress,
     GetErc20Metadata,
+    ChainIdU256,
+    RandomSeed,
+    CollectedFees,
+    MaxLogsPerTx,
+    CodeHash,
+    EngineMetadata,
+    RelayerAccount,
+    Precompiles,
+    GasRefundsEnabled,
+    SimulateDeploy,
+    EvmMemoryLimit,
+    CodeHashSelf,
+    TipRecipient,
+    Erc20Template,
+    AccountExists,
+    LogLevel,
+    DiagnosticsEnabled,
+    GetFixedGasInfo,
+    Governance,
+    ModExpAlgorithm,
+    AllowZeroGasPrice,
+    Account,
+    ExitFee,
+    StorageDepositAmount,
+    FtOnTransferGasLimit,
+    UsedProofsCount,
+    AllowChainlessTx,
+    BlockLogsBloom,
+    Features,
+    AllowEmptyInitCode,
+    FeeDistribution,
+    MaxNonceGap,
+    XccNearGasPerEvmGas,
+    Paymaster,
+    TxCountTrackingEnabled,
+    TxCount,
+    Accounts,
+    VerifySignature,
+    ViewStepLimit,
+    ExportWhitelists,
+    ReceiptsPersistenceEnabled,
+    Receipt,
+    Deployers,
+    RandomSeedOverride,
+    MaxRefundQuotient,
+    PrecompileInfo,
+    UnregisterFee,
+    Erc20Generation,
+    GenesisInfo,
+    RejectUnknownPrecompiles,
+    FeeRegime,
+    EvmConfig,
+    Erc20DeployGasLimit,
 }
 
 impl AsRef<str> for View {
@@ -259,6 +505,7 @@ impl AsRef<str> for View {
             View::Balance => "get_balance",
             View::Nonce => "get_nonce",
             View::StorageAt => "get_storage_at",
+            View::TxHash => "tx_hash",
             View::View => "get_view",
             View::IsUsedProof => "is_used_proof",
             View::FtTotalSupply => "ft_total_supply",
@@ -279,6 +526,59 @@ impl AsRef<str> for View {
             View::GetWhitelistStatus => "get_whitelist_status",
             View::FactoryWnearAddress => "factory_get_wnear_address",
             View::GetErc20Metadata => "get_erc20_metadata",
+            View::ChainIdU256 => "get_chain_id_u256",
+            View::RandomSeed => "get_random_seed",
+            View::CollectedFees => "get_collected_fees",
+            View::MaxLogsPerTx => "get_max_logs_per_tx",
+            View::CodeHash => "get_code_hash",
+            View::EngineMetadata => "get_engine_metadata",
+            View::RelayerAccount => "get_relayer_account",
+            View::Precompiles => "get_precompiles",
+            View::GasRefundsEnabled => "get_gas_refunds_enabled",
+            View::SimulateDeploy => "simulate_deploy",
+            View::EvmMemoryLimit => "get_evm_memory_limit",
+            View::CodeHashSelf => "get_code_hash_self",
+            View::TipRecipient => "get_tip_recipient",
+            View::Erc20Template => "get_erc20_template",
+            View::AccountExists => "account_exists",
+            View::LogLevel => "get_log_level",
+            View::DiagnosticsEnabled => "get_diagnostics_enabled",
+            View::GetFixedGasInfo => "get_fixed_gas_info",
+            View::Governance => "get_governance",
+            View::ModExpAlgorithm => "get_modexp_algorithm",
+            View::AllowZeroGasPrice => "get_allow_zero_gas_price",
+            View::Account => "get_account",
+            View::ExitFee => "get_exit_fee",
+            View::StorageDepositAmount => "get_storage_deposit_amount",
+            View::FtOnTransferGasLimit => "get_ft_on_transfer_gas_limit",
+            View::UsedProofsCount => "get_used_proofs_count",
+            View::AllowChainlessTx => "get_allow_chainless_tx",
+            View::BlockLogsBloom => "get_block_logs_bloom",
+            View::Features => "get_features",
+            View::AllowEmptyInitCode => "get_allow_empty_init_code",
+            View::FeeDistribution => "get_fee_distribution",
+            View::MaxNonceGap => "get_max_nonce_gap",
+            View::XccNearGasPerEvmGas => "get_xcc_near_gas_per_evm_gas",
+            View::Paymaster => "get_paymaster",
+            View::TxCountTrackingEnabled => "get_tx_count_tracking_enabled",
+            View::TxCount => "get_tx_count",
+            View::Accounts => "get_accounts",
+            View::VerifySignature => "verify_signature",
+            View::ViewStepLimit => "get_view_step_limit",
+            View::ExportWhitelists => "export_whitelists",
+            View::ReceiptsPersistenceEnabled => "get_receipts_persistence_enabled",
+            View::Receipt => "get_receipt",
+            View::Deployers => "get_deployers",
+            View::RandomSeedOverride => "get_random_seed_override",
+            View::MaxRefundQuotient => "get_max_refund_quotient",
+            View::PrecompileInfo => "get_precompile_info",
+            View::UnregisterFee => "get_unregister_fee",
+            View::Erc20Generation => "get_erc20_generation",
+            View::GenesisInfo => "get_genesis_info",
+            View::RejectUnknownPrecompiles => "get_reject_unknown_precompiles",
+            View::FeeRegime => "get_fee_regime",
+            View::EvmConfig => "get_evm_config",
+            View::Erc20DeployGasLimit => "get_erc20_deploy_gas_limit",
         }
     }
 }
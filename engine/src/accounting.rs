@@ -1,3 +1,4 @@
+use aurora_engine_types::types::Address;
 use aurora_engine_types::U256;
 use core::cmp::Ordering;
 
@@ -53,6 +54,42 @@ pub enum Net {
     Lost(U256),
 }
 
+/// Quotient of the refund cap introduced by EIP-3529.
+pub const DEFAULT_MAX_REFUND_QUOTIENT: u64 = 5;
+
+/// Returns the amount of gas used by an EVM execution. `total_used_gas` is the value without the
+/// refunds (e.g. for clearing storage slots with `SSTORE`), which are deducted up to
+/// `total_used_gas / max_refund_quotient`. A zero quotient caps the refunds at zero, the same as
+/// disabling them.
+#[must_use]
+pub fn used_gas(
+    total_used_gas: u64,
+    refunded_gas: i64,
+    max_refund_quotient: u64,
+    refunds_enabled: bool,
+) -> u64 {
+    if !refunds_enabled {
+        return total_used_gas;
+    }
+
+    let refunded_gas = u64::try_from(refunded_gas).unwrap_or_default();
+    let max_refund = total_used_gas
+        .checked_div(max_refund_quotient)
+        .unwrap_or_default();
+    total_used_gas - refunded_gas.min(max_refund)
+}
+
+/// Returns the account credited with the priority fee of a transaction. If no tip recipient is
+/// configured, the relayer receives it. The zero address means that the priority fee is burned.
+#[must_use]
+pub fn priority_fee_recipient(tip_recipient: Option<Address>, relayer: Address) -> Option<Address> {
+    match tip_recipient {
+        None => Some(relayer),
+        Some(recipient) if recipient == Address::zero() => None,
+        Some(recipient) => Some(recipient),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,4 +180,43 @@ mod tests {
 
         assert_eq!(expected_net, actual_net);
     }
+
+    #[test]
+    fn test_used_gas_without_refunds() {
+        assert_eq!(
+            used_gas(26_000, 4_800, DEFAULT_MAX_REFUND_QUOTIENT, true),
+            21_200
+        );
+        assert_eq!(
+            used_gas(26_000, 4_800, DEFAULT_MAX_REFUND_QUOTIENT, false),
+            26_000
+        );
+    }
+
+    #[test]
+    fn test_used_gas_refund_cap() {
+        // The refund is capped at 50_000 / 5.
+        assert_eq!(
+            used_gas(50_000, 19_900, DEFAULT_MAX_REFUND_QUOTIENT, true),
+            40_000
+        );
+        assert_eq!(used_gas(50_000, 19_900, 10, true), 45_000);
+        assert_eq!(used_gas(50_000, 19_900, 2, true), 30_100);
+        assert_eq!(used_gas(50_000, 19_900, 0, true), 50_000);
+        // The refund counter can be negative in the middle of an execution.
+        assert_eq!(used_gas(50_000, -100, 2, true), 50_000);
+    }
+
+    #[test]
+    fn test_priority_fee_recipient() {
+        let relayer = Address::from_array([1; 20]);
+        let recipient = Address::from_array([2; 20]);
+
+        assert_eq!(priority_fee_recipient(None, relayer), Some(relayer));
+        assert_eq!(
+            priority_fee_recipient(Some(recipient), relayer),
+            Some(recipient)
+        );
+        assert_eq!(priority_fee_recipient(Some(Address::zero()), relayer), None);
+    }
 }
@@ -7,10 +7,9 @@
 //! the smart contract and the standalone.
 
 use crate::{
-    contract_methods::connector::EthConnectorContract,
     contract_methods::{
         predecessor_address, require_key_manager_only, require_owner_only, require_paused,
-        require_running, ContractError,
+        require_running, silo, ContractError,
     },
     engine::{self, Engine},
     errors,
@@ -23,6 +22,7 @@ use crate::{
 };
 use aurora_engine_hashchain::{bloom::Bloom, hashchain::Hashchain};
 use aurora_engine_modexp::AuroraModExp;
+use aurora_engine_precompiles::native::exit_fee;
 use aurora_engine_sdk::{
     env::Env,
     error::ReadU64Error,
@@ -32,22 +32,38 @@ use aurora_engine_sdk::{
 use aurora_engine_types::parameters::engine::{FullAccessKeyArgs, UpgradeParams};
 use aurora_engine_types::types::{NearGas, ZERO_YOCTO};
 use aurora_engine_types::{
-    borsh::BorshDeserialize,
+    borsh::{self, BorshDeserialize},
     parameters::{
+        connector::SetExitFeeArgs,
         engine::{
-            NewCallArgs, PausePrecompilesCallArgs, RelayerKeyArgs, RelayerKeyManagerArgs,
-            SetOwnerArgs, SetUpgradeDelayBlocksArgs, StartHashchainArgs,
+            DeployerAllowlistStatusArgs, DeployerArgs, EngineFeatures, EngineMetadata,
+            FinalizeUpgradeStageArgs, GetDeployersArgs, GovernanceInfo, NewCallArgs,
+            PausePrecompilesCallArgs, RegisterRelayersBatchArgs, RelayerKeyArgs,
+            RelayerKeyManagerArgs, SetErc20DeployGasLimitArgs, SetErc20TemplateArgs,
+            SetEvmMemoryLimitArgs, SetFeeDistributionArgs, SetFtOnTransferGasLimitArgs,
+            SetLogLevelArgs, SetMaxLogsPerTxArgs, SetMaxNonceGapArgs, SetMaxRefundQuotientArgs,
+            SetModExpAlgorithmArgs, SetOwnerArgs, SetPaymasterArgs, SetRandomSeedOverrideArgs,
+            SetRelaxedNonceArgs, SetTipRecipientArgs, SetUnregisterFeeArgs,
+            SetUpgradeDelayBlocksArgs, SetViewStepLimitArgs, SimulateDeployArgs,
+            StageUpgradeChunkArgs, StartHashchainArgs, TransactionStatus,
+            WithdrawCollectedFeesArgs,
         },
         promise::{PromiseAction, PromiseBatchAction},
+        silo::{WhitelistArgs, WhitelistsArgs},
     },
     storage::{self, KeyPrefix},
-    types::{Address, Yocto},
-    vec, ToString,
+    types::{u256_to_arr, Address, RawH256, Yocto},
+    vec, ToString, Vec, H256, U256,
 };
 use function_name::named;
 
 const CODE_KEY: &[u8; 4] = b"CODE";
 const CODE_STAGE_KEY: &[u8; 10] = b"CODE_STAGE";
+const CODE_HASH_KEY: &[u8; 9] = b"CODE_HASH";
+const PENDING_CODE_HASH_KEY: &[u8; 17] = b"PENDING_CODE_HASH";
+const CODE_CHUNKS_KEY: &[u8; 11] = b"CODE_CHUNKS";
+const CODE_CHUNKS_INDEX_KEY: &[u8; 17] = b"CODE_CHUNKS_INDEX";
+const ENGINE_METADATA_KEY: &[u8; 15] = b"ENGINE_METADATA";
 const GAS_FOR_STATE_MIGRATION: NearGas = NearGas::new(50_000_000_000_000);
 
 #[named]
@@ -60,6 +76,7 @@ pub fn new<I: IO + Copy, E: Env>(mut io: I, env: &E) -> Result<(), ContractError
     let args = NewCallArgs::deserialize(&input).map_err(|_| errors::ERR_BORSH_DESERIALIZE)?;
 
     let initial_hashchain = args.initial_hashchain();
+    let code_hash = args.code_hash();
     let state: EngineState = args.into();
 
     if let Some(block_hashchain) = initial_hashchain {
@@ -79,6 +96,20 @@ pub fn new<I: IO + Copy, E: Env>(mut io: I, env: &E) -> Result<(), ContractError
             &Bloom::default(),
         )?;
         crate::hashchain::save_hashchain(&mut io, &hashchain)?;
+        crate::hashchain::save_initial_hashchain(
+            &mut io,
+            &StartHashchainArgs {
+                block_height: block_height.saturating_sub(1),
+                block_hashchain,
+            },
+        );
+    }
+
+    if let Some(code_hash) = code_hash {
+        io.write_storage(
+            &storage::bytes_to_key(KeyPrefix::Config, CODE_HASH_KEY),
+            &code_hash,
+        );
     }
 
     state::set_state(&mut io, &state)?;
@@ -92,6 +123,48 @@ pub fn get_version<I: IO>(mut io: I) -> Result<(), ContractError> {
     Ok(())
 }
 
+/// Returns the borsh-encoded keccak256 hash of the engine code (not the staged one). The NEAR
+/// runtime doesn't give a contract access to its own code, so the hash is recorded by `new` if
+/// the deployer provides it, and when the code is deployed via `upgrade` or `deploy_upgrade`.
+/// The value is only guaranteed to be the hash of the running code for the upgrades made through
+/// the engine: it is `None` or outdated if the code was deployed by other means (e.g. directly
+/// with a full access key), and the hash given to `new` isn't verified.
+pub fn get_code_hash_self<I: IO>(mut io: I) -> Result<(), ContractError> {
+    let code_hash = io
+        .read_storage(&storage::bytes_to_key(KeyPrefix::Config, CODE_HASH_KEY))
+        .and_then(|value| value.to_value::<RawH256>().ok());
+    let data = borsh::to_vec(&code_hash).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+/// Records the hash of the code which is about to be deployed. The hash becomes the one returned
+/// by `get_code_hash_self` only once the deployment succeeded (see `state_migration`).
+pub fn set_pending_code_hash<I: IO>(io: &mut I, code_hash: H256) {
+    io.write_storage(
+        &storage::bytes_to_key(KeyPrefix::Config, PENDING_CODE_HASH_KEY),
+        &code_hash.0,
+    );
+}
+
+/// Called by the engine itself right after the new code was deployed.
+pub fn state_migration<I: IO, E: Env>(mut io: I, env: &E) -> Result<(), ContractError> {
+    // The pending code hash is only committed by the receipt which deployed the code.
+    if env.predecessor_account_id() != env.current_account_id() {
+        return Ok(());
+    }
+    if let Some(code_hash) = io.remove_storage(&storage::bytes_to_key(
+        KeyPrefix::Config,
+        PENDING_CODE_HASH_KEY,
+    )) {
+        io.write_storage(
+            &storage::bytes_to_key(KeyPrefix::Config, CODE_HASH_KEY),
+            &code_hash.to_vec(),
+        );
+    }
+    Ok(())
+}
+
 pub fn get_owner<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
     let state = state::get_state(&io)?;
     io.return_output(state.owner_id.as_bytes());
@@ -118,24 +191,15 @@ pub fn set_owner<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractErr
     })
 }
 
-pub fn get_bridge_prover<I: IO + Copy + PromiseHandler>(mut io: I) -> Result<(), ContractError> {
-    let connector = EthConnectorContract::init(io)?;
-
-    #[cfg(not(feature = "ext-connector"))]
-    io.return_output(connector.get_bridge_prover().as_bytes());
-
-    #[cfg(feature = "ext-connector")]
-    {
-        let promise_args = connector.get_bridge_prover();
-        let promise_id = unsafe { io.promise_create_call(&promise_args) };
-        io.promise_return(promise_id);
-    }
-
+pub fn get_chain_id<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    io.return_output(&state::get_state(&io)?.chain_id);
     Ok(())
 }
 
-pub fn get_chain_id<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
-    io.return_output(&state::get_state(&io)?.chain_id);
+/// Returns the chain id as a 32-byte big-endian value, the same way the `CHAINID` opcode sees it.
+pub fn get_chain_id_u256<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let chain_id = U256::from_big_endian(&state::get_state(&io)?.chain_id);
+    io.return_output(&u256_to_arr(&chain_id));
     Ok(())
 }
 
@@ -145,6 +209,25 @@ pub fn get_upgrade_delay_blocks<I: IO + Copy>(mut io: I) -> Result<(), ContractE
     Ok(())
 }
 
+pub fn get_state_version<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let state = state::get_state(&io)?;
+    io.return_output(&state.state_version.to_le_bytes());
+    Ok(())
+}
+
+/// Returns the owner, the key manager and the upgrade delay in a single borsh-encoded struct.
+pub fn get_governance<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let state = state::get_state(&io)?;
+    let governance = GovernanceInfo {
+        owner: state.owner_id,
+        key_manager: state.key_manager,
+        upgrade_delay_blocks: state.upgrade_delay_blocks,
+    };
+    let data = borsh::to_vec(&governance).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
 #[named]
 pub fn set_upgrade_delay_blocks<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
     with_hashchain(io, env, function_name!(), |mut io| {
@@ -180,8 +263,93 @@ pub fn stage_upgrade<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), Contrac
     })
 }
 
+/// Stores a chunk of the new code for staging. The chunks must be uploaded in order;
+/// index `0` discards any previous partial upload and starts a new one. Each chunk is stored under
+/// its own key, so they are only joined once by `finalize_upgrade_stage`.
+#[named]
+pub fn stage_upgrade_chunk<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: StageUpgradeChunkArgs = io.read_input_borsh()?;
+        let index_key = storage::bytes_to_key(KeyPrefix::Config, CODE_CHUNKS_INDEX_KEY);
+        let index = u64::from(args.index);
+
+        if index == 0 {
+            // The first chunk is overwritten below.
+            let previous_count = io.read_u64(&index_key).unwrap_or_default();
+            for previous_index in 1..previous_count {
+                io.remove_storage(&upgrade_chunk_key(previous_index));
+            }
+        } else {
+            let expected_index = io
+                .read_u64(&index_key)
+                .map_err(|_| errors::ERR_INVALID_UPGRADE_CHUNK)?;
+            if index != expected_index {
+                return Err(errors::ERR_INVALID_UPGRADE_CHUNK.into());
+            }
+        }
+
+        io.write_storage(&upgrade_chunk_key(index), &args.bytes);
+        io.write_storage(&index_key, &(index + 1).to_le_bytes());
+        Ok(())
+    })
+}
+
+/// Joins the chunks uploaded by `stage_upgrade_chunk`, validates the code and stages it for
+/// deployment. The chunks are removed in any case, so the output is borsh-encoded `false` if the
+/// length or the keccak256 hash of the assembled code doesn't match the expected one (returning
+/// an error would revert the clean-up).
+#[named]
+pub fn finalize_upgrade_stage<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        let delay_block_height = env.block_height() + state.upgrade_delay_blocks;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: FinalizeUpgradeStageArgs = io.read_input_borsh()?;
+        let chunks_count = io
+            .remove_storage(&storage::bytes_to_key(
+                KeyPrefix::Config,
+                CODE_CHUNKS_INDEX_KEY,
+            ))
+            .and_then(|value| value.to_value::<u64>().ok())
+            .ok_or(errors::ERR_NO_UPGRADE_CHUNKS)?;
+        let mut code = Vec::new();
+        for index in 0..chunks_count {
+            if let Some(chunk) = io.remove_storage(&upgrade_chunk_key(index)) {
+                code.extend(chunk.to_vec());
+            }
+        }
+
+        let is_valid =
+            code.len() as u64 == args.total_len && aurora_engine_sdk::keccak(&code).0 == args.hash;
+        if is_valid {
+            io.write_storage(&storage::bytes_to_key(KeyPrefix::Config, CODE_KEY), &code);
+            io.write_storage(
+                &storage::bytes_to_key(KeyPrefix::Config, CODE_STAGE_KEY),
+                &delay_block_height.to_le_bytes(),
+            );
+        }
+
+        let data = borsh::to_vec(&is_valid).map_err(|_| errors::ERR_SERIALIZE)?;
+        io.return_output(&data);
+        Ok(())
+    })
+}
+
+fn upgrade_chunk_key(index: u64) -> Vec<u8> {
+    storage::bytes_to_key(
+        KeyPrefix::Config,
+        &[CODE_CHUNKS_KEY.as_slice(), &index.to_le_bytes()].concat(),
+    )
+}
+
 pub fn upgrade<I: IO + Copy, E: Env, H: PromiseHandler>(
-    io: I,
+    mut io: I,
     env: &E,
     handler: &mut H,
 ) -> Result<(), ContractError> {
@@ -198,6 +366,7 @@ pub fn upgrade<I: IO + Copy, E: Env, H: PromiseHandler>(
         ),
         Err(_) => (input, GAS_FOR_STATE_MIGRATION), // Backward compatibility
     };
+    set_pending_code_hash(&mut io, aurora_engine_sdk::keccak(&code));
 
     let target_account_id = env.current_account_id();
     let batch = PromiseBatchAction {
@@ -411,60 +580,886 @@ pub fn register_relayer<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), Cont
 }
 
 #[named]
-pub fn start_hashchain<I: IO + Copy, E: Env>(mut io: I, env: &E) -> Result<(), ContractError> {
-    let mut state = state::get_state(&io)?;
-    require_paused(&state)?;
-    require_key_manager_only(&state, &env.predecessor_account_id())?;
+pub fn register_relayers_batch<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        let predecessor_account_id = env.predecessor_account_id();
+        // The key manager is allowed as well, but any other caller gets the owner-only error.
+        if state.key_manager.as_ref() != Some(&predecessor_account_id) {
+            require_owner_only(&state, &predecessor_account_id)?;
+        }
 
-    let input = io.read_input().to_vec();
-    let args = StartHashchainArgs::try_from_slice(&input).map_err(|_| errors::ERR_SERIALIZE)?;
-    let block_height = env.block_height();
+        let args: RegisterRelayersBatchArgs = io.read_input_borsh()?;
+        let current_account_id = env.current_account_id();
+        let mut engine: Engine<_, E, AuroraModExp> = Engine::new_with_state(
+            state,
+            predecessor_address(&predecessor_account_id),
+            current_account_id,
+            io,
+            env,
+        );
+        let mut added: u32 = 0;
+
+        for (account_id, evm_address) in args.relayers {
+            // Already registered relayers are skipped rather than overwritten.
+            if engine.get_relayer(account_id.as_bytes()).is_some() {
+                continue;
+            }
+            engine.register_relayer(account_id.as_bytes(), evm_address);
+            added += 1;
+        }
 
-    // Starting hashchain must be for an earlier block
-    if block_height < args.block_height {
-        return Err(errors::ERR_ARGS.into());
-    }
+        io.return_output(&added.to_le_bytes());
+        Ok(())
+    })
+}
 
-    let mut hashchain = Hashchain::new(
-        state.chain_id,
-        env.current_account_id(),
-        args.block_height + 1,
-        args.block_hashchain,
-    );
+#[named]
+pub fn set_relaxed_nonce<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
 
-    if hashchain.get_current_block_height() < block_height {
-        hashchain.move_to_block(block_height)?;
-    }
+        let args: SetRelaxedNonceArgs = io.read_input_borsh()?;
+        let current_account_id = env.current_account_id();
+        let engine: Engine<_, E, AuroraModExp> = Engine::new_with_state(
+            state,
+            predecessor_address(&env.predecessor_account_id()),
+            current_account_id,
+            io,
+            env,
+        );
 
-    hashchain.add_block_tx(
-        block_height,
-        function_name!(),
-        &input,
-        &[],
-        &Bloom::default(),
-    )?;
-    crate::hashchain::save_hashchain(&mut io, &hashchain)?;
+        // The relaxed nonce mode is only allowed for registered relayers.
+        if args.enabled && engine.get_relayer(args.relayer.as_bytes()).is_none() {
+            return Err(errors::ERR_RELAYER_NOT_REGISTERED.into());
+        }
 
-    state.is_paused = false;
-    state::set_state(&mut io, &state)?;
+        engine::set_relaxed_nonce(&mut io, &args.relayer, args.enabled);
+        Ok(())
+    })
+}
 
+#[named]
+pub fn add_deployer<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: DeployerArgs = io.read_input_borsh()?;
+        engine::add_deployer(&mut io, &args.address);
+        Ok(())
+    })
+}
+
+#[named]
+pub fn remove_deployer<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: DeployerArgs = io.read_input_borsh()?;
+        engine::remove_deployer(&mut io, &args.address);
+        Ok(())
+    })
+}
+
+#[named]
+pub fn set_deployer_allowlist_status<I: IO + Copy, E: Env>(
+    io: I,
+    env: &E,
+) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: DeployerAllowlistStatusArgs = io.read_input_borsh()?;
+        engine::set_deployer_allowlist_status(&mut io, args.enabled);
+        Ok(())
+    })
+}
+
+/// Returns a page of the addresses allowed to deploy contracts.
+pub fn get_deployers<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let args: GetDeployersArgs = io.read_input_borsh()?;
+    let deployers = engine::get_deployers(&io, args.from_index, args.limit);
+    let data = borsh::to_vec(&deployers).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
     Ok(())
 }
 
-pub fn get_latest_hashchain<I: IO>(io: &mut I) -> Result<(), ContractError> {
-    let result = crate::hashchain::read_current_hashchain(io)?.map(|hc| {
-        let block_height = hc.get_current_block_height() - 1;
-        let hashchain = hex::encode(hc.get_previous_block_hashchain());
-        serde_json::json!({
-            "block_height": block_height,
-            "hashchain": hashchain,
-        })
-    });
+/// Returns the total amount of fees collected by the engine as a 32-byte big-endian value.
+pub fn get_collected_fees<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let amount = engine::get_collected_fees(&io);
+    io.return_output(&amount.to_bytes());
+    Ok(())
+}
 
-    let bytes = serde_json::to_vec(&serde_json::json!({ "result": result }))
-        .map_err(|_| errors::ERR_SERIALIZE)?;
-    io.return_output(&bytes);
+#[named]
+pub fn withdraw_collected_fees<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: WithdrawCollectedFeesArgs = io.read_input_borsh()?;
+        let shares = engine::get_fee_distribution(&io);
+        // The treasury from the arguments would be ignored by the configured distribution,
+        // and without a distribution the fees must not be credited to the zero address.
+        if !shares.is_empty() && args.treasury != Address::zero() {
+            return Err(errors::ERR_TREASURY_WITH_FEE_DISTRIBUTION.into());
+        }
+        if shares.is_empty() && args.treasury == Address::zero() {
+            return Err(errors::ERR_ZERO_TREASURY.into());
+        }
+        // The accumulator is reset in the same call, so the fees can't be withdrawn twice.
+        let amount = engine::take_collected_fees(&mut io);
+        let parts = if shares.is_empty() {
+            vec![(args.treasury, amount)]
+        } else {
+            engine::split_fees(amount, &shares)
+        };
+
+        for (address, part) in parts {
+            engine::add_balance(&mut io, &address, part)
+                .map_err(|_| errors::ERR_BALANCE_OVERFLOW)?;
+            aurora_engine_sdk::log!("Withdrawn collected fees: {part} to {}", address.encode());
+        }
+
+        io.return_output(&amount.to_bytes());
+        Ok(())
+    })
+}
+
+pub fn get_fee_distribution<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let shares = engine::get_fee_distribution(&io);
+    let data = borsh::to_vec(&shares).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+#[named]
+pub fn set_fee_distribution<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetFeeDistributionArgs = io.read_input_borsh()?;
+        if !args.is_valid() {
+            return Err(errors::ERR_INVALID_FEE_DISTRIBUTION.into());
+        }
+        engine::set_fee_distribution(&mut io, &args.shares);
+        Ok(())
+    })
+}
+
+pub fn get_max_logs_per_tx<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let max_logs = engine::get_max_logs_per_tx(&io);
+    let data = borsh::to_vec(&max_logs).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+#[named]
+pub fn set_max_logs_per_tx<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetMaxLogsPerTxArgs = io.read_input_borsh()?;
+        engine::set_max_logs_per_tx(&mut io, args.max_logs);
+        Ok(())
+    })
+}
+
+pub fn get_max_nonce_gap<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let max_gap = engine::get_max_nonce_gap(&io);
+    let data = borsh::to_vec(&max_gap).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+#[named]
+pub fn set_max_nonce_gap<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetMaxNonceGapArgs = io.read_input_borsh()?;
+        engine::set_max_nonce_gap(&mut io, args.max_gap);
+        Ok(())
+    })
+}
+
+pub fn get_max_refund_quotient<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let quotient = engine::get_max_refund_quotient(&io);
+    let data = borsh::to_vec(&quotient).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+#[named]
+pub fn set_max_refund_quotient<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetMaxRefundQuotientArgs = io.read_input_borsh()?;
+        engine::set_max_refund_quotient(&mut io, args.quotient);
+        Ok(())
+    })
+}
+
+pub fn get_evm_config<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let config = engine::get_evm_config(&io);
+    let data = borsh::to_vec(&config).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+pub fn get_features<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let features = EngineFeatures {
+        ext_connector: cfg!(feature = "ext-connector"),
+        silo_enabled: silo::is_silo_mode_on(&io),
+        fork: engine::EVM_FORK.into(),
+        tracing: cfg!(feature = "tracing"),
+    };
+    let data = borsh::to_vec(&features).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+pub fn get_engine_metadata<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let metadata = io
+        .read_storage(&storage::bytes_to_key(
+            KeyPrefix::Config,
+            ENGINE_METADATA_KEY,
+        ))
+        .map(|value| value.to_value::<EngineMetadata>())
+        .transpose()?;
+    let data = borsh::to_vec(&metadata).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+#[named]
+pub fn set_engine_metadata<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let metadata: EngineMetadata = io.read_input_borsh()?;
+        if !metadata.is_valid() {
+            return Err(errors::ERR_ENGINE_METADATA_TOO_LONG.into());
+        }
+        io.write_borsh(
+            &storage::bytes_to_key(KeyPrefix::Config, ENGINE_METADATA_KEY),
+            &metadata,
+        );
+        Ok(())
+    })
+}
+
+pub fn get_evm_memory_limit<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let memory_limit = engine::get_evm_memory_limit(&io);
+    let data = borsh::to_vec(&memory_limit).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+#[named]
+pub fn set_evm_memory_limit<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetEvmMemoryLimitArgs = io.read_input_borsh()?;
+        engine::set_evm_memory_limit(&mut io, args.memory_limit);
+        Ok(())
+    })
+}
+
+pub fn get_view_step_limit<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let step_limit = engine::get_view_step_limit(&io);
+    let data = borsh::to_vec(&step_limit).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+/// Sets the maximum number of EVM opcodes a view call may execute. The contract only exports it
+/// if it is built with the `view-step-limit` feature, which is the only build enforcing the limit.
+#[named]
+pub fn set_view_step_limit<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetViewStepLimitArgs = io.read_input_borsh()?;
+        engine::set_view_step_limit(&mut io, args.step_limit);
+        Ok(())
+    })
+}
+
+pub fn get_random_seed_override<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let random_seed = engine::get_random_seed_override(&io).map(|seed| seed.0);
+    let data = borsh::to_vec(&random_seed).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+/// Pins the seed returned by the random seed precompile, which makes the contracts relying on it
+/// deterministic in the test silos. The contract only exports it if it is built with the
+/// `random-seed-override` feature.
+#[named]
+pub fn set_random_seed_override<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetRandomSeedOverrideArgs = io.read_input_borsh()?;
+        engine::set_random_seed_override(&mut io, args.random_seed);
+        Ok(())
+    })
+}
+
+pub fn export_whitelists<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let whitelists = silo::export_whitelists(&io);
+    let data = borsh::to_vec(&whitelists).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+#[named]
+pub fn import_whitelists<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: WhitelistsArgs = io.read_input_borsh()?;
+        silo::import_whitelists(&io, args)
+    })
+}
+
+#[named]
+pub fn index_whitelist_entries<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: Vec<WhitelistArgs> = io.read_input_borsh()?;
+        silo::index_whitelist_entries(&io, args)
+    })
+}
+
+pub fn get_tip_recipient<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let tip_recipient = engine::get_tip_recipient(&io);
+    let data = borsh::to_vec(&tip_recipient).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+#[named]
+pub fn set_tip_recipient<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetTipRecipientArgs = io.read_input_borsh()?;
+        engine::set_tip_recipient(&mut io, args.tip_recipient);
+        Ok(())
+    })
+}
+
+pub fn get_paymaster<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let paymaster = engine::get_paymaster(&io);
+    let data = borsh::to_vec(&paymaster).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+#[named]
+pub fn set_paymaster<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetPaymasterArgs = io.read_input_borsh()?;
+        engine::set_paymaster(&mut io, args.paymaster);
+        Ok(())
+    })
+}
+
+pub fn get_log_level<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let log_level = engine::get_log_level(&io);
+    let data = borsh::to_vec(&log_level).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+#[named]
+pub fn set_log_level<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetLogLevelArgs = io.read_input_borsh()?;
+        engine::set_log_level(&mut io, args.log_level);
+        Ok(())
+    })
+}
+
+pub fn get_diagnostics_enabled<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let enabled = engine::is_diagnostics_enabled(&io);
+    let data = borsh::to_vec(&enabled).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+/// Enables or disables populating the optional details of `SubmitResult`
+/// (e.g. the created promises) during `submit`.
+#[named]
+pub fn set_diagnostics_enabled<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let enabled: bool = io.read_input_borsh()?;
+        engine::set_diagnostics_enabled(&mut io, enabled);
+        Ok(())
+    })
+}
+
+pub fn get_tx_count_tracking_enabled<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let enabled = engine::is_tx_count_tracking_enabled(&io);
+    let data = borsh::to_vec(&enabled).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+/// Enables or disables counting the transactions submitted by each address. The counter is
+/// written on every `submit`, so it is disabled by default to avoid the storage cost.
+#[named]
+pub fn set_tx_count_tracking_enabled<I: IO + Copy, E: Env>(
+    io: I,
+    env: &E,
+) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let enabled: bool = io.read_input_borsh()?;
+        engine::set_tx_count_tracking_enabled(&mut io, enabled);
+        Ok(())
+    })
+}
+
+pub fn get_genesis_info<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let genesis_info = engine::get_genesis_info(&io);
+    let data = borsh::to_vec(&genesis_info).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+pub fn get_receipts_persistence_enabled<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let enabled = engine::is_receipts_persistence_enabled(&io);
+    let data = borsh::to_vec(&enabled).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+/// Enables or disables persisting the receipts of the transactions executed by `submit`.
+/// Every receipt is stored forever, so it is disabled by default to control the storage growth.
+#[named]
+pub fn set_receipts_persistence_enabled<I: IO + Copy, E: Env>(
+    io: I,
+    env: &E,
+) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let enabled: bool = io.read_input_borsh()?;
+        engine::set_receipts_persistence_enabled(&mut io, enabled);
+        Ok(())
+    })
+}
+
+pub fn get_modexp_algorithm<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let algorithm = engine::get_modexp_algorithm(&io);
+    let data = borsh::to_vec(&algorithm).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+/// Selects the implementation of the modexp precompile without redeploying the contract.
+#[named]
+pub fn set_modexp_algorithm<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetModExpAlgorithmArgs = io.read_input_borsh()?;
+        engine::set_modexp_algorithm(&mut io, args.algorithm);
+        Ok(())
+    })
+}
+
+pub fn get_allow_zero_gas_price<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let allowed = engine::is_zero_gas_price_allowed(&io);
+    let data = borsh::to_vec(&allowed).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+/// Allows or rejects the transactions with a zero gas price in `submit`.
+/// The transactions with a zero gas price are allowed by default.
+#[named]
+pub fn set_allow_zero_gas_price<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let allowed: bool = io.read_input_borsh()?;
+        engine::set_zero_gas_price_allowed(&mut io, allowed);
+        Ok(())
+    })
+}
+
+pub fn get_reject_unknown_precompiles<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let rejected = engine::is_unknown_precompiles_rejected(&io);
+    let data = borsh::to_vec(&rejected).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+/// Makes the calls to the reserved precompile addresses without a precompile revert with
+/// `ERR_UNKNOWN_PRECOMPILE`. By default such calls succeed as calls to an empty account.
+#[named]
+pub fn set_reject_unknown_precompiles<I: IO + Copy, E: Env>(
+    io: I,
+    env: &E,
+) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let rejected: bool = io.read_input_borsh()?;
+        engine::set_unknown_precompiles_rejected(&mut io, rejected);
+        Ok(())
+    })
+}
+
+pub fn get_exit_fee<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let token = Address::from_array(io.read_input_arr20()?);
+    let config = exit_fee::get_exit_fee(&io, &token);
+    let data = borsh::to_vec(&config).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+/// Sets the fee deducted by the exit precompiles from the exits of the token
+/// and transferred to the treasury. There is no fee by default.
+#[named]
+pub fn set_exit_fee<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetExitFeeArgs = io.read_input_borsh()?;
+        if let Some(config) = &args.config {
+            if !config.fee.is_valid() {
+                return Err(errors::ERR_INVALID_EXIT_FEE.into());
+            }
+        }
+        exit_fee::set_exit_fee(&mut io, &args.token, args.config.as_ref());
+        Ok(())
+    })
+}
+
+pub fn get_allow_empty_init_code<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let allowed = engine::is_empty_init_code_allowed(&io);
+    let data = borsh::to_vec(&allowed).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+/// Allows or rejects the contract creations with an empty init code in `deploy_code` and `submit`.
+/// Such creations are allowed by default, as in the EVM.
+#[named]
+pub fn set_allow_empty_init_code<I: IO + Copy, E: Env>(
+    io: I,
+    env: &E,
+) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let allowed: bool = io.read_input_borsh()?;
+        engine::set_empty_init_code_allowed(&mut io, allowed);
+        Ok(())
+    })
+}
+
+pub fn get_allow_chainless_tx<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let allowed = engine::is_chainless_tx_allowed(&io);
+    let data = borsh::to_vec(&allowed).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+/// Allows or rejects the legacy transactions signed without a chain ID in `submit`.
+/// Such transactions are allowed by default.
+#[named]
+pub fn set_allow_chainless_tx<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let allowed: bool = io.read_input_borsh()?;
+        engine::set_chainless_tx_allowed(&mut io, allowed);
+        Ok(())
+    })
+}
+
+pub fn get_ft_on_transfer_gas_limit<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let gas_limit = engine::get_ft_on_transfer_gas_limit(&io);
+    let data = borsh::to_vec(&gas_limit).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+#[named]
+pub fn set_ft_on_transfer_gas_limit<I: IO + Copy, E: Env>(
+    io: I,
+    env: &E,
+) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetFtOnTransferGasLimitArgs = io.read_input_borsh()?;
+        let bounds =
+            engine::MIN_FT_ON_TRANSFER_GAS_LIMIT..=engine::DEFAULT_FT_ON_TRANSFER_GAS_LIMIT;
+        if args.gas_limit.is_some_and(|limit| !bounds.contains(&limit)) {
+            return Err(errors::ERR_INVALID_GAS_LIMIT.into());
+        }
+        engine::set_ft_on_transfer_gas_limit(&mut io, args.gas_limit);
+        Ok(())
+    })
+}
+
+pub fn get_unregister_fee<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let fee = engine::get_unregister_fee(&io);
+    let data = borsh::to_vec(&fee).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+#[named]
+pub fn set_unregister_fee<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetUnregisterFeeArgs = io.read_input_borsh()?;
+        engine::set_unregister_fee(&mut io, args.fee);
+        Ok(())
+    })
+}
+
+pub fn get_erc20_deploy_gas_limit<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let gas_limit = engine::get_erc20_deploy_gas_limit(&io);
+    let data = borsh::to_vec(&gas_limit).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+#[named]
+pub fn set_erc20_deploy_gas_limit<I: IO + Copy, E: Env>(
+    io: I,
+    env: &E,
+) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetErc20DeployGasLimitArgs = io.read_input_borsh()?;
+        if args
+            .gas_limit
+            .is_some_and(|limit| limit < engine::MIN_ERC20_DEPLOY_GAS_LIMIT)
+        {
+            return Err(errors::ERR_INVALID_GAS_LIMIT.into());
+        }
+        engine::set_erc20_deploy_gas_limit(&mut io, args.gas_limit);
+        Ok(())
+    })
+}
+
+pub fn get_erc20_template<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let erc20_template = engine::get_erc20_template(&io);
+    let data = borsh::to_vec(&erc20_template).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&data);
+    Ok(())
+}
+
+/// Sets the bytecode used by `deploy_erc20_token` instead of the built-in `EvmErc20` binary.
+/// The template must be non-empty and its deployment with the constructor arguments of the
+/// built-in binary (name, symbol, decimals and admin) must succeed.
+#[named]
+pub fn set_erc20_template<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetErc20TemplateArgs = io.read_input_borsh()?;
+        if let Some(code) = &args.code {
+            validate_erc20_template(io, env, state, code)?;
+        }
+        engine::set_erc20_template(&mut io, args.code.as_deref());
+        Ok(())
+    })
+}
+
+fn validate_erc20_template<I: IO + Copy, E: Env>(
+    io: I,
+    env: &E,
+    state: EngineState,
+    code: &[u8],
+) -> Result<(), ContractError> {
+    if code.is_empty() {
+        return Err(errors::ERR_INVALID_ERC20_TEMPLATE.into());
+    }
+
+    let current_account_id = env.current_account_id();
+    let sender = engine::current_address(&current_account_id);
+    let input = engine::setup_deploy_erc20_input(&current_account_id, None, Some(code));
+    let engine: Engine<_, _> = Engine::new_with_state(state, sender, current_account_id, io, env);
+    let result = engine
+        .simulate_deploy(SimulateDeployArgs {
+            sender,
+            code: input,
+        })
+        .map_err(|_| errors::ERR_INVALID_ERC20_TEMPLATE)?;
+
+    if matches!(result.status, TransactionStatus::Succeed(_)) {
+        Ok(())
+    } else {
+        Err(errors::ERR_INVALID_ERC20_TEMPLATE.into())
+    }
+}
+
+#[named]
+pub fn start_hashchain<I: IO + Copy, E: Env>(mut io: I, env: &E) -> Result<(), ContractError> {
+    let mut state = state::get_state(&io)?;
+    require_paused(&state)?;
+    require_key_manager_only(&state, &env.predecessor_account_id())?;
+
+    let input = io.read_input().to_vec();
+    let args = StartHashchainArgs::try_from_slice(&input).map_err(|_| errors::ERR_SERIALIZE)?;
+    let block_height = env.block_height();
+
+    // Starting hashchain must be for an earlier block
+    if block_height < args.block_height {
+        return Err(errors::ERR_ARGS.into());
+    }
+
+    let mut hashchain = Hashchain::new(
+        state.chain_id,
+        env.current_account_id(),
+        args.block_height + 1,
+        args.block_hashchain,
+    );
+
+    if hashchain.get_current_block_height() < block_height {
+        hashchain.move_to_block(block_height)?;
+    }
+
+    hashchain.add_block_tx(
+        block_height,
+        function_name!(),
+        &input,
+        &[],
+        &Bloom::default(),
+    )?;
+    crate::hashchain::save_hashchain(&mut io, &hashchain)?;
+    crate::hashchain::save_initial_hashchain(&mut io, &args);
+
+    state.is_paused = false;
+    state::set_state(&mut io, &state)?;
+
+    Ok(())
+}
+
+pub fn get_initial_hashchain<I: IO>(io: &mut I) -> Result<(), ContractError> {
+    let result = crate::hashchain::read_initial_hashchain(io).map(|args| {
+        serde_json::json!({
+            "block_height": args.block_height,
+            "hashchain": hex::encode(args.block_hashchain),
+        })
+    });
+
+    let bytes = serde_json::to_vec(&serde_json::json!({ "result": result }))
+        .map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&bytes);
+    Ok(())
+}
+
+pub fn get_latest_hashchain<I: IO>(io: &mut I) -> Result<(), ContractError> {
+    let result = crate::hashchain::read_current_hashchain(io)?.map(|hc| {
+        let block_height = hc.get_current_block_height() - 1;
+        let hashchain = hex::encode(hc.get_previous_block_hashchain());
+        serde_json::json!({
+            "block_height": block_height,
+            "hashchain": hashchain,
+        })
+    });
+
+    let bytes = serde_json::to_vec(&serde_json::json!({ "result": result }))
+        .map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&bytes);
+
+    Ok(())
+}
 
+/// Returns the logs bloom accumulated over the transactions of the block at the given height.
+pub fn get_block_logs_bloom<I: IO + Copy, E: Env>(mut io: I, env: &E) -> Result<(), ContractError> {
+    let block_height: u64 = io.read_input_borsh()?;
+    let bloom = Some(block_height)
+        .filter(|height| *height <= env.block_height())
+        .and_then(|height| crate::hashchain::read_block_logs_bloom(&io, height))
+        .ok_or(errors::ERR_BLOCK_LOGS_BLOOM_NOT_FOUND)?;
+    io.return_output(bloom.as_bytes());
     Ok(())
 }
 
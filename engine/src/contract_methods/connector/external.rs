@@ -170,6 +170,7 @@ pub fn ft_on_transfer<I: IO + Copy, E: Env, H: PromiseHandler>(
             &predecessor_account_id,
             &args,
             &current_account_id,
+            crate::engine::get_ft_on_transfer_gas_limit(&io),
             handler,
         );
         result.ok()
@@ -299,6 +300,14 @@ pub fn get_paused_flags<I: IO + Copy + PromiseHandler>(mut io: I) -> Result<(),
     Ok(())
 }
 
+pub fn get_bridge_prover<I: IO + Copy + PromiseHandler>(mut io: I) -> Result<(), ContractError> {
+    let promise_args = EthConnectorContract::init(io)?.get_bridge_prover();
+    let promise_id = unsafe { io.promise_create_call(&promise_args) };
+    io.promise_return(promise_id);
+
+    Ok(())
+}
+
 pub fn ft_metadata<I: IO + Copy + PromiseHandler>(mut io: I) -> Result<(), ContractError> {
     let promise_args = EthConnectorContract::init(io)?.get_metadata();
     let promise_id = unsafe { io.promise_create_call(&promise_args) };
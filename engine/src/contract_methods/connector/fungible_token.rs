@@ -345,10 +345,9 @@ impl<I: IO + Copy> FungibleTokenOps<I> {
             if balance == ZERO_NEP141_WEI || force {
                 self.accounts_remove(&account_id);
                 self.total_eth_supply_on_near -= balance;
-                let storage_deposit = self.storage_balance_of(&account_id);
                 let action = PromiseAction::Transfer {
                     // The `+ 1` is to cover the 1 yoctoNEAR necessary to call this function in the first place.
-                    amount: storage_deposit.total + Yocto::new(1),
+                    amount: self.storage_unregister_refund() + Yocto::new(1),
                 };
                 let promise = PromiseBatchAction {
                     target_account_id: account_id,
@@ -364,9 +363,21 @@ impl<I: IO + Copy> FungibleTokenOps<I> {
         }
     }
 
+    /// Deposit required by `storage_deposit` to register a new account.
+    pub fn storage_deposit_amount(&self) -> Yocto {
+        Yocto::new(u128::from(self.account_storage_usage) * sdk::storage_byte_cost())
+    }
+
+    /// Part of the registration deposit refunded by `storage_unregister`: the deposit minus the
+    /// unregister fee set by the owner (if any).
+    pub fn storage_unregister_refund(&self) -> Yocto {
+        let deposit = self.storage_deposit_amount().as_u128();
+        let fee = engine::get_unregister_fee(&self.io).as_u128();
+        Yocto::new(deposit.saturating_sub(fee))
+    }
+
     pub fn storage_balance_bounds(&self) -> StorageBalanceBounds {
-        let required_storage_balance =
-            Yocto::new(u128::from(self.account_storage_usage) * sdk::storage_byte_cost());
+        let required_storage_balance = self.storage_deposit_amount();
         StorageBalanceBounds {
             min: required_storage_balance,
             max: Some(required_storage_balance),
@@ -398,11 +409,11 @@ impl<I: IO + Copy> FungibleTokenOps<I> {
         amount: Yocto,
         registration_only: Option<bool>,
     ) -> Result<(StorageBalance, Option<PromiseBatchAction>), errors::StorageFundingError> {
-        let promise = if self.accounts_contains_key(account_id) {
+        let refund = if self.accounts_contains_key(account_id) {
             sdk::log!("The account is already registered, refunding the deposit");
             amount
         } else {
-            let min_balance = self.storage_balance_bounds().min;
+            let min_balance = self.storage_deposit_amount();
 
             if amount < min_balance {
                 return Err(errors::StorageFundingError::InsufficientDeposit);
@@ -411,8 +422,8 @@ impl<I: IO + Copy> FungibleTokenOps<I> {
             self.internal_register_account(account_id);
             amount - min_balance
         };
-        let promise = if amount > ZERO_YOCTO {
-            let action = PromiseAction::Transfer { amount };
+        let promise = if refund > ZERO_YOCTO {
+            let action = PromiseAction::Transfer { amount: refund };
             let promise = PromiseBatchAction {
                 target_account_id: predecessor_account_id,
                 actions: vec![action],
@@ -507,3 +518,54 @@ impl<I: IO + Copy> FungibleTokenOps<I> {
         self.io.write_storage(&key, &accounts_counter.to_le_bytes());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::FungibleTokenOps;
+    use crate::engine;
+    use crate::prelude::{sdk, PromiseAction};
+    use aurora_engine_test_doubles::io::{Storage, StoragePointer};
+    use aurora_engine_types::account_id::AccountId;
+    use aurora_engine_types::types::Yocto;
+    use std::cell::RefCell;
+
+    const ACCOUNT_STORAGE_USAGE: u64 = 100;
+
+    #[test]
+    fn test_storage_unregister_refund() {
+        let storage = RefCell::new(Storage::default());
+        let mut io = StoragePointer(&storage);
+        let deposit = u128::from(ACCOUNT_STORAGE_USAGE) * sdk::storage_byte_cost();
+        let fee = deposit / 10;
+
+        // By default, the whole deposit is refunded.
+        assert_eq!(unregister_refund(io), Yocto::new(deposit));
+
+        // The fee set by the owner is withheld from the refund.
+        engine::set_unregister_fee(&mut io, Some(Yocto::new(fee)));
+        assert_eq!(unregister_refund(io), Yocto::new(deposit - fee));
+
+        // A fee above the deposit doesn't make the refund underflow.
+        engine::set_unregister_fee(&mut io, Some(Yocto::new(deposit + 1)));
+        assert_eq!(unregister_refund(io), Yocto::new(0));
+
+        engine::set_unregister_fee(&mut io, None);
+        assert_eq!(unregister_refund(io), Yocto::new(deposit));
+    }
+
+    /// Registers an account, unregisters it and returns the refunded amount (without the
+    /// attached yoctoNEAR).
+    fn unregister_refund(io: StoragePointer<'_>) -> Yocto {
+        let account_id: AccountId = "alice.near".parse().unwrap();
+        let mut ft = FungibleTokenOps::new(io);
+        ft.account_storage_usage = ACCOUNT_STORAGE_USAGE;
+        ft.internal_register_account(&account_id);
+
+        let (_, promise) = ft.internal_storage_unregister(account_id, None).unwrap();
+        assert_eq!(promise.actions.len(), 1);
+        match &promise.actions[0] {
+            PromiseAction::Transfer { amount } => Yocto::new(amount.as_u128() - 1),
+            other => panic!("Unexpected action: {other:?}"),
+        }
+    }
+}
@@ -103,6 +103,7 @@ pub fn set_eth_connector_contract_data<I: IO + Copy, E: Env>(
 pub fn withdraw<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
     with_hashchain(io, env, function_name!(), |io| {
         require_running(&state::get_state(&io)?)?;
+        require_connector_running(&io)?;
         env.assert_one_yocto()?;
         let args = io.read_input_borsh()?;
         let current_account_id = env.current_account_id();
@@ -143,6 +144,7 @@ pub fn deposit<I: IO + Copy, E: Env, H: PromiseHandler>(
 ) -> Result<Option<PromiseWithCallbackArgs>, ContractError> {
     with_hashchain(io, env, function_name!(), |io| {
         require_running(&state::get_state(&io)?)?;
+        require_connector_running(&io)?;
         let raw_proof = io.read_input().to_vec();
         let current_account_id = env.current_account_id();
         let predecessor_account_id = env.predecessor_account_id();
@@ -165,6 +167,7 @@ pub fn deposit<I: IO + Copy, E: Env, H: PromiseHandler>(
 pub fn ft_transfer<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
     with_hashchain(io, env, function_name!(), |io| {
         require_running(&state::get_state(&io)?)?;
+        require_connector_running(&io)?;
         env.assert_one_yocto()?;
         let predecessor_account_id = env.predecessor_account_id();
         let args: TransferCallArgs = serde_json::from_slice(&io.read_input().to_vec())
@@ -182,6 +185,7 @@ pub fn ft_transfer_call<I: IO + Copy, E: Env, H: PromiseHandler>(
 ) -> Result<Option<PromiseWithCallbackArgs>, ContractError> {
     with_hashchain(io, env, function_name!(), |io| {
         require_running(&state::get_state(&io)?)?;
+        require_connector_running(&io)?;
         // Check is payable
         env.assert_one_yocto()?;
 
@@ -234,6 +238,7 @@ pub fn ft_on_transfer<I: IO + Copy, E: Env, H: PromiseHandler>(
                 &predecessor_account_id,
                 &args,
                 &current_account_id,
+                crate::engine::get_ft_on_transfer_gas_limit(&io),
                 handler,
             );
             result.ok()
@@ -334,6 +339,53 @@ pub fn storage_balance_of<I: IO + Copy>(io: I) -> Result<(), ContractError> {
     Ok(())
 }
 
+pub fn get_storage_deposit_amount<I: IO + Copy>(io: I) -> Result<(), ContractError> {
+    EthConnectorContract::init(io)?.get_storage_deposit_amount();
+    Ok(())
+}
+
+#[named]
+pub fn pause_connector<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+        require_running(&state)?;
+        require_connector_running(&io)?;
+        io.write_storage(&connector_paused_key(), &[1]);
+        Ok(())
+    })
+}
+
+#[named]
+pub fn resume_connector<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+        require_running(&state)?;
+        if !is_connector_paused(&io) {
+            return Err(crate::errors::ERR_CONNECTOR_RUNNING.into());
+        }
+        io.remove_storage(&connector_paused_key());
+        Ok(())
+    })
+}
+
+/// Returns whether deposits, withdrawals and transfers of the connector are paused.
+pub fn is_connector_paused<I: IO>(io: &I) -> bool {
+    io.storage_has_key(&connector_paused_key())
+}
+
+fn require_connector_running<I: IO>(io: &I) -> Result<(), ContractError> {
+    if is_connector_paused(io) {
+        return Err(crate::errors::ERR_CONNECTOR_PAUSED.into());
+    }
+    Ok(())
+}
+
+fn connector_paused_key() -> Vec<u8> {
+    construct_contract_key(EthConnectorStorageId::ConnectorPaused)
+}
+
 #[named]
 pub fn set_paused_flags<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
     with_hashchain(io, env, function_name!(), |io| {
@@ -357,6 +409,13 @@ pub fn get_paused_flags<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
     Ok(())
 }
 
+pub fn get_bridge_prover<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let connector = EthConnectorContract::init(io)?;
+    io.return_output(connector.get_bridge_prover().as_bytes());
+
+    Ok(())
+}
+
 pub fn is_used_proof<I: IO + Copy + PromiseHandler>(mut io: I) -> Result<(), ContractError> {
     let args: IsUsedProofCallArgs = io.read_input_borsh()?;
 
@@ -437,6 +496,11 @@ pub fn get_accounts_counter<I: IO + Copy>(io: I) -> Result<(), ContractError> {
     Ok(())
 }
 
+pub fn get_used_proofs_count<I: IO + Copy>(io: I) -> Result<(), ContractError> {
+    EthConnectorContract::init(io)?.get_used_proofs_count();
+    Ok(())
+}
+
 pub fn ft_metadata<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
     let metadata = get_metadata(&io).unwrap_or_default();
     io.return_output(&serde_json::to_vec(&metadata).unwrap_or_default());
@@ -1029,6 +1093,13 @@ impl<I: IO + Copy> EthConnectorContract<I> {
             .return_output(&self.ft.storage_balance_of(&args.account_id).to_json_bytes());
     }
 
+    /// Return the deposit required to register an account in the storage.
+    pub fn get_storage_deposit_amount(&mut self) {
+        let amount = self.ft.storage_deposit_amount();
+        self.io
+            .return_output(&serde_json::to_vec(&amount).unwrap_or_default());
+    }
+
     /// `ft_on_transfer` callback function.
     pub fn ft_on_transfer(
         &mut self,
@@ -1053,6 +1124,13 @@ impl<I: IO + Copy> EthConnectorContract<I> {
             .return_output(&self.ft.get_accounts_counter().to_le_bytes());
     }
 
+    /// Return the number of deposit proofs which have been used.
+    /// Proofs recorded before the counter was introduced are not taken into account.
+    pub fn get_used_proofs_count(&mut self) {
+        self.io
+            .return_output(&self.used_proofs_count().to_le_bytes());
+    }
+
     /// Return account id of the prover smart contract.
     pub const fn get_bridge_prover(&self) -> &AccountId {
         &self.contract.prover_account
@@ -1078,6 +1156,28 @@ impl<I: IO + Copy> EthConnectorContract<I> {
     /// Save already used event proof as hash key
     fn save_used_event(&mut self, key: &str) {
         self.io.write_borsh(&Self::used_event_key(key), &0u8);
+        self.increment_used_proofs_counter();
+    }
+
+    /// Key of the counter of used proofs.
+    fn used_proofs_counter_key() -> Vec<u8> {
+        construct_contract_key(EthConnectorStorageId::UsedProofsCounter)
+    }
+
+    /// Read the counter of used proofs. It is absent until the first proof is recorded.
+    fn used_proofs_count(&self) -> u64 {
+        self.io
+            .read_u64(&Self::used_proofs_counter_key())
+            .unwrap_or(0)
+    }
+
+    fn increment_used_proofs_counter(&mut self) {
+        let count = self
+            .used_proofs_count()
+            .checked_add(1)
+            .expect(crate::errors::ERR_USED_PROOFS_COUNTER_OVERFLOW);
+        self.io
+            .write_storage(&Self::used_proofs_counter_key(), &count.to_le_bytes());
     }
 
     /// Check if the event of the proof has already been used.
@@ -17,7 +17,7 @@ use aurora_engine_types::parameters::connector::{
 };
 use aurora_engine_types::parameters::engine::errors::ParseArgsError;
 use aurora_engine_types::parameters::engine::{
-    DeployErc20TokenArgs, GetErc20FromNep141CallArgs, SubmitResult,
+    DeployErc20TokenCallArgs, GetErc20FromNep141CallArgs, SubmitResult,
 };
 use aurora_engine_types::parameters::{
     ExitToNearPrecompileCallbackCallArgs, PromiseAction, PromiseBatchAction,
@@ -123,8 +123,22 @@ pub fn deploy_erc20_token<I: IO + Copy, E: Env, H: PromiseHandler>(
     with_hashchain(io, env, function_name!(), |mut io| {
         require_running(&state::get_state(&io)?)?;
         // AccountId of NEP-141 token on NEAR
-        let args: DeployErc20TokenArgs = io.read_input_borsh()?;
-        let address = engine::deploy_erc20_token(args, io, env, handler)?;
+        let args = DeployErc20TokenCallArgs::deserialize(&io.read_input().to_vec())
+            .ok_or(crate::errors::ERR_BORSH_DESERIALIZE)?;
+        let existing_address = if args.return_existing() {
+            engine::get_erc20_from_nep141(&io, args.nep141())
+                .ok()
+                .and_then(|bytes| Address::try_from_slice(&bytes).ok())
+        } else {
+            None
+        };
+        let address = match existing_address {
+            Some(address) => address,
+            None => {
+                let deterministic = args.deterministic();
+                engine::deploy_erc20_token(args.into(), deterministic, io, env, handler)?
+            }
+        };
 
         io.return_output(
             &borsh::to_vec(address.as_bytes()).map_err(|_| crate::errors::ERR_SERIALIZE)?,
@@ -292,6 +306,18 @@ pub fn storage_balance_of<I: IO + Copy + PromiseHandler>(io: I) -> Result<(), Co
     Ok(())
 }
 
+#[cfg(not(feature = "ext-connector"))]
+pub fn pause_connector<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    internal::pause_connector(io, env)?;
+    Ok(())
+}
+
+#[cfg(not(feature = "ext-connector"))]
+pub fn resume_connector<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
+    internal::resume_connector(io, env)?;
+    Ok(())
+}
+
 pub fn set_paused_flags<I: IO + Copy, E: Env>(io: I, env: &E) -> Result<(), ContractError> {
     #[cfg(not(feature = "ext-connector"))]
     internal::set_paused_flags(io, env)?;
@@ -310,6 +336,16 @@ pub fn get_paused_flags<I: IO + Copy + PromiseHandler>(io: I) -> Result<(), Cont
     Ok(())
 }
 
+/// Return the account id of the prover contract used by the eth-connector.
+pub fn get_bridge_prover<I: IO + Copy + PromiseHandler>(io: I) -> Result<(), ContractError> {
+    #[cfg(not(feature = "ext-connector"))]
+    internal::get_bridge_prover(io)?;
+    #[cfg(feature = "ext-connector")]
+    external::get_bridge_prover(io)?;
+
+    Ok(())
+}
+
 pub fn is_used_proof<I: IO + Copy + PromiseHandler>(io: I) -> Result<(), ContractError> {
     #[cfg(not(feature = "ext-connector"))]
     internal::is_used_proof(io)?;
@@ -366,6 +402,18 @@ pub fn get_accounts_counter<I: IO + Copy>(io: I) -> Result<(), ContractError> {
     Ok(())
 }
 
+#[cfg(not(feature = "ext-connector"))]
+pub fn get_used_proofs_count<I: IO + Copy>(io: I) -> Result<(), ContractError> {
+    internal::get_used_proofs_count(io)?;
+    Ok(())
+}
+
+#[cfg(not(feature = "ext-connector"))]
+pub fn get_storage_deposit_amount<I: IO + Copy>(io: I) -> Result<(), ContractError> {
+    internal::get_storage_deposit_amount(io)?;
+    Ok(())
+}
+
 #[named]
 pub fn set_erc20_metadata<I: IO + Copy, E: Env, H: PromiseHandler>(
     io: I,
@@ -415,6 +463,23 @@ pub fn get_erc20_metadata<I: IO + Copy, E: Env>(mut io: I, env: &E) -> Result<()
     Ok(())
 }
 
+/// Returns the generation of the ERC-20 token bridged from a NEP-141 token, which increases
+/// every time the token is mapped to a new ERC-20.
+pub fn get_erc20_generation<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let erc20_identifier: Erc20Identifier =
+        serde_json::from_slice(&io.read_input().to_vec()).map_err(Into::<ParseArgsError>::into)?;
+    let nep141 = match erc20_identifier {
+        Erc20Identifier::Nep141 { account_id } => Some(account_id),
+        Erc20Identifier::Erc20 { address } => engine::nep141_erc20_map(io)
+            .lookup_right(&engine::ERC20Address(address))
+            .map(|nep141| nep141.0),
+    };
+    let generation = nep141.map_or(0, |nep141| engine::get_erc20_generation(&io, &nep141));
+
+    io.return_output(&serde_json::to_vec(&generation).map_err(|_| crate::errors::ERR_SERIALIZE)?);
+    Ok(())
+}
+
 pub fn set_eth_connector_contract_account<I: IO + Copy, E: Env>(
     io: I,
     env: &E,
@@ -1,8 +1,10 @@
+#[cfg(feature = "verbose-errors")]
+use crate::overlay_io::{Overlay, OverlayIO};
 use crate::{
     contract_methods::{predecessor_address, require_running, ContractError},
-    engine::{self, Engine},
+    engine::{self, Engine, EngineError, EngineErrorKind},
     errors,
-    hashchain::with_logs_hashchain,
+    hashchain::{with_batch_logs_hashchain, with_logs_hashchain},
     state,
 };
 use aurora_engine_modexp::AuroraModExp;
@@ -11,10 +13,18 @@ use aurora_engine_sdk::{
     io::{StorageIntermediate, IO},
     promise::PromiseHandler,
 };
+#[cfg(feature = "verbose-errors")]
+use aurora_engine_types::parameters::engine::{SubmitError, TransactionStatus};
 use aurora_engine_types::{
     borsh,
-    parameters::engine::{CallArgs, SubmitArgs, SubmitResult},
+    parameters::engine::{
+        CallArgs, SubmitArgs, SubmitArgsV2, SubmitBatchArgs, SubmitBatchResult,
+        SubmitBatchTxResult, SubmitResult,
+    },
+    String, Vec,
 };
+#[cfg(feature = "verbose-errors")]
+use core::cell::RefCell;
 use function_name::named;
 
 #[named]
@@ -26,16 +36,18 @@ pub fn deploy_code<I: IO + Copy, E: Env, H: PromiseHandler>(
     with_logs_hashchain(io, env, function_name!(), |mut io| {
         let state = state::get_state(&io)?;
         require_running(&state)?;
+        let sender = predecessor_address(&env.predecessor_account_id());
+        if !engine::is_deploy_allowed(&io, &sender) {
+            return Err(errors::ERR_DEPLOY_NOT_ALLOWED.into());
+        }
         let input = io.read_input().to_vec();
+        if input.is_empty() && !engine::is_empty_init_code_allowed(&io) {
+            return Err(errors::ERR_EMPTY_INIT_CODE.into());
+        }
         let current_account_id = env.current_account_id();
-        let mut engine: Engine<_, E, AuroraModExp> = Engine::new_with_state(
-            state,
-            predecessor_address(&env.predecessor_account_id()),
-            current_account_id,
-            io,
-            env,
-        );
-        let result = engine.deploy_code_with_input(input, None, handler)?;
+        let mut engine: Engine<_, E, AuroraModExp> =
+            Engine::new_with_state(state, sender, current_account_id, io, env);
+        let result = with_selfdestruct_log(|| engine.deploy_code_with_input(input, None, handler))?;
         let result_bytes = borsh::to_vec(&result).map_err(|_| errors::ERR_SERIALIZE)?;
         io.return_output(&result_bytes);
         Ok(result)
@@ -63,7 +75,7 @@ pub fn call<I: IO + Copy, E: Env, H: PromiseHandler>(
             io,
             env,
         );
-        let result = engine.call_with_args(args, handler)?;
+        let result = with_selfdestruct_log(|| engine.call_with_args(args, handler))?;
         let result_bytes = borsh::to_vec(&result).map_err(|_| errors::ERR_SERIALIZE)?;
         io.return_output(&result_bytes);
         Ok(result)
@@ -86,17 +98,18 @@ pub fn submit<I: IO + Copy, E: Env, H: PromiseHandler>(
             tx_data,
             ..Default::default()
         };
-        let result = engine::submit(
-            io,
-            env,
-            &args,
-            state,
-            current_account_id,
-            relayer_address,
-            handler,
-        )?;
-        let result_bytes = borsh::to_vec(&result).map_err(|_| errors::ERR_SERIALIZE)?;
-        io.return_output(&result_bytes);
+        let result = with_selfdestruct_log(|| {
+            engine::submit(
+                io,
+                env,
+                &args,
+                state,
+                current_account_id,
+                relayer_address,
+                handler,
+            )
+        })?;
+        io.return_output(&submit_output(&result)?);
 
         Ok(result)
     })
@@ -111,21 +124,208 @@ pub fn submit_with_args<I: IO + Copy, E: Env, H: PromiseHandler>(
     with_logs_hashchain(io, env, function_name!(), |mut io| {
         let state = state::get_state(&io)?;
         require_running(&state)?;
-        let args: SubmitArgs = io.read_input_borsh()?;
+        let args = SubmitArgsV2::deserialize(&io.read_input().to_vec())
+            .ok_or(errors::ERR_BORSH_DESERIALIZE)?;
+        // The deadline is set by the relayer outside the signed transaction.
+        if args
+            .valid_until_block
+            .map_or(false, |height| env.block_height() > height)
+        {
+            return Err(EngineError::from(EngineErrorKind::TxExpired).into());
+        }
+        let args: SubmitArgs = args.into();
         let current_account_id = env.current_account_id();
         let relayer_address = predecessor_address(&env.predecessor_account_id());
-        let result = engine::submit(
-            io,
-            env,
-            &args,
-            state,
-            current_account_id,
-            relayer_address,
-            handler,
-        )?;
+        let result = with_selfdestruct_log(|| {
+            engine::submit(
+                io,
+                env,
+                &args,
+                state,
+                current_account_id,
+                relayer_address,
+                handler,
+            )
+        })?;
+        io.return_output(&submit_output(&result)?);
+
+        Ok(result)
+    })
+}
+
+/// Executes the transactions of the batch in order, so they can use the consecutive nonces of
+/// the same sender. In the atomic mode the whole batch is reverted if one of the transactions
+/// doesn't succeed. Otherwise, a transaction which fails doesn't affect the others: a reverted
+/// transaction is reported with its own status and a transaction which is rejected before its
+/// execution (e.g. because of an invalid nonce) is skipped and reported with its error.
+#[named]
+pub fn submit_batch<I: IO + Copy, E: Env, H: PromiseHandler>(
+    io: I,
+    env: &E,
+    handler: &mut H,
+) -> Result<SubmitBatchResult, ContractError> {
+    with_batch_logs_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        let batch: SubmitBatchArgs = io.read_input_borsh()?;
+        let current_account_id = env.current_account_id();
+        let relayer_address = predecessor_address(&env.predecessor_account_id());
+        let mut results = Vec::with_capacity(batch.transactions.len());
+
+        for (index, tx_data) in batch.transactions.into_iter().enumerate() {
+            let args = SubmitArgs {
+                tx_data,
+                ..Default::default()
+            };
+            let result = with_selfdestruct_log(|| {
+                engine::submit(
+                    io,
+                    env,
+                    &args,
+                    state.clone(),
+                    current_account_id.clone(),
+                    relayer_address,
+                    handler,
+                )
+            });
+            let result = match result {
+                Ok(result) if batch.atomic && !result.status.is_ok() => {
+                    aurora_engine_sdk::log!(
+                        "Transaction {index} of the batch failed: {:?}",
+                        result.status
+                    );
+                    return Err(errors::ERR_BATCH_TX_FAILED.into());
+                }
+                Ok(result) => SubmitBatchTxResult::Executed(result),
+                Err(error) if !batch.atomic && error.is_rejection() => {
+                    let message = String::from_utf8_lossy(error.as_ref()).into_owned();
+                    SubmitBatchTxResult::Rejected(message)
+                }
+                Err(error) => return Err(error.into()),
+            };
+            results.push(result);
+        }
+
+        let result = SubmitBatchResult::new(results);
         let result_bytes = borsh::to_vec(&result).map_err(|_| errors::ERR_SERIALIZE)?;
         io.return_output(&result_bytes);
 
         Ok(result)
     })
 }
+
+/// Returns the borsh-encoded output of `submit` for an executed transaction.
+#[cfg(not(feature = "verbose-errors"))]
+fn submit_output(result: &SubmitResult) -> Result<Vec<u8>, ContractError> {
+    Ok(borsh::to_vec(result).map_err(|_| errors::ERR_SERIALIZE)?)
+}
+
+/// Returns the borsh-encoded output of `submit` for an executed transaction. With the
+/// `verbose-errors` feature the output is `Result<SubmitResult, SubmitError>`, where the
+/// transactions which didn't succeed (e.g. reverted) are reported as errors.
+#[cfg(feature = "verbose-errors")]
+fn submit_output(result: &SubmitResult) -> Result<Vec<u8>, ContractError> {
+    let output = if result.status.is_ok() {
+        Ok(result)
+    } else {
+        let message = engine::submit_result_or_err(result.clone())
+            .err()
+            .map(|e| String::from_utf8_lossy(e.as_ref()).into_owned())
+            .unwrap_or_default();
+        let revert_reason = match &result.status {
+            TransactionStatus::Revert(data) => decode_revert_reason(data),
+            _ => None,
+        };
+        Err(SubmitError {
+            status: Some(result.status.clone()),
+            message,
+            gas_used: result.gas_used,
+            revert_reason,
+        })
+    };
+    Ok(borsh::to_vec(&output).map_err(|_| errors::ERR_SERIALIZE)?)
+}
+
+/// Runs `f` (`submit` or `submit_with_args`) for the engines built with the `verbose-errors`
+/// feature. The storage writes of `f` are kept in an overlay and only applied if it succeeds.
+/// A failed call doesn't panic: its state changes are discarded and the error is returned as
+/// the `Result<SubmitResult, SubmitError>` output. The feature is meant for developer silos only.
+#[cfg(feature = "verbose-errors")]
+pub fn with_verbose_errors<I, F>(mut io: I, f: F)
+where
+    I: IO + Copy,
+    F: FnOnce(OverlayIO<'_, I>) -> Result<SubmitResult, ContractError>,
+{
+    let overlay = RefCell::new(Overlay::new());
+    let overlay_io = OverlayIO::new(io, &overlay);
+    match f(overlay_io) {
+        Ok(_) => overlay_io.commit(),
+        Err(error) => {
+            let output: Result<SubmitResult, SubmitError> = Err(submit_error(&error));
+            if let Ok(bytes) = borsh::to_vec(&output) {
+                io.return_output(&bytes);
+            }
+        }
+    }
+}
+
+/// Returns the `SubmitError` of a `submit` call which failed before executing the transaction.
+#[cfg(feature = "verbose-errors")]
+#[must_use]
+pub fn submit_error(error: &ContractError) -> SubmitError {
+    SubmitError {
+        status: None,
+        message: String::from_utf8_lossy((*error.message).as_ref()).into_owned(),
+        gas_used: 0,
+        revert_reason: None,
+    }
+}
+
+/// Decodes the reason of a revert from the revert data encoded as `Error(string)`, which
+/// Solidity uses for `revert("reason")` and `require(condition, "reason")`.
+#[cfg(feature = "verbose-errors")]
+fn decode_revert_reason(data: &[u8]) -> Option<String> {
+    const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+    let payload = data.strip_prefix(&ERROR_SELECTOR)?;
+    match ethabi::decode(&[ethabi::ParamType::String], payload)
+        .ok()?
+        .pop()?
+    {
+        ethabi::Token::String(reason) => Some(reason),
+        _ => None,
+    }
+}
+
+/// Emits a log for every `SELFDESTRUCT` executed by `f`. The opcode is deprecated (EIP-6049),
+/// so the logs help to find the contracts which rely on its current behaviour.
+///
+/// The EVM event listener is global and installing it replaces the listener of the caller, so
+/// the logger is only installed in the contract. In the standalone engine it would hide the
+/// events from the standalone tracers.
+#[cfg(all(feature = "selfdestruct-log", feature = "contract"))]
+fn with_selfdestruct_log<R, F: FnOnce() -> R>(f: F) -> R {
+    struct SelfDestructLogger;
+
+    impl evm::tracing::EventListener for SelfDestructLogger {
+        fn event(&mut self, event: evm::tracing::Event) {
+            if let evm::tracing::Event::Suicide {
+                address, target, ..
+            } = event
+            {
+                aurora_engine_sdk::log!(
+                    "SELFDESTRUCT is deprecated: contract 0x{} beneficiary 0x{}",
+                    hex::encode(address),
+                    hex::encode(target)
+                );
+            }
+        }
+    }
+
+    evm::tracing::using(&mut SelfDestructLogger, f)
+}
+
+#[cfg(not(all(feature = "selfdestruct-log", feature = "contract")))]
+fn with_selfdestruct_log<R, F: FnOnce() -> R>(f: F) -> R {
+    f()
+}
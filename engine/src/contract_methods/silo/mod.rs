@@ -3,14 +3,19 @@ use aurora_engine_sdk::io::{StorageIntermediate, IO};
 use aurora_engine_sdk::{env::Env, types::SdkUnwrap};
 use aurora_engine_types::account_id::AccountId;
 use aurora_engine_types::parameters::silo::{
-    SiloParamsArgs, WhitelistArgs, WhitelistKind, WhitelistKindArgs, WhitelistStatusArgs,
+    FeeRegime, FixedGasInfo, SiloConfigArgs, SiloParamsArgs, WhitelistAccountArgs,
+    WhitelistAddressArgs, WhitelistArgs, WhitelistKind, WhitelistKindArgs, WhitelistStatusArgs,
+    WhitelistsArgs,
 };
 use aurora_engine_types::storage::{bytes_to_key, KeyPrefix};
-use aurora_engine_types::types::{Address, EthGas};
+use aurora_engine_types::types::{Address, EthGas, Wei};
 use aurora_engine_types::AsBytes;
 
+use crate::contract_methods::ContractError;
 #[cfg(feature = "contract")]
 use crate::engine::EngineErrorKind;
+use crate::engine::{self, BASE_FEE_PER_GAS};
+use crate::errors;
 use crate::prelude::Vec;
 
 use whitelist::Whitelist;
@@ -19,6 +24,13 @@ mod whitelist;
 
 const GAS_COST_KEY: &[u8] = b"GAS_COST_KEY";
 const ERC20_FALLBACK_KEY: &[u8] = b"ERC20_FALLBACK_KEY";
+const GAS_REFUNDS_DISABLED_KEY: &[u8] = b"GAS_REFUNDS_DISABLED_KEY";
+const WHITELIST_KINDS: [WhitelistKind; 4] = [
+    WhitelistKind::Admin,
+    WhitelistKind::EvmAdmin,
+    WhitelistKind::Account,
+    WhitelistKind::Address,
+];
 
 /// Return SILO parameters.
 pub fn get_silo_params<I: IO>(io: &I) -> Option<SiloParamsArgs> {
@@ -53,6 +65,33 @@ pub fn get_fixed_gas<I: IO>(io: &I) -> Option<EthGas> {
         .and_then(|bytes| bytes.to_value().ok())
 }
 
+/// Return gas amount per transaction together with the current base fee per gas.
+pub fn get_fixed_gas_info<I: IO>(io: &I) -> FixedGasInfo {
+    FixedGasInfo {
+        fixed_gas: get_fixed_gas(io),
+        gas_price: Wei::new(BASE_FEE_PER_GAS).to_bytes(),
+    }
+}
+
+/// Return the fee regime of the transactions, see [`FeeRegime`] for the precedence rules.
+pub fn get_fee_regime<I: IO>(io: &I) -> FeeRegime {
+    let base_fee_per_gas = Wei::new(BASE_FEE_PER_GAS).to_bytes();
+    let zero_gas_price_allowed = engine::is_zero_gas_price_allowed(io);
+
+    match get_fixed_gas(io) {
+        Some(fixed_gas) => FeeRegime::FixedGas {
+            fixed_gas,
+            base_fee_per_gas,
+            fixed_fee: Wei::new(fixed_gas.as_u256().saturating_mul(BASE_FEE_PER_GAS)).to_bytes(),
+            zero_gas_price_allowed,
+        },
+        None => FeeRegime::Market {
+            base_fee_per_gas,
+            zero_gas_price_allowed,
+        },
+    }
+}
+
 /// Set gas amount per transaction.
 pub fn set_fixed_gas<I: IO>(io: &mut I, gas: Option<EthGas>) {
     let key = fixed_gas_key();
@@ -81,6 +120,143 @@ pub fn set_erc20_fallback_address<I: IO>(io: &mut I, address: Option<Address>) {
     }
 }
 
+/// Return true if the gas refunds (e.g. for clearing storage slots) are applied. Enabled by default.
+pub fn is_gas_refunds_enabled<I: IO>(io: &I) -> bool {
+    !io.storage_has_key(&gas_refunds_disabled_key())
+}
+
+/// Enable or disable the gas refunds.
+pub fn set_gas_refunds_enabled<I: IO>(io: &mut I, enabled: bool) {
+    let key = gas_refunds_disabled_key();
+
+    if enabled {
+        io.remove_storage(&key);
+    } else {
+        io.write_storage(&key, &[1]);
+    }
+}
+
+/// Apply the whole silo configuration (silo parameters, white lists statuses and entries).
+/// Nothing is written if any part of the configuration is invalid.
+pub fn configure_silo<I: IO + Copy>(io: &mut I, args: SiloConfigArgs) -> Result<(), ContractError> {
+    validate_whitelists(&args.whitelist_statuses, &args.whitelist_entries)?;
+
+    set_silo_params(io, Some(args.silo_params));
+    for status in &args.whitelist_statuses {
+        set_whitelist_status(io, status);
+    }
+    add_entry_to_whitelist_batch(io, args.whitelist_entries);
+
+    Ok(())
+}
+
+/// Return the statuses and the entries of all the white lists.
+/// Only the indexed entries are returned, see [`index_whitelist_entries`] for the entries added
+/// before the white lists kept an index of their entries.
+pub fn export_whitelists<I: IO + Copy>(io: &I) -> WhitelistsArgs {
+    let whitelist_statuses = WHITELIST_KINDS
+        .iter()
+        .map(|&kind| get_whitelist_status(io, &WhitelistKindArgs { kind }))
+        .collect();
+    let whitelist_entries = WHITELIST_KINDS
+        .iter()
+        .flat_map(|&kind| {
+            Whitelist::init(io, kind)
+                .entries()
+                .into_iter()
+                .filter_map(move |entry| match kind {
+                    WhitelistKind::EvmAdmin | WhitelistKind::Address => {
+                        Address::try_from_slice(&entry).ok().map(|address| {
+                            WhitelistArgs::WhitelistAddressArgs(WhitelistAddressArgs {
+                                kind,
+                                address,
+                            })
+                        })
+                    }
+                    WhitelistKind::Admin | WhitelistKind::Account => {
+                        AccountId::try_from(entry).ok().map(|account_id| {
+                            WhitelistArgs::WhitelistAccountArgs(WhitelistAccountArgs {
+                                kind,
+                                account_id,
+                            })
+                        })
+                    }
+                })
+        })
+        .collect();
+
+    WhitelistsArgs {
+        whitelist_statuses,
+        whitelist_entries,
+    }
+}
+
+/// Replace the white lists with the exported ones: the indexed entries of all the white lists
+/// are removed before the provided statuses and entries are applied, so the entries added before
+/// the white lists kept an index must be indexed with [`index_whitelist_entries`] first.
+/// Nothing is written if any part of the provided white lists is invalid.
+pub fn import_whitelists<I: IO + Copy>(io: &I, args: WhitelistsArgs) -> Result<(), ContractError> {
+    validate_whitelists(&args.whitelist_statuses, &args.whitelist_entries)?;
+
+    for kind in WHITELIST_KINDS {
+        Whitelist::init(io, kind).clear();
+    }
+    for status in &args.whitelist_statuses {
+        set_whitelist_status(io, status);
+    }
+    add_entry_to_whitelist_batch(io, args.whitelist_entries);
+
+    Ok(())
+}
+
+/// Index the entries added before the white lists kept an index of their entries, so they are
+/// exported and cleared like the other ones. The contract can't iterate over its storage, so such
+/// entries are provided by the owner, e.g. read by key prefix from the standalone storage.
+/// Nothing is written if any of the entries is invalid or missing from its white list.
+pub fn index_whitelist_entries<I: IO + Copy>(
+    io: &I,
+    entries: Vec<WhitelistArgs>,
+) -> Result<(), ContractError> {
+    validate_whitelists(&[], &entries)?;
+
+    let is_present = |args: &WhitelistArgs| {
+        let (kind, entry) = get_kind_and_entry(args);
+        Whitelist::init(io, kind).is_exist(entry)
+    };
+    if !entries.iter().all(is_present) {
+        return Err(errors::ERR_WHITELIST_ENTRY_NOT_FOUND.into());
+    }
+    // Adding an entry which has no position in the index only indexes it.
+    add_entry_to_whitelist_batch(io, entries);
+
+    Ok(())
+}
+
+fn validate_whitelists(
+    statuses: &[WhitelistStatusArgs],
+    entries: &[WhitelistArgs],
+) -> Result<(), ContractError> {
+    for (i, status) in statuses.iter().enumerate() {
+        if statuses[..i].iter().any(|other| other.kind == status.kind) {
+            return Err(errors::ERR_DUPLICATE_WHITELIST_STATUS.into());
+        }
+    }
+
+    let is_valid_entry = |entry: &WhitelistArgs| match entry {
+        WhitelistArgs::WhitelistAddressArgs(args) => {
+            matches!(args.kind, WhitelistKind::EvmAdmin | WhitelistKind::Address)
+        }
+        WhitelistArgs::WhitelistAccountArgs(args) => {
+            matches!(args.kind, WhitelistKind::Admin | WhitelistKind::Account)
+        }
+    };
+    if !entries.iter().all(is_valid_entry) {
+        return Err(errors::ERR_INVALID_WHITELIST_ENTRY.into());
+    }
+
+    Ok(())
+}
+
 /// Add an entry to a white list depending on a kind of list types in provided arguments.
 pub fn add_entry_to_whitelist<I: IO + Copy>(io: &I, args: &WhitelistArgs) {
     let (kind, entry) = get_kind_and_entry(args);
@@ -174,6 +350,10 @@ fn erc20_fallback_address_key() -> Vec<u8> {
     bytes_to_key(KeyPrefix::Silo, ERC20_FALLBACK_KEY)
 }
 
+fn gas_refunds_disabled_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Silo, GAS_REFUNDS_DISABLED_KEY)
+}
+
 fn get_kind_and_entry(args: &WhitelistArgs) -> (WhitelistKind, &dyn AsBytes) {
     match args {
         WhitelistArgs::WhitelistAddressArgs(args) => (args.kind, &args.address),
@@ -198,6 +378,88 @@ mod access_test {
         assert_eq!(get_fixed_gas(&io), cost);
     }
 
+    #[test]
+    fn test_get_fixed_gas_info() {
+        let storage = RefCell::new(Storage::default());
+        let mut io = StoragePointer(&storage);
+        let zero = Wei::zero().to_bytes();
+
+        let info = get_fixed_gas_info(&io);
+        assert_eq!(info.fixed_gas, None);
+        assert_eq!(info.gas_price, zero);
+
+        set_fixed_gas(&mut io, Some(EthGas::new(1000)));
+        let info = get_fixed_gas_info(&io);
+        assert_eq!(info.fixed_gas, Some(EthGas::new(1000)));
+        assert_eq!(info.gas_price, zero);
+    }
+
+    #[test]
+    fn test_set_gas_refunds_enabled() {
+        let storage = RefCell::new(Storage::default());
+        let mut io = StoragePointer(&storage);
+
+        assert!(is_gas_refunds_enabled(&io));
+        set_gas_refunds_enabled(&mut io, false);
+        assert!(!is_gas_refunds_enabled(&io));
+        set_gas_refunds_enabled(&mut io, true);
+        assert!(is_gas_refunds_enabled(&io));
+    }
+
+    #[test]
+    fn test_configure_silo() {
+        let storage = RefCell::new(Storage::default());
+        let mut io = StoragePointer(&storage);
+        let account_id: AccountId = "some-account.near".parse().unwrap();
+        let address = Address::from_array([1; 20]);
+        let silo_params = SiloParamsArgs {
+            fixed_gas: EthGas::new(1000),
+            erc20_fallback_address: Address::from_array([2; 20]),
+        };
+        let status = |kind| WhitelistStatusArgs { kind, active: true };
+        let mut args = SiloConfigArgs {
+            silo_params: silo_params.clone(),
+            whitelist_statuses: vec![
+                status(WhitelistKind::Account),
+                status(WhitelistKind::Account),
+            ],
+            whitelist_entries: vec![WhitelistArgs::WhitelistAccountArgs(
+                aurora_engine_types::parameters::silo::WhitelistAccountArgs {
+                    kind: WhitelistKind::Address,
+                    account_id: account_id.clone(),
+                },
+            )],
+        };
+
+        // Nothing is written if the bundle is invalid.
+        assert!(configure_silo(&mut io, args.clone()).is_err());
+        args.whitelist_statuses.pop();
+        assert!(configure_silo(&mut io, args.clone()).is_err());
+        assert_eq!(get_silo_params(&io), None);
+        assert!(storage.borrow().kv_store.is_empty());
+
+        args.whitelist_entries = vec![
+            WhitelistArgs::WhitelistAccountArgs(
+                aurora_engine_types::parameters::silo::WhitelistAccountArgs {
+                    kind: WhitelistKind::Account,
+                    account_id: account_id.clone(),
+                },
+            ),
+            WhitelistArgs::WhitelistAddressArgs(
+                aurora_engine_types::parameters::silo::WhitelistAddressArgs {
+                    kind: WhitelistKind::Address,
+                    address,
+                },
+            ),
+        ];
+        configure_silo(&mut io, args).unwrap();
+
+        assert_eq!(get_silo_params(&io), Some(silo_params));
+        assert!(is_account_allowed(&io, &account_id));
+        assert!(is_address_allowed(&io, &address));
+        assert!(!is_account_allowed(&io, &"other.near".parse().unwrap()));
+    }
+
     #[test]
     fn test_adding_entry_to_whitelist() {
         let storage = RefCell::new(Storage::default());
@@ -218,6 +480,50 @@ mod access_test {
         assert!(is_allow_submit(&io, &account_id, &address));
     }
 
+    #[test]
+    fn test_index_whitelist_entries() {
+        let storage = RefCell::new(Storage::default());
+        let mut io = StoragePointer(&storage);
+        let legacy = Address::from_array([1; 20]);
+        let missing = Address::from_array([2; 20]);
+        let entry = |address| {
+            WhitelistArgs::WhitelistAddressArgs(
+                aurora_engine_types::parameters::silo::WhitelistAddressArgs {
+                    kind: WhitelistKind::Address,
+                    address,
+                },
+            )
+        };
+        // The entries added before the index existed are stored with an empty value.
+        let mut legacy_key = vec![u8::from(WhitelistKind::Address)];
+        legacy_key.extend_from_slice(legacy.as_bytes());
+        io.write_storage(&bytes_to_key(KeyPrefix::Whitelist, &legacy_key), &[]);
+        assert!(is_address_allowed(&io, &legacy));
+        assert!(export_whitelists(&io).whitelist_entries.is_empty());
+
+        // Nothing is indexed if an entry is missing from its white list.
+        assert!(index_whitelist_entries(&io, vec![entry(legacy), entry(missing)]).is_err());
+        assert!(export_whitelists(&io).whitelist_entries.is_empty());
+        assert!(!is_address_allowed(&io, &missing));
+
+        index_whitelist_entries(&io, vec![entry(legacy)]).unwrap();
+        assert_eq!(
+            export_whitelists(&io).whitelist_entries,
+            vec![entry(legacy)]
+        );
+
+        // The indexed entries are removed by the import.
+        import_whitelists(
+            &io,
+            WhitelistsArgs {
+                whitelist_statuses: Vec::new(),
+                whitelist_entries: Vec::new(),
+            },
+        )
+        .unwrap();
+        assert!(!is_address_allowed(&io, &legacy));
+    }
+
     #[test]
     fn test_check_set_whitelist_status() {
         let storage = RefCell::new(Storage::default());
@@ -8,6 +8,8 @@ use aurora_engine_types::AsBytes;
 use crate::prelude::Vec;
 
 const STATUS: &[u8] = b"LIST_STATUS";
+const LENGTH: &[u8] = b"LIST_LENGTH";
+const ENTRY: &[u8] = b"LIST_ENTRY";
 
 /// `Whitelist` for checking access before interacting with the Aurora EVM.
 /// * io - I/O trait handler
@@ -54,18 +56,99 @@ where
         bytes_to_key(KeyPrefix::Whitelist, &bytes)
     }
 
-    /// Add a new element to the whitelist.
+    fn entry_key(&self, index: u32) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(ENTRY.len() + 4);
+
+        bytes.extend_from_slice(ENTRY);
+        bytes.extend_from_slice(&index.to_le_bytes());
+        self.key(&bytes)
+    }
+
+    /// Number of the elements in the index of the whitelist.
+    fn len(&self) -> u32 {
+        self.io
+            .read_storage(&self.key(LENGTH))
+            .and_then(|value| value.to_vec().try_into().ok())
+            .map_or(0, u32::from_le_bytes)
+    }
+
+    fn set_len(&mut self, len: u32) {
+        let key = self.key(LENGTH);
+
+        if len == 0 {
+            self.io.remove_storage(&key);
+        } else {
+            self.io.write_storage(&key, &len.to_le_bytes());
+        }
+    }
+
+    /// Position of the element in the index. The elements added before the index existed are
+    /// stored with an empty value, so they have no position.
+    fn index_of(&self, element_key: &[u8]) -> Option<u32> {
+        self.io
+            .read_storage(element_key)
+            .and_then(|value| value.to_vec().try_into().ok())
+            .map(u32::from_le_bytes)
+    }
+
+    /// Add a new element to the whitelist. An element added before the index existed is indexed.
     pub fn add<A: AsBytes + ?Sized>(&mut self, element: &A) {
         let key = self.key(element.as_bytes());
-        self.io.write_storage(&key, &[]);
+
+        if self.index_of(&key).is_some() {
+            return;
+        }
+
+        let index = self.len();
+        self.io
+            .write_storage(&self.entry_key(index), element.as_bytes());
+        self.io.write_storage(&key, &index.to_le_bytes());
+        self.set_len(index + 1);
     }
 
     /// Remove a new element from the whitelist.
     pub fn remove<A: AsBytes + ?Sized>(&mut self, element: &A) {
         let key = self.key(element.as_bytes());
+
+        if let Some(index) = self.index_of(&key) {
+            // Move the last element of the index into the freed position.
+            let last = self.len().saturating_sub(1);
+            if index != last {
+                if let Some(last_element) = self.io.read_storage(&self.entry_key(last)) {
+                    let last_element = last_element.to_vec();
+                    self.io.write_storage(&self.entry_key(index), &last_element);
+                    self.io
+                        .write_storage(&self.key(&last_element), &index.to_le_bytes());
+                }
+            }
+            self.io.remove_storage(&self.entry_key(last));
+            self.set_len(last);
+        }
+
         self.io.remove_storage(&key);
     }
 
+    /// Return all the indexed elements of the whitelist.
+    pub fn entries(&self) -> Vec<Vec<u8>> {
+        (0..self.len())
+            .filter_map(|index| self.io.read_storage(&self.entry_key(index)))
+            .map(|value| value.to_vec())
+            .collect()
+    }
+
+    /// Remove all the indexed elements from the whitelist.
+    pub fn clear(&mut self) {
+        for element in self.entries() {
+            let key = self.key(&element);
+            self.io.remove_storage(&key);
+        }
+        for index in 0..self.len() {
+            let key = self.entry_key(index);
+            self.io.remove_storage(&key);
+        }
+        self.set_len(0);
+    }
+
     /// Check if the element is present in the whitelist.
     pub fn is_exist<A: AsBytes + ?Sized>(&self, element: &A) -> bool {
         let key = self.key(element.as_bytes());
@@ -98,6 +181,7 @@ mod tests {
     use aurora_engine_test_doubles::io::{Storage, StoragePointer};
     use aurora_engine_types::account_id::AccountId;
     use aurora_engine_types::types::Address;
+    use aurora_engine_types::AsBytes;
     use std::cell::RefCell;
 
     #[test]
@@ -144,4 +228,35 @@ mod tests {
         white_list.disable();
         assert!(!white_list.is_enabled());
     }
+
+    #[test]
+    fn test_whitelist_entries() {
+        let storage = RefCell::new(Storage::default());
+        let io = StoragePointer(&storage);
+        let mut white_list = Whitelist::init(&io, WhitelistKind::Address);
+        let addresses: Vec<_> = (1..=3).map(|i| Address::from_array([i; 20])).collect();
+
+        for address in &addresses {
+            white_list.add(address);
+        }
+        // Adding an element twice doesn't duplicate it in the index.
+        white_list.add(&addresses[0]);
+        assert_eq!(white_list.entries().len(), 3);
+
+        white_list.remove(&addresses[0]);
+        assert!(!white_list.is_exist(&addresses[0]));
+        assert_eq!(
+            white_list.entries(),
+            vec![
+                addresses[2].as_bytes().to_vec(),
+                addresses[1].as_bytes().to_vec()
+            ]
+        );
+        assert!(white_list.is_exist(&addresses[2]));
+
+        white_list.clear();
+        assert!(white_list.entries().is_empty());
+        assert!(!white_list.is_exist(&addresses[1]));
+        assert!(!white_list.is_exist(&addresses[2]));
+    }
 }
@@ -14,7 +14,10 @@ use aurora_engine_sdk::{
 use aurora_engine_types::{
     account_id::AccountId,
     borsh, format,
-    parameters::{engine::SubmitResult, xcc::WithdrawWnearToRouterArgs},
+    parameters::{
+        engine::SubmitResult,
+        xcc::{SetNearGasPerEvmGasArgs, WithdrawWnearToRouterArgs},
+    },
     types::Address,
 };
 use function_name::named;
@@ -120,6 +123,33 @@ pub fn factory_get_wnear_address<I: IO + Copy>(mut io: I) -> Result<(), Contract
     Ok(())
 }
 
+pub fn get_xcc_near_gas_per_evm_gas<I: IO + Copy>(mut io: I) -> Result<(), ContractError> {
+    let value = xcc::get_near_gas_per_evm_gas(&io);
+    let bytes = borsh::to_vec(&value).map_err(|_| errors::ERR_SERIALIZE)?;
+    io.return_output(&bytes);
+    Ok(())
+}
+
+#[named]
+pub fn set_xcc_near_gas_per_evm_gas<I: IO + Copy, E: Env>(
+    io: I,
+    env: &E,
+) -> Result<(), ContractError> {
+    with_hashchain(io, env, function_name!(), |mut io| {
+        let state = state::get_state(&io)?;
+        require_running(&state)?;
+        require_owner_only(&state, &env.predecessor_account_id())?;
+
+        let args: SetNearGasPerEvmGasArgs = io.read_input_borsh()?;
+        // Zero would make the precompile divide by zero.
+        if args.near_gas_per_evm_gas == Some(0) {
+            return Err(errors::ERR_INVALID_NEAR_GAS_PER_EVM_GAS.into());
+        }
+        xcc::set_near_gas_per_evm_gas(&mut io, args.near_gas_per_evm_gas);
+        Ok(())
+    })
+}
+
 #[named]
 pub fn fund_xcc_sub_account<I: IO + Copy, E: Env, H: PromiseHandler>(
     io: I,
@@ -130,15 +160,18 @@ pub fn fund_xcc_sub_account<I: IO + Copy, E: Env, H: PromiseHandler>(
         let state = state::get_state(&io)?;
         require_running(&state)?;
 
-        let args: xcc::FundXccArgs = io.read_input_borsh()?;
+        let args: xcc::FundXccArgsV2 = xcc::FundXccCallArgs::deserialize(&io.read_input().to_vec())
+            .ok_or(errors::ERR_BORSH_DESERIALIZE)?
+            .into();
 
         // If a specific wNEAR account is specified then this transaction must
         // come from a trusted user. The wNEAR account must be accurate for the
         // XCC sub-account to work properly.
-        // This method can be public when `args.wnear_account_id.is_none()`
-        // because then the Engine figures out the correct wNEAR account on
-        // its own.
-        if args.wnear_account_id.is_some() {
+        // Skipping the wNEAR registration is also restricted because a router
+        // created without it cannot receive wNEAR.
+        // This method can be public otherwise because then the Engine figures
+        // out the correct wNEAR account on its own.
+        if args.wnear_account_id.is_some() || args.skip_wnear_registration {
             require_owner_only(&state, &env.predecessor_account_id())?;
         }
 
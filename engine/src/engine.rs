@@ -1,10 +1,12 @@
 use crate::parameters::{
-    CallArgs, NEP141FtOnTransferArgs, ResultLog, SubmitArgs, SubmitResult, ViewCallArgs,
+    CallArgs, NEP141FtOnTransferArgs, ResultLog, SimulateDeployArgs, SimulateDeployResult,
+    SubmitArgs, SubmitResult, ViewCallArgs,
 };
 use aurora_engine_types::public_key::PublicKey;
 use aurora_engine_types::PhantomData;
 use core::mem;
 use evm::backend::{Apply, ApplyBackend, Backend, Basic, Log};
+use evm::executor::stack::StackState;
 use evm::{executor, Opcode};
 use evm::{Config, CreateScheme, ExitError, ExitFatal, ExitReason};
 
@@ -24,15 +26,18 @@ use crate::pausables::{
     EngineAuthorizer, EnginePrecompilesPauser, PausedPrecompilesChecker, PrecompileFlags,
 };
 use crate::prelude::parameters::RefundCallArgs;
+use crate::prelude::precompiles::modexp::{DynModExp, ModExpFn};
 use crate::prelude::precompiles::native::{exit_to_ethereum, exit_to_near};
+use crate::prelude::precompiles::secp256k1;
 use crate::prelude::precompiles::xcc::cross_contract_call;
-use crate::prelude::precompiles::Precompiles;
+use crate::prelude::precompiles::{AllPrecompiles, Berlin, Precompiles};
 use crate::prelude::transactions::{EthTransactionKind, NormalizedEthTransaction};
 use crate::prelude::{
     address_to_key, bytes_to_key, format, sdk, storage_to_key, u256_to_arr, vec, AccountId,
-    Address, BTreeMap, BorshDeserialize, Cow, KeyPrefix, PromiseArgs, PromiseCreateArgs, String,
-    Vec, Wei, Yocto, ERC20_DIGITS_SELECTOR, ERC20_MINT_SELECTOR, ERC20_NAME_SELECTOR,
-    ERC20_SET_METADATA_SELECTOR, ERC20_SYMBOL_SELECTOR, H160, H256, U256,
+    Address, BTreeMap, BorshDeserialize, Box, Cow, KeyPrefix, PromiseArgs, PromiseCreateArgs,
+    String, Vec, Wei, Yocto, ERC20_DIGITS_SELECTOR, ERC20_MINT_SELECTOR, ERC20_NAME_SELECTOR,
+    ERC20_SET_METADATA_SELECTOR, ERC20_SYMBOL_SELECTOR, H160, H256, PAYMASTER_SPONSOR_SELECTOR,
+    U256,
 };
 use crate::state::EngineState;
 use aurora_engine_modexp::{AuroraModExp, ModExpAlgorithm};
@@ -40,9 +45,14 @@ use aurora_engine_precompiles::PrecompileConstructorContext;
 use aurora_engine_types::parameters::connector::{
     Erc20Identifier, Erc20Metadata, MirrorErc20TokenArgs,
 };
-use aurora_engine_types::parameters::engine::FunctionCallArgsV2;
-use aurora_engine_types::types::EthGas;
-use core::cell::RefCell;
+use aurora_engine_types::parameters::engine::{
+    AccountInfo, EvmConfig, FeeShare, FunctionCallArgsV2, GenesisInfo, LogLevel,
+    ModExpAlgorithmKind, PrecompileInfo, TransactionReceipt, VerifySignatureArgs,
+    FEE_DISTRIBUTION_TOTAL_BASIS_POINTS,
+};
+use aurora_engine_types::parameters::PromiseBatchAction;
+use aurora_engine_types::types::{EthGas, RawH256};
+use core::cell::{OnceCell, RefCell};
 use core::iter::once;
 
 /// Used as the first byte in the concatenation of data used to compute the blockhash.
@@ -51,6 +61,73 @@ const BLOCK_HASH_PREFIX: u8 = 0;
 const BLOCK_HASH_PREFIX_SIZE: usize = 1;
 const BLOCK_HEIGHT_SIZE: usize = 8;
 const CHAIN_ID_SIZE: usize = 32;
+/// Key of the accumulator of the fees collected by the engine. Only the base fee is collected,
+/// so the accumulator doesn't grow while `BASE_FEE_PER_GAS` is zero.
+const COLLECTED_FEES_KEY: &[u8] = b"COLLECTED_FEES";
+/// Key of the weights used to split the collected fees between the treasuries.
+const FEE_DISTRIBUTION_KEY: &[u8] = b"FEE_DISTRIBUTION";
+/// Key of the maximum number of logs a single transaction may emit.
+const MAX_LOGS_PER_TX_KEY: &[u8] = b"MAX_LOGS_PER_TX";
+/// Key of the maximum nonce gap allowed in the relaxed nonce mode.
+const MAX_NONCE_GAP_KEY: &[u8] = b"MAX_NONCE_GAP";
+/// Key of the quotient of the refund cap.
+const MAX_REFUND_QUOTIENT_KEY: &[u8] = b"MAX_REFUND_QUOTIENT";
+/// Key of the maximum size of the EVM memory.
+const EVM_MEMORY_LIMIT_KEY: &[u8] = b"EVM_MEMORY_LIMIT";
+/// Key of the maximum number of EVM opcodes a view call may execute.
+const VIEW_STEP_LIMIT_KEY: &[u8] = b"VIEW_STEP_LIMIT";
+/// Key of the address which receives the priority fees.
+const TIP_RECIPIENT_KEY: &[u8] = b"TIP_RECIPIENT";
+/// Key of the address of the contract which sponsors the gas of the transactions.
+const PAYMASTER_KEY: &[u8] = b"PAYMASTER";
+/// Key of the bytecode used to deploy the bridged ERC-20 tokens.
+const ERC20_TEMPLATE_KEY: &[u8] = b"ERC20_TEMPLATE";
+/// Key for storing the verbosity of the engine logs.
+const LOG_LEVEL_KEY: &[u8] = b"LOG_LEVEL";
+/// Key for storing the flag which enables the optional details of `SubmitResult`.
+const DIAGNOSTICS_KEY: &[u8] = b"DIAGNOSTICS";
+/// Key for storing the flag which enables counting the transactions submitted by each address.
+const TX_COUNT_TRACKING_KEY: &[u8] = b"TX_COUNT_TRACKING";
+/// Key for storing the flag which enables persisting the receipts of the submitted transactions.
+const RECEIPTS_PERSISTENCE_KEY: &[u8] = b"RECEIPTS_PERSISTENCE";
+/// Key for storing the seed which replaces the NEAR block seed in the random seed precompile.
+const RANDOM_SEED_OVERRIDE_KEY: &[u8] = b"RANDOM_SEED_OVERRIDE";
+/// Key for storing the implementation of the modexp precompile selected by the owner.
+const MODEXP_ALGORITHM_KEY: &[u8] = b"MODEXP_ALGORITHM";
+/// Key for storing the flag which rejects the transactions with a zero gas price.
+const ZERO_GAS_PRICE_DISALLOWED_KEY: &[u8] = b"ZERO_GAS_PRICE_DISALLOWED";
+/// Key for storing the flag which rejects the contract creations with an empty init code.
+const EMPTY_INIT_CODE_DISALLOWED_KEY: &[u8] = b"EMPTY_INIT_CODE_DISALLOWED";
+/// Key for storing the flag which rejects the legacy transactions signed without a chain ID.
+const CHAINLESS_TX_DISALLOWED_KEY: &[u8] = b"CHAINLESS_TX_DISALLOWED";
+/// Key of the EVM gas limit of the calls made when receiving NEP-141 tokens in `ft_on_transfer`.
+const FT_ON_TRANSFER_GAS_LIMIT_KEY: &[u8] = b"FT_ON_TRANSFER_GAS_LIMIT";
+/// Key of the EVM gas limit of the deployments made by `deploy_erc20_token`.
+const ERC20_DEPLOY_GAS_LIMIT_KEY: &[u8] = b"ERC20_DEPLOY_GAS_LIMIT";
+/// Key of the fee withheld from the storage deposit refunded by `storage_unregister`.
+const UNREGISTER_FEE_KEY: &[u8] = b"UNREGISTER_FEE";
+/// Prefix of the keys of the generations of the ERC-20 tokens mapped to NEP-141 tokens.
+const ERC20_GENERATION_KEY: &[u8] = b"ERC20_GENERATION";
+/// Key of the block of the first transaction executed by `submit`.
+const GENESIS_INFO_KEY: &[u8] = b"GENESIS_INFO";
+/// Key for storing the flag which reverts the calls to the reserved precompile addresses
+/// without a precompile.
+const UNKNOWN_PRECOMPILES_REJECTED_KEY: &[u8] = b"UNKNOWN_PRECOMPILES_REJECTED";
+
+/// Default EVM gas limit of the `ft_on_transfer` calls. The calls are only bounded
+/// by the NEAR gas attached to the receipt. It is also the highest limit which can be set,
+/// so the configured limit can only lower the gas available to the calls.
+pub const DEFAULT_FT_ON_TRANSFER_GAS_LIMIT: u64 = u64::MAX;
+/// The `ft_on_transfer` gas limit can't be lower than the base cost of an EVM call.
+pub const MIN_FT_ON_TRANSFER_GAS_LIMIT: u64 = 21_000;
+/// Default EVM gas limit of the `deploy_erc20_token` deployments. As for `ft_on_transfer`, the
+/// deployments are only bounded by the attached NEAR gas, whatever the size of the ERC-20 template.
+pub const DEFAULT_ERC20_DEPLOY_GAS_LIMIT: u64 = u64::MAX;
+/// The `deploy_erc20_token` gas limit can't be lower than the base cost of a contract creation.
+pub const MIN_ERC20_DEPLOY_GAS_LIMIT: u64 = 53_000;
+/// EVM gas limit of the `sponsor` call made to the paymaster. Nobody pays for the call,
+/// so it is bounded to keep the NEAR gas of `submit` predictable.
+pub const PAYMASTER_GAS_LIMIT: u64 = 100_000;
 
 /// Block height where the bug fix for parsing transactions to the zero address
 /// is deployed. The current value is only approximate; will be updated once the
@@ -75,6 +152,20 @@ impl From<EngineErrorKind> for EngineError {
     }
 }
 
+impl EngineError {
+    /// Returns `true` if the transaction was rejected before its execution (e.g. because it
+    /// couldn't be parsed or its nonce is invalid), in which case it didn't change the state.
+    #[must_use]
+    pub const fn is_rejection(&self) -> bool {
+        match self.kind {
+            EngineErrorKind::EvmError(_) | EngineErrorKind::EvmFatal(_) => false,
+            // The errors of the refund after the execution report the gas used by the transaction.
+            EngineErrorKind::GasPayment(_) => self.gas_used == 0,
+            _ => true,
+        }
+    }
+}
+
 impl AsRef<[u8]> for EngineError {
     fn as_ref(&self) -> &[u8] {
         self.kind.as_bytes()
@@ -105,6 +196,11 @@ pub enum EngineErrorKind {
     NonExistedKey,
     Erc20FromNep141,
     RejectCallerWithCode,
+    DeployNotAllowed,
+    ZeroGasPrice,
+    EmptyInitCode,
+    NonceGapTooLarge,
+    TxExpired,
 }
 
 impl EngineErrorKind {
@@ -145,6 +241,11 @@ impl EngineErrorKind {
             Self::NonExistedKey => errors::ERR_FUNCTION_CALL_KEY_NOT_FOUND,
             Self::Erc20FromNep141 => errors::ERR_GETTING_ERC20_FROM_NEP141,
             Self::RejectCallerWithCode => errors::ERR_REJECT_CALL_WITH_CODE,
+            Self::DeployNotAllowed => errors::ERR_DEPLOY_NOT_ALLOWED,
+            Self::ZeroGasPrice => errors::ERR_ZERO_GAS_PRICE,
+            Self::EmptyInitCode => errors::ERR_EMPTY_INIT_CODE,
+            Self::NonceGapTooLarge => errors::ERR_NONCE_GAP_TOO_LARGE,
+            Self::TxExpired => errors::ERR_TX_EXPIRED,
             Self::EvmFatal(_) | Self::EvmError(_) => unreachable!(), // unused misc
         }
     }
@@ -235,6 +336,8 @@ pub enum GasPaymentError {
     EthAmountOverflow,
     /// Not enough balance for account to cover the gas cost
     OutOfFund,
+    /// Not enough balance for the paymaster to cover the gas cost of a sponsored transaction
+    PaymasterOutOfFund,
 }
 
 impl AsRef<[u8]> for GasPaymentError {
@@ -243,6 +346,7 @@ impl AsRef<[u8]> for GasPaymentError {
             Self::BalanceOverflow(overflow) => overflow.as_ref(),
             Self::EthAmountOverflow => errors::ERR_GAS_ETH_AMOUNT_OVERFLOW,
             Self::OutOfFund => errors::ERR_OUT_OF_FUND,
+            Self::PaymasterOutOfFund => errors::ERR_PAYMASTER_OUT_OF_FUND,
         }
     }
 }
@@ -376,13 +480,19 @@ impl AsRef<[u8]> for ReadMetadataError {
 pub struct StackExecutorParams<'a, I, E, H> {
     precompiles: Precompiles<'a, I, E, H>,
     gas_limit: u64,
+    config: Config,
 }
 
 impl<'env, I: IO + Copy, E: Env, H: ReadOnlyPromiseHandler> StackExecutorParams<'env, I, E, H> {
-    const fn new(gas_limit: u64, precompiles: Precompiles<'env, I, E, H>) -> Self {
+    fn new(
+        gas_limit: u64,
+        precompiles: Precompiles<'env, I, E, H>,
+        engine_config: &EngineConfig,
+    ) -> Self {
         Self {
             precompiles,
             gas_limit,
+            config: evm_config(engine_config.evm_memory_limit),
         }
     }
 
@@ -391,14 +501,25 @@ impl<'env, I: IO + Copy, E: Env, H: ReadOnlyPromiseHandler> StackExecutorParams<
         &'a self,
         engine: &'a Engine<'env, I, E, M>,
     ) -> executor::stack::StackExecutor<
-        'static,
         'a,
-        executor::stack::MemoryStackState<Engine<'env, I, E, M>>,
+        'a,
+        executor::stack::MemoryStackState<'a, 'a, Engine<'env, I, E, M>>,
         Precompiles<'env, I, E, H>,
     > {
-        let metadata = executor::stack::StackSubstateMetadata::new(self.gas_limit, CONFIG);
+        let metadata = executor::stack::StackSubstateMetadata::new(self.gas_limit, &self.config);
         let state = executor::stack::MemoryStackState::new(metadata, engine);
-        executor::stack::StackExecutor::new_with_precompiles(state, CONFIG, &self.precompiles)
+        executor::stack::StackExecutor::new_with_precompiles(state, &self.config, &self.precompiles)
+    }
+
+    /// The EVM halts with `ExitFatal::NotSupported` if the execution tries to expand the memory
+    /// beyond the configured limit. Such executions are treated as running out of gas.
+    fn memory_limit_exit_reason(&self, exit_reason: ExitReason) -> ExitReason {
+        match exit_reason {
+            ExitReason::Fatal(ExitFatal::NotSupported) if self.config.memory_limit < usize::MAX => {
+                ExitReason::Error(ExitError::OutOfGas)
+            }
+            exit_reason => exit_reason,
+        }
     }
 }
 
@@ -409,6 +530,53 @@ pub struct GasPaymentResult {
     pub priority_fee_per_gas: U256,
 }
 
+/// Flags set by the owner which are read by the transactions. They are loaded from the storage
+/// once per engine instead of once per use, see [`Engine::config`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EngineConfig {
+    /// Whether the relaxed nonce mode is enabled for the predecessor of the call.
+    pub relaxed_nonce: bool,
+    pub gas_refunds_enabled: bool,
+    pub evm_memory_limit: Option<u64>,
+    pub log_level: LogLevel,
+    pub modexp_algorithm: ModExpAlgorithmKind,
+    pub zero_gas_price_allowed: bool,
+    pub random_seed_override: Option<H256>,
+    pub max_refund_quotient: u64,
+    pub max_logs_per_tx: Option<u64>,
+}
+
+impl EngineConfig {
+    #[must_use]
+    pub fn load<I: IO, E: Env>(io: &I, env: &E) -> Self {
+        Self {
+            relaxed_nonce: is_relaxed_nonce_enabled(io, &env.predecessor_account_id()),
+            gas_refunds_enabled: silo::is_gas_refunds_enabled(io),
+            evm_memory_limit: get_evm_memory_limit(io),
+            log_level: get_log_level(io),
+            modexp_algorithm: get_modexp_algorithm(io),
+            zero_gas_price_allowed: is_zero_gas_price_allowed(io),
+            random_seed_override: get_random_seed_override(io),
+            max_refund_quotient: get_max_refund_quotient(io),
+            max_logs_per_tx: get_max_logs_per_tx(io),
+        }
+    }
+
+    /// Returns whether the logs of the given level are enabled.
+    #[must_use]
+    pub fn is_log_level_enabled(&self, log_level: LogLevel) -> bool {
+        self.log_level >= log_level
+    }
+
+    /// Returns whether the number of logs emitted by a transaction is above the limit.
+    #[must_use]
+    pub fn exceeds_logs_limit(&self, logs_count: usize) -> bool {
+        self.max_logs_per_tx.map_or(false, |max_logs| {
+            usize::try_from(max_logs).map_or(false, |max_logs| logs_count > max_logs)
+        })
+    }
+}
+
 pub struct Engine<'env, I: IO, E: Env, M = AuroraModExp> {
     state: EngineState,
     origin: Address,
@@ -420,10 +588,16 @@ pub struct Engine<'env, I: IO, E: Env, M = AuroraModExp> {
     account_info_cache: RefCell<FullCache<Address, Basic>>,
     contract_code_cache: RefCell<FullCache<Address, Vec<u8>>>,
     contract_storage_cache: RefCell<FullCache<(Address, H256), H256>>,
+    config: OnceCell<EngineConfig>,
     modexp_algorithm: PhantomData<M>,
 }
 
 pub(crate) const CONFIG: &Config = &Config::cancun();
+/// Name of the hard fork of the EVM configuration.
+pub(crate) const EVM_FORK: &str = "cancun";
+
+/// Base fee per gas. Aurora doesn't burn any part of the fee, so it is always zero.
+pub const BASE_FEE_PER_GAS: U256 = U256::zero();
 
 impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
     pub fn new(
@@ -454,10 +628,32 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
             account_info_cache: RefCell::new(FullCache::default()),
             contract_code_cache: RefCell::new(FullCache::default()),
             contract_storage_cache: RefCell::new(FullCache::default()),
+            config: OnceCell::new(),
             modexp_algorithm: PhantomData,
         }
     }
 
+    /// Same as `new_with_state` for the callers which already loaded the configuration.
+    pub fn new_with_config(
+        state: EngineState,
+        config: EngineConfig,
+        origin: Address,
+        current_account_id: AccountId,
+        io: I,
+        env: &'env E,
+    ) -> Self {
+        let engine = Self::new_with_state(state, origin, current_account_id, io, env);
+        let _ = engine.config.set(config);
+        engine
+    }
+
+    /// Returns the configuration set by the owner, which is loaded on the first use.
+    #[must_use]
+    pub fn config(&self) -> &EngineConfig {
+        self.config
+            .get_or_init(|| EngineConfig::load(&self.io, self.env))
+    }
+
     pub fn charge_gas(
         &mut self,
         sender: &Address,
@@ -469,12 +665,8 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
             return Ok(GasPaymentResult::default());
         }
 
-        let priority_fee_per_gas = transaction
-            .max_priority_fee_per_gas
-            .min(transaction.max_fee_per_gas - self.block_base_fee_per_gas());
-        let priority_fee_per_gas = max_gas_price.map_or(priority_fee_per_gas, |price| {
-            price.min(priority_fee_per_gas)
-        });
+        let priority_fee_per_gas =
+            priority_fee_per_gas(transaction, self.block_base_fee_per_gas(), max_gas_price);
         let effective_gas_price = priority_fee_per_gas + self.block_base_fee_per_gas();
         // First we try to use `fixed_gas`. At this point we already know that the `fixed_gas` is
         // less than the `gas_limit`. It allows to avoid refund unused gas to the sender later.
@@ -524,7 +716,7 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
         let pause_flags = EnginePrecompilesPauser::from_io(self.io).paused();
         let precompiles = self.create_precompiles(pause_flags, handler);
 
-        let executor_params = StackExecutorParams::new(gas_limit, precompiles);
+        let executor_params = StackExecutorParams::new(gas_limit, precompiles, self.config());
         let mut executor = executor_params.make_executor(self);
         let scheme = address.map_or_else(
             || CreateScheme::Legacy {
@@ -547,16 +739,27 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
             ),
             CreateScheme::Create2 { .. } => unreachable!(),
         };
+        let exit_reason = executor_params.memory_limit_exit_reason(exit_reason);
         let result = if exit_reason.is_succeed() {
             address.0.to_vec()
         } else {
             return_value
         };
 
-        let used_gas = executor.used_gas();
+        let gasometer = executor.state().metadata().gasometer();
+        let used_gas = accounting::used_gas(
+            gasometer.total_used_gas(),
+            gasometer.refunded_gas(),
+            self.config().max_refund_quotient,
+            self.config().gas_refunds_enabled,
+        );
         let status = exit_reason.into_result(result)?;
 
         let (values, logs) = executor.into_state().deconstruct();
+        let logs: Vec<Log> = logs.into_iter().collect();
+        if self.config().exceeds_logs_limit(logs.len()) {
+            return Ok(self.too_many_logs_result(&origin, used_gas));
+        }
         let logs = filter_promises_from_logs(&self.io, handler, logs, &self.current_account_id);
 
         self.apply(values, Vec::<Log>::new(), true);
@@ -617,7 +820,7 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
         let pause_flags = EnginePrecompilesPauser::from_io(self.io).paused();
         let precompiles = self.create_precompiles(pause_flags, handler);
 
-        let executor_params = StackExecutorParams::new(gas_limit, precompiles);
+        let executor_params = StackExecutorParams::new(gas_limit, precompiles, self.config());
         let mut executor = executor_params.make_executor(self);
         let (exit_reason, result) = executor.transact_call(
             origin.raw(),
@@ -627,11 +830,22 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
             gas_limit,
             access_list,
         );
-
-        let used_gas = executor.used_gas();
+        let exit_reason = executor_params.memory_limit_exit_reason(exit_reason);
+
+        let gasometer = executor.state().metadata().gasometer();
+        let used_gas = accounting::used_gas(
+            gasometer.total_used_gas(),
+            gasometer.refunded_gas(),
+            self.config().max_refund_quotient,
+            self.config().gas_refunds_enabled,
+        );
         let status = exit_reason.into_result(result)?;
 
         let (values, logs) = executor.into_state().deconstruct();
+        let logs: Vec<Log> = logs.into_iter().collect();
+        if self.config().exceeds_logs_limit(logs.len()) {
+            return Ok(self.too_many_logs_result(origin, used_gas));
+        }
         let logs = filter_promises_from_logs(&self.io, handler, logs, &self.current_account_id);
         // The logs could be encoded as base64 or hex string.
         self.apply(values, Vec::<Log>::new(), true);
@@ -639,6 +853,15 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
         Ok(SubmitResult::new(status, used_gas, logs))
     }
 
+    /// Result of a transaction which emitted more logs than allowed. Like a revert, the state
+    /// changes of the transaction are discarded, but the nonce of the sender is still bumped and
+    /// the gas is charged, so the transaction can't be replayed for free.
+    fn too_many_logs_result(&mut self, origin: &Address, used_gas: u64) -> SubmitResult {
+        increment_nonce(&mut self.io, origin);
+        let status = TransactionStatus::Other(Cow::Borrowed("ERR_TOO_MANY_LOGS"));
+        SubmitResult::new(status, used_gas, Vec::new())
+    }
+
     pub fn view_with_args(&self, args: ViewCallArgs) -> Result<TransactionStatus, EngineErrorKind> {
         let origin = &args.sender;
         let contract = &args.address;
@@ -648,7 +871,7 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
         let pause_flags = EnginePrecompilesPauser::from_io(self.io).paused();
         let precompiles = self.create_precompiles(pause_flags, &handler);
 
-        let executor_params = StackExecutorParams::new(u64::MAX, precompiles);
+        let executor_params = StackExecutorParams::new(u64::MAX, precompiles, self.config());
         self.view(
             origin,
             contract,
@@ -658,6 +881,53 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
         )
     }
 
+    /// Runs the deployment of the code by the sender without persisting any changes. The address
+    /// of the contract is computed from the current nonce of the sender, which isn't incremented.
+    pub fn simulate_deploy(
+        &self,
+        args: SimulateDeployArgs,
+    ) -> Result<SimulateDeployResult, EngineErrorKind> {
+        // View calls cannot interact with promises
+        let handler = aurora_engine_sdk::promise::Noop;
+        let pause_flags = EnginePrecompilesPauser::from_io(self.io).paused();
+        let precompiles = self.create_precompiles(pause_flags, &handler);
+
+        let executor_params = StackExecutorParams::new(u64::MAX, precompiles, self.config());
+        let mut executor = executor_params.make_executor(self);
+        let scheme = CreateScheme::Legacy {
+            caller: args.sender.raw(),
+        };
+        let address = executor.create_address(scheme);
+        let (exit_reason, return_value) = executor.transact_create(
+            args.sender.raw(),
+            U256::zero(),
+            args.code,
+            executor_params.gas_limit,
+            Vec::new(),
+        );
+        let exit_reason = executor_params.memory_limit_exit_reason(exit_reason);
+        let result = if exit_reason.is_succeed() {
+            address.0.to_vec()
+        } else {
+            return_value
+        };
+
+        let gasometer = executor.state().metadata().gasometer();
+        let gas_used = accounting::used_gas(
+            gasometer.total_used_gas(),
+            gasometer.refunded_gas(),
+            self.config().max_refund_quotient,
+            self.config().gas_refunds_enabled,
+        );
+        let status = exit_reason.into_result(result)?;
+
+        Ok(SimulateDeployResult {
+            address: Address::new(address),
+            gas_used,
+            status,
+        })
+    }
+
     pub fn view(
         &self,
         origin: &Address,
@@ -675,7 +945,49 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
             executor_params.gas_limit,
             Vec::new(),
         );
-        status.into_result(result)
+        executor_params
+            .memory_limit_exit_reason(status)
+            .into_result(result)
+    }
+
+    /// Asks the paymaster whether it sponsors the gas of the transaction. The paymaster approves
+    /// by returning `true` from `sponsor(address sender, address to, uint256 maxFee)`, where `to`
+    /// is the zero address for deployments. Any other outcome, including a revert, is a denial.
+    pub fn is_sponsored_by(
+        &self,
+        paymaster: &Address,
+        transaction: &NormalizedEthTransaction,
+    ) -> bool {
+        let max_fee = transaction
+            .gas_limit
+            .saturating_mul(transaction.max_fee_per_gas);
+        let args = ethabi::encode(&[
+            ethabi::Token::Address(transaction.address.raw()),
+            ethabi::Token::Address(transaction.to.unwrap_or_default().raw()),
+            ethabi::Token::Uint(max_fee),
+        ]);
+        let input = [PAYMASTER_SPONSOR_SELECTOR, args.as_slice()].concat();
+        // The sponsorship check cannot interact with promises
+        let handler = aurora_engine_sdk::promise::Noop;
+        let pause_flags = EnginePrecompilesPauser::from_io(self.io).paused();
+        let precompiles = self.create_precompiles(pause_flags, &handler);
+        let executor_params =
+            StackExecutorParams::new(PAYMASTER_GAS_LIMIT, precompiles, self.config());
+
+        let Ok(TransactionStatus::Succeed(output)) = self.view(
+            &transaction.address,
+            paymaster,
+            Wei::zero(),
+            input,
+            &executor_params,
+        ) else {
+            return false;
+        };
+
+        matches!(
+            ethabi::decode(&[ethabi::ParamType::Bool], &output).as_deref(),
+            Ok([ethabi::Token::Bool(true)])
+        )
     }
 
     fn relayer_key(account_id: &[u8]) -> Vec<u8> {
@@ -685,6 +997,8 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
     pub fn register_relayer(&mut self, account_id: &[u8], evm_address: Address) {
         let key = Self::relayer_key(account_id);
         self.io.write_storage(&key, evm_address.as_bytes());
+        self.io
+            .write_storage(&relayer_account_key(&evm_address), account_id);
     }
 
     pub fn get_relayer(&self, account_id: &[u8]) -> Option<Address> {
@@ -709,6 +1023,7 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
             Ok(_) => return Err(RegisterTokenError::TokenAlreadyRegistered),
         }
 
+        increment_erc20_generation(&mut self.io, &nep141_token);
         let erc20_token = ERC20Address(erc20_token);
         let nep141_token = NEP141Account(nep141_token);
         nep141_erc20_map(self.io).insert(&nep141_token, &erc20_token);
@@ -754,6 +1069,7 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
         token: &AccountId,
         args: &NEP141FtOnTransferArgs,
         current_account_id: &AccountId,
+        gas_limit: u64,
         handler: &mut P,
     ) -> Result<SubmitResult, EngineError> {
         const INVALID_MESSAGE: &str = "receive_erc20_tokens invalid message";
@@ -806,7 +1122,7 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
                 &erc20_token,
                 Wei::zero(),
                 setup_receive_erc20_tokens_input(args, &recipient),
-                u64::MAX,
+                gas_limit,
                 Vec::new(), // TODO: are there values we should put here?
                 handler,
             )
@@ -893,25 +1209,76 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
         )
     }
 
+    /// Returns the addresses of all precompiles available in this engine instance, including the
+    /// Aurora-specific ones (e.g. `ExitToNear`, `CrossContractCall`). Paused precompiles are included.
+    pub fn precompile_addresses(&self) -> Vec<Address> {
+        // The set of precompiles doesn't depend on the promise handler.
+        let handler = aurora_engine_sdk::promise::Noop;
+        self.create_precompiles(PrecompileFlags::empty(), &handler)
+            .all_precompiles
+            .into_keys()
+            .collect()
+    }
+
+    /// Returns the precompiles available in this engine instance together with the hard fork
+    /// which introduced them.
+    pub fn precompile_info(&self) -> Vec<PrecompileInfo> {
+        // The set of precompiles doesn't depend on the promise handler or the random seed.
+        let handler = aurora_engine_sdk::promise::Noop;
+        Precompiles::precompile_forks(|| PrecompileConstructorContext {
+            current_account_id: self.current_account_id.clone(),
+            random_seed: H256::zero(),
+            io: self.io,
+            env: self.env,
+            promise_handler: handler.read_only(),
+            mod_exp_algorithm: self.modexp_algorithm,
+        })
+        .into_iter()
+        .map(|(address, fork)| PrecompileInfo {
+            address,
+            fork: fork.into(),
+        })
+        .collect()
+    }
+
     fn create_precompiles<P: PromiseHandler>(
         &self,
         pause_flags: PrecompileFlags,
         handler: &P,
     ) -> Precompiles<'env, I, E, P::ReadOnly> {
         let current_account_id = self.current_account_id.clone();
-        let random_seed = self.env.random_seed();
+        let random_seed = self
+            .config()
+            .random_seed_override
+            .unwrap_or_else(|| self.env.random_seed());
         let io = self.io;
         let env = self.env;
         let ro_promise_handler = handler.read_only();
 
-        let precompiles = Precompiles::new_london(PrecompileConstructorContext {
+        let ctx = PrecompileConstructorContext {
             current_account_id,
             random_seed,
             io,
             env,
             promise_handler: ro_promise_handler,
             mod_exp_algorithm: self.modexp_algorithm,
-        });
+        };
+        let mut precompiles = Precompiles::new_for_fork(EVM_FORK, ctx);
+
+        // The default algorithm is dispatched statically by `M`, the other ones are only known
+        // at runtime.
+        let modexp: Option<ModExpFn> = match self.config().modexp_algorithm {
+            ModExpAlgorithmKind::Aurora => None,
+            ModExpAlgorithmKind::Num => Some(aurora_engine_modexp::modexp_num),
+        };
+        if let Some(modexp) = modexp {
+            precompiles.all_precompiles.insert(
+                DynModExp::<Berlin>::ADDRESS,
+                AllPrecompiles::Generic(Box::new(DynModExp::<Berlin>::new(modexp))),
+            );
+        }
+
+        precompiles.reject_unknown = is_unknown_precompiles_rejected(&io);
 
         Self::apply_pause_flags_to_precompiles(precompiles, pause_flags)
     }
@@ -928,6 +1295,7 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Engine<'env, I, E, M> {
                 .copied()
                 .collect(),
             all_precompiles: precompiles.all_precompiles,
+            reject_unknown: precompiles.reject_unknown,
         }
     }
 
@@ -1006,28 +1374,27 @@ pub fn submit_with_alt_modexp<
     handler: &mut P,
 ) -> EngineResult<SubmitResult> {
     #[cfg(feature = "contract")]
-    let transaction = NormalizedEthTransaction::try_from(
-        EthTransactionKind::try_from(args.tx_data.as_slice())
-            .map_err(EngineErrorKind::FailedTransactionParse)?,
-    )
-    .map_err(|_e| EngineErrorKind::InvalidSignature)?;
+    let eth_transaction = EthTransactionKind::try_from(args.tx_data.as_slice())
+        .map_err(EngineErrorKind::FailedTransactionParse)?;
 
     #[cfg(not(feature = "contract"))]
     // The standalone engine must use the backwards compatible parser to reproduce the NEAR state,
     // but the contract itself does not need to make such checks because it never executes historical
     // transactions.
-    let transaction: NormalizedEthTransaction = {
+    let eth_transaction: EthTransactionKind = {
         let adapter =
             aurora_engine_transactions::backwards_compatibility::EthTransactionKindAdapter::new(
                 ZERO_ADDRESS_FIX_HEIGHT,
             );
         let block_height = env.block_height();
-        let tx: EthTransactionKind = adapter
+        adapter
             .try_parse_bytes(args.tx_data.as_slice(), block_height)
-            .map_err(EngineErrorKind::FailedTransactionParse)?;
-        tx.try_into()
-            .map_err(|_e| EngineErrorKind::InvalidSignature)?
+            .map_err(EngineErrorKind::FailedTransactionParse)?
     };
+    // The hash is only needed to persist the receipt of the transaction.
+    let tx_hash = is_receipts_persistence_enabled(&io).then(|| eth_transaction.hash());
+    let transaction = NormalizedEthTransaction::try_from(eth_transaction)
+        .map_err(|_e| EngineErrorKind::InvalidSignature)?;
     // Retrieve the signer of the transaction:
     let sender = transaction.address;
 
@@ -1036,16 +1403,40 @@ pub fn submit_with_alt_modexp<
     // Check if the sender has rights to submit transactions or deploy code on SILO mode.
     assert_access(&io, env, &fixed_gas, &transaction)?;
 
-    // Validate the chain ID, if provided inside the signature:
-    if let Some(chain_id) = transaction.chain_id {
-        if U256::from(chain_id) != U256::from(state.chain_id) {
+    // Check if the sender is allowed to deploy contracts if the deployer allowlist is enabled.
+    if transaction.to.is_none() && !is_deploy_allowed(&io, &sender) {
+        return Err(EngineErrorKind::DeployNotAllowed.into());
+    }
+
+    if transaction.to.is_none() && transaction.data.is_empty() && !is_empty_init_code_allowed(&io) {
+        return Err(EngineErrorKind::EmptyInitCode.into());
+    }
+
+    // Validate the chain ID, if provided inside the signature. The transactions without a chain
+    // ID (e.g. the EIP-1820 deployment, see #520) are accepted unless rejected by the owner:
+    match transaction.chain_id {
+        Some(chain_id) if U256::from(chain_id) != U256::from(state.chain_id) => {
+            return Err(EngineErrorKind::InvalidChainId.into());
+        }
+        None if !is_chainless_tx_allowed(&io) => {
             return Err(EngineErrorKind::InvalidChainId.into());
         }
+        _ => (),
     }
 
-    sdk::log!("signer_address {:?}", sender);
+    let config = EngineConfig::load(&io, env);
+    sdk::log_if!(
+        config.is_log_level_enabled(LogLevel::Debug),
+        "signer_address {:?}",
+        sender
+    );
 
-    check_nonce(&io, &sender, &transaction.nonce)?;
+    let relaxed_nonce = config.relaxed_nonce;
+    if relaxed_nonce {
+        check_nonce_relaxed(&io, &sender, &transaction.nonce)?;
+    } else {
+        check_nonce(&io, &sender, &transaction.nonce)?;
+    }
 
     // Check that fixed gas is not greater than gasLimit from the transaction.
     if fixed_gas.map_or(false, |gas| gas.as_u256() > transaction.gas_limit) {
@@ -1053,7 +1444,7 @@ pub fn submit_with_alt_modexp<
     }
 
     // Check intrinsic gas is covered by transaction gas limit
-    match transaction.intrinsic_gas(CONFIG) {
+    let intrinsic_gas = match transaction.intrinsic_gas(CONFIG) {
         Err(_e) => {
             return Err(EngineErrorKind::GasOverflow.into());
         }
@@ -1061,35 +1452,71 @@ pub fn submit_with_alt_modexp<
             if transaction.gas_limit < intrinsic_gas.into() {
                 return Err(EngineErrorKind::IntrinsicGasNotMet.into());
             }
+            intrinsic_gas
         }
-    }
+    };
 
     if transaction.max_priority_fee_per_gas > transaction.max_fee_per_gas {
         return Err(EngineErrorKind::MaxPriorityGasFeeTooLarge.into());
     }
 
-    let mut engine: Engine<_, _, M> =
-        Engine::new_with_state(state, sender, current_account_id, io, env);
+    // The gas price is checked once capped by `max_gas_price`, which can bring it down to zero.
+    let max_gas_price = args.max_gas_price.map(Into::into);
+    let gas_price =
+        priority_fee_per_gas(&transaction, BASE_FEE_PER_GAS, max_gas_price) + BASE_FEE_PER_GAS;
+    if gas_price.is_zero() && !config.zero_gas_price_allowed {
+        return Err(EngineErrorKind::ZeroGasPrice.into());
+    }
+
+    let gas_limit = transaction
+        .gas_limit
+        .try_into()
+        .map_err(|_| EngineErrorKind::GasOverflow)?;
+    let diagnostics = is_diagnostics_enabled(&io);
+    let mut handler = PromiseRecorder::new(handler, diagnostics);
+    let mut engine: Engine<_, _, M> = Engine::new_with_config(
+        state.clone(),
+        config.clone(),
+        sender,
+        current_account_id.clone(),
+        io,
+        env,
+    );
     // EIP-3607
     if !engine.code(sender.raw()).is_empty() {
         return Err(EngineErrorKind::RejectCallerWithCode.into());
     }
-    let max_gas_price = args.max_gas_price.map(Into::into);
-    let prepaid_amount = match engine.charge_gas(&sender, &transaction, max_gas_price, fixed_gas) {
+    // The gas is paid by the paymaster if it is configured and sponsors the transaction.
+    // The check runs on its own engine instance: the accounts it reads are cached, and the
+    // cached balances would be stale once the gas is charged.
+    let payer = get_paymaster(&io)
+        .filter(|paymaster| {
+            let sponsorship_engine: Engine<_, _, M> =
+                Engine::new_with_config(state, config, sender, current_account_id, io, env);
+            sponsorship_engine.is_sponsored_by(paymaster, &transaction)
+        })
+        .unwrap_or(sender);
+    let prepaid_amount = match engine.charge_gas(&payer, &transaction, max_gas_price, fixed_gas) {
         Ok(gas_result) => gas_result,
+        Err(GasPaymentError::OutOfFund) if payer != sender => {
+            return Err(EngineErrorKind::GasPayment(GasPaymentError::PaymasterOutOfFund).into());
+        }
         Err(err) => {
             return Err(EngineErrorKind::GasPayment(err).into());
         }
     };
-    let gas_limit = transaction
-        .gas_limit
-        .try_into()
-        .map_err(|_| EngineErrorKind::GasOverflow)?;
     let access_list = transaction
         .access_list
         .into_iter()
         .map(|a| (a.address, a.storage_keys))
         .collect();
+    if relaxed_nonce {
+        // Skip the gap so that the nonce used by the transaction can never be used again.
+        set_nonce(&mut io, &sender, &transaction.nonce);
+    }
+    if is_tx_count_tracking_enabled(&io) {
+        increment_tx_count(&mut io, &sender);
+    }
     let result = if let Some(receiver) = transaction.to {
         engine.call(
             &sender,
@@ -1098,7 +1525,7 @@ pub fn submit_with_alt_modexp<
             transaction.data,
             gas_limit,
             access_list,
-            handler,
+            &mut handler,
         )
         // TODO: charge for storage
     } else {
@@ -1110,7 +1537,7 @@ pub fn submit_with_alt_modexp<
             None,
             gas_limit,
             access_list,
-            handler,
+            &mut handler,
         )
         // TODO: charge for storage
     };
@@ -1123,7 +1550,7 @@ pub fn submit_with_alt_modexp<
 
     refund_unused_gas(
         &mut io,
-        &sender,
+        &payer,
         gas_used,
         &prepaid_amount,
         &relayer_address,
@@ -1134,8 +1561,32 @@ pub fn submit_with_alt_modexp<
         kind: EngineErrorKind::GasPayment(e),
     })?;
 
+    record_genesis_info(&mut io, env);
+
+    if let (Some(tx_hash), Ok(submit_result)) = (tx_hash, &result) {
+        let receipt = TransactionReceipt {
+            status: submit_result.status.clone(),
+            gas_used: submit_result.gas_used,
+            logs: submit_result.logs.clone(),
+            contract_address: transaction
+                .to
+                .is_none()
+                .then(|| create_legacy_address(&sender, &transaction.nonce)),
+            block_height: env.block_height(),
+        };
+        store_receipt(&mut io, &tx_hash, &receipt);
+    }
+
     // return result to user
-    result
+    result.map(|mut submit_result| {
+        submit_result.effective_gas_price =
+            u128::try_from(prepaid_amount.effective_gas_price).unwrap_or(u128::MAX);
+        if diagnostics {
+            submit_result.promises = handler.promises;
+            submit_result.intrinsic_gas = Some(intrinsic_gas);
+        }
+        submit_result
+    })
 }
 
 #[must_use]
@@ -1245,7 +1696,7 @@ pub fn refund_unused_gas<I: IO>(
         return Ok(());
     }
 
-    let (refund, relayer_reward) = {
+    let (refund, priority_fee, collected_fee) = {
         let gas_to_wei = |price: U256| {
             fixed_gas
                 .map_or_else(|| gas_used.into(), EthGas::as_u256)
@@ -1254,26 +1705,581 @@ pub fn refund_unused_gas<I: IO>(
                 .ok_or(GasPaymentError::EthAmountOverflow)
         };
 
-        let spent_amount = gas_to_wei(gas_result.effective_gas_price)?;
-        let reward_amount = gas_to_wei(gas_result.priority_fee_per_gas)?;
+        let spent_amount = gas_to_wei(gas_result.effective_gas_price)?;
+        let reward_amount = gas_to_wei(gas_result.priority_fee_per_gas)?;
+
+        let refund = gas_result
+            .prepaid_amount
+            .checked_sub(spent_amount)
+            .ok_or(GasPaymentError::EthAmountOverflow)?;
+        // The part of the spent amount which is not the priority fee (i.e. the base fee)
+        // is collected by the engine.
+        let fee = spent_amount
+            .checked_sub(reward_amount)
+            .unwrap_or_else(Wei::zero);
+
+        (refund, reward_amount, fee)
+    };
+
+    if !refund.is_zero() {
+        add_balance(io, sender, refund)?;
+    }
+
+    if !priority_fee.is_zero() {
+        if let Some(recipient) = accounting::priority_fee_recipient(get_tip_recipient(io), *relayer)
+        {
+            add_balance(io, &recipient, priority_fee)?;
+        }
+    }
+
+    if !collected_fee.is_zero() {
+        add_collected_fees(io, collected_fee)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the maximum number of logs a single transaction may emit, `None` means unlimited.
+pub fn get_max_logs_per_tx<I: IO>(io: &I) -> Option<u64> {
+    io.read_u64(&max_logs_per_tx_key()).ok()
+}
+
+pub fn set_max_logs_per_tx<I: IO>(io: &mut I, max_logs: Option<u64>) {
+    let key = max_logs_per_tx_key();
+
+    if let Some(max_logs) = max_logs {
+        io.write_storage(&key, &max_logs.to_le_bytes());
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn max_logs_per_tx_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, MAX_LOGS_PER_TX_KEY)
+}
+
+/// Returns the maximum size of the EVM memory in bytes, `None` means unlimited.
+pub fn get_evm_memory_limit<I: IO>(io: &I) -> Option<u64> {
+    io.read_u64(&evm_memory_limit_key()).ok()
+}
+
+pub fn set_evm_memory_limit<I: IO>(io: &mut I, memory_limit: Option<u64>) {
+    let key = evm_memory_limit_key();
+
+    if let Some(memory_limit) = memory_limit {
+        io.write_storage(&key, &memory_limit.to_le_bytes());
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn evm_memory_limit_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, EVM_MEMORY_LIMIT_KEY)
+}
+
+/// Returns the maximum number of EVM opcodes a view call may execute, `None` means unlimited.
+pub fn get_view_step_limit<I: IO>(io: &I) -> Option<u64> {
+    io.read_u64(&view_step_limit_key()).ok()
+}
+
+pub fn set_view_step_limit<I: IO>(io: &mut I, step_limit: Option<u64>) {
+    let key = view_step_limit_key();
+
+    if let Some(step_limit) = step_limit {
+        io.write_storage(&key, &step_limit.to_le_bytes());
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn view_step_limit_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, VIEW_STEP_LIMIT_KEY)
+}
+
+/// Fails the call with `ERR_VIEW_STEP_LIMIT` once the EVM executes more opcodes than the limit
+/// set by `set_view_step_limit`. The EVM can't be halted from its tracing events, so the call is
+/// failed the same way `sdk_unwrap` fails it, which is the error status NEAR returns for views.
+/// The events are counted by installing the global EVM runtime listener, which would replace the
+/// listener of the standalone tracing, so the limit is only enforced by the contract built with
+/// the `view-step-limit` feature.
+#[cfg(all(feature = "view-step-limit", feature = "contract"))]
+pub fn with_view_step_limit<I: IO, R, F: FnOnce() -> R>(io: &I, f: F) -> R {
+    struct StepCounter {
+        steps: u64,
+        limit: u64,
+    }
+
+    impl evm_runtime::tracing::EventListener for StepCounter {
+        fn event(&mut self, event: evm_runtime::tracing::Event) {
+            if let evm_runtime::tracing::Event::Step { .. } = event {
+                self.steps += 1;
+                if self.steps > self.limit {
+                    aurora_engine_sdk::panic_utf8(errors::ERR_VIEW_STEP_LIMIT.as_bytes());
+                }
+            }
+        }
+    }
+
+    let Some(limit) = get_view_step_limit(io) else {
+        return f();
+    };
+    evm_runtime::tracing::using(&mut StepCounter { steps: 0, limit }, f)
+}
+
+#[cfg(not(all(feature = "view-step-limit", feature = "contract")))]
+pub fn with_view_step_limit<I: IO, R, F: FnOnce() -> R>(_io: &I, f: F) -> R {
+    f()
+}
+
+/// Returns the seed which replaces the NEAR block seed in the random seed precompile. It can only
+/// be set by the engines built with the `random-seed-override` feature, but it is always read so
+/// the standalone engine replays the test silos the same way.
+pub fn get_random_seed_override<I: IO>(io: &I) -> Option<H256> {
+    io.read_storage(&random_seed_override_key())
+        .and_then(|value| RawH256::try_from(value.to_vec()).ok())
+        .map(H256)
+}
+
+pub fn set_random_seed_override<I: IO>(io: &mut I, random_seed: Option<RawH256>) {
+    let key = random_seed_override_key();
+
+    if let Some(random_seed) = random_seed {
+        io.write_storage(&key, &random_seed);
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn random_seed_override_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, RANDOM_SEED_OVERRIDE_KEY)
+}
+
+/// Returns the address which receives the priority fees, `None` means the relayer receives them.
+pub fn get_tip_recipient<I: IO>(io: &I) -> Option<Address> {
+    io.read_storage(&tip_recipient_key())
+        .and_then(|value| Address::try_from_slice(&value.to_vec()).ok())
+}
+
+pub fn set_tip_recipient<I: IO>(io: &mut I, tip_recipient: Option<Address>) {
+    let key = tip_recipient_key();
+
+    if let Some(tip_recipient) = tip_recipient {
+        io.write_storage(&key, tip_recipient.as_bytes());
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn tip_recipient_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, TIP_RECIPIENT_KEY)
+}
+
+/// Returns the address of the contract which may sponsor the gas of the transactions,
+/// `None` means the senders always pay for themselves.
+pub fn get_paymaster<I: IO>(io: &I) -> Option<Address> {
+    io.read_storage(&paymaster_key())
+        .and_then(|value| Address::try_from_slice(&value.to_vec()).ok())
+}
+
+pub fn set_paymaster<I: IO>(io: &mut I, paymaster: Option<Address>) {
+    let key = paymaster_key();
+
+    if let Some(paymaster) = paymaster {
+        io.write_storage(&key, paymaster.as_bytes());
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn paymaster_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, PAYMASTER_KEY)
+}
+
+/// Returns whether the optional details of `SubmitResult` are populated during `submit`.
+pub fn is_diagnostics_enabled<I: IO>(io: &I) -> bool {
+    io.storage_has_key(&diagnostics_key())
+}
+
+pub fn set_diagnostics_enabled<I: IO>(io: &mut I, enabled: bool) {
+    let key = diagnostics_key();
+
+    if enabled {
+        io.write_storage(&key, &[1]);
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn diagnostics_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, DIAGNOSTICS_KEY)
+}
+
+/// Returns whether `submit` counts the transactions sent by each address.
+pub fn is_tx_count_tracking_enabled<I: IO>(io: &I) -> bool {
+    io.storage_has_key(&tx_count_tracking_key())
+}
+
+pub fn set_tx_count_tracking_enabled<I: IO>(io: &mut I, enabled: bool) {
+    let key = tx_count_tracking_key();
+
+    if enabled {
+        io.write_storage(&key, &[1]);
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn tx_count_tracking_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, TX_COUNT_TRACKING_KEY)
+}
+
+/// Returns the number of transactions submitted by the address while the tracking was enabled.
+/// Unlike the nonce it is not affected by the gaps allowed in the relaxed nonce mode.
+pub fn get_tx_count<I: IO>(io: &I, address: &Address) -> u64 {
+    io.read_u64(&address_to_key(KeyPrefix::TxCount, address))
+        .unwrap_or_default()
+}
+
+fn increment_tx_count<I: IO>(io: &mut I, address: &Address) {
+    let tx_count = get_tx_count(io, address).saturating_add(1);
+    io.write_storage(
+        &address_to_key(KeyPrefix::TxCount, address),
+        &tx_count.to_le_bytes(),
+    );
+}
+
+/// Returns whether `submit` persists the receipts of the executed transactions.
+pub fn is_receipts_persistence_enabled<I: IO>(io: &I) -> bool {
+    io.storage_has_key(&receipts_persistence_key())
+}
+
+pub fn set_receipts_persistence_enabled<I: IO>(io: &mut I, enabled: bool) {
+    let key = receipts_persistence_key();
+
+    if enabled {
+        io.write_storage(&key, &[1]);
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn receipts_persistence_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, RECEIPTS_PERSISTENCE_KEY)
+}
+
+/// Returns the receipt of the transaction with the given hash. Only the transactions executed
+/// while the persistence was enabled have receipts. The receipts are never pruned, disabling
+/// the persistence only stops recording the new ones.
+pub fn get_receipt<I: IO>(io: &I, tx_hash: &H256) -> Option<TransactionReceipt> {
+    io.read_storage(&bytes_to_key(KeyPrefix::Receipt, tx_hash.as_bytes()))
+        .and_then(|value| value.to_value().ok())
+}
+
+fn store_receipt<I: IO>(io: &mut I, tx_hash: &H256, receipt: &TransactionReceipt) {
+    let key = bytes_to_key(KeyPrefix::Receipt, tx_hash.as_bytes());
+    io.write_borsh(&key, receipt);
+}
+
+/// Returns the block of the first transaction executed by `submit`, `None` if there wasn't any.
+pub fn get_genesis_info<I: IO>(io: &I) -> Option<GenesisInfo> {
+    io.read_storage(&genesis_info_key())
+        .and_then(|value| value.to_value().ok())
+}
+
+/// Records the current block as the genesis of the engine unless it is already recorded,
+/// so the value written by the first transaction is never overwritten.
+fn record_genesis_info<I: IO, E: Env>(io: &mut I, env: &E) {
+    let key = genesis_info_key();
+
+    if !io.storage_has_key(&key) {
+        let genesis_info = GenesisInfo {
+            block_height: env.block_height(),
+            block_timestamp: env.block_timestamp().nanos(),
+        };
+        io.write_borsh(&key, &genesis_info);
+    }
+}
+
+fn genesis_info_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, GENESIS_INFO_KEY)
+}
+
+/// Returns whether the calls to the reserved precompile addresses without a precompile
+/// revert with `ERR_UNKNOWN_PRECOMPILE`.
+pub fn is_unknown_precompiles_rejected<I: IO>(io: &I) -> bool {
+    io.storage_has_key(&unknown_precompiles_rejected_key())
+}
+
+pub fn set_unknown_precompiles_rejected<I: IO>(io: &mut I, rejected: bool) {
+    let key = unknown_precompiles_rejected_key();
+
+    if rejected {
+        io.write_storage(&key, &[1]);
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn unknown_precompiles_rejected_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, UNKNOWN_PRECOMPILES_REJECTED_KEY)
+}
+
+/// Returns the priority fee per gas paid by the transaction at the given base fee, capped by
+/// the `max_gas_price` of the submit arguments.
+fn priority_fee_per_gas(
+    transaction: &NormalizedEthTransaction,
+    base_fee_per_gas: U256,
+    max_gas_price: Option<U256>,
+) -> U256 {
+    let priority_fee_per_gas = transaction
+        .max_priority_fee_per_gas
+        .min(transaction.max_fee_per_gas - base_fee_per_gas);
+    max_gas_price.map_or(priority_fee_per_gas, |price| {
+        price.min(priority_fee_per_gas)
+    })
+}
+
+/// Returns whether the transactions with a zero gas price are accepted by `submit`.
+pub fn is_zero_gas_price_allowed<I: IO>(io: &I) -> bool {
+    !io.storage_has_key(&zero_gas_price_disallowed_key())
+}
+
+pub fn set_zero_gas_price_allowed<I: IO>(io: &mut I, allowed: bool) {
+    let key = zero_gas_price_disallowed_key();
+
+    if allowed {
+        io.remove_storage(&key);
+    } else {
+        io.write_storage(&key, &[1]);
+    }
+}
+
+fn zero_gas_price_disallowed_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, ZERO_GAS_PRICE_DISALLOWED_KEY)
+}
+
+pub fn is_empty_init_code_allowed<I: IO>(io: &I) -> bool {
+    !io.storage_has_key(&empty_init_code_disallowed_key())
+}
+
+pub fn set_empty_init_code_allowed<I: IO>(io: &mut I, allowed: bool) {
+    let key = empty_init_code_disallowed_key();
+
+    if allowed {
+        io.remove_storage(&key);
+    } else {
+        io.write_storage(&key, &[1]);
+    }
+}
+
+fn empty_init_code_disallowed_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, EMPTY_INIT_CODE_DISALLOWED_KEY)
+}
+
+/// Returns whether the legacy transactions without a chain ID are accepted by `submit`.
+pub fn is_chainless_tx_allowed<I: IO>(io: &I) -> bool {
+    !io.storage_has_key(&chainless_tx_disallowed_key())
+}
+
+pub fn set_chainless_tx_allowed<I: IO>(io: &mut I, allowed: bool) {
+    let key = chainless_tx_disallowed_key();
+
+    if allowed {
+        io.remove_storage(&key);
+    } else {
+        io.write_storage(&key, &[1]);
+    }
+}
+
+fn chainless_tx_disallowed_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, CHAINLESS_TX_DISALLOWED_KEY)
+}
+
+/// Returns the EVM gas limit of the calls made when receiving NEP-141 tokens in `ft_on_transfer`.
+pub fn get_ft_on_transfer_gas_limit<I: IO>(io: &I) -> u64 {
+    io.read_u64(&ft_on_transfer_gas_limit_key())
+        .unwrap_or(DEFAULT_FT_ON_TRANSFER_GAS_LIMIT)
+}
+
+pub fn set_ft_on_transfer_gas_limit<I: IO>(io: &mut I, gas_limit: Option<u64>) {
+    let key = ft_on_transfer_gas_limit_key();
+
+    if let Some(gas_limit) = gas_limit {
+        io.write_storage(&key, &gas_limit.to_le_bytes());
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn ft_on_transfer_gas_limit_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, FT_ON_TRANSFER_GAS_LIMIT_KEY)
+}
+
+/// Returns the EVM gas limit of the deployments made by `deploy_erc20_token`.
+pub fn get_erc20_deploy_gas_limit<I: IO>(io: &I) -> u64 {
+    io.read_u64(&erc20_deploy_gas_limit_key())
+        .unwrap_or(DEFAULT_ERC20_DEPLOY_GAS_LIMIT)
+}
+
+pub fn set_erc20_deploy_gas_limit<I: IO>(io: &mut I, gas_limit: Option<u64>) {
+    let key = erc20_deploy_gas_limit_key();
+
+    if let Some(gas_limit) = gas_limit {
+        io.write_storage(&key, &gas_limit.to_le_bytes());
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn erc20_deploy_gas_limit_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, ERC20_DEPLOY_GAS_LIMIT_KEY)
+}
+
+/// Returns the fee withheld from the storage deposit refunded when an account is unregistered
+/// from the eth-connector. Zero (a full refund) if it was never set.
+pub fn get_unregister_fee<I: IO>(io: &I) -> Yocto {
+    io.read_storage(&unregister_fee_key())
+        .and_then(|value| Yocto::try_from_slice(&value.to_vec()).ok())
+        .unwrap_or_default()
+}
+
+pub fn set_unregister_fee<I: IO>(io: &mut I, fee: Option<Yocto>) {
+    let key = unregister_fee_key();
+
+    if let Some(fee) = fee {
+        io.write_borsh(&key, &fee);
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn unregister_fee_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, UNREGISTER_FEE_KEY)
+}
+
+/// Returns the verbosity of the engine logs, `LogLevel::Error` if it was never set.
+pub fn get_log_level<I: IO>(io: &I) -> LogLevel {
+    io.read_storage(&log_level_key())
+        .and_then(|value| LogLevel::try_from_slice(&value.to_vec()).ok())
+        .unwrap_or_default()
+}
+
+pub fn set_log_level<I: IO>(io: &mut I, log_level: LogLevel) {
+    io.write_storage(&log_level_key(), &[log_level as u8]);
+}
+
+fn log_level_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, LOG_LEVEL_KEY)
+}
+
+/// Returns the implementation of the modexp precompile, the built-in one by default.
+pub fn get_modexp_algorithm<I: IO>(io: &I) -> ModExpAlgorithmKind {
+    io.read_storage(&modexp_algorithm_key())
+        .and_then(|value| ModExpAlgorithmKind::try_from_slice(&value.to_vec()).ok())
+        .unwrap_or_default()
+}
+
+pub fn set_modexp_algorithm<I: IO>(io: &mut I, algorithm: ModExpAlgorithmKind) {
+    io.write_storage(&modexp_algorithm_key(), &[algorithm as u8]);
+}
+
+fn modexp_algorithm_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, MODEXP_ALGORITHM_KEY)
+}
+
+/// Returns the bytecode used to deploy the bridged ERC-20 tokens, `None` means the built-in one.
+pub fn get_erc20_template<I: IO>(io: &I) -> Option<Vec<u8>> {
+    io.read_storage(&erc20_template_key())
+        .map(|value| value.to_vec())
+}
+
+pub fn set_erc20_template<I: IO>(io: &mut I, erc20_template: Option<&[u8]>) {
+    let key = erc20_template_key();
+
+    if let Some(erc20_template) = erc20_template {
+        io.write_storage(&key, erc20_template);
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn erc20_template_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, ERC20_TEMPLATE_KEY)
+}
+
+/// Returns the total amount of fees collected by the engine which were not withdrawn yet.
+/// The engine only collects the base fee, which is zero as long as `BASE_FEE_PER_GAS` is,
+/// so the amount stays zero until a base fee is introduced.
+pub fn get_collected_fees<I: IO>(io: &I) -> Wei {
+    let raw = io
+        .read_u256(&collected_fees_key())
+        .unwrap_or_else(|_| U256::zero());
+    Wei::new(raw)
+}
+
+fn add_collected_fees<I: IO>(io: &mut I, amount: Wei) -> Result<(), BalanceOverflow> {
+    let total = get_collected_fees(io)
+        .checked_add(amount)
+        .ok_or(BalanceOverflow)?;
+    io.write_storage(&collected_fees_key(), &total.to_bytes());
+    Ok(())
+}
+
+/// Resets the collected fees and returns the amount collected so far.
+pub fn take_collected_fees<I: IO>(io: &mut I) -> Wei {
+    let amount = get_collected_fees(io);
+    io.remove_storage(&collected_fees_key());
+    amount
+}
+
+fn collected_fees_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, COLLECTED_FEES_KEY)
+}
 
-        let refund = gas_result
-            .prepaid_amount
-            .checked_sub(spent_amount)
-            .ok_or(GasPaymentError::EthAmountOverflow)?;
+/// Returns the shares used to split the collected fees, empty if the fees go to a single treasury.
+pub fn get_fee_distribution<I: IO>(io: &I) -> Vec<FeeShare> {
+    io.read_storage(&fee_distribution_key())
+        .and_then(|value| value.to_value().ok())
+        .unwrap_or_default()
+}
 
-        (refund, reward_amount)
-    };
+pub fn set_fee_distribution<I: IO>(io: &mut I, shares: &[FeeShare]) {
+    let key = fee_distribution_key();
 
-    if !refund.is_zero() {
-        add_balance(io, sender, refund)?;
+    if shares.is_empty() {
+        io.remove_storage(&key);
+    } else {
+        io.write_borsh(&key, &shares);
     }
+}
+
+fn fee_distribution_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, FEE_DISTRIBUTION_KEY)
+}
 
-    if !relayer_reward.is_zero() {
-        add_balance(io, relayer, relayer_reward)?;
+/// Splits the amount according to the shares given in basis points. The remainder left after
+/// rounding down is added to the last share, so the parts always sum to the amount.
+#[must_use]
+pub fn split_fees(amount: Wei, shares: &[FeeShare]) -> Vec<(Address, Wei)> {
+    let total = U256::from(FEE_DISTRIBUTION_TOTAL_BASIS_POINTS);
+    let raw = amount.raw();
+    let mut remaining = raw;
+    let mut result: Vec<(Address, Wei)> = shares
+        .iter()
+        .map(|share| {
+            let basis_points = U256::from(share.basis_points);
+            // Equals to `raw * basis_points / total` without an overflow.
+            let part = (raw / total) * basis_points + (raw % total) * basis_points / total;
+            remaining = remaining.saturating_sub(part);
+            (share.address, Wei::new(part))
+        })
+        .collect();
+
+    if let Some((_, last)) = result.last_mut() {
+        *last = Wei::new(last.raw() + remaining);
     }
 
-    Ok(())
+    result
 }
 
 #[must_use]
@@ -1290,16 +2296,15 @@ pub fn setup_receive_erc20_tokens_input(
     [selector, tail.as_slice()].concat()
 }
 
+/// Returns the input used to deploy a bridged ERC-20 token. The `erc20_template` bytecode
+/// is used if present, the built-in `EvmErc20` binary otherwise.
 #[must_use]
 pub fn setup_deploy_erc20_input(
     current_account_id: &AccountId,
     erc20_metadata: Option<Erc20Metadata>,
+    erc20_template: Option<&[u8]>,
 ) -> Vec<u8> {
-    #[cfg(feature = "error_refund")]
-    let erc20_contract = include_bytes!("../../etc/eth-contracts/res/EvmErc20V2.bin");
-    #[cfg(not(feature = "error_refund"))]
-    let erc20_contract = include_bytes!("../../etc/eth-contracts/res/EvmErc20.bin");
-
+    let erc20_contract = erc20_code(erc20_template);
     let erc20_admin_address = current_address(current_account_id);
     let erc20_metadata = erc20_metadata.unwrap_or_default();
 
@@ -1313,15 +2318,43 @@ pub fn setup_deploy_erc20_input(
     [erc20_contract, deploy_args.as_slice()].concat()
 }
 
+/// Returns the code of the ERC-20 contract: the template set by the owner or the builtin one.
+fn erc20_code(erc20_template: Option<&[u8]>) -> &[u8] {
+    #[cfg(feature = "error_refund")]
+    let builtin_erc20_contract = include_bytes!("../../etc/eth-contracts/res/EvmErc20V2.bin");
+    #[cfg(not(feature = "error_refund"))]
+    let builtin_erc20_contract = include_bytes!("../../etc/eth-contracts/res/EvmErc20.bin");
+    erc20_template.unwrap_or(builtin_erc20_contract)
+}
+
+/// Returns the address of the ERC-20 token of a NEP-141 token deployed by `deploy_erc20_token`
+/// with the deterministic scheme. It is the `CREATE2` address with the zero address as the
+/// deployer, the hash of the NEP-141 account id as the salt and the hash of the ERC-20 code as
+/// the init code hash. The constructor arguments aren't hashed since they contain the address of
+/// the engine, therefore the address is the same on every silo with the same ERC-20 code.
+#[must_use]
+pub fn deterministic_erc20_address(nep141: &AccountId, erc20_template: Option<&[u8]>) -> Address {
+    let salt = aurora_engine_sdk::keccak(nep141.as_bytes());
+    let code_hash = aurora_engine_sdk::keccak(erc20_code(erc20_template));
+    create2_address(&Address::zero(), &salt, &code_hash)
+}
+
 /// Used to bridge NEP-141 tokens from NEAR to Aurora. On Aurora the NEP-141 becomes an ERC-20.
+/// If `deterministic` is set, the ERC-20 is deployed at the address returned by
+/// `deterministic_erc20_address` instead of the address derived from the nonce of the caller.
 pub fn deploy_erc20_token<I: IO + Copy, E: Env, P: PromiseHandler>(
     args: DeployErc20TokenArgs,
+    deterministic: bool,
     io: I,
     env: &E,
     handler: &mut P,
 ) -> Result<Address, DeployErc20Error> {
     let current_account_id = env.current_account_id();
-    let input = setup_deploy_erc20_input(&current_account_id, None);
+    let erc20_template = get_erc20_template(&io);
+    let gas_limit = get_erc20_deploy_gas_limit(&io);
+    let input = setup_deploy_erc20_input(&current_account_id, None, erc20_template.as_deref());
+    let address =
+        deterministic.then(|| deterministic_erc20_address(&args.nep141, erc20_template.as_deref()));
     let mut engine: Engine<_, _> = Engine::new(
         aurora_engine_sdk::types::near_account_to_evm_address(
             env.predecessor_account_id().as_bytes(),
@@ -1332,7 +2365,17 @@ pub fn deploy_erc20_token<I: IO + Copy, E: Env, P: PromiseHandler>(
     )
     .map_err(DeployErc20Error::State)?;
 
-    let address = match engine.deploy_code_with_input(input, None, handler) {
+    let origin = Address::new(engine.origin());
+    let result = engine.deploy_code(
+        origin,
+        Wei::zero(),
+        input,
+        address,
+        gas_limit,
+        Vec::new(),
+        handler,
+    );
+    let address = match result {
         Ok(result) => match result.status {
             TransactionStatus::Succeed(ret) => {
                 Address::new(H160(ret.as_slice().try_into().unwrap()))
@@ -1360,7 +2403,12 @@ pub fn mirror_erc20_token<I: IO + Copy, E: Env, P: PromiseHandler>(
     handler: &mut P,
 ) -> Result<Address, DeployErc20Error> {
     let current_account_id = env.current_account_id();
-    let input = setup_deploy_erc20_input(&current_account_id, Some(erc20_metadata));
+    let erc20_template = get_erc20_template(&io);
+    let input = setup_deploy_erc20_input(
+        &current_account_id,
+        Some(erc20_metadata),
+        erc20_template.as_deref(),
+    );
     let mut engine: Engine<_, _> = Engine::new(
         aurora_engine_sdk::types::near_account_to_evm_address(
             env.predecessor_account_id().as_bytes(),
@@ -1414,6 +2462,16 @@ pub fn get_code_size<I: IO>(io: &I, address: &Address) -> usize {
         .unwrap_or(0)
 }
 
+/// Returns the hash of the code stored at the address following EIP-1052 semantics:
+/// zero for non-existent accounts and the hash of empty code for accounts without code.
+pub fn get_code_hash<I: IO>(io: &I, address: &Address) -> H256 {
+    let code = get_code(io, address);
+    if code.is_empty() && get_nonce(io, address).is_zero() && get_balance(io, address).is_zero() {
+        return H256::zero();
+    }
+    aurora_engine_sdk::keccak(&code)
+}
+
 pub fn set_nonce<I: IO>(io: &mut I, address: &Address, nonce: &U256) {
     io.write_storage(
         &address_to_key(KeyPrefix::Nonce, address),
@@ -1444,12 +2502,265 @@ pub fn check_nonce<I: IO>(
     Ok(())
 }
 
+/// Same as `check_nonce`, but allows gaps: any nonce which is not lower than
+/// the current account nonce is accepted, as long as the gap does not exceed
+/// the maximum set by `set_max_nonce_gap`.
+pub fn check_nonce_relaxed<I: IO>(
+    io: &I,
+    address: &Address,
+    transaction_nonce: &U256,
+) -> Result<(), EngineErrorKind> {
+    let account_nonce = get_nonce(io, address);
+
+    if transaction_nonce < &account_nonce {
+        return Err(EngineErrorKind::IncorrectNonce(format!(
+            "ERR_INCORRECT_NONCE: ac: {account_nonce}, tx: {transaction_nonce}"
+        )));
+    }
+
+    if get_max_nonce_gap(io).map_or(false, |max_gap| {
+        *transaction_nonce - account_nonce > U256::from(max_gap)
+    }) {
+        return Err(EngineErrorKind::NonceGapTooLarge);
+    }
+
+    Ok(())
+}
+
+/// Returns the maximum gap above the account nonce allowed in the relaxed nonce mode,
+/// `None` means unlimited. The strict mode always requires the exact next nonce.
+pub fn get_max_nonce_gap<I: IO>(io: &I) -> Option<u64> {
+    io.read_u64(&max_nonce_gap_key()).ok()
+}
+
+pub fn set_max_nonce_gap<I: IO>(io: &mut I, max_gap: Option<u64>) {
+    let key = max_nonce_gap_key();
+
+    if let Some(max_gap) = max_gap {
+        io.write_storage(&key, &max_gap.to_le_bytes());
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn max_nonce_gap_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, MAX_NONCE_GAP_KEY)
+}
+
+/// Returns the EVM configuration with the overrides set by the owner applied.
+fn evm_config(memory_limit: Option<u64>) -> Config {
+    let mut config = CONFIG.clone();
+    if let Some(memory_limit) = memory_limit {
+        config.memory_limit = usize::try_from(memory_limit).unwrap_or(usize::MAX);
+    }
+    config
+}
+
+/// Returns the parameters of the EVM configuration in effect.
+pub fn get_evm_config<I: IO>(io: &I) -> EvmConfig {
+    let config = evm_config(get_evm_memory_limit(io));
+    let to_u64 = |value: usize| u64::try_from(value).unwrap_or(u64::MAX);
+    let optional_opcodes: [(bool, &[&str]); 10] = [
+        (config.has_delegate_call, &["DELEGATECALL"]),
+        (config.has_revert, &["REVERT"]),
+        (
+            config.has_return_data,
+            &["RETURNDATASIZE", "RETURNDATACOPY"],
+        ),
+        (config.has_bitwise_shifting, &["SHL", "SHR", "SAR"]),
+        (config.has_create2, &["CREATE2"]),
+        (config.has_ext_code_hash, &["EXTCODEHASH"]),
+        (config.has_chain_id, &["CHAINID"]),
+        (config.has_self_balance, &["SELFBALANCE"]),
+        (config.has_base_fee, &["BASEFEE"]),
+        (config.has_push0, &["PUSH0"]),
+    ];
+    let enabled_opcodes = optional_opcodes
+        .into_iter()
+        .filter(|(enabled, _)| *enabled)
+        .flat_map(|(_, opcodes)| opcodes.iter().copied().map(String::from))
+        .collect();
+
+    EvmConfig {
+        fork: EVM_FORK.into(),
+        gas_transaction_create: config.gas_transaction_create,
+        gas_transaction_call: config.gas_transaction_call,
+        gas_transaction_zero_data: config.gas_transaction_zero_data,
+        gas_transaction_non_zero_data: config.gas_transaction_non_zero_data,
+        gas_call: config.gas_call,
+        call_stipend: config.call_stipend,
+        create_contract_limit: config.create_contract_limit.map(to_u64),
+        max_initcode_size: config.max_initcode_size.map(to_u64),
+        stack_limit: to_u64(config.stack_limit),
+        call_stack_limit: to_u64(config.call_stack_limit),
+        memory_limit: (config.memory_limit < usize::MAX).then(|| to_u64(config.memory_limit)),
+        max_refund_quotient: get_max_refund_quotient(io),
+        gas_refunds_enabled: silo::is_gas_refunds_enabled(io),
+        enabled_opcodes,
+    }
+}
+
+/// Returns the quotient of the refund cap, the refunds of an execution are capped at
+/// `gas_used / quotient`. The default is the quotient of EIP-3529.
+pub fn get_max_refund_quotient<I: IO>(io: &I) -> u64 {
+    io.read_u64(&max_refund_quotient_key())
+        .unwrap_or(accounting::DEFAULT_MAX_REFUND_QUOTIENT)
+}
+
+pub fn set_max_refund_quotient<I: IO>(io: &mut I, quotient: Option<u64>) {
+    let key = max_refund_quotient_key();
+
+    if let Some(quotient) = quotient {
+        io.write_storage(&key, &quotient.to_le_bytes());
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn max_refund_quotient_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::Config, MAX_REFUND_QUOTIENT_KEY)
+}
+
+/// Returns the account of the relayer registered with the given EVM address.
+/// Only the relayers registered (or re-registered) after the reverse mapping was introduced
+/// can be found. If the relayer has been re-registered with another address since then,
+/// the stale entry is ignored.
+pub fn get_relayer_account<I: IO>(io: &I, evm_address: &Address) -> Option<AccountId> {
+    let account_id = io.read_storage(&relayer_account_key(evm_address))?.to_vec();
+    let registered_address =
+        io.read_storage(&bytes_to_key(KeyPrefix::RelayerEvmAddressMap, &account_id))?;
+    if registered_address.to_vec() != evm_address.as_bytes() {
+        return None;
+    }
+    AccountId::try_from(account_id).ok()
+}
+
+fn relayer_account_key(evm_address: &Address) -> Vec<u8> {
+    bytes_to_key(KeyPrefix::RelayerAccountIdMap, evm_address.as_bytes())
+}
+
+/// Returns `true` if the relaxed nonce mode is enabled for the given relayer.
+/// The mode only has effect for relayers which are registered via `register_relayer`.
+pub fn is_relaxed_nonce_enabled<I: IO>(io: &I, relayer: &AccountId) -> bool {
+    io.storage_has_key(&bytes_to_key(
+        KeyPrefix::RelayerEvmAddressMap,
+        relayer.as_bytes(),
+    )) && io.storage_has_key(&relaxed_nonce_key(relayer))
+}
+
+pub fn set_relaxed_nonce<I: IO>(io: &mut I, relayer: &AccountId, enabled: bool) {
+    let key = relaxed_nonce_key(relayer);
+
+    if enabled {
+        io.write_storage(&key, &[1]);
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+fn relaxed_nonce_key(relayer: &AccountId) -> Vec<u8> {
+    bytes_to_key(KeyPrefix::RelaxedNonce, relayer.as_bytes())
+}
+
+/// Returns `true` if the address is allowed to deploy contracts.
+/// Deployment is open to everyone unless the deployer allowlist is enabled.
+pub fn is_deploy_allowed<I: IO>(io: &I, address: &Address) -> bool {
+    !is_deployer_allowlist_enabled(io)
+        || io.storage_has_key(&address_to_key(KeyPrefix::DeployerAllowlist, address))
+}
+
+pub fn is_deployer_allowlist_enabled<I: IO>(io: &I) -> bool {
+    io.storage_has_key(&deployer_allowlist_status_key())
+}
+
+pub fn set_deployer_allowlist_status<I: IO>(io: &mut I, enabled: bool) {
+    let key = deployer_allowlist_status_key();
+
+    if enabled {
+        io.write_storage(&key, &[1]);
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
+/// Adds the address to the allowlist. The allowed address keeps its position in the index
+/// of the allowlist, which makes the allowlist enumerable by `get_deployers`.
+pub fn add_deployer<I: IO>(io: &mut I, address: &Address) {
+    let key = address_to_key(KeyPrefix::DeployerAllowlist, address);
+
+    if io.read_u32(&key).is_ok() {
+        return;
+    }
+
+    let index = get_deployers_count(io);
+    io.write_storage(&deployer_entry_key(index), address.as_bytes());
+    io.write_storage(&key, &index.to_le_bytes());
+    io.write_storage(&deployers_count_key(), &(index + 1).to_le_bytes());
+}
+
+pub fn remove_deployer<I: IO>(io: &mut I, address: &Address) {
+    let key = address_to_key(KeyPrefix::DeployerAllowlist, address);
+
+    if let Ok(index) = io.read_u32(&key) {
+        // Move the last address of the index into the freed position.
+        let last = get_deployers_count(io).saturating_sub(1);
+        if index != last {
+            if let Some(last_address) = io
+                .read_storage(&deployer_entry_key(last))
+                .and_then(|value| Address::try_from_slice(&value.to_vec()).ok())
+            {
+                io.write_storage(&deployer_entry_key(index), last_address.as_bytes());
+                io.write_storage(
+                    &address_to_key(KeyPrefix::DeployerAllowlist, &last_address),
+                    &index.to_le_bytes(),
+                );
+            }
+        }
+        io.remove_storage(&deployer_entry_key(last));
+        io.write_storage(&deployers_count_key(), &last.to_le_bytes());
+    }
+
+    io.remove_storage(&key);
+}
+
+/// Returns the number of the addresses in the index of the deployer allowlist.
+pub fn get_deployers_count<I: IO>(io: &I) -> u32 {
+    io.read_u32(&deployers_count_key()).unwrap_or_default()
+}
+
+/// Returns at most `limit` addresses of the deployer allowlist starting from `from_index`.
+/// The addresses added before the allowlist kept an index of its entries are not returned.
+pub fn get_deployers<I: IO>(io: &I, from_index: u32, limit: u32) -> Vec<Address> {
+    let to_index = from_index
+        .saturating_add(limit)
+        .min(get_deployers_count(io));
+
+    (from_index..to_index)
+        .filter_map(|index| io.read_storage(&deployer_entry_key(index)))
+        .filter_map(|value| Address::try_from_slice(&value.to_vec()).ok())
+        .collect()
+}
+
+fn deployer_allowlist_status_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::DeployerAllowlist, b"STATUS")
+}
+
+fn deployers_count_key() -> Vec<u8> {
+    bytes_to_key(KeyPrefix::DeployerAllowlist, b"COUNT")
+}
+
+fn deployer_entry_key(index: u32) -> Vec<u8> {
+    bytes_to_key(
+        KeyPrefix::DeployerAllowlist,
+        &[b"ENTRY".as_slice(), &index.to_le_bytes()].concat(),
+    )
+}
+
 pub fn get_nonce<I: IO>(io: &I, address: &Address) -> U256 {
     io.read_u256(&address_to_key(KeyPrefix::Nonce, address))
         .unwrap_or_else(|_| U256::zero())
 }
 
-#[cfg(test)]
 pub fn increment_nonce<I: IO>(io: &mut I, address: &Address) {
     let account_nonce = get_nonce(io, address);
     let new_nonce = account_nonce.saturating_add(U256::one());
@@ -1466,11 +2777,49 @@ pub fn create_legacy_address(caller: &Address, nonce: &U256) -> Address {
     Address::try_from_slice(&hash_bytes[12..]).unwrap()
 }
 
+/// Returns the address of a contract created with `CREATE2`, see EIP-1014.
+#[must_use]
+pub fn create2_address(caller: &Address, salt: &H256, init_code_hash: &H256) -> Address {
+    let hash = aurora_engine_sdk::keccak(
+        &[
+            &[0xff][..],
+            caller.as_bytes(),
+            salt.as_bytes(),
+            init_code_hash.as_bytes(),
+        ]
+        .concat(),
+    );
+    Address::try_from_slice(&hash.as_bytes()[12..]).unwrap()
+}
+
 #[must_use]
 pub const fn nep141_erc20_map<I: IO>(io: I) -> BijectionMap<NEP141Account, ERC20Address, I> {
     BijectionMap::new(KeyPrefix::Nep141Erc20Map, KeyPrefix::Erc20Nep141Map, io)
 }
 
+/// Returns the generation of the ERC-20 token mapped to the NEP-141 token: the number of times
+/// the mapping has been written (each time with a freshly deployed ERC-20). Zero if the token has
+/// never been mapped.
+pub fn get_erc20_generation<I: IO>(io: &I, nep141_account_id: &AccountId) -> u64 {
+    io.read_u64(&erc20_generation_key(nep141_account_id))
+        .unwrap_or(0)
+}
+
+fn increment_erc20_generation<I: IO>(io: &mut I, nep141_account_id: &AccountId) {
+    let generation = get_erc20_generation(io, nep141_account_id).saturating_add(1);
+    io.write_storage(
+        &erc20_generation_key(nep141_account_id),
+        &generation.to_le_bytes(),
+    );
+}
+
+fn erc20_generation_key(nep141_account_id: &AccountId) -> Vec<u8> {
+    bytes_to_key(
+        KeyPrefix::Config,
+        &[ERC20_GENERATION_KEY, nep141_account_id.as_bytes()].concat(),
+    )
+}
+
 pub fn get_erc20_from_nep141<I: IO>(
     io: &I,
     nep141_account_id: &AccountId,
@@ -1550,6 +2899,50 @@ pub fn is_account_empty<I: IO>(io: &I, address: &Address) -> bool {
         && !storage_has_key(io, address, &H256::zero(), get_generation(io, address))
 }
 
+/// Returns whether the account exists in the state, i.e. any of its balance, nonce or code
+/// entries is present. Following EIP-161 an account touched by a transaction is removed once
+/// it is empty (zero balance and nonce, no code), so it no longer exists. However, an existing
+/// account is not necessarily non-empty: an entry holding a zero value still makes it exist,
+/// which is why this is not equivalent to `is_account_empty` or a zero balance check.
+pub fn account_exists<I: IO>(io: &I, address: &Address) -> bool {
+    io.storage_has_key(&address_to_key(KeyPrefix::Balance, address))
+        || io.storage_has_key(&address_to_key(KeyPrefix::Nonce, address))
+        || io.storage_has_key(&address_to_key(KeyPrefix::Code, address))
+}
+
+/// Returns whether the signature of the message hash was produced by the expected address.
+/// Malformed signatures and recovery failures are reported as a mismatch.
+#[must_use]
+pub fn verify_signature(args: &VerifySignatureArgs) -> bool {
+    let Ok(mut signature) = <[u8; 65]>::try_from(args.signature.as_slice()) else {
+        return false;
+    };
+    // The recovery id is expected to be 0 or 1, but the Ethereum signatures often use 27 or 28.
+    signature[64] = match signature[64] {
+        v @ 0..=1 => v,
+        v @ 27..=28 => v - 27,
+        _ => return false,
+    };
+
+    matches!(
+        secp256k1::ecrecover(H256(args.hash), &signature),
+        Ok(address) if address == args.address
+    )
+}
+
+/// Maximum number of addresses which can be queried by a single `get_accounts` call.
+pub const MAX_ACCOUNTS_PER_QUERY: usize = 100;
+
+/// Returns the balance, nonce, code hash and existence of the account at once.
+pub fn get_account_info<I: IO>(io: &I, address: &Address) -> AccountInfo {
+    AccountInfo {
+        balance: get_balance(io, address).to_bytes(),
+        nonce: u256_to_arr(&get_nonce(io, address)),
+        code_hash: get_code_hash(io, address).0,
+        exists: account_exists(io, address),
+    }
+}
+
 /// Increments storage generation for a given address.
 pub fn set_generation<I: IO>(io: &mut I, address: &Address, generation: u32) {
     io.write_storage(
@@ -1603,6 +2996,82 @@ fn remove_account<I: IO + Copy>(io: &mut I, address: &Address, generation: u32)
     remove_all_storage(io, address, generation);
 }
 
+/// A `PromiseHandler` which records the arguments of the promises it creates, so they can be
+/// returned in the `SubmitResult`. Batch actions are not recorded because they are not
+/// represented by `PromiseCreateArgs`.
+struct PromiseRecorder<'a, H> {
+    inner: &'a mut H,
+    enabled: bool,
+    promises: Vec<PromiseCreateArgs>,
+}
+
+impl<'a, H> PromiseRecorder<'a, H> {
+    fn new(inner: &'a mut H, enabled: bool) -> Self {
+        Self {
+            inner,
+            enabled,
+            promises: Vec::new(),
+        }
+    }
+
+    fn record<'b, T: IntoIterator<Item = &'b PromiseCreateArgs>>(&mut self, promises: T) {
+        if self.enabled {
+            self.promises.extend(promises.into_iter().cloned());
+        }
+    }
+}
+
+impl<'a, H: PromiseHandler> PromiseHandler for PromiseRecorder<'a, H> {
+    type ReadOnly = H::ReadOnly;
+
+    fn promise_results_count(&self) -> u64 {
+        self.inner.promise_results_count()
+    }
+
+    fn promise_result(&self, index: u64) -> Option<aurora_engine_types::types::PromiseResult> {
+        self.inner.promise_result(index)
+    }
+
+    unsafe fn promise_create_call(&mut self, args: &PromiseCreateArgs) -> PromiseId {
+        self.record([args]);
+        self.inner.promise_create_call(args)
+    }
+
+    unsafe fn promise_create_and_combine(&mut self, args: &[PromiseCreateArgs]) -> PromiseId {
+        self.record(args);
+        self.inner.promise_create_and_combine(args)
+    }
+
+    unsafe fn promise_attach_callback(
+        &mut self,
+        base: PromiseId,
+        callback: &PromiseCreateArgs,
+    ) -> PromiseId {
+        self.record([callback]);
+        self.inner.promise_attach_callback(base, callback)
+    }
+
+    unsafe fn promise_create_batch(&mut self, args: &PromiseBatchAction) -> PromiseId {
+        self.inner.promise_create_batch(args)
+    }
+
+    unsafe fn promise_attach_batch_callback(
+        &mut self,
+        base: PromiseId,
+        args: &PromiseBatchAction,
+    ) -> PromiseId {
+        self.inner.promise_attach_batch_callback(base, args)
+    }
+
+    fn promise_return(&mut self, promise: PromiseId) {
+        self.inner.promise_return(promise);
+    }
+
+    fn read_only(&self) -> Self::ReadOnly {
+        self.inner.read_only()
+    }
+}
+
 fn filter_promises_from_logs<I, T, P>(
     io: &I,
     handler: &mut P,
@@ -1851,7 +3320,7 @@ impl<'env, I: IO + Copy, E: Env, M: ModExpAlgorithm> Backend for Engine<'env, I,
     ///
     /// TODO: doc.aurora.dev link
     fn block_base_fee_per_gas(&self) -> U256 {
-        U256::zero()
+        BASE_FEE_PER_GAS
     }
 
     /// Returns the states chain ID.
@@ -1996,7 +3465,12 @@ impl<'env, J: IO + Copy, E: Env, M: ModExpAlgorithm> ApplyBackend for Engine<'en
                     if let Some(code) = code {
                         set_code(&mut self.io, &address, &code);
                         code_bytes_written = code.len();
-                        sdk::log!("code_write_at_address {:?} {}", address, code_bytes_written);
+                        sdk::log_if!(
+                            self.config().is_log_level_enabled(LogLevel::Debug),
+                            "code_write_at_address {:?} {}",
+                            address,
+                            code_bytes_written
+                        );
                     }
 
                     let next_generation = if reset_storage {
@@ -2015,6 +3489,10 @@ impl<'env, J: IO + Copy, E: Env, M: ModExpAlgorithm> ApplyBackend for Engine<'en
                         writes_counter += 1;
                     }
 
+                    // The beneficiary of a `SELFDESTRUCT` is applied here like any other account
+                    // receiving ETH, so a new beneficiary is created with a non-zero balance and
+                    // is never removed as empty. The balance always goes to the beneficiary: the
+                    // transfer is made by the EVM executor, so it can't be routed elsewhere here.
                     // We only need to remove the account if:
                     // 1. we are supposed to delete an empty account
                     // 2. the account is empty
@@ -2074,7 +3552,8 @@ impl<'env, J: IO + Copy, E: Env, M: ModExpAlgorithm> ApplyBackend for Engine<'en
         if code_bytes_written > 0 {
             writes_counter += 1;
         }
-        sdk::log!(
+        sdk::log_if!(
+            self.config().is_log_level_enabled(LogLevel::Debug),
             "total_writes_count {}\ntotal_written_bytes {}",
             writes_counter,
             total_bytes
@@ -2082,7 +3561,9 @@ impl<'env, J: IO + Copy, E: Env, M: ModExpAlgorithm> ApplyBackend for Engine<'en
     }
 }
 
-fn submit_result_or_err(submit_result: SubmitResult) -> Result<SubmitResult, EngineError> {
+pub(crate) fn submit_result_or_err(
+    submit_result: SubmitResult,
+) -> Result<SubmitResult, EngineError> {
     match submit_result.status {
         TransactionStatus::Succeed(_) => Ok(submit_result),
         TransactionStatus::Revert(bytes) => {
@@ -2421,7 +3902,13 @@ mod tests {
             .register_token(erc20_token, nep141_token.clone())
             .unwrap();
         engine
-            .receive_erc20_tokens(&nep141_token, &args, &current_account_id, &mut handler)
+            .receive_erc20_tokens(
+                &nep141_token,
+                &args,
+                &current_account_id,
+                DEFAULT_FT_ON_TRANSFER_GAS_LIMIT,
+                &mut handler,
+            )
             .unwrap();
 
         let storage = storage.borrow();
@@ -2449,11 +3936,78 @@ mod tests {
         };
         let nonce = U256::zero();
         let expected_address = create_legacy_address(&origin, &nonce);
-        let actual_address = deploy_erc20_token(args, io, &env, &mut handler).unwrap();
+        let actual_address = deploy_erc20_token(args, false, io, &env, &mut handler).unwrap();
 
         assert_eq!(expected_address, actual_address);
     }
 
+    #[test]
+    fn test_deterministic_erc20_address_is_the_same_on_every_silo() {
+        let nep141 = AccountId::new("testcoin").unwrap();
+        let deploy = |current_account_id: &str, predecessor_account_id: &str| {
+            let env = Fixed {
+                current_account_id: current_account_id.parse().unwrap(),
+                predecessor_account_id: predecessor_account_id.parse().unwrap(),
+                ..Fixed::default()
+            };
+            let storage = RefCell::new(Storage::default());
+            let mut io = StoragePointer(&storage);
+            state::set_state(&mut io, &EngineState::default()).unwrap();
+
+            let args = DeployErc20TokenArgs {
+                nep141: nep141.clone(),
+            };
+            let address = deploy_erc20_token(args, true, io, &env, &mut Noop).unwrap();
+            assert!(!get_code(&io, &address).is_empty());
+            address
+        };
+
+        // The engines differ by their account ids and by the callers of `deploy_erc20_token`.
+        let first_address = deploy("aurora", "relayer.near");
+        let second_address = deploy("silo.aurora", "operator.near");
+
+        assert_eq!(first_address, second_address);
+        assert_eq!(first_address, deterministic_erc20_address(&nep141, None));
+        assert_ne!(
+            first_address,
+            deterministic_erc20_address(&"othercoin".parse().unwrap(), None)
+        );
+    }
+
+    #[test]
+    fn test_erc20_generation_increments_on_redeploy() {
+        let env = Fixed::default();
+        let origin = aurora_engine_sdk::types::near_account_to_evm_address(
+            env.predecessor_account_id().as_bytes(),
+        );
+        let storage = RefCell::new(Storage::default());
+        let mut io = StoragePointer(&storage);
+        add_balance(&mut io, &origin, Wei::new_u64(22000)).unwrap();
+        state::set_state(&mut io, &EngineState::default()).unwrap();
+
+        let nep141 = AccountId::new("testcoin").unwrap();
+        let mut handler = Noop;
+        assert_eq!(get_erc20_generation(&io, &nep141), 0);
+
+        let args = DeployErc20TokenArgs {
+            nep141: nep141.clone(),
+        };
+        let first_address =
+            deploy_erc20_token(args.clone(), false, io, &env, &mut handler).unwrap();
+        assert_eq!(get_erc20_generation(&io, &nep141), 1);
+
+        // Drop the mapping, so the NEP-141 token can be bridged again with a new ERC-20.
+        io.remove_storage(&bytes_to_key(KeyPrefix::Nep141Erc20Map, nep141.as_bytes()));
+        io.remove_storage(&bytes_to_key(
+            KeyPrefix::Erc20Nep141Map,
+            first_address.as_bytes(),
+        ));
+        let second_address = deploy_erc20_token(args, false, io, &env, &mut handler).unwrap();
+
+        assert_ne!(first_address, second_address);
+        assert_eq!(get_erc20_generation(&io, &nep141), 2);
+    }
+
     #[test]
     fn test_get_erc20_metadata() {
         let env = Fixed::default();
@@ -2472,7 +4026,7 @@ mod tests {
         let nep141 = AccountId::new("testcoin").unwrap();
         let mut handler = Noop;
         let args = DeployErc20TokenArgs { nep141 };
-        let erc20_address = deploy_erc20_token(args, io, &env, &mut handler).unwrap();
+        let erc20_address = deploy_erc20_token(args, false, io, &env, &mut handler).unwrap();
         let metadata = engine
             .get_erc20_metadata(&Erc20Identifier::Erc20 {
                 address: erc20_address,
@@ -2797,6 +4351,67 @@ mod tests {
         assert_eq!(expected_refund, actual_refund);
     }
 
+    #[test]
+    fn test_refund_gas_collects_base_fee() {
+        let origin = Address::zero();
+        let storage = RefCell::new(Storage::default());
+        let mut io = StoragePointer(&storage);
+        let relayer = make_address(1, 1);
+        let gas_result = GasPaymentResult {
+            prepaid_amount: Wei::new_u64(30_000),
+            effective_gas_price: 3.into(),
+            priority_fee_per_gas: 1.into(),
+        };
+        let gas_used = 4000;
+
+        assert_eq!(get_collected_fees(&io), Wei::zero());
+        refund_unused_gas(&mut io, &origin, gas_used, &gas_result, &relayer, None).unwrap();
+        refund_unused_gas(&mut io, &origin, gas_used, &gas_result, &relayer, None).unwrap();
+
+        assert_eq!(get_balance(&io, &relayer), Wei::new_u64(2 * gas_used));
+        assert_eq!(get_collected_fees(&io), Wei::new_u64(2 * gas_used * 2));
+
+        assert_eq!(take_collected_fees(&mut io), Wei::new_u64(2 * gas_used * 2));
+        assert_eq!(get_collected_fees(&io), Wei::zero());
+    }
+
+    #[test]
+    fn test_split_fees_by_distribution() {
+        let storage = RefCell::new(Storage::default());
+        let mut io = StoragePointer(&storage);
+        let dev_fund = make_address(1, 1);
+        let burn = make_address(2, 2);
+        let shares = vec![
+            FeeShare {
+                address: dev_fund,
+                basis_points: 7_000,
+            },
+            FeeShare {
+                address: burn,
+                basis_points: 3_000,
+            },
+        ];
+
+        assert!(get_fee_distribution(&io).is_empty());
+        set_fee_distribution(&mut io, &shares);
+        assert_eq!(get_fee_distribution(&io), shares);
+
+        assert_eq!(
+            split_fees(Wei::new_u64(10_000), &shares),
+            vec![(dev_fund, Wei::new_u64(7_000)), (burn, Wei::new_u64(3_000))]
+        );
+        // The rounding remainder goes to the last share.
+        assert_eq!(
+            split_fees(Wei::new_u64(11), &shares),
+            vec![(dev_fund, Wei::new_u64(7)), (burn, Wei::new_u64(4))]
+        );
+        let parts = split_fees(Wei::new(U256::MAX), &shares);
+        assert_eq!(parts[0].1.raw() + parts[1].1.raw(), U256::MAX);
+
+        set_fee_distribution(&mut io, &[]);
+        assert!(get_fee_distribution(&io).is_empty());
+    }
+
     #[test]
     fn test_refund_fixed_gas_pays_expected_amount() {
         let origin = Address::zero();
@@ -2862,6 +4477,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_create2_address() {
+        // Example 0 of EIP-1014.
+        let created_address = create2_address(
+            &Address::zero(),
+            &H256::zero(),
+            &aurora_engine_sdk::keccak(&[0x00]),
+        );
+
+        assert_eq!(
+            created_address.encode(),
+            "4d1a2e2bb4f88f0250f26ffff098b0b30b26bf38"
+        );
+    }
+
     #[test]
     fn test_filtering_promises_from_logs_with_none_keeps_all() {
         let storage = RefCell::new(Storage::default());
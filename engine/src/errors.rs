@@ -26,6 +26,8 @@ pub const ERR_NOT_OWNER: &[u8; 13] = b"ERR_NOT_OWNER";
 pub const ERR_PAUSED: &[u8; 10] = b"ERR_PAUSED";
 pub const ERR_FT_PAUSED: &[u8; 13] = b"ERR_FT_PAUSED";
 pub const ERR_RUNNING: &[u8; 11] = b"ERR_RUNNING";
+pub const ERR_CONNECTOR_PAUSED: &[u8; 20] = b"ERR_CONNECTOR_PAUSED";
+pub const ERR_CONNECTOR_RUNNING: &[u8; 21] = b"ERR_CONNECTOR_RUNNING";
 
 pub const ERR_SERIALIZE: &str = "ERR_SERIALIZE";
 pub const ERR_PROMISE_ENCODING: &str = "ERR_PROMISE_ENCODING";
@@ -45,6 +47,7 @@ pub const ERR_CREATE_COLLISION: &[u8; 20] = b"ERR_CREATE_COLLISION";
 pub const ERR_CREATE_CONTRACT_LIMIT: &[u8; 25] = b"ERR_CREATE_CONTRACT_LIMIT";
 pub const ERR_INVALID_OPCODE: &[u8; 18] = b"ERR_INVALID_OPCODE";
 pub const ERR_OUT_OF_FUND: &[u8; 15] = b"ERR_OUT_OF_FUND";
+pub const ERR_PAYMASTER_OUT_OF_FUND: &[u8; 25] = b"ERR_PAYMASTER_OUT_OF_FUND";
 pub const ERR_CREATE_EMPTY: &[u8; 16] = b"ERR_CREATE_EMPTY";
 pub const ERR_MAX_NONCE: &[u8; 13] = b"ERR_MAX_NONCE";
 pub const ERR_NOT_SUPPORTED: &[u8; 17] = b"ERR_NOT_SUPPORTED";
@@ -99,6 +102,7 @@ pub const ERR_SAME_KEY_MANAGER: &[u8] = b"ERR_SAME_KEY_MANAGER";
 pub const ERR_FUNCTION_CALL_KEY_NOT_FOUND: &[u8] = b"ERR_FUNCTION_CALL_KEY_NOT_FOUND";
 pub const ERR_KEY_MANAGER_IS_NOT_SET: &[u8] = b"ERR_KEY_MANAGER_IS_NOT_SET";
 pub const ERR_ACCOUNTS_COUNTER_OVERFLOW: &str = "ERR_ACCOUNTS_COUNTER_OVERFLOW";
+pub const ERR_USED_PROOFS_COUNTER_OVERFLOW: &str = "ERR_USED_PROOFS_COUNTER_OVERFLOW";
 pub const ERR_DECODING_TOKEN: &[u8] = b"ERR_DECODING_TOKEN";
 pub const ERR_GETTING_TOKEN: &[u8] = b"ERR_GETTING_TOKEN";
 pub const ERR_WRONG_TOKEN_TYPE: &[u8] = b"ERR_WRONG_TOKEN_TYPE";
@@ -110,3 +114,26 @@ pub const ERR_INVALID_NEP141_ACCOUNT_ID: &[u8] = b"ERR_INVALID_NEP141_ACCOUNT_ID
 pub const ERR_NEP141_NOT_FOUND: &[u8] = b"ERR_NEP141_NOT_FOUND";
 pub const ERR_NEP141_TOKEN_ALREADY_REGISTERED: &[u8] = b"ERR_NEP141_TOKEN_ALREADY_REGISTERED";
 pub const ERR_REJECT_CALL_WITH_CODE: &[u8] = b"ERR_REJECT_CALL_WITH_CODE";
+pub const ERR_RELAYER_NOT_REGISTERED: &[u8] = b"ERR_RELAYER_NOT_REGISTERED";
+pub const ERR_DEPLOY_NOT_ALLOWED: &[u8] = b"ERR_DEPLOY_NOT_ALLOWED";
+pub const ERR_TOO_MANY_ACCOUNTS: &[u8] = b"ERR_TOO_MANY_ACCOUNTS";
+pub const ERR_VIEW_STEP_LIMIT: &str = "ERR_VIEW_STEP_LIMIT";
+pub const ERR_ZERO_GAS_PRICE: &[u8] = b"ERR_ZERO_GAS_PRICE";
+pub const ERR_EMPTY_INIT_CODE: &[u8] = b"ERR_EMPTY_INIT_CODE";
+pub const ERR_NONCE_GAP_TOO_LARGE: &[u8] = b"ERR_NONCE_GAP_TOO_LARGE";
+pub const ERR_TX_EXPIRED: &[u8] = b"ERR_TX_EXPIRED";
+pub const ERR_BATCH_TX_FAILED: &[u8] = b"ERR_BATCH_TX_FAILED";
+pub const ERR_INVALID_NEAR_GAS_PER_EVM_GAS: &[u8] = b"ERR_INVALID_NEAR_GAS_PER_EVM_GAS";
+pub const ERR_ENGINE_METADATA_TOO_LONG: &[u8] = b"ERR_ENGINE_METADATA_TOO_LONG";
+pub const ERR_INVALID_UPGRADE_CHUNK: &[u8] = b"ERR_INVALID_UPGRADE_CHUNK";
+pub const ERR_NO_UPGRADE_CHUNKS: &[u8] = b"ERR_NO_UPGRADE_CHUNKS";
+pub const ERR_INVALID_ERC20_TEMPLATE: &[u8] = b"ERR_INVALID_ERC20_TEMPLATE";
+pub const ERR_INVALID_EXIT_FEE: &[u8] = b"ERR_INVALID_EXIT_FEE";
+pub const ERR_INVALID_FEE_DISTRIBUTION: &[u8] = b"ERR_INVALID_FEE_DISTRIBUTION";
+pub const ERR_TREASURY_WITH_FEE_DISTRIBUTION: &[u8] = b"ERR_TREASURY_WITH_FEE_DISTRIBUTION";
+pub const ERR_ZERO_TREASURY: &[u8] = b"ERR_ZERO_TREASURY";
+pub const ERR_INVALID_GAS_LIMIT: &[u8] = b"ERR_INVALID_GAS_LIMIT";
+pub const ERR_INVALID_WHITELIST_ENTRY: &[u8] = b"ERR_INVALID_WHITELIST_ENTRY";
+pub const ERR_DUPLICATE_WHITELIST_STATUS: &[u8] = b"ERR_DUPLICATE_WHITELIST_STATUS";
+pub const ERR_WHITELIST_ENTRY_NOT_FOUND: &[u8] = b"ERR_WHITELIST_ENTRY_NOT_FOUND";
+pub const ERR_BLOCK_LOGS_BLOOM_NOT_FOUND: &[u8] = b"ERR_BLOCK_LOGS_BLOOM_NOT_FOUND";
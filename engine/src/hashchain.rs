@@ -10,12 +10,26 @@ use aurora_engine_sdk::{
     io::{StorageIntermediate, IO},
 };
 use aurora_engine_types::{
-    parameters::engine::SubmitResult,
+    borsh::{BorshDeserialize, BorshSerialize},
+    parameters::engine::{
+        StartHashchainArgs, SubmitBatchResult, SubmitBatchTxResult, SubmitResult,
+    },
     storage::{self, KeyPrefix},
+    Vec,
 };
 use core::cell::RefCell;
 
 pub const HASHCHAIN_STATE: &[u8] = b"HC_STATE";
+pub const BLOCK_LOGS_BLOOM: &[u8] = b"BLOCK_LOGS_BLOOM";
+pub const INITIAL_HASHCHAIN: &[u8] = b"HC_INITIAL";
+
+/// Logs bloom accumulated over the transactions of the latest block which emitted logs.
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+#[borsh(crate = "aurora_engine_types::borsh")]
+struct BlockLogsBloom {
+    block_height: u64,
+    bloom: Bloom,
+}
 
 pub fn with_hashchain<I, E, T, F>(
     mut io: I,
@@ -51,7 +65,7 @@ where
 }
 
 pub fn with_logs_hashchain<I, E, F>(
-    mut io: I,
+    io: I,
     env: &E,
     function_name: &str,
     f: F,
@@ -60,6 +74,48 @@ where
     I: IO + Copy,
     E: Env,
     F: for<'a> FnOnce(CachedIO<'a, I>) -> Result<SubmitResult, ContractError>,
+{
+    with_bloom_hashchain(io, env, function_name, f, |result| {
+        bloom::get_logs_bloom(&result.logs)
+    })
+}
+
+/// Same as `with_logs_hashchain`, but for the methods executing several transactions. The bloom
+/// of the call covers the logs of all of them.
+pub fn with_batch_logs_hashchain<I, E, F>(
+    io: I,
+    env: &E,
+    function_name: &str,
+    f: F,
+) -> Result<SubmitBatchResult, ContractError>
+where
+    I: IO + Copy,
+    E: Env,
+    F: for<'a> FnOnce(CachedIO<'a, I>) -> Result<SubmitBatchResult, ContractError>,
+{
+    with_bloom_hashchain(io, env, function_name, f, |batch| {
+        let mut log_bloom = Bloom::default();
+        for result in &batch.results {
+            if let SubmitBatchTxResult::Executed(result) = result {
+                log_bloom.accrue_bloom(&bloom::get_logs_bloom(&result.logs));
+            }
+        }
+        log_bloom
+    })
+}
+
+fn with_bloom_hashchain<I, E, T, F, B>(
+    mut io: I,
+    env: &E,
+    function_name: &str,
+    f: F,
+    get_bloom: B,
+) -> Result<T, ContractError>
+where
+    I: IO + Copy,
+    E: Env,
+    F: for<'a> FnOnce(CachedIO<'a, I>) -> Result<T, ContractError>,
+    B: FnOnce(&T) -> Bloom,
 {
     let block_height = env.block_height();
     let maybe_hashchain = load_hashchain(&io, block_height)?;
@@ -68,8 +124,10 @@ where
     let hashchain_io = CachedIO::new(io, &cache);
     let result = f(hashchain_io)?;
 
+    let log_bloom = get_bloom(&result);
+    accrue_block_logs_bloom(&mut io, block_height, &log_bloom);
+
     if let Some(mut hashchain) = maybe_hashchain {
-        let log_bloom = bloom::get_logs_bloom(&result.logs);
         let cache_ref = cache.borrow();
         hashchain.add_block_tx(
             block_height,
@@ -84,6 +142,47 @@ where
     Ok(result)
 }
 
+/// Merges the logs bloom of a transaction into the bloom of its block. The accumulated
+/// bloom is reset once a transaction of a newer block emits logs.
+fn accrue_block_logs_bloom<I: IO>(io: &mut I, block_height: u64, log_bloom: &Bloom) {
+    // The transactions without logs don't change the bloom, so the storage write is skipped.
+    if log_bloom == &Bloom::default() {
+        return;
+    }
+
+    let mut block_logs_bloom = read_latest_block_logs_bloom(io);
+    if block_logs_bloom.block_height != block_height {
+        block_logs_bloom = BlockLogsBloom {
+            block_height,
+            bloom: Bloom::default(),
+        };
+    }
+    block_logs_bloom.bloom.accrue_bloom(log_bloom);
+    io.write_borsh(&block_logs_bloom_key(), &block_logs_bloom);
+}
+
+/// Returns the logs bloom of the block at the given height. The bloom is known only for
+/// the latest block which emitted logs and the later blocks (where it is empty).
+pub fn read_block_logs_bloom<I: IO>(io: &I, block_height: u64) -> Option<Bloom> {
+    let block_logs_bloom = read_latest_block_logs_bloom(io);
+
+    match block_height.cmp(&block_logs_bloom.block_height) {
+        core::cmp::Ordering::Less => None,
+        core::cmp::Ordering::Equal => Some(block_logs_bloom.bloom),
+        core::cmp::Ordering::Greater => Some(Bloom::default()),
+    }
+}
+
+fn read_latest_block_logs_bloom<I: IO>(io: &I) -> BlockLogsBloom {
+    io.read_storage(&block_logs_bloom_key())
+        .and_then(|value| value.to_value().ok())
+        .unwrap_or_default()
+}
+
+fn block_logs_bloom_key() -> Vec<u8> {
+    storage::bytes_to_key(KeyPrefix::Hashchain, BLOCK_LOGS_BLOOM)
+}
+
 fn load_hashchain<I: IO>(io: &I, block_height: u64) -> Result<Option<Hashchain>, ContractError> {
     let mut maybe_hashchain = read_current_hashchain(io)?;
     if let Some(hashchain) = maybe_hashchain.as_mut() {
@@ -105,6 +204,22 @@ pub fn read_current_hashchain<I: IO>(io: &I) -> Result<Option<Hashchain>, Contra
     Ok(maybe_hashchain)
 }
 
+/// Returns the hashchain of the block preceding the one where the hashchain was started (either
+/// by `new` or by the latest `start_hashchain`). The engines which started the hashchain before
+/// it was recorded return `None`, the same as the engines without the hashchain.
+pub fn read_initial_hashchain<I: IO>(io: &I) -> Option<StartHashchainArgs> {
+    io.read_storage(&initial_hashchain_key())
+        .and_then(|value| value.to_value().ok())
+}
+
+pub fn save_initial_hashchain<I: IO>(io: &mut I, initial_hashchain: &StartHashchainArgs) {
+    io.write_borsh(&initial_hashchain_key(), initial_hashchain);
+}
+
+fn initial_hashchain_key() -> Vec<u8> {
+    storage::bytes_to_key(KeyPrefix::Hashchain, INITIAL_HASHCHAIN)
+}
+
 pub fn save_hashchain<I: IO>(io: &mut I, hashchain: &Hashchain) -> Result<(), ContractError> {
     let key = storage::bytes_to_key(KeyPrefix::Hashchain, HASHCHAIN_STATE);
     let bytes = hashchain
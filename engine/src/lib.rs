@@ -36,6 +36,8 @@ pub mod contract_methods;
 pub mod engine;
 pub mod errors;
 pub mod hashchain;
+#[cfg(feature = "verbose-errors")]
+pub mod overlay_io;
 pub mod pausables;
 mod prelude;
 pub mod state;
@@ -78,9 +80,13 @@ pub unsafe fn on_alloc_error(_: core::alloc::Layout) -> ! {
 #[cfg(feature = "contract")]
 mod contract {
     use crate::engine::{self, Engine};
-    use crate::parameters::{GetErc20FromNep141CallArgs, GetStorageAtArgs, ViewCallArgs};
+    use crate::parameters::{
+        GetErc20FromNep141CallArgs, GetStorageAtArgs, SimulateDeployArgs, VerifySignatureArgs,
+        ViewCallArgs,
+    };
     use crate::prelude::sdk::types::{SdkExpect, SdkUnwrap};
     use crate::prelude::storage::{bytes_to_key, KeyPrefix};
+    use crate::prelude::transactions::EthTransactionKind;
     use crate::prelude::{sdk, u256_to_arr, Address, ToString, Vec, H256};
     use crate::{
         contract_methods::{self, silo, ContractError},
@@ -91,7 +97,8 @@ mod contract {
     use aurora_engine_sdk::near_runtime::{Runtime, ViewEnv};
     use aurora_engine_types::borsh;
     use aurora_engine_types::parameters::silo::{
-        FixedGasArgs, SiloParamsArgs, WhitelistArgs, WhitelistKindArgs, WhitelistStatusArgs,
+        FixedGasArgs, SiloConfigArgs, SiloParamsArgs, WhitelistArgs, WhitelistKindArgs,
+        WhitelistStatusArgs,
     };
 
     const CODE_KEY: &[u8; 4] = b"CODE";
@@ -119,6 +126,16 @@ mod contract {
             .sdk_unwrap();
     }
 
+    /// Get the keccak256 hash of the engine code recorded by `new` and by the upgrades made
+    /// through the engine.
+    #[no_mangle]
+    pub extern "C" fn get_code_hash_self() {
+        let io = Runtime;
+        contract_methods::admin::get_code_hash_self(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
     /// Get owner account id for this contract.
     #[no_mangle]
     pub extern "C" fn get_owner() {
@@ -142,7 +159,7 @@ mod contract {
     #[no_mangle]
     pub extern "C" fn get_bridge_prover() {
         let io = Runtime;
-        contract_methods::admin::get_bridge_prover(io)
+        contract_methods::connector::get_bridge_prover(io)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
@@ -156,6 +173,15 @@ mod contract {
             .sdk_unwrap();
     }
 
+    /// Get chain id for this contract as a 32-byte big-endian value.
+    #[no_mangle]
+    pub extern "C" fn get_chain_id_u256() {
+        let io = Runtime;
+        contract_methods::admin::get_chain_id_u256(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
     #[no_mangle]
     pub extern "C" fn get_upgrade_delay_blocks() {
         let io = Runtime;
@@ -164,6 +190,15 @@ mod contract {
             .sdk_unwrap();
     }
 
+    /// Get the schema version of the engine state, as little-endian `u32`.
+    #[no_mangle]
+    pub extern "C" fn get_state_version() {
+        let io = Runtime;
+        contract_methods::admin::get_state_version(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
     #[no_mangle]
     pub extern "C" fn set_upgrade_delay_blocks() {
         let io = Runtime;
@@ -173,6 +208,15 @@ mod contract {
             .sdk_unwrap();
     }
 
+    /// Get the owner, the relayer key manager and the upgrade delay in one call.
+    #[no_mangle]
+    pub extern "C" fn get_governance() {
+        let io = Runtime;
+        contract_methods::admin::get_governance(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
     #[no_mangle]
     pub extern "C" fn get_upgrade_index() {
         let io = Runtime;
@@ -203,6 +247,26 @@ mod contract {
             .sdk_unwrap();
     }
 
+    /// Append a chunk of new code to the upgrade staging buffer.
+    #[no_mangle]
+    pub extern "C" fn stage_upgrade_chunk() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::stage_upgrade_chunk(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Validate the code assembled from the uploaded chunks and stage it for deployment.
+    #[no_mangle]
+    pub extern "C" fn finalize_upgrade_stage() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::finalize_upgrade_stage(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
     /// Deploy staged upgrade.
     #[no_mangle]
     pub extern "C" fn deploy_upgrade() {
@@ -215,7 +279,8 @@ mod contract {
         if io.block_height() <= index {
             sdk::panic_utf8(errors::ERR_NOT_ALLOWED_TOO_EARLY);
         }
-        Runtime::self_deploy(&bytes_to_key(KeyPrefix::Config, CODE_KEY));
+        let code_hash = Runtime::self_deploy(&bytes_to_key(KeyPrefix::Config, CODE_KEY));
+        contract_methods::admin::set_pending_code_hash(&mut io, code_hash);
         io.remove_storage(&bytes_to_key(KeyPrefix::Config, CODE_STAGE_KEY));
     }
 
@@ -223,9 +288,12 @@ mod contract {
     /// to make any necessary changes to the state such that it aligns with the newly deployed
     /// code.
     #[no_mangle]
-    #[allow(clippy::missing_const_for_fn)]
     pub extern "C" fn state_migration() {
-        // TODO: currently we don't have migrations
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::state_migration(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
     }
 
     /// Resumes previously [`paused`] precompiles.
@@ -284,184 +352,802 @@ mod contract {
     /// MUTATIVE METHODS
     /// Deploy code into the EVM.
     #[no_mangle]
-    pub extern "C" fn deploy_code() {
+    pub extern "C" fn deploy_code() {
+        let io = Runtime;
+        let env = Runtime;
+        let mut handler = Runtime;
+        contract_methods::evm_transactions::deploy_code(io, &env, &mut handler)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Call method on the EVM contract.
+    #[no_mangle]
+    pub extern "C" fn call() {
+        let io = Runtime;
+        let env = Runtime;
+        let mut handler = Runtime;
+        contract_methods::evm_transactions::call(io, &env, &mut handler)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Process signed Ethereum transaction.
+    /// Must match `CHAIN_ID` to make sure it's signed for given chain vs replayed from another chain.
+    #[no_mangle]
+    pub extern "C" fn submit() {
+        let io = Runtime;
+        let env = Runtime;
+        let mut handler = Runtime;
+        #[cfg(feature = "verbose-errors")]
+        contract_methods::evm_transactions::with_verbose_errors(io, |io| {
+            contract_methods::evm_transactions::submit(io, &env, &mut handler)
+        });
+        #[cfg(not(feature = "verbose-errors"))]
+        contract_methods::evm_transactions::submit(io, &env, &mut handler)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Analog of the `submit` function, but waits for the `SubmitArgsV2` (or the legacy
+    /// `SubmitArgs`) structure rather than the array of bytes representing the transaction.
+    #[no_mangle]
+    pub extern "C" fn submit_with_args() {
+        let io = Runtime;
+        let env = Runtime;
+        let mut handler = Runtime;
+        #[cfg(feature = "verbose-errors")]
+        contract_methods::evm_transactions::with_verbose_errors(io, |io| {
+            contract_methods::evm_transactions::submit_with_args(io, &env, &mut handler)
+        });
+        #[cfg(not(feature = "verbose-errors"))]
+        contract_methods::evm_transactions::submit_with_args(io, &env, &mut handler)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Executes several transactions in order within a single call, optionally reverting all of
+    /// them if one doesn't succeed.
+    #[no_mangle]
+    pub extern "C" fn submit_batch() {
+        let io = Runtime;
+        let env = Runtime;
+        let mut handler = Runtime;
+        contract_methods::evm_transactions::submit_batch(io, &env, &mut handler)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    #[no_mangle]
+    pub extern "C" fn register_relayer() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::register_relayer(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Register several relayers at once. Already registered relayers are skipped.
+    #[no_mangle]
+    pub extern "C" fn register_relayers_batch() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::register_relayers_batch(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Updates the bytecode for user's router contracts created by the engine.
+    /// These contracts are where cross-contract calls initiated by the EVM precompile
+    /// will be sent from.
+    #[no_mangle]
+    pub extern "C" fn factory_update() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::xcc::factory_update(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Updates the bytecode version for the given account. This is only called as a callback
+    /// when a new version of the router contract is deployed to an account.
+    #[no_mangle]
+    pub extern "C" fn factory_update_address_version() {
+        let io = Runtime;
+        let env = Runtime;
+        let handler = Runtime;
+        contract_methods::xcc::factory_update_address_version(io, &env, &handler)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Sets the address for the `wNEAR` ERC-20 contract. This contract will be used by the
+    /// cross-contract calls feature to have users pay for their NEAR transactions.
+    #[no_mangle]
+    pub extern "C" fn factory_set_wnear_address() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::xcc::factory_set_wnear_address(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Returns the address for the `wNEAR` ERC-20 contract in borsh format.
+    #[no_mangle]
+    pub extern "C" fn factory_get_wnear_address() {
+        let io = Runtime;
+        contract_methods::xcc::factory_get_wnear_address(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Returns the amount of NEAR gas attached to the cross-contract calls which is charged
+    /// as one EVM gas, in borsh format.
+    #[no_mangle]
+    pub extern "C" fn get_xcc_near_gas_per_evm_gas() {
+        let io = Runtime;
+        contract_methods::xcc::get_xcc_near_gas_per_evm_gas(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Sets the amount of NEAR gas attached to the cross-contract calls which is charged
+    /// as one EVM gas. The default is `CROSS_CONTRACT_CALL_NEAR_GAS`.
+    #[no_mangle]
+    pub extern "C" fn set_xcc_near_gas_per_evm_gas() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::xcc::set_xcc_near_gas_per_evm_gas(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Create and/or fund an XCC sub-account directly (as opposed to having one be automatically
+    /// created via the XCC precompile in the EVM). The purpose of this method is to enable
+    /// XCC on engine instances where wrapped NEAR (`wNEAR`) is not bridged.
+    #[no_mangle]
+    pub extern "C" fn fund_xcc_sub_account() {
+        let io = Runtime;
+        let env = Runtime;
+        let mut handler = Runtime;
+        contract_methods::xcc::fund_xcc_sub_account(io, &env, &mut handler)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// A private function (only callable by the contract itself) used as part of the XCC flow.
+    /// This function uses the exit to Near precompile to move wNear from Aurora to a user's
+    /// XCC account.
+    #[no_mangle]
+    pub extern "C" fn withdraw_wnear_to_router() {
+        let io = Runtime;
+        let env = Runtime;
+        let mut handler = Runtime;
+        contract_methods::xcc::withdraw_wnear_to_router(io, &env, &mut handler)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Mirror existing ERC-20 token on the main Aurora contract.
+    /// Notice: It works if the SILO mode is on.
+    #[no_mangle]
+    pub extern "C" fn mirror_erc20_token() {
+        let io = Runtime;
+        let mut handler = Runtime;
+        contract_methods::connector::mirror_erc20_token(io, &mut handler)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Callback used by the `mirror_erc20_token` function.
+    #[no_mangle]
+    pub extern "C" fn mirror_erc20_token_callback() {
+        let io = Runtime;
+        let env = Runtime;
+        let mut handler = Runtime;
+        contract_methods::connector::mirror_erc20_token_callback(io, &env, &mut handler)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Sets relayer key manager.
+    #[no_mangle]
+    pub extern "C" fn set_key_manager() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_key_manager(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Adds a relayer function call key.
+    #[no_mangle]
+    pub extern "C" fn add_relayer_key() {
+        let io = Runtime;
+        let env = Runtime;
+        let mut handler = Runtime;
+        contract_methods::admin::add_relayer_key(io, &env, &mut handler)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Removes a relayer function call key.
+    #[no_mangle]
+    pub extern "C" fn remove_relayer_key() {
+        let io = Runtime;
+        let env = Runtime;
+        let mut handler = Runtime;
+        contract_methods::admin::remove_relayer_key(io, &env, &mut handler)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Enable or disable the relaxed nonce mode for a registered relayer.
+    #[no_mangle]
+    pub extern "C" fn set_relaxed_nonce() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_relaxed_nonce(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Add an address to the deployer allowlist.
+    #[no_mangle]
+    pub extern "C" fn add_deployer() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::add_deployer(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Remove an address from the deployer allowlist.
+    #[no_mangle]
+    pub extern "C" fn remove_deployer() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::remove_deployer(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Enable or disable enforcement of the deployer allowlist.
+    #[no_mangle]
+    pub extern "C" fn set_deployer_allowlist_status() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_deployer_allowlist_status(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get a page of the deployer allowlist. The input is `GetDeployersArgs` and the result is
+    /// the borsh-encoded list of the addresses.
+    #[no_mangle]
+    pub extern "C" fn get_deployers() {
+        let io = Runtime;
+        contract_methods::admin::get_deployers(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the total amount of fees collected by the engine. Only the base fee is collected,
+    /// which is currently zero, so the amount stays zero until a base fee is introduced.
+    #[no_mangle]
+    pub extern "C" fn get_collected_fees() {
+        let io = Runtime;
+        contract_methods::admin::get_collected_fees(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Move the collected fees to the treasury address, which can't be zero, or split them
+    /// according to the fee distribution if it is set, in which case the treasury address must
+    /// be zero.
+    #[no_mangle]
+    pub extern "C" fn withdraw_collected_fees() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::withdraw_collected_fees(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the shares used to split the collected fees between the treasuries. The fee distribution
+    /// is held behind the `fee-distribution` feature until the engine collects a non-zero fee.
+    #[cfg(feature = "fee-distribution")]
+    #[no_mangle]
+    pub extern "C" fn get_fee_distribution() {
+        let io = Runtime;
+        contract_methods::admin::get_fee_distribution(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Set the shares used to split the collected fees between the treasuries.
+    #[cfg(feature = "fee-distribution")]
+    #[no_mangle]
+    pub extern "C" fn set_fee_distribution() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_fee_distribution(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the maximum number of logs a single transaction may emit.
+    #[no_mangle]
+    pub extern "C" fn get_max_logs_per_tx() {
+        let io = Runtime;
+        contract_methods::admin::get_max_logs_per_tx(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Set the maximum number of logs a single transaction may emit.
+    #[no_mangle]
+    pub extern "C" fn set_max_logs_per_tx() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_max_logs_per_tx(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the maximum nonce gap allowed for the relayers in the relaxed nonce mode.
+    #[no_mangle]
+    pub extern "C" fn get_max_nonce_gap() {
+        let io = Runtime;
+        contract_methods::admin::get_max_nonce_gap(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Set the maximum nonce gap allowed for the relayers in the relaxed nonce mode.
+    #[no_mangle]
+    pub extern "C" fn set_max_nonce_gap() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_max_nonce_gap(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the quotient of the cap of the gas refunds.
+    #[no_mangle]
+    pub extern "C" fn get_max_refund_quotient() {
+        let io = Runtime;
+        contract_methods::admin::get_max_refund_quotient(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Set the quotient of the cap of the gas refunds.
+    #[no_mangle]
+    pub extern "C" fn set_max_refund_quotient() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_max_refund_quotient(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the capabilities of the engine instance.
+    #[no_mangle]
+    pub extern "C" fn get_features() {
+        let io = Runtime;
+        contract_methods::admin::get_features(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the parameters of the EVM configuration in effect.
+    #[no_mangle]
+    pub extern "C" fn get_evm_config() {
+        let io = Runtime;
+        contract_methods::admin::get_evm_config(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the human-readable description of the engine instance.
+    #[no_mangle]
+    pub extern "C" fn get_engine_metadata() {
+        let io = Runtime;
+        contract_methods::admin::get_engine_metadata(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Set the human-readable description of the engine instance.
+    #[no_mangle]
+    pub extern "C" fn set_engine_metadata() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_engine_metadata(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the maximum size of the EVM memory.
+    #[no_mangle]
+    pub extern "C" fn get_evm_memory_limit() {
+        let io = Runtime;
+        contract_methods::admin::get_evm_memory_limit(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Set the maximum size of the EVM memory.
+    #[no_mangle]
+    pub extern "C" fn set_evm_memory_limit() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_evm_memory_limit(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the maximum number of EVM opcodes a view call may execute. Only exported by the engines
+    /// built with the `view-step-limit` feature, which are the only ones enforcing the limit.
+    #[cfg(feature = "view-step-limit")]
+    #[no_mangle]
+    pub extern "C" fn get_view_step_limit() {
+        let io = Runtime;
+        contract_methods::admin::get_view_step_limit(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Set the maximum number of EVM opcodes a view call may execute. Only exported by the engines
+    /// built with the `view-step-limit` feature, which are the only ones enforcing the limit.
+    #[cfg(feature = "view-step-limit")]
+    #[no_mangle]
+    pub extern "C" fn set_view_step_limit() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_view_step_limit(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the seed which replaces the NEAR block seed in the random seed precompile.
+    #[no_mangle]
+    pub extern "C" fn get_random_seed_override() {
+        let io = Runtime;
+        contract_methods::admin::get_random_seed_override(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Pin the seed returned by the random seed precompile. Only exported by the engines built
+    /// with the `random-seed-override` feature, so the production builds can't pin the randomness.
+    #[cfg(feature = "random-seed-override")]
+    #[no_mangle]
+    pub extern "C" fn set_random_seed_override() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_random_seed_override(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the EVM gas limit used for the ERC-20 mint triggered by `ft_on_transfer`.
+    #[no_mangle]
+    pub extern "C" fn get_ft_on_transfer_gas_limit() {
+        let io = Runtime;
+        contract_methods::admin::get_ft_on_transfer_gas_limit(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Set the EVM gas limit used for the ERC-20 mint triggered by `ft_on_transfer`. The limit
+    /// can only be lowered from the default, which is bounded by the attached NEAR gas only.
+    #[no_mangle]
+    pub extern "C" fn set_ft_on_transfer_gas_limit() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_ft_on_transfer_gas_limit(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the fee withheld from the storage deposit refunded by `storage_unregister`.
+    #[no_mangle]
+    pub extern "C" fn get_unregister_fee() {
+        let io = Runtime;
+        contract_methods::admin::get_unregister_fee(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Set the fee withheld from the storage deposit refunded by `storage_unregister`.
+    #[no_mangle]
+    pub extern "C" fn set_unregister_fee() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_unregister_fee(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the EVM gas limit used for the ERC-20 deployments made by `deploy_erc20_token`.
+    #[no_mangle]
+    pub extern "C" fn get_erc20_deploy_gas_limit() {
+        let io = Runtime;
+        contract_methods::admin::get_erc20_deploy_gas_limit(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Set the EVM gas limit used for the ERC-20 deployments made by `deploy_erc20_token`. The
+    /// limit can only be lowered from the default, which is bounded by the attached NEAR gas only.
+    #[no_mangle]
+    pub extern "C" fn set_erc20_deploy_gas_limit() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_erc20_deploy_gas_limit(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the bytecode used to deploy the bridged ERC-20 tokens.
+    #[no_mangle]
+    pub extern "C" fn get_erc20_template() {
+        let io = Runtime;
+        contract_methods::admin::get_erc20_template(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Set the bytecode used to deploy the bridged ERC-20 tokens.
+    #[no_mangle]
+    pub extern "C" fn set_erc20_template() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_erc20_template(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the address which receives the priority fees.
+    #[no_mangle]
+    pub extern "C" fn get_tip_recipient() {
+        let io = Runtime;
+        contract_methods::admin::get_tip_recipient(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Set the address which receives the priority fees.
+    #[no_mangle]
+    pub extern "C" fn set_tip_recipient() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_tip_recipient(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the address of the contract which may sponsor the gas of the transactions.
+    #[no_mangle]
+    pub extern "C" fn get_paymaster() {
+        let io = Runtime;
+        contract_methods::admin::get_paymaster(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Set the address of the contract which may sponsor the gas of the transactions.
+    #[no_mangle]
+    pub extern "C" fn set_paymaster() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_paymaster(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get the verbosity of the engine logs.
+    #[no_mangle]
+    pub extern "C" fn get_log_level() {
+        let io = Runtime;
+        contract_methods::admin::get_log_level(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Set the verbosity of the engine logs.
+    #[no_mangle]
+    pub extern "C" fn set_log_level() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_log_level(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get whether the optional details of the submit results are populated.
+    #[no_mangle]
+    pub extern "C" fn get_diagnostics_enabled() {
+        let io = Runtime;
+        contract_methods::admin::get_diagnostics_enabled(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Enable or disable populating the optional details of the submit results.
+    #[no_mangle]
+    pub extern "C" fn set_diagnostics_enabled() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::set_diagnostics_enabled(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Get whether the transactions submitted by each address are counted.
+    #[no_mangle]
+    pub extern "C" fn get_tx_count_tracking_enabled() {
+        let io = Runtime;
+        contract_methods::admin::get_tx_count_tracking_enabled(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Enable or disable counting the transactions submitted by each address.
+    #[no_mangle]
+    pub extern "C" fn set_tx_count_tracking_enabled() {
         let io = Runtime;
         let env = Runtime;
-        let mut handler = Runtime;
-        contract_methods::evm_transactions::deploy_code(io, &env, &mut handler)
+        contract_methods::admin::set_tx_count_tracking_enabled(io, &env)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
-    /// Call method on the EVM contract.
+    /// Get the block of the first transaction executed by `submit`.
     #[no_mangle]
-    pub extern "C" fn call() {
+    pub extern "C" fn get_genesis_info() {
         let io = Runtime;
-        let env = Runtime;
-        let mut handler = Runtime;
-        contract_methods::evm_transactions::call(io, &env, &mut handler)
+        contract_methods::admin::get_genesis_info(io)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
-    /// Process signed Ethereum transaction.
-    /// Must match `CHAIN_ID` to make sure it's signed for given chain vs replayed from another chain.
+    /// Get whether the receipts of the submitted transactions are persisted.
     #[no_mangle]
-    pub extern "C" fn submit() {
+    pub extern "C" fn get_receipts_persistence_enabled() {
         let io = Runtime;
-        let env = Runtime;
-        let mut handler = Runtime;
-        contract_methods::evm_transactions::submit(io, &env, &mut handler)
+        contract_methods::admin::get_receipts_persistence_enabled(io)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
-    /// Analog of the `submit` function, but waits for the `SubmitArgs` structure rather than
-    /// the array of bytes representing the transaction.
+    /// Enable or disable persisting the receipts of the submitted transactions.
     #[no_mangle]
-    pub extern "C" fn submit_with_args() {
+    pub extern "C" fn set_receipts_persistence_enabled() {
         let io = Runtime;
         let env = Runtime;
-        let mut handler = Runtime;
-        contract_methods::evm_transactions::submit_with_args(io, &env, &mut handler)
+        contract_methods::admin::set_receipts_persistence_enabled(io, &env)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
+    /// Get the implementation of the modexp precompile.
     #[no_mangle]
-    pub extern "C" fn register_relayer() {
+    pub extern "C" fn get_modexp_algorithm() {
         let io = Runtime;
-        let env = Runtime;
-        contract_methods::admin::register_relayer(io, &env)
+        contract_methods::admin::get_modexp_algorithm(io)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
-    /// Updates the bytecode for user's router contracts created by the engine.
-    /// These contracts are where cross-contract calls initiated by the EVM precompile
-    /// will be sent from.
+    /// Select the implementation of the modexp precompile.
     #[no_mangle]
-    pub extern "C" fn factory_update() {
+    pub extern "C" fn set_modexp_algorithm() {
         let io = Runtime;
         let env = Runtime;
-        contract_methods::xcc::factory_update(io, &env)
+        contract_methods::admin::set_modexp_algorithm(io, &env)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
-    /// Updates the bytecode version for the given account. This is only called as a callback
-    /// when a new version of the router contract is deployed to an account.
+    /// Get whether the transactions with a zero gas price are accepted.
     #[no_mangle]
-    pub extern "C" fn factory_update_address_version() {
+    pub extern "C" fn get_allow_zero_gas_price() {
         let io = Runtime;
-        let env = Runtime;
-        let handler = Runtime;
-        contract_methods::xcc::factory_update_address_version(io, &env, &handler)
+        contract_methods::admin::get_allow_zero_gas_price(io)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
-    /// Sets the address for the `wNEAR` ERC-20 contract. This contract will be used by the
-    /// cross-contract calls feature to have users pay for their NEAR transactions.
+    /// Allow or reject the transactions with a zero gas price.
     #[no_mangle]
-    pub extern "C" fn factory_set_wnear_address() {
+    pub extern "C" fn set_allow_zero_gas_price() {
         let io = Runtime;
         let env = Runtime;
-        contract_methods::xcc::factory_set_wnear_address(io, &env)
+        contract_methods::admin::set_allow_zero_gas_price(io, &env)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
-    /// Returns the address for the `wNEAR` ERC-20 contract in borsh format.
+    /// Get whether the calls to the unimplemented precompile addresses are reverted.
     #[no_mangle]
-    pub extern "C" fn factory_get_wnear_address() {
+    pub extern "C" fn get_reject_unknown_precompiles() {
         let io = Runtime;
-        contract_methods::xcc::factory_get_wnear_address(io)
+        contract_methods::admin::get_reject_unknown_precompiles(io)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
-    /// Create and/or fund an XCC sub-account directly (as opposed to having one be automatically
-    /// created via the XCC precompile in the EVM). The purpose of this method is to enable
-    /// XCC on engine instances where wrapped NEAR (`wNEAR`) is not bridged.
+    /// Revert or allow the calls to the unimplemented precompile addresses.
     #[no_mangle]
-    pub extern "C" fn fund_xcc_sub_account() {
+    pub extern "C" fn set_reject_unknown_precompiles() {
         let io = Runtime;
         let env = Runtime;
-        let mut handler = Runtime;
-        contract_methods::xcc::fund_xcc_sub_account(io, &env, &mut handler)
+        contract_methods::admin::set_reject_unknown_precompiles(io, &env)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
-    /// A private function (only callable by the contract itself) used as part of the XCC flow.
-    /// This function uses the exit to Near precompile to move wNear from Aurora to a user's
-    /// XCC account.
+    /// Get whether the contract creations with an empty init code are accepted.
     #[no_mangle]
-    pub extern "C" fn withdraw_wnear_to_router() {
+    pub extern "C" fn get_allow_empty_init_code() {
         let io = Runtime;
-        let env = Runtime;
-        let mut handler = Runtime;
-        contract_methods::xcc::withdraw_wnear_to_router(io, &env, &mut handler)
+        contract_methods::admin::get_allow_empty_init_code(io)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
-    /// Mirror existing ERC-20 token on the main Aurora contract.
-    /// Notice: It works if the SILO mode is on.
+    /// Allow or reject the contract creations with an empty init code.
     #[no_mangle]
-    pub extern "C" fn mirror_erc20_token() {
+    pub extern "C" fn set_allow_empty_init_code() {
         let io = Runtime;
-        let mut handler = Runtime;
-        contract_methods::connector::mirror_erc20_token(io, &mut handler)
+        let env = Runtime;
+        contract_methods::admin::set_allow_empty_init_code(io, &env)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
-    /// Callback used by the `mirror_erc20_token` function.
+    /// Get whether the legacy transactions without a chain ID are accepted.
     #[no_mangle]
-    pub extern "C" fn mirror_erc20_token_callback() {
+    pub extern "C" fn get_allow_chainless_tx() {
         let io = Runtime;
-        let env = Runtime;
-        let mut handler = Runtime;
-        contract_methods::connector::mirror_erc20_token_callback(io, &env, &mut handler)
+        contract_methods::admin::get_allow_chainless_tx(io)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
-    /// Sets relayer key manager.
+    /// Allow or reject the legacy transactions without a chain ID.
     #[no_mangle]
-    pub extern "C" fn set_key_manager() {
+    pub extern "C" fn set_allow_chainless_tx() {
         let io = Runtime;
         let env = Runtime;
-        contract_methods::admin::set_key_manager(io, &env)
+        contract_methods::admin::set_allow_chainless_tx(io, &env)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
-    /// Adds a relayer function call key.
+    /// Get the fee charged on the exits of the token.
     #[no_mangle]
-    pub extern "C" fn add_relayer_key() {
+    pub extern "C" fn get_exit_fee() {
         let io = Runtime;
-        let env = Runtime;
-        let mut handler = Runtime;
-        contract_methods::admin::add_relayer_key(io, &env, &mut handler)
+        contract_methods::admin::get_exit_fee(io)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
-    /// Removes a relayer function call key.
+    /// Set the fee charged on the exits of the token.
     #[no_mangle]
-    pub extern "C" fn remove_relayer_key() {
+    pub extern "C" fn set_exit_fee() {
         let io = Runtime;
         let env = Runtime;
-        let mut handler = Runtime;
-        contract_methods::admin::remove_relayer_key(io, &env, &mut handler)
+        contract_methods::admin::set_exit_fee(io, &env)
             .map_err(ContractError::msg)
             .sdk_unwrap();
     }
 
-    /// Initialize the hashchain.
     #[no_mangle]
     pub extern "C" fn start_hashchain() {
         let io = Runtime;
@@ -493,10 +1179,47 @@ mod contract {
         let current_account_id = io.current_account_id();
         let engine: Engine<_, _> =
             Engine::new(args.sender, current_account_id, io, &env).sdk_unwrap();
-        let result = Engine::view_with_args(&engine, args).sdk_unwrap();
+        let result = engine::with_view_step_limit(&io, || Engine::view_with_args(&engine, args))
+            .sdk_unwrap();
+        io.return_output(&borsh::to_vec(&result).sdk_expect(errors::ERR_SERIALIZE));
+    }
+
+    /// Simulates the deployment of a contract without persisting any changes. Returns the
+    /// address of the contract, the gas used and the status of the execution.
+    #[no_mangle]
+    pub extern "C" fn simulate_deploy() {
+        let mut io = Runtime;
+        let env = ViewEnv;
+        let args: SimulateDeployArgs = io.read_input_borsh().sdk_unwrap();
+        let current_account_id = io.current_account_id();
+        let engine: Engine<_, _> =
+            Engine::new(args.sender, current_account_id, io, &env).sdk_unwrap();
+        let result = engine.simulate_deploy(args).sdk_unwrap();
         io.return_output(&borsh::to_vec(&result).sdk_expect(errors::ERR_SERIALIZE));
     }
 
+    #[no_mangle]
+    pub extern "C" fn get_precompiles() {
+        let mut io = Runtime;
+        let env = ViewEnv;
+        let current_account_id = io.current_account_id();
+        let engine: Engine<_, _> =
+            Engine::new(Address::zero(), current_account_id, io, &env).sdk_unwrap();
+        let addresses = engine.precompile_addresses();
+        io.return_output(&borsh::to_vec(&addresses).sdk_expect(errors::ERR_SERIALIZE));
+    }
+
+    #[no_mangle]
+    pub extern "C" fn get_precompile_info() {
+        let mut io = Runtime;
+        let env = ViewEnv;
+        let current_account_id = io.current_account_id();
+        let engine: Engine<_, _> =
+            Engine::new(Address::zero(), current_account_id, io, &env).sdk_unwrap();
+        let precompile_info = engine.precompile_info();
+        io.return_output(&borsh::to_vec(&precompile_info).sdk_expect(errors::ERR_SERIALIZE));
+    }
+
     #[no_mangle]
     pub extern "C" fn get_block_hash() {
         let mut io = Runtime;
@@ -517,6 +1240,71 @@ mod contract {
         io.return_output(&code);
     }
 
+    #[no_mangle]
+    pub extern "C" fn get_code_hash() {
+        let mut io = Runtime;
+        let address = io.read_input_arr20().sdk_unwrap();
+        let code_hash = engine::get_code_hash(&io, &Address::from_array(address));
+        io.return_output(code_hash.as_bytes());
+    }
+
+    /// Returns a borsh-encoded bool (a single byte) telling whether the account exists in the
+    /// state, which differs from an account with zero balance, nonce and no code (see EIP-161).
+    #[no_mangle]
+    pub extern "C" fn account_exists() {
+        let mut io = Runtime;
+        let address = io.read_input_arr20().sdk_unwrap();
+        let exists = engine::account_exists(&io, &Address::from_array(address));
+
+        io.return_output(&[u8::from(exists)]);
+    }
+
+    /// Returns the borsh-encoded balance, nonce, code hash and existence of the account,
+    /// following the semantics of `get_code_hash` and `account_exists`.
+    #[no_mangle]
+    pub extern "C" fn get_account() {
+        let mut io = Runtime;
+        let address = io.read_input_arr20().sdk_unwrap();
+        let account_info = engine::get_account_info(&io, &Address::from_array(address));
+        io.return_output(&borsh::to_vec(&account_info).sdk_expect(errors::ERR_SERIALIZE));
+    }
+
+    /// Returns a single byte: 1 if the signature of the message hash was produced by the given
+    /// address and 0 otherwise, including when the signature is malformed or can't be recovered.
+    #[no_mangle]
+    pub extern "C" fn verify_signature() {
+        let mut io = Runtime;
+        let args: VerifySignatureArgs = io.read_input_borsh().sdk_unwrap();
+        io.return_output(&[u8::from(engine::verify_signature(&args))]);
+    }
+
+    /// Same as `get_account` for each of the borsh-encoded addresses, the results are returned
+    /// in the same order. The non-existent accounts have zero balance and nonce. At most
+    /// `MAX_ACCOUNTS_PER_QUERY` addresses can be queried at once.
+    #[no_mangle]
+    pub extern "C" fn get_accounts() {
+        let mut io = Runtime;
+        let addresses: Vec<Address> = io.read_input_borsh().sdk_unwrap();
+        if addresses.len() > engine::MAX_ACCOUNTS_PER_QUERY {
+            sdk::panic_utf8(errors::ERR_TOO_MANY_ACCOUNTS);
+        }
+        let accounts: Vec<_> = addresses
+            .iter()
+            .map(|address| engine::get_account_info(&io, address))
+            .collect();
+        io.return_output(&borsh::to_vec(&accounts).sdk_expect(errors::ERR_SERIALIZE));
+    }
+
+    #[no_mangle]
+    pub extern "C" fn get_relayer_account() {
+        let mut io = Runtime;
+        let address = io.read_input_arr20().sdk_unwrap();
+        // Unregistered addresses return empty output.
+        if let Some(account_id) = engine::get_relayer_account(&io, &Address::from_array(address)) {
+            io.return_output(account_id.as_bytes());
+        }
+    }
+
     #[no_mangle]
     pub extern "C" fn get_balance() {
         let mut io = Runtime;
@@ -533,6 +1321,30 @@ mod contract {
         io.return_output(&u256_to_arr(&nonce));
     }
 
+    /// Get the number of transactions submitted by the address while the counting was enabled
+    /// with `set_tx_count_tracking_enabled`. It can differ from `get_nonce` because the nonce may
+    /// skip values in the relaxed nonce mode and counts the transactions sent before the tracking
+    /// was enabled.
+    #[no_mangle]
+    pub extern "C" fn get_tx_count() {
+        let mut io = Runtime;
+        let address = io.read_input_arr20().sdk_unwrap();
+        let tx_count = engine::get_tx_count(&io, &Address::from_array(address));
+        io.return_output(&u256_to_arr(&tx_count.into()));
+    }
+
+    /// Get the receipt of the transaction with the given 32-byte hash, or `None` if the
+    /// transaction was not executed while the receipts persistence was enabled with
+    /// `set_receipts_persistence_enabled`. The receipts are never pruned: they are kept after
+    /// the persistence is disabled, which only stops recording the new ones.
+    #[no_mangle]
+    pub extern "C" fn get_receipt() {
+        let mut io = Runtime;
+        let tx_hash = io.read_input_arr32().sdk_unwrap();
+        let receipt = engine::get_receipt(&io, &H256(tx_hash));
+        io.return_output(&borsh::to_vec(&receipt).sdk_expect(errors::ERR_SERIALIZE));
+    }
+
     #[no_mangle]
     pub extern "C" fn get_storage_at() {
         let mut io = Runtime;
@@ -543,6 +1355,35 @@ mod contract {
         io.return_output(&value.0);
     }
 
+    /// Return the hash of the given raw signed transaction, i.e. the keccak256 of
+    /// its canonical encoding. The result matches the hash displayed by Ethereum tooling.
+    #[no_mangle]
+    pub extern "C" fn tx_hash() {
+        let mut io = Runtime;
+        let tx_bytes = io.read_input().to_vec();
+        let tx = EthTransactionKind::try_from(tx_bytes.as_slice()).sdk_unwrap();
+        io.return_output(tx.hash().as_bytes());
+    }
+
+    /// Return the 32-byte random seed of the current block. This is the NEAR VRF-derived
+    /// seed, i.e. the same value the `RandomSeed` precompile returns within a transaction
+    /// executed in this block.
+    #[no_mangle]
+    pub extern "C" fn get_random_seed() {
+        let mut io = Runtime;
+        let env = ViewEnv;
+        io.return_output(env.random_seed().as_bytes());
+    }
+
+    /// Return the hashchain the engine started from, `null` if it wasn't recorded.
+    #[no_mangle]
+    pub extern "C" fn get_initial_hashchain() {
+        let mut io = Runtime;
+        contract_methods::admin::get_initial_hashchain(&mut io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
     #[no_mangle]
     pub extern "C" fn get_latest_hashchain() {
         let mut io = Runtime;
@@ -551,6 +1392,16 @@ mod contract {
             .sdk_unwrap();
     }
 
+    /// Return the logs bloom of the block at the given height.
+    #[no_mangle]
+    pub extern "C" fn get_block_logs_bloom() {
+        let io = Runtime;
+        let env = ViewEnv;
+        contract_methods::admin::get_block_logs_bloom(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
     /// Return metadata of the ERC-20 contract.
     #[no_mangle]
     pub extern "C" fn get_erc20_metadata() {
@@ -786,6 +1637,17 @@ mod contract {
             .sdk_unwrap();
     }
 
+    /// Returns the amount of yoctoNEAR which has to be attached to `storage_deposit`
+    /// to register an account.
+    #[no_mangle]
+    #[cfg(not(feature = "ext-connector"))]
+    pub extern "C" fn get_storage_deposit_amount() {
+        let io = Runtime;
+        contract_methods::connector::get_storage_deposit_amount(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
     #[no_mangle]
     pub extern "C" fn get_eth_connector_contract_account() {
         let io = Runtime;
@@ -820,6 +1682,29 @@ mod contract {
             .sdk_unwrap();
     }
 
+    /// Pauses deposits, withdrawals and transfers of the eth-connector.
+    /// The EVM execution is not affected.
+    #[no_mangle]
+    #[cfg(not(feature = "ext-connector"))]
+    pub extern "C" fn pause_connector() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::connector::pause_connector(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Resumes deposits, withdrawals and transfers of the eth-connector.
+    #[no_mangle]
+    #[cfg(not(feature = "ext-connector"))]
+    pub extern "C" fn resume_connector() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::connector::resume_connector(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
     #[no_mangle]
     #[cfg(not(feature = "ext-connector"))]
     pub extern "C" fn get_accounts_counter() {
@@ -829,6 +1714,16 @@ mod contract {
             .sdk_unwrap();
     }
 
+    /// Returns the number of deposit proofs used by the eth-connector.
+    #[no_mangle]
+    #[cfg(not(feature = "ext-connector"))]
+    pub extern "C" fn get_used_proofs_count() {
+        let io = Runtime;
+        contract_methods::connector::get_used_proofs_count(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
     #[no_mangle]
     pub extern "C" fn get_erc20_from_nep141() {
         let mut io = Runtime;
@@ -841,6 +1736,15 @@ mod contract {
         );
     }
 
+    /// Returns the generation of the ERC-20 token bridged from a NEP-141 token.
+    #[no_mangle]
+    pub extern "C" fn get_erc20_generation() {
+        let io = Runtime;
+        contract_methods::connector::get_erc20_generation(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
     #[no_mangle]
     pub extern "C" fn get_nep141_from_erc20() {
         let mut io = Runtime;
@@ -958,6 +1862,26 @@ mod contract {
         io.return_output(&borsh::to_vec(&cost).map_err(|e| e.to_string()).sdk_unwrap());
     }
 
+    #[no_mangle]
+    pub extern "C" fn get_fixed_gas_info() {
+        let mut io = Runtime;
+        let info = silo::get_fixed_gas_info(&io);
+
+        io.return_output(&borsh::to_vec(&info).map_err(|e| e.to_string()).sdk_unwrap());
+    }
+
+    #[no_mangle]
+    pub extern "C" fn get_fee_regime() {
+        let mut io = Runtime;
+        let regime = silo::get_fee_regime(&io);
+
+        io.return_output(
+            &borsh::to_vec(&regime)
+                .map_err(|e| e.to_string())
+                .sdk_unwrap(),
+        );
+    }
+
     #[no_mangle]
     pub extern "C" fn set_fixed_gas() {
         let mut io = Runtime;
@@ -992,6 +1916,40 @@ mod contract {
         silo::set_silo_params(&mut io, args);
     }
 
+    #[no_mangle]
+    pub extern "C" fn configure_silo() {
+        let mut io = Runtime;
+        require_running(&state::get_state(&io).sdk_unwrap());
+        silo::assert_admin(&io).sdk_unwrap();
+
+        let args: SiloConfigArgs = io.read_input_borsh().sdk_unwrap();
+        silo::configure_silo(&mut io, args)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    #[no_mangle]
+    pub extern "C" fn get_gas_refunds_enabled() {
+        let mut io = Runtime;
+        let enabled = silo::is_gas_refunds_enabled(&io);
+
+        io.return_output(
+            &borsh::to_vec(&enabled)
+                .map_err(|e| e.to_string())
+                .sdk_unwrap(),
+        );
+    }
+
+    #[no_mangle]
+    pub extern "C" fn set_gas_refunds_enabled() {
+        let mut io = Runtime;
+        require_running(&state::get_state(&io).sdk_unwrap());
+        silo::assert_admin(&io).sdk_unwrap();
+
+        let enabled: bool = io.read_input_borsh().sdk_unwrap();
+        silo::set_gas_refunds_enabled(&mut io, enabled);
+    }
+
     #[no_mangle]
     pub extern "C" fn set_whitelist_status() {
         let io = Runtime;
@@ -1043,6 +2001,36 @@ mod contract {
         silo::remove_entry_from_whitelist(&io, &args);
     }
 
+    /// Return the statuses and the entries of all the white lists as a single blob.
+    #[no_mangle]
+    pub extern "C" fn export_whitelists() {
+        let io = Runtime;
+        contract_methods::admin::export_whitelists(io)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Replace all the white lists with the blob returned by `export_whitelists`.
+    #[no_mangle]
+    pub extern "C" fn import_whitelists() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::import_whitelists(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
+    /// Index the white list entries added before the white lists kept an index of their entries,
+    /// so they are exported and replaced by `import_whitelists` as well.
+    #[no_mangle]
+    pub extern "C" fn index_whitelist_entries() {
+        let io = Runtime;
+        let env = Runtime;
+        contract_methods::admin::index_whitelist_entries(io, &env)
+            .map_err(ContractError::msg)
+            .sdk_unwrap();
+    }
+
     // TODO: rust-2023-08-24#[allow(clippy::empty_line_after_doc_comments)]
     /// Utility methods.
     fn internal_get_upgrade_index() -> u64 {
@@ -0,0 +1,164 @@
+//! This module contains `OverlayIO`, a light wrapper over any IO instance which keeps
+//! the storage writes in memory instead of applying them to the underlying instance.
+//! The writes are only applied by `OverlayIO::commit`, so dropping the wrapper discards
+//! them. The input and output functions of the trait are passed through.
+//! The purpose of this struct is to return the error of a failed `submit` without
+//! panicking (with the `verbose-errors` feature) while still discarding its state changes.
+
+use crate::prelude::{BTreeMap, Vec};
+use aurora_engine_sdk::io::{StorageIntermediate, IO};
+use core::cell::RefCell;
+
+/// The pending storage changes: `Some(value)` for a write and `None` for a removal.
+pub type Overlay = BTreeMap<Vec<u8>, Option<Vec<u8>>>;
+
+#[derive(Debug, Clone, Copy)]
+pub struct OverlayIO<'overlay, I> {
+    inner: I,
+    overlay: &'overlay RefCell<Overlay>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OverlayValue<T> {
+    Inner(T),
+    Pending(Vec<u8>),
+}
+
+impl<'overlay, I: IO> OverlayIO<'overlay, I> {
+    #[must_use]
+    pub const fn new(io: I, overlay: &'overlay RefCell<Overlay>) -> Self {
+        Self { inner: io, overlay }
+    }
+
+    /// Applies the pending storage changes to the underlying IO instance.
+    pub fn commit(mut self) {
+        let overlay = core::mem::take(&mut *self.overlay.borrow_mut());
+        for (key, value) in overlay {
+            let Some(value) = value else {
+                self.inner.remove_storage(&key);
+                continue;
+            };
+            self.inner.write_storage(&key, &value);
+        }
+    }
+
+    fn replace(&self, key: &[u8], value: Option<Vec<u8>>) -> Option<OverlayValue<I::StorageValue>> {
+        let previous = self.read_storage(key).map(|v| v.to_vec());
+        self.overlay.borrow_mut().insert(key.to_vec(), value);
+        previous.map(OverlayValue::Pending)
+    }
+}
+
+impl<T: StorageIntermediate> StorageIntermediate for OverlayValue<T> {
+    fn len(&self) -> usize {
+        match self {
+            Self::Inner(x) => x.len(),
+            Self::Pending(bytes) => bytes.len(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            Self::Inner(x) => x.is_empty(),
+            Self::Pending(bytes) => bytes.is_empty(),
+        }
+    }
+
+    fn copy_to_slice(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Inner(x) => x.copy_to_slice(buffer),
+            Self::Pending(bytes) => buffer.copy_from_slice(bytes),
+        }
+    }
+}
+
+impl<'overlay, I: IO> IO for OverlayIO<'overlay, I> {
+    type StorageValue = OverlayValue<I::StorageValue>;
+
+    fn read_input(&self) -> Self::StorageValue {
+        OverlayValue::Inner(self.inner.read_input())
+    }
+
+    fn return_output(&mut self, value: &[u8]) {
+        self.inner.return_output(value);
+    }
+
+    fn read_storage(&self, key: &[u8]) -> Option<Self::StorageValue> {
+        self.overlay.borrow().get(key).map_or_else(
+            || self.inner.read_storage(key).map(OverlayValue::Inner),
+            |value| value.clone().map(OverlayValue::Pending),
+        )
+    }
+
+    fn storage_has_key(&self, key: &[u8]) -> bool {
+        self.overlay
+            .borrow()
+            .get(key)
+            .map_or_else(|| self.inner.storage_has_key(key), Option::is_some)
+    }
+
+    fn write_storage(&mut self, key: &[u8], value: &[u8]) -> Option<Self::StorageValue> {
+        self.replace(key, Some(value.to_vec()))
+    }
+
+    fn write_storage_direct(
+        &mut self,
+        key: &[u8],
+        value: Self::StorageValue,
+    ) -> Option<Self::StorageValue> {
+        self.replace(key, Some(value.to_vec()))
+    }
+
+    fn remove_storage(&mut self, key: &[u8]) -> Option<Self::StorageValue> {
+        self.replace(key, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Overlay, OverlayIO};
+    use aurora_engine_sdk::io::{StorageIntermediate, IO};
+    use aurora_engine_test_doubles::io::{Storage, StoragePointer};
+    use core::cell::RefCell;
+
+    #[test]
+    fn test_overlay_io_applies_writes_on_commit_only() {
+        let storage = RefCell::new(Storage::default());
+        let mut io = StoragePointer(&storage);
+        io.write_storage(b"kept", b"old");
+        io.write_storage(b"removed", b"old");
+
+        let overlay = RefCell::new(Overlay::new());
+        let mut overlay_io = OverlayIO::new(io, &overlay);
+        let previous = overlay_io.write_storage(b"kept", b"new");
+        assert_eq!(previous.map(|v| v.to_vec()), Some(b"old".to_vec()));
+        overlay_io.write_storage(b"added", b"new");
+        overlay_io.remove_storage(b"removed");
+
+        assert_eq!(
+            overlay_io.read_storage(b"kept").map(|v| v.to_vec()),
+            Some(b"new".to_vec())
+        );
+        assert!(overlay_io.storage_has_key(b"added"));
+        assert!(!overlay_io.storage_has_key(b"removed"));
+        // Nothing is written to the underlying storage before the commit.
+        assert_eq!(
+            io.read_storage(b"kept").map(|v| v.to_vec()),
+            Some(b"old".to_vec())
+        );
+        assert!(!io.storage_has_key(b"added"));
+        assert!(io.storage_has_key(b"removed"));
+
+        overlay_io.commit();
+
+        assert_eq!(
+            io.read_storage(b"kept").map(|v| v.to_vec()),
+            Some(b"new".to_vec())
+        );
+        assert_eq!(
+            io.read_storage(b"added").map(|v| v.to_vec()),
+            Some(b"new".to_vec())
+        );
+        assert!(!io.storage_has_key(b"removed"));
+    }
+}
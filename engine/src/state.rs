@@ -1,5 +1,5 @@
 use crate::parameters::{
-    LegacyNewCallArgs, NewCallArgs, NewCallArgsV2, NewCallArgsV3, NewCallArgsV4,
+    LegacyNewCallArgs, NewCallArgs, NewCallArgsV2, NewCallArgsV3, NewCallArgsV4, NewCallArgsV5,
 };
 use aurora_engine_sdk::io::{StorageIntermediate, IO};
 use aurora_engine_types::account_id::AccountId;
@@ -12,6 +12,14 @@ pub use error::EngineStateError;
 /// Key for storing the state of the engine.
 pub const STATE_KEY: &[u8; 5] = b"STATE";
 
+/// Schema version of the engine state written by this version of the contract.
+/// Future migrations bump it and branch on the version found in storage.
+pub const CURRENT_STATE_VERSION: u32 = 0;
+
+/// Schema version of the states stored before the version was tracked. It must stay the same
+/// when `CURRENT_STATE_VERSION` is bumped, so the migrations can tell such states apart.
+pub const LEGACY_STATE_VERSION: u32 = 0;
+
 /// Engine internal state, mostly configuration.
 /// Should not contain anything large or enumerable.
 #[derive(Default, Clone, PartialEq, Eq, Debug)]
@@ -27,6 +35,8 @@ pub struct EngineState {
     pub is_paused: bool,
     /// Relayer key manager.
     pub key_manager: Option<AccountId>,
+    /// Schema version of the state, see `CURRENT_STATE_VERSION`.
+    pub state_version: u32,
 }
 
 impl EngineState {
@@ -57,6 +67,7 @@ pub enum BorshableEngineState<'a> {
     V1(BorshableEngineStateV1<'a>),
     V2(BorshableEngineStateV2<'a>),
     V3(BorshableEngineStateV3<'a>),
+    V4(BorshableEngineStateV4<'a>),
 }
 
 #[derive(BorshSerialize, BorshDeserialize, Default, Clone, PartialEq, Eq, Debug)]
@@ -86,14 +97,26 @@ pub struct BorshableEngineStateV3<'a> {
     pub key_manager: Option<Cow<'a, AccountId>>,
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Default, Clone, PartialEq, Eq, Debug)]
+#[borsh(crate = "aurora_engine_types::borsh")]
+pub struct BorshableEngineStateV4<'a> {
+    pub chain_id: [u8; 32],
+    pub owner_id: Cow<'a, AccountId>,
+    pub upgrade_delay_blocks: u64,
+    pub is_paused: bool,
+    pub key_manager: Option<Cow<'a, AccountId>>,
+    pub state_version: u32,
+}
+
 impl<'a> From<&'a EngineState> for BorshableEngineState<'a> {
     fn from(state: &'a EngineState) -> Self {
-        Self::V3(BorshableEngineStateV3 {
+        Self::V4(BorshableEngineStateV4 {
             chain_id: state.chain_id,
             owner_id: Cow::Borrowed(&state.owner_id),
             upgrade_delay_blocks: state.upgrade_delay_blocks,
             is_paused: state.is_paused,
             key_manager: state.key_manager.as_ref().map(Cow::Borrowed),
+            state_version: state.state_version,
         })
     }
 }
@@ -104,6 +127,7 @@ impl<'a> From<BorshableEngineState<'a>> for EngineState {
             BorshableEngineState::V1(state) => state.into(),
             BorshableEngineState::V2(state) => state.into(),
             BorshableEngineState::V3(state) => state.into(),
+            BorshableEngineState::V4(state) => state.into(),
         }
     }
 }
@@ -116,6 +140,7 @@ impl<'a> From<BorshableEngineStateV1<'a>> for EngineState {
             upgrade_delay_blocks: state.upgrade_delay_blocks,
             is_paused: false,
             key_manager: None,
+            state_version: LEGACY_STATE_VERSION,
         }
     }
 }
@@ -128,6 +153,7 @@ impl<'a> From<BorshableEngineStateV2<'a>> for EngineState {
             upgrade_delay_blocks: state.upgrade_delay_blocks,
             is_paused: false,
             key_manager: None,
+            state_version: LEGACY_STATE_VERSION,
         }
     }
 }
@@ -140,6 +166,20 @@ impl<'a> From<BorshableEngineStateV3<'a>> for EngineState {
             upgrade_delay_blocks: state.upgrade_delay_blocks,
             is_paused: state.is_paused,
             key_manager: state.key_manager.map(Cow::into_owned),
+            state_version: LEGACY_STATE_VERSION,
+        }
+    }
+}
+
+impl<'a> From<BorshableEngineStateV4<'a>> for EngineState {
+    fn from(state: BorshableEngineStateV4<'a>) -> Self {
+        Self {
+            chain_id: state.chain_id,
+            owner_id: state.owner_id.into_owned(),
+            upgrade_delay_blocks: state.upgrade_delay_blocks,
+            is_paused: state.is_paused,
+            key_manager: state.key_manager.map(Cow::into_owned),
+            state_version: state.state_version,
         }
     }
 }
@@ -152,6 +192,7 @@ impl From<LegacyNewCallArgs> for EngineState {
             upgrade_delay_blocks: args.upgrade_delay_blocks,
             is_paused: false,
             key_manager: None,
+            state_version: CURRENT_STATE_VERSION,
         }
     }
 }
@@ -164,6 +205,7 @@ impl From<NewCallArgsV2> for EngineState {
             upgrade_delay_blocks: args.upgrade_delay_blocks,
             is_paused: false,
             key_manager: None,
+            state_version: CURRENT_STATE_VERSION,
         }
     }
 }
@@ -176,6 +218,7 @@ impl From<NewCallArgsV3> for EngineState {
             upgrade_delay_blocks: args.upgrade_delay_blocks,
             is_paused: false,
             key_manager: Some(args.key_manager),
+            state_version: CURRENT_STATE_VERSION,
         }
     }
 }
@@ -188,6 +231,20 @@ impl From<NewCallArgsV4> for EngineState {
             upgrade_delay_blocks: args.upgrade_delay_blocks,
             is_paused: false,
             key_manager: Some(args.key_manager),
+            state_version: CURRENT_STATE_VERSION,
+        }
+    }
+}
+
+impl From<NewCallArgsV5> for EngineState {
+    fn from(args: NewCallArgsV5) -> Self {
+        Self {
+            chain_id: args.chain_id,
+            owner_id: args.owner_id,
+            upgrade_delay_blocks: args.upgrade_delay_blocks,
+            is_paused: false,
+            key_manager: Some(args.key_manager),
+            state_version: CURRENT_STATE_VERSION,
         }
     }
 }
@@ -199,6 +256,7 @@ impl From<NewCallArgs> for EngineState {
             NewCallArgs::V2(args) => args.into(),
             NewCallArgs::V3(args) => args.into(),
             NewCallArgs::V4(args) => args.into(),
+            NewCallArgs::V5(args) => args.into(),
         }
     }
 }
@@ -281,4 +339,43 @@ mod tests {
 
         assert_eq!(expected_error, actual_error);
     }
+
+    #[test]
+    fn test_state_version_round_trip() {
+        let storage = RefCell::new(Storage::default());
+        let mut io = StoragePointer(&storage);
+
+        let state = EngineState {
+            owner_id: "aurora".parse().unwrap(),
+            upgrade_delay_blocks: 5,
+            key_manager: Some("key_manager.near".parse().unwrap()),
+            state_version: 7,
+            ..EngineState::default()
+        };
+        set_state(&mut io, &state).unwrap();
+
+        assert_eq!(get_state(&io).unwrap(), state);
+    }
+
+    #[test]
+    fn test_legacy_state_has_legacy_state_version() {
+        let storage = RefCell::new(Storage::default());
+        let mut io = StoragePointer(&storage);
+
+        let owner_id: AccountId = "aurora".parse().unwrap();
+        let legacy = BorshableEngineState::V3(BorshableEngineStateV3 {
+            owner_id: Cow::Borrowed(&owner_id),
+            upgrade_delay_blocks: 5,
+            ..BorshableEngineStateV3::default()
+        });
+        io.write_storage(
+            &bytes_to_key(KeyPrefix::Config, STATE_KEY),
+            &borsh::to_vec(&legacy).unwrap(),
+        );
+
+        let state = get_state(&io).unwrap();
+        assert_eq!(state.owner_id, owner_id);
+        assert_eq!(state.upgrade_delay_blocks, 5);
+        assert_eq!(state.state_version, LEGACY_STATE_VERSION);
+    }
 }
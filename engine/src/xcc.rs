@@ -14,7 +14,9 @@ use aurora_engine_types::storage::{self, KeyPrefix};
 use aurora_engine_types::types::{Address, NearGas, Yocto, ZERO_YOCTO};
 use aurora_engine_types::{format, Cow, Vec, U256};
 
-pub use aurora_engine_types::parameters::xcc::{AddressVersionUpdateArgs, FundXccArgs};
+pub use aurora_engine_types::parameters::xcc::{
+    AddressVersionUpdateArgs, FundXccArgs, FundXccArgsV2, FundXccCallArgs,
+};
 
 pub const ERR_NO_ROUTER_CODE: &str = "ERR_MISSING_XCC_BYTECODE";
 pub const ERR_INVALID_ACCOUNT: &str = "ERR_INVALID_XCC_ACCOUNT";
@@ -36,8 +38,9 @@ pub const WITHDRAW_TO_NEAR_SELECTOR: [u8; 4] = [0x6b, 0x35, 0x18, 0x48];
 const FIRST_UPGRADABLE: &[u8] = b"first_upgrd";
 
 pub use aurora_engine_precompiles::xcc::state::{
-    get_code_version_of_address, get_latest_code_version, get_wnear_address, ERR_CORRUPTED_STORAGE,
-    STORAGE_AMOUNT, VERSION_KEY, WNEAR_KEY,
+    get_code_version_of_address, get_latest_code_version, get_near_gas_per_evm_gas,
+    get_wnear_address, ERR_CORRUPTED_STORAGE, NEAR_GAS_PER_EVM_GAS_KEY, STORAGE_AMOUNT,
+    VERSION_KEY, WNEAR_KEY,
 };
 pub use aurora_engine_types::parameters::xcc::CodeVersion;
 
@@ -69,7 +72,7 @@ pub fn fund_xcc_sub_account<I, P, E>(
     io: &I,
     handler: &mut P,
     env: &E,
-    args: FundXccArgs,
+    args: FundXccArgsV2,
 ) -> Result<(), FundXccError>
 where
     P: PromiseHandler,
@@ -110,7 +113,7 @@ where
         let init_args = format!(
             r#"{{"wnear_account": "{}", "must_register": {}}}"#,
             wnear_account.as_ref(),
-            create_needed,
+            create_needed && !args.skip_wnear_registration,
         );
         if create_needed {
             if fund_amount < STORAGE_AMOUNT {
@@ -379,6 +382,18 @@ pub fn set_wnear_address<I: IO>(io: &mut I, address: &Address) {
     io.write_storage(&key, address.as_bytes());
 }
 
+/// Set the amount of NEAR gas charged as one EVM gas by the cross-contract call precompile,
+/// `None` restores the default.
+pub fn set_near_gas_per_evm_gas<I: IO>(io: &mut I, near_gas_per_evm_gas: Option<u64>) {
+    let key = storage::bytes_to_key(KeyPrefix::CrossContractCall, NEAR_GAS_PER_EVM_GAS_KEY);
+
+    if let Some(value) = near_gas_per_evm_gas {
+        io.write_storage(&key, &value.to_le_bytes());
+    } else {
+        io.remove_storage(&key);
+    }
+}
+
 /// Set the version of the router contract deployed for the given address.
 pub fn set_code_version_of_address<I: IO>(io: &mut I, address: &Address, version: CodeVersion) {
     let key = storage::bytes_to_key(KeyPrefix::CrossContractCall, address.as_bytes());
@@ -569,7 +584,14 @@ impl<'a, H: PromiseHandler> PromiseHandler for PromiseInterceptor<'a, H> {
 
 #[cfg(test)]
 mod tests {
+    use super::{FundXccArgsV2, RouterCode, STORAGE_AMOUNT};
+    use aurora_engine_sdk::env::Fixed;
+    use aurora_engine_test_doubles::io::{Storage, StoragePointer};
+    use aurora_engine_test_doubles::promise::{PromiseArgs, PromiseTracker};
+    use aurora_engine_types::parameters::PromiseAction;
+    use aurora_engine_types::types::make_address;
     use aurora_engine_types::{account_id::AccountId, types::Yocto, U256};
+    use std::cell::RefCell;
 
     #[test]
     fn test_withdraw_to_near_encoding() {
@@ -607,4 +629,50 @@ mod tests {
             expected_tx_data
         );
     }
+
+    #[test]
+    fn test_fund_xcc_sub_account_skip_wnear_registration() {
+        fn initialize_args(skip_wnear_registration: bool) -> String {
+            let storage = RefCell::new(Storage::default());
+            let mut io = StoragePointer(&storage);
+            super::update_router_code(&mut io, &RouterCode::new(vec![0xab; 8]));
+            let env = Fixed {
+                current_account_id: "aurora".parse().unwrap(),
+                attached_deposit: STORAGE_AMOUNT.as_u128(),
+                ..Fixed::default()
+            };
+            let mut handler = PromiseTracker::default();
+            let args = FundXccArgsV2 {
+                target: make_address(1, 1),
+                wnear_account_id: Some("wrap.near".parse().unwrap()),
+                skip_wnear_registration,
+            };
+            super::fund_xcc_sub_account(&io, &mut handler, &env, args).unwrap();
+
+            let Some(PromiseArgs::Batch(batch)) = handler.scheduled_promises.remove(&0) else {
+                panic!("Expected a batch promise creating the router");
+            };
+            batch
+                .actions
+                .into_iter()
+                .find_map(|action| match action {
+                    PromiseAction::FunctionCall { name, args, .. } if name == "initialize" => {
+                        Some(String::from_utf8(args).unwrap())
+                    }
+                    _ => None,
+                })
+                .expect("Expected the router to be initialized")
+        }
+
+        // The router registers with wNEAR by default.
+        assert_eq!(
+            initialize_args(false),
+            r#"{"wnear_account": "wrap.near", "must_register": true}"#
+        );
+        // With the flag set the router does not create the registration promise.
+        assert_eq!(
+            initialize_args(true),
+            r#"{"wnear_account": "wrap.near", "must_register": false}"#
+        );
+    }
 }
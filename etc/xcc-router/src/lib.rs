@@ -4,7 +4,7 @@ use aurora_engine_types::parameters::{
 };
 use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
 use near_sdk::collections::LazyOption;
-use near_sdk::json_types::U64;
+use near_sdk::json_types::{Base64VecU8, U64};
 use near_sdk::store::LookupMap;
 use near_sdk::BorshStorageKey;
 use near_sdk::{
@@ -149,15 +149,53 @@ impl Router {
     /// It is intentional that this function can be called by anyone (not just the parent).
     /// There is no security risk to allowing this function to be open because it can only
     /// act on promises that were created via `schedule`.
+    /// The optional `callback` is a borsh-encoded `PromiseCreateArgs` which is chained to the
+    /// scheduled promise to receive its result. The callback is executed on behalf of this
+    /// account, therefore only the parent is allowed to attach it.
     #[payable]
-    pub fn execute_scheduled(&mut self, nonce: U64) {
+    pub fn execute_scheduled(&mut self, nonce: U64, callback: Option<Base64VecU8>) {
+        let callback = callback.map(|bytes| {
+            self.assert_preconditions();
+            PromiseCreateArgs::try_from_slice(&bytes.0)
+                .map_err(|_| Error::InvalidCallback)
+                .unwrap_or_else(env_panic)
+        });
         let Some(promise) = self.scheduled_promises.remove(&nonce.0) else {
             env::panic_str("ERR_PROMISE_NOT_FOUND")
         };
-        let promise_id = Self::promise_create(promise);
+        let mut promise_id = Self::promise_create(promise);
+        if let Some(callback) = callback {
+            promise_id = Self::then_promise_create(promise_id, &callback);
+        }
         env::promise_return(promise_id);
     }
 
+    /// Returns the scheduled promises which have not been executed yet, together with their
+    /// nonces. The result is paginated by nonce: only the nonces in the range
+    /// `[from_nonce, from_nonce + limit)` are inspected, so the next page starts at
+    /// `from_nonce + limit`. The result is borsh-encoded.
+    /// Note that while anyone can trigger `execute_scheduled` for these entries, only the parent
+    /// can meaningfully act on them (e.g. by attaching a callback to receive the result).
+    #[result_serializer(borsh)]
+    pub fn get_scheduled_promises(&self, from_nonce: U64, limit: U64) -> Vec<(u64, PromiseArgs)> {
+        let next_nonce = self.nonce.get().unwrap_or_default();
+        let end = from_nonce.0.saturating_add(limit.0).min(next_nonce);
+
+        (from_nonce.0..end)
+            .filter_map(|nonce| {
+                self.scheduled_promises
+                    .get(&nonce)
+                    .map(|promise| (nonce, promise.clone()))
+            })
+            .collect()
+    }
+
+    /// Returns the nonce which will be assigned to the next scheduled promise, i.e. the number
+    /// of promises scheduled so far (executed or not).
+    pub fn get_nonce(&self) -> U64 {
+        self.nonce.get().unwrap_or_default().into()
+    }
+
     /// Allows the parent contract to trigger an update to the logic of this contract
     /// (by deploying a new contract to this account);
     #[payable]
@@ -220,8 +258,10 @@ impl Router {
 
     fn cb_promise_create(promise: &PromiseWithCallbackArgs) -> PromiseIndex {
         let base = Self::base_promise_create(&promise.base);
-        let promise = &promise.callback;
+        Self::then_promise_create(base, &promise.callback)
+    }
 
+    fn then_promise_create(base: PromiseIndex, promise: &PromiseCreateArgs) -> PromiseIndex {
         env::promise_then(
             base,
             promise.target_account_id.as_ref().parse().unwrap(),
@@ -401,6 +441,7 @@ enum Error {
     ContractNotInitialized,
     IllegalCaller,
     CallbackOfFailedPromise,
+    InvalidCallback,
 }
 
 impl AsRef<str> for Error {
@@ -409,6 +450,7 @@ impl AsRef<str> for Error {
             Self::ContractNotInitialized => "ERR_CONTRACT_NOT_INITIALIZED",
             Self::IllegalCaller => ERR_ILLEGAL_CALLER,
             Self::CallbackOfFailedPromise => "ERR_CALLBACK_OF_FAILED_PROMISE",
+            Self::InvalidCallback => "ERR_INVALID_CALLBACK",
         }
     }
 }
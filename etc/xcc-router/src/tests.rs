@@ -170,7 +170,7 @@ fn test_schedule_and_execute() {
     testing_env!(VMContextBuilder::new()
         .predecessor_account_id(bob())
         .build());
-    contract.execute_scheduled(0.into());
+    contract.execute_scheduled(0.into(), None);
 
     assert_eq!(contract.nonce.get().unwrap(), 1);
     assert!(!contract.scheduled_promises.contains_key(&0));
@@ -185,6 +185,155 @@ fn test_schedule_and_execute() {
     validate_function_call_action(&receipt.actions, promise, 0);
 }
 
+#[test]
+fn test_schedule_and_execute_with_callback() {
+    let (parent, mut contract) = create_contract();
+
+    let promise = PromiseCreateArgs {
+        target_account_id: bob().as_str().parse().unwrap(),
+        method: "some_method".into(),
+        args: b"hello_world".to_vec(),
+        attached_balance: Yocto::new(56),
+        attached_gas: NearGas::new(100_000_000_000_000),
+    };
+    let callback = PromiseCreateArgs {
+        target_account_id: carol().as_str().parse().unwrap(),
+        method: "another_method".into(),
+        args: b"goodbye_world".to_vec(),
+        attached_balance: Yocto::new(0),
+        attached_gas: NearGas::new(10_000_000_000_000),
+    };
+
+    contract.schedule(PromiseArgs::Create(promise.clone()));
+
+    testing_env!(VMContextBuilder::new()
+        .predecessor_account_id(parent)
+        .build());
+    contract.execute_scheduled(
+        0.into(),
+        Some(near_sdk::borsh::to_vec(&callback).unwrap().into()),
+    );
+
+    assert!(!contract.scheduled_promises.contains_key(&0));
+
+    // the callback is chained to the scheduled promise
+    let receipts = test_utils::get_created_receipts();
+    assert_eq!(receipts.len(), 2);
+    let base = &receipts[0];
+    let chained = &receipts[1];
+    assert_eq!(
+        base.receiver_id.as_str(),
+        promise.target_account_id.as_ref()
+    );
+    assert_eq!(
+        chained.receiver_id.as_str(),
+        callback.target_account_id.as_ref()
+    );
+
+    validate_function_call_action(&base.actions, promise, 0);
+    validate_function_call_action(&chained.actions, callback, 2);
+}
+
+// Only the parent can attach a callback to a scheduled promise.
+#[test]
+#[should_panic]
+fn test_execute_scheduled_with_callback_wrong_caller() {
+    let (_parent, mut contract) = create_contract();
+
+    let promise = PromiseCreateArgs {
+        target_account_id: bob().as_str().parse().unwrap(),
+        method: "some_method".into(),
+        args: b"hello_world".to_vec(),
+        attached_balance: Yocto::new(56),
+        attached_gas: NearGas::new(100_000_000_000_000),
+    };
+    let callback = PromiseCreateArgs {
+        target_account_id: carol().as_str().parse().unwrap(),
+        method: "another_method".into(),
+        args: b"goodbye_world".to_vec(),
+        attached_balance: Yocto::new(0),
+        attached_gas: NearGas::new(10_000_000_000_000),
+    };
+
+    contract.schedule(PromiseArgs::Create(promise));
+
+    testing_env!(VMContextBuilder::new()
+        .predecessor_account_id(bob())
+        .build());
+    contract.execute_scheduled(
+        0.into(),
+        Some(near_sdk::borsh::to_vec(&callback).unwrap().into()),
+    );
+}
+
+#[test]
+fn test_get_scheduled_promises() {
+    let (_parent, mut contract) = create_contract();
+
+    let promises: Vec<_> = (0..5)
+        .map(|i| PromiseCreateArgs {
+            target_account_id: bob().as_str().parse().unwrap(),
+            method: format!("method_{i}"),
+            args: b"hello_world".to_vec(),
+            attached_balance: Yocto::new(56),
+            attached_gas: NearGas::new(100_000_000_000_000),
+        })
+        .collect();
+    for promise in &promises {
+        contract.schedule(PromiseArgs::Create(promise.clone()));
+    }
+
+    // executed promises are no longer listed
+    contract.execute_scheduled(1.into(), None);
+
+    let page = contract.get_scheduled_promises(0.into(), 3.into());
+    assert_eq!(
+        page,
+        vec![
+            (0, PromiseArgs::Create(promises[0].clone())),
+            (2, PromiseArgs::Create(promises[2].clone())),
+        ]
+    );
+
+    let page = contract.get_scheduled_promises(3.into(), 3.into());
+    assert_eq!(
+        page,
+        vec![
+            (3, PromiseArgs::Create(promises[3].clone())),
+            (4, PromiseArgs::Create(promises[4].clone())),
+        ]
+    );
+
+    assert!(contract
+        .get_scheduled_promises(5.into(), 3.into())
+        .is_empty());
+    assert!(contract
+        .get_scheduled_promises(0.into(), 0.into())
+        .is_empty());
+}
+
+#[test]
+fn test_get_nonce() {
+    let (_parent, mut contract) = create_contract();
+    assert_eq!(contract.get_nonce(), 0.into());
+
+    let promise = PromiseCreateArgs {
+        target_account_id: bob().as_str().parse().unwrap(),
+        method: "some_method".into(),
+        args: b"hello_world".to_vec(),
+        attached_balance: Yocto::new(56),
+        attached_gas: NearGas::new(100_000_000_000_000),
+    };
+    for _ in 0..3 {
+        contract.schedule(PromiseArgs::Create(promise.clone()));
+    }
+    assert_eq!(contract.get_nonce(), 3.into());
+
+    // executing a scheduled promise doesn't change the nonce
+    contract.execute_scheduled(1.into(), None);
+    assert_eq!(contract.get_nonce(), 3.into());
+}
+
 fn validate_function_call_action(
     actions: &[MockAction],
     promise: PromiseCreateArgs,